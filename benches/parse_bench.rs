@@ -204,6 +204,34 @@ BU_: ECM
     });
 }
 
+fn bench_decode_message_not_found(c: &mut Criterion) {
+    let mut dbc_content = String::from(
+        r#"VERSION "1.0"
+
+BU_: ECM
+
+"#,
+    );
+
+    // Create 100 messages; look up IDs that are not among them
+    for i in 0..100 {
+        dbc_content.push_str(&format!("BO_ {} Message{} : 8 ECM\n", 256 + i, i));
+        dbc_content.push_str(" SG_ Signal : 0|8@1+ (1,0) [0|255] \"\"\n");
+    }
+
+    let dbc = Dbc::parse(&dbc_content).unwrap();
+    let payload = [0x42; 8];
+    let missing_ids = [0x10, 0x200, 0x7FF];
+
+    c.bench_function("decode_message_not_found", |b| {
+        b.iter(|| {
+            for id in missing_ids {
+                let _ = dbc.decode(black_box(id), black_box(&payload), false);
+            }
+        })
+    });
+}
+
 fn bench_decode_high_throughput(c: &mut Criterion) {
     let mut dbc_content = String::from(
         r#"VERSION "1.0"
@@ -437,6 +465,7 @@ criterion_group!(
     bench_decode_message_lookup_first,
     bench_decode_message_lookup_middle,
     bench_decode_message_lookup_last,
+    bench_decode_message_not_found,
     bench_decode_high_throughput,
     bench_decode_big_endian,
     bench_decode_little_endian,
@@ -460,6 +489,7 @@ criterion_group!(
     bench_decode_message_lookup_first,
     bench_decode_message_lookup_middle,
     bench_decode_message_lookup_last,
+    bench_decode_message_not_found,
     bench_decode_high_throughput,
     bench_decode_big_endian,
     bench_decode_little_endian,
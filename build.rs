@@ -35,6 +35,7 @@ fn main() {
     let has_heapless = env::var("CARGO_FEATURE_HEAPLESS").is_ok();
     let has_std = env::var("CARGO_FEATURE_STD").is_ok();
     let has_attributes = env::var("CARGO_FEATURE_ATTRIBUTES").is_ok();
+    let has_long_names = env::var("CARGO_FEATURE_LONG_NAMES").is_ok();
 
     // std includes alloc, so we only need to check if neither alloc nor heapless is enabled
     // Note: This check provides a better error message, but mayheap will also enforce this
@@ -81,10 +82,11 @@ fn main() {
         .unwrap_or(64); // Default to 64
 
     // Allow override of MAX_NAME_SIZE via environment variable
+    // Default to 32 (per DBC specification), or 128 with `long-names` for AUTOSAR-derived files
     let max_name_size = env::var("DBC_MAX_NAME_SIZE")
         .ok()
         .and_then(|s| s.parse::<usize>().ok())
-        .unwrap_or(32); // Default to 32 (per DBC specification)
+        .unwrap_or(if has_long_names { 128 } else { 32 });
 
     // Allow override of MAX_EXTENDED_MULTIPLEXING via environment variable
     let max_extended_multiplexing = env::var("DBC_MAX_EXTENDED_MULTIPLEXING")
@@ -92,6 +94,30 @@ fn main() {
         .and_then(|s| s.parse::<usize>().ok())
         .unwrap_or(512); // Default to 512 (power of 2, per-file limit for extended multiplexing entries)
 
+    // Allow override of MAX_SIGNAL_TYPES via environment variable
+    let max_signal_types = env::var("DBC_MAX_SIGNAL_TYPES")
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(64); // Default to 64 (SGTYPE_ entries are rarely used)
+
+    // Allow override of MAX_SIGNAL_TYPE_REFS via environment variable
+    let max_signal_type_refs = env::var("DBC_MAX_SIGNAL_TYPE_REFS")
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(512); // Default to 512 (per-file limit for SIG_TYPE_REF_ entries)
+
+    // Allow override of MAX_SIGNAL_GROUPS via environment variable
+    let max_signal_groups = env::var("DBC_MAX_SIGNAL_GROUPS")
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(256); // Default to 256 (per-file limit for SIG_GROUP_ entries)
+
+    // Allow override of MAX_ENVIRONMENT_VARIABLES via environment variable
+    let max_environment_variables = env::var("DBC_MAX_ENVIRONMENT_VARIABLES")
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(64); // Default to 64 (EV_ entries are rarely used)
+
     // Attribute limits (only when attributes feature is enabled)
     let (max_attribute_definitions, max_attribute_values, max_attribute_enum_values) =
         if has_attributes {
@@ -136,6 +162,22 @@ fn main() {
                 max_extended_multiplexing,
                 "MAX_EXTENDED_MULTIPLEXING",
             ),
+            ("DBC_MAX_SIGNAL_TYPES", max_signal_types, "MAX_SIGNAL_TYPES"),
+            (
+                "DBC_MAX_SIGNAL_TYPE_REFS",
+                max_signal_type_refs,
+                "MAX_SIGNAL_TYPE_REFS",
+            ),
+            (
+                "DBC_MAX_SIGNAL_GROUPS",
+                max_signal_groups,
+                "MAX_SIGNAL_GROUPS",
+            ),
+            (
+                "DBC_MAX_ENVIRONMENT_VARIABLES",
+                max_environment_variables,
+                "MAX_ENVIRONMENT_VARIABLES",
+            ),
         ];
 
         // Add attribute constants only when feature is enabled
@@ -183,25 +225,47 @@ fn main() {
     let dest_path = std::path::Path::new(&out_dir).join("limits.rs");
 
     let mut limits_content = format!(
-        r#"#[allow(dead_code)]
+        r#"/// Maximum number of signals per message (`DBC_MAX_SIGNALS_PER_MESSAGE`).
+#[allow(dead_code)]
 pub const MAX_SIGNALS_PER_MESSAGE: usize = {};
+/// Maximum number of messages per database (`DBC_MAX_MESSAGES`).
 #[allow(dead_code)]
 pub const MAX_MESSAGES: usize = {};
+/// Maximum number of nodes in `BU_` (`DBC_MAX_NODES`).
 #[allow(dead_code)]
 pub const MAX_NODES: usize = {};
+/// Maximum number of value descriptions per signal (`DBC_MAX_VALUE_DESCRIPTIONS`).
 #[allow(dead_code)]
 pub const MAX_VALUE_DESCRIPTIONS: usize = {};
+/// Maximum length of a name in bytes (`DBC_MAX_NAME_SIZE`).
 #[allow(dead_code)]
 pub const MAX_NAME_SIZE: usize = {};
+/// Maximum number of `SG_MUL_VAL_` entries per database (`DBC_MAX_EXTENDED_MULTIPLEXING`).
 #[allow(dead_code)]
 pub const MAX_EXTENDED_MULTIPLEXING: usize = {};
+/// Maximum number of `SGTYPE_` entries per database (`DBC_MAX_SIGNAL_TYPES`).
+#[allow(dead_code)]
+pub const MAX_SIGNAL_TYPES: usize = {};
+/// Maximum number of `SIG_TYPE_REF_` entries per database (`DBC_MAX_SIGNAL_TYPE_REFS`).
+#[allow(dead_code)]
+pub const MAX_SIGNAL_TYPE_REFS: usize = {};
+/// Maximum number of `SIG_GROUP_` entries per database (`DBC_MAX_SIGNAL_GROUPS`).
+#[allow(dead_code)]
+pub const MAX_SIGNAL_GROUPS: usize = {};
+/// Maximum number of `EV_` entries per database (`DBC_MAX_ENVIRONMENT_VARIABLES`).
+#[allow(dead_code)]
+pub const MAX_ENVIRONMENT_VARIABLES: usize = {};
 "#,
         max_signals,
         max_messages,
         max_nodes,
         max_value_descriptions,
         max_name_size,
-        max_extended_multiplexing
+        max_extended_multiplexing,
+        max_signal_types,
+        max_signal_type_refs,
+        max_signal_groups,
+        max_environment_variables
     );
 
     // Add attribute constants only when feature is enabled
@@ -211,10 +275,13 @@ pub const MAX_EXTENDED_MULTIPLEXING: usize = {};
         max_attribute_enum_values,
     ) {
         limits_content.push_str(&format!(
-            r#"#[allow(dead_code)]
+            r#"/// Maximum number of `BA_DEF_` entries per database (`DBC_MAX_ATTRIBUTE_DEFINITIONS`).
+#[allow(dead_code)]
 pub const MAX_ATTRIBUTE_DEFINITIONS: usize = {};
+/// Maximum number of `BA_` entries per database (`DBC_MAX_ATTRIBUTE_VALUES`).
 #[allow(dead_code)]
 pub const MAX_ATTRIBUTE_VALUES: usize = {};
+/// Maximum number of values per enum attribute (`DBC_MAX_ATTRIBUTE_ENUM_VALUES`).
 #[allow(dead_code)]
 pub const MAX_ATTRIBUTE_ENUM_VALUES: usize = {};
 "#,
@@ -231,6 +298,10 @@ pub const MAX_ATTRIBUTE_ENUM_VALUES: usize = {};
     println!("cargo:rerun-if-env-changed=DBC_MAX_VALUE_DESCRIPTIONS");
     println!("cargo:rerun-if-env-changed=DBC_MAX_NAME_SIZE");
     println!("cargo:rerun-if-env-changed=DBC_MAX_EXTENDED_MULTIPLEXING");
+    println!("cargo:rerun-if-env-changed=DBC_MAX_SIGNAL_TYPES");
+    println!("cargo:rerun-if-env-changed=DBC_MAX_SIGNAL_TYPE_REFS");
+    println!("cargo:rerun-if-env-changed=DBC_MAX_SIGNAL_GROUPS");
+    println!("cargo:rerun-if-env-changed=DBC_MAX_ENVIRONMENT_VARIABLES");
     println!("cargo:rerun-if-env-changed=DBC_MAX_ATTRIBUTE_DEFINITIONS");
     println!("cargo:rerun-if-env-changed=DBC_MAX_ATTRIBUTE_VALUES");
     println!("cargo:rerun-if-env-changed=DBC_MAX_ATTRIBUTE_ENUM_VALUES");
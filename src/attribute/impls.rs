@@ -95,6 +95,16 @@ impl AttributeValueType {
         }
     }
 
+    /// Returns the label at `index` if this is an enum type.
+    ///
+    /// `BA_` assignments of enum attributes store the zero-based index of the label.
+    #[inline]
+    #[must_use]
+    pub fn enum_label(&self, index: i64) -> Option<&str> {
+        let index = usize::try_from(index).ok()?;
+        self.enum_values()?.get(index).map(|label| label.as_str())
+    }
+
     /// Returns the integer range if this is an INT or HEX type.
     #[inline]
     #[must_use]
@@ -153,6 +163,47 @@ impl AttributeValue {
         }
     }
 
+    /// Gets the value as a string slice, if applicable.
+    ///
+    /// Equivalent to [`as_string`](Self::as_string).
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        self.as_string()
+    }
+
+    /// Converts a numeric value to `f64`, coercing integers.
+    ///
+    /// Returns `None` for string values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///
+    /// BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+    /// BA_ "GenMsgCycleTime" BO_ 256 100;
+    /// "#)?;
+    /// let cycle_time = dbc.message_attribute(256, "GenMsgCycleTime").and_then(|v| v.to_f64());
+    /// assert_eq!(cycle_time, Some(100.0));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_f64(&self) -> Option<f64> {
+        match self {
+            Self::Int(v) => Some(*v as f64),
+            Self::Float(v) => Some(*v),
+            Self::String(_) => None,
+        }
+    }
+
     /// Returns true if this is an integer value.
     #[inline]
     #[must_use]
@@ -201,3 +252,54 @@ impl AttributeDefinition {
         &self.value_type
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+    use super::*;
+    use crate::attribute::AttributeString;
+
+    #[test]
+    fn test_attribute_value_int_accessors() {
+        let value = AttributeValue::Int(42);
+        assert_eq!(value.as_int(), Some(42));
+        assert_eq!(value.as_float(), None);
+        assert_eq!(value.as_str(), None);
+        assert_eq!(value.to_f64(), Some(42.0));
+    }
+
+    #[test]
+    fn test_attribute_value_float_accessors() {
+        let value = AttributeValue::Float(2.5);
+        assert_eq!(value.as_int(), None);
+        assert_eq!(value.as_float(), Some(2.5));
+        assert_eq!(value.as_str(), None);
+        assert_eq!(value.to_f64(), Some(2.5));
+    }
+
+    #[test]
+    fn test_attribute_value_string_accessors() {
+        let value = AttributeValue::String(AttributeString::try_from("Cyclic").unwrap());
+        assert_eq!(value.as_int(), None);
+        assert_eq!(value.as_float(), None);
+        assert_eq!(value.as_str(), Some("Cyclic"));
+        assert_eq!(value.as_string(), Some("Cyclic"));
+        assert_eq!(value.to_f64(), None);
+    }
+
+    #[test]
+    fn test_value_type_enum_label() {
+        let mut values = EnumValues::new();
+        for label in ["Cyclic", "Spontaneous"] {
+            values.push(label.try_into().unwrap()).unwrap();
+        }
+        let value_type = AttributeValueType::Enum { values };
+        assert_eq!(value_type.enum_label(0), Some("Cyclic"));
+        assert_eq!(value_type.enum_label(1), Some("Spontaneous"));
+        assert_eq!(value_type.enum_label(2), None);
+        assert_eq!(value_type.enum_label(-1), None);
+
+        let value_type = AttributeValueType::Int { min: 0, max: 10 };
+        assert_eq!(value_type.enum_label(0), None);
+    }
+}
@@ -54,6 +54,7 @@ pub type AttributeDefinitions = Vec<AttributeDefinition, { crate::MAX_ATTRIBUTE_
 /// - `BO_` = Message
 /// - `SG_` = Signal
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AttributeObjectType {
     /// Network/database level attribute (no object prefix in BA_DEF_)
     #[default]
@@ -70,6 +71,7 @@ pub enum AttributeObjectType {
 ///
 /// Defines the type and constraints for an attribute's values.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AttributeValueType {
     /// Integer type with min/max range: `INT min max`
     Int {
@@ -105,6 +107,7 @@ pub enum AttributeValueType {
 ///
 /// Represents the actual value assigned to an attribute.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AttributeValue {
     /// Integer value (for INT and HEX types)
     Int(i64),
@@ -127,6 +130,7 @@ pub enum AttributeValue {
 /// This defines a message attribute named "GenMsgCycleTime" with
 /// integer values in range 0-10000.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AttributeDefinition {
     /// Attribute name (quoted identifier in DBC)
     name: Name,
@@ -156,6 +160,7 @@ impl AttributeDefinition {
 ///
 /// Identifies which specific object an attribute value applies to.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AttributeTarget {
     /// Network/database level (global)
     Network,
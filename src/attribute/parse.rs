@@ -192,12 +192,12 @@ pub(crate) fn parse_attribute_assignment(
     } else if parser.starts_with(b"BO_") {
         parser.expect(b"BO_").ok()?;
         parser.skip_newlines_and_spaces();
-        let msg_id = parser.parse_u32().ok()?;
+        let msg_id = parser.parse_message_id().ok()?;
         AttributeTarget::Message(msg_id)
     } else if parser.starts_with(b"SG_") {
         parser.expect(b"SG_").ok()?;
         parser.skip_newlines_and_spaces();
-        let msg_id = parser.parse_u32().ok()?;
+        let msg_id = parser.parse_message_id().ok()?;
         parser.skip_newlines_and_spaces();
         let signal_name_bytes = parser.parse_identifier().ok()?;
         let signal_name = Name::try_from(signal_name_bytes).ok()?;
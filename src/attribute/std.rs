@@ -43,6 +43,8 @@ impl Display for AttributeValue {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
             Self::Int(v) => write!(f, "{}", v),
+            // Keep a decimal point so the value is read back as a float
+            Self::Float(v) if v.is_finite() && v.fract() == 0.0 => write!(f, "{:.1}", v),
             Self::Float(v) => write!(f, "{}", v),
             Self::String(s) => write!(f, "\"{}\"", s.as_str()),
         }
@@ -34,6 +34,7 @@ pub use builder::BitTimingBuilder;
 /// - The keyword `BS_:` is required but values are typically omitted
 /// - BTR values are only present if baudrate is specified
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BitTiming {
     baudrate: Option<u32>,
     btr1: Option<u32>,
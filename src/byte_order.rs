@@ -4,6 +4,7 @@
 /// - `0` = BigEndian (Motorola format)
 /// - `1` = LittleEndian (Intel format)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ByteOrder {
     /// Little-endian byte order (Intel format, `1` in DBC files).
     ///
@@ -16,9 +17,77 @@ pub enum ByteOrder {
 }
 
 impl ByteOrder {
+    /// Parse the Intel/Motorola name used by Vector tools, ignoring ASCII case.
+    ///
+    /// `"Intel"` is [`LittleEndian`](Self::LittleEndian) and `"Motorola"` is
+    /// [`BigEndian`](Self::BigEndian). Returns `None` for any other name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::ByteOrder;
+    ///
+    /// assert_eq!(ByteOrder::from_intel_motorola("Intel"), Some(ByteOrder::LittleEndian));
+    /// assert_eq!(ByteOrder::from_intel_motorola("motorola"), Some(ByteOrder::BigEndian));
+    /// assert_eq!(ByteOrder::from_intel_motorola("big"), None);
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn from_intel_motorola(s: &str) -> Option<Self> {
+        if s.eq_ignore_ascii_case("intel") {
+            Some(ByteOrder::LittleEndian)
+        } else if s.eq_ignore_ascii_case("motorola") {
+            Some(ByteOrder::BigEndian)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the Intel/Motorola name of this byte order: `"Intel"` or `"Motorola"`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::ByteOrder;
+    ///
+    /// assert_eq!(ByteOrder::LittleEndian.as_intel_motorola(), "Intel");
+    /// assert_eq!(ByteOrder::BigEndian.as_intel_motorola(), "Motorola");
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn as_intel_motorola(&self) -> &'static str {
+        match self {
+            ByteOrder::LittleEndian => "Intel",
+            ByteOrder::BigEndian => "Motorola",
+        }
+    }
+
+    /// Returns the digit written after `@` in a `SG_` definition: `'0'` for big-endian,
+    /// `'1'` for little-endian.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::ByteOrder;
+    ///
+    /// assert_eq!(ByteOrder::BigEndian.as_dbc_digit(), '0');
+    /// assert_eq!(ByteOrder::LittleEndian.as_dbc_digit(), '1');
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn as_dbc_digit(&self) -> char {
+        match self {
+            ByteOrder::BigEndian => '0',
+            ByteOrder::LittleEndian => '1',
+        }
+    }
+
     /// Extract bits from data based on byte order.
     /// Inlined for hot path optimization.
     ///
+    /// Bytes past the end of `data` read as zero, so a signal that extends beyond a
+    /// short payload yields a truncated value instead of panicking. Use
+    /// [`checked_extract_bits`](Self::checked_extract_bits) to detect truncation.
+    ///
     /// # Performance
     ///
     /// This method uses optimized fast paths for common cases:
@@ -26,45 +95,77 @@ impl ByteOrder {
     /// - Other cases use a generic loop-based extraction
     #[inline]
     pub(crate) fn extract_bits(self, data: &[u8], start_bit: usize, length: usize) -> u64 {
-        match self {
-            ByteOrder::LittleEndian => {
-                // Fast path: byte-aligned little-endian signals (most common case)
-                let bit_offset = start_bit % 8;
-                let byte_idx = start_bit / 8;
+        // Fast path: byte-aligned little-endian signals (most common case)
+        if self == ByteOrder::LittleEndian && start_bit % 8 == 0 {
+            let byte_idx = start_bit / 8;
+            // Direct memory reads only when the whole signal is inside `data`
+            match (length, data.get(byte_idx..)) {
+                (8, Some(&[b0, ..])) => return b0 as u64,
+                (16, Some(&[b0, b1, ..])) => return u16::from_le_bytes([b0, b1]) as u64,
+                (32, Some(&[b0, b1, b2, b3, ..])) => {
+                    return u32::from_le_bytes([b0, b1, b2, b3]) as u64;
+                }
+                (64, Some(&[b0, b1, b2, b3, b4, b5, b6, b7, ..])) => {
+                    return u64::from_le_bytes([b0, b1, b2, b3, b4, b5, b6, b7]);
+                }
+                _ => {} // Fall through to generic path
+            }
+        }
 
-                if bit_offset == 0 {
-                    // Byte-aligned - use direct memory reads
-                    match length {
-                        8 => return data[byte_idx] as u64,
-                        16 => {
-                            // SAFETY: bounds checked by caller (end_byte < data.len())
-                            return u16::from_le_bytes([data[byte_idx], data[byte_idx + 1]]) as u64;
-                        }
-                        32 => {
-                            return u32::from_le_bytes([
-                                data[byte_idx],
-                                data[byte_idx + 1],
-                                data[byte_idx + 2],
-                                data[byte_idx + 3],
-                            ]) as u64;
-                        }
-                        64 => {
-                            return u64::from_le_bytes([
-                                data[byte_idx],
-                                data[byte_idx + 1],
-                                data[byte_idx + 2],
-                                data[byte_idx + 3],
-                                data[byte_idx + 4],
-                                data[byte_idx + 5],
-                                data[byte_idx + 6],
-                                data[byte_idx + 7],
-                            ]);
-                        }
-                        _ => {} // Fall through to generic path
-                    }
+        self.extract_bits_with(start_bit, length, |byte_idx| {
+            data.get(byte_idx).copied().unwrap_or(0)
+        })
+    }
+
+    /// Extract `length` bits starting at `start_bit` from `data`, or `None` if the signal
+    /// extends beyond the end of `data` or `length` exceeds 64 bits.
+    ///
+    /// `start_bit` is the start bit as written in a `SG_` definition: the least
+    /// significant bit for little-endian and the most significant bit for big-endian
+    /// signals. The returned value is the raw, unsigned bit pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::ByteOrder;
+    ///
+    /// let data = [0x34, 0x12, 0xAB];
+    /// assert_eq!(ByteOrder::LittleEndian.checked_extract_bits(&data, 0, 16), Some(0x1234));
+    /// assert_eq!(ByteOrder::BigEndian.checked_extract_bits(&data, 7, 16), Some(0x3412));
+    /// // A 32-bit signal does not fit in 3 bytes
+    /// assert_eq!(ByteOrder::LittleEndian.checked_extract_bits(&data, 0, 32), None);
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn checked_extract_bits(self, data: &[u8], start_bit: usize, length: usize) -> Option<u64> {
+        if length > 64 {
+            return None;
+        }
+        let mut truncated = false;
+        let value =
+            self.extract_bits_with(start_bit, length, |byte_idx| match data.get(byte_idx) {
+                Some(&byte) => byte,
+                None => {
+                    truncated = true;
+                    0
                 }
+            });
+        (!truncated).then_some(value)
+    }
+
+    /// Generic bit extraction reading bytes through `read_byte`.
+    #[inline]
+    fn extract_bits_with(
+        self,
+        start_bit: usize,
+        length: usize,
+        mut read_byte: impl FnMut(usize) -> u8,
+    ) -> u64 {
+        // The value is assembled by shifting into a u64
+        debug_assert!(length <= 64, "bit length {length} exceeds 64 bits");
 
-                // Generic path: extract bits sequentially from start_bit forward
+        match self {
+            ByteOrder::LittleEndian => {
+                // Extract bits sequentially from start_bit forward
                 let mut value: u64 = 0;
                 let mut bits_remaining = length;
                 let mut current_bit = start_bit;
@@ -74,7 +175,7 @@ impl ByteOrder {
                     let bit_in_byte = current_bit % 8;
                     let bits_to_take = bits_remaining.min(8 - bit_in_byte);
 
-                    let byte = data[byte_idx] as u64;
+                    let byte = read_byte(byte_idx) as u64;
                     let mask = ((1u64 << bits_to_take) - 1) << bit_in_byte;
                     let extracted = (byte & mask) >> bit_in_byte;
 
@@ -114,7 +215,7 @@ impl ByteOrder {
                     // We want to extract 'bits_to_take' bits starting from bit_in_byte going down
                     // Physical positions: (7 - bit_in_byte) to (7 - bit_in_byte + bits_to_take - 1)
                     let physical_start = 7 - bit_in_byte;
-                    let byte = data[byte_num] as u64;
+                    let byte = read_byte(byte_num) as u64;
 
                     // Create mask for bits_to_take consecutive bits starting at physical_start
                     let mask = ((1u64 << bits_to_take) - 1) << physical_start;
@@ -292,6 +393,57 @@ mod tests {
         _assert_hash::<ByteOrder>();
     }
 
+    #[test]
+    fn test_byte_order_intel_motorola() {
+        for name in ["Intel", "intel", "INTEL"] {
+            assert_eq!(
+                ByteOrder::from_intel_motorola(name),
+                Some(ByteOrder::LittleEndian)
+            );
+        }
+        for name in ["Motorola", "motorola", "MOTOROLA"] {
+            assert_eq!(
+                ByteOrder::from_intel_motorola(name),
+                Some(ByteOrder::BigEndian)
+            );
+        }
+        for name in ["", "Intel ", "LittleEndian", "0"] {
+            assert_eq!(ByteOrder::from_intel_motorola(name), None);
+        }
+
+        for order in [ByteOrder::LittleEndian, ByteOrder::BigEndian] {
+            assert_eq!(
+                ByteOrder::from_intel_motorola(order.as_intel_motorola()),
+                Some(order)
+            );
+        }
+    }
+
+    #[test]
+    fn test_byte_order_dbc_digit() {
+        assert_eq!(ByteOrder::BigEndian.as_dbc_digit(), '0');
+        assert_eq!(ByteOrder::LittleEndian.as_dbc_digit(), '1');
+
+        // The digit matches the @0/@1 notation read by the signal parser
+        for (line, digit, order) in [
+            (
+                &b"SG_ S : 7|8@0+ (1,0) [0|255] \"\""[..],
+                '0',
+                ByteOrder::BigEndian,
+            ),
+            (
+                &b"SG_ S : 0|8@1+ (1,0) [0|255] \"\""[..],
+                '1',
+                ByteOrder::LittleEndian,
+            ),
+        ] {
+            let mut parser = crate::Parser::new(line).unwrap();
+            let signal = crate::Signal::parse(&mut parser).unwrap();
+            assert_eq!(signal.byte_order(), order);
+            assert_eq!(signal.byte_order().as_dbc_digit(), digit);
+        }
+    }
+
     #[test]
     fn test_extract_bits_little_endian() {
         // Test value 0x1234: little-endian bytes are [0x34, 0x12] (LSB first)
@@ -383,6 +535,63 @@ mod tests {
         assert_eq!(raw_value, 0x12F);
     }
 
+    #[test]
+    fn test_extract_bits_beyond_data_reads_zero() {
+        // Big-endian 24-bit signal at bit 16 runs past a 3-byte buffer
+        let data = [0xFF, 0xFF, 0xFF];
+        let raw_value = ByteOrder::BigEndian.extract_bits(&data, 16, 24);
+        assert!(raw_value < 1 << 24);
+        assert_eq!(
+            ByteOrder::BigEndian.checked_extract_bits(&data, 16, 24),
+            None
+        );
+
+        // Byte-aligned little-endian fast path falls back to the saturating loop
+        let data = [0x01, 0x02, 0x03];
+        assert_eq!(ByteOrder::LittleEndian.extract_bits(&data, 0, 32), 0x030201);
+        assert_eq!(ByteOrder::LittleEndian.extract_bits(&data, 0, 64), 0x030201);
+        assert_eq!(ByteOrder::LittleEndian.extract_bits(&data, 24, 8), 0);
+        assert_eq!(
+            ByteOrder::LittleEndian.checked_extract_bits(&data, 0, 32),
+            None
+        );
+        assert_eq!(
+            ByteOrder::LittleEndian.checked_extract_bits(&data, 20, 5),
+            None
+        );
+        assert_eq!(ByteOrder::LittleEndian.extract_bits(&[], 4, 12), 0);
+    }
+
+    #[test]
+    fn test_checked_extract_bits_in_bounds() {
+        let data = [0x34, 0x12, 0xAB, 0xCD];
+        for (start_bit, length) in [(0, 8), (0, 16), (0, 32), (4, 12), (20, 12)] {
+            assert_eq!(
+                ByteOrder::LittleEndian.checked_extract_bits(&data, start_bit, length),
+                Some(ByteOrder::LittleEndian.extract_bits(&data, start_bit, length))
+            );
+        }
+        for (start_bit, length) in [(7, 8), (7, 16), (15, 16), (7, 32)] {
+            assert_eq!(
+                ByteOrder::BigEndian.checked_extract_bits(&data, start_bit, length),
+                Some(ByteOrder::BigEndian.extract_bits(&data, start_bit, length))
+            );
+        }
+    }
+
+    #[test]
+    fn test_checked_extract_bits_rejects_long_signals() {
+        let data = [0xFF; 16];
+        assert_eq!(
+            ByteOrder::LittleEndian.checked_extract_bits(&data, 0, 65),
+            None
+        );
+        assert_eq!(
+            ByteOrder::BigEndian.checked_extract_bits(&data, 7, 65),
+            None
+        );
+    }
+
     // ============================================================================
     // insert_bits tests
     // ============================================================================
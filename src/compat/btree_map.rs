@@ -200,3 +200,28 @@ where
     V: Eq,
 {
 }
+
+#[cfg(feature = "alloc")]
+impl<K, V, const N: usize> IntoIterator for BTreeMap<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = alloc::collections::btree_map::IntoIter<K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl<K, V, const N: usize> IntoIterator for BTreeMap<K, V, N>
+where
+    K: Eq,
+{
+    type Item = (K, V);
+    type IntoIter = heapless::linear_map::IntoIter<K, V, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
@@ -16,6 +16,8 @@ compile_error!("Either the `alloc` or `heapless` feature must be enabled");
 extern crate alloc;
 
 mod btree_map;
+#[cfg(feature = "serde")]
+mod serde;
 mod string;
 mod vec;
 
@@ -59,7 +61,8 @@ pub type ValueDescEntries = Vec<ValueDescEntry, { MAX_VALUE_DESCRIPTIONS }>;
 /// # Errors
 ///
 /// Returns `Error::Expected` with `MAX_NAME_SIZE_EXCEEDED` message if the name
-/// exceeds `MAX_NAME_SIZE` (32 characters by default, per DBC specification).
+/// exceeds `MAX_NAME_SIZE` (32 characters by default, per DBC specification; 128 with the
+/// `long-names` feature).
 #[inline]
 pub fn validate_name<S: AsRef<str>>(name: S) -> Result<Name> {
     let name_str: &str = name.as_ref();
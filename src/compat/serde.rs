@@ -0,0 +1,112 @@
+//! `serde` support for the compat collections.
+//!
+//! `String` serializes as a plain string and `Vec` as a sequence. `BTreeMap` serializes as
+//! a sequence of `(key, value)` pairs, so that maps with non-string keys (tuples, enums)
+//! work with formats like JSON. Deserializing fails if the capacity `N` is exceeded.
+
+use super::{BTreeMap, String, Vec};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error, SeqAccess, Visitor},
+};
+
+impl<const N: usize> Serialize for String<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for String<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct StringVisitor<const N: usize>;
+
+        impl<const N: usize> Visitor<'_> for StringVisitor<N> {
+            type Value = String<N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a string of at most {N} bytes")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+                String::try_from(v).map_err(|_| E::invalid_length(v.len(), &self))
+            }
+        }
+
+        deserializer.deserialize_str(StringVisitor::<N>)
+    }
+}
+
+impl<T: Serialize, const N: usize> Serialize for Vec<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Vec<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VecVisitor<T, const N: usize>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for VecVisitor<T, N> {
+            type Value = Vec<T, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of at most {N} elements")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut vec = Vec::new();
+                while let Some(element) = seq.next_element()? {
+                    if vec.push(element).is_err() {
+                        return Err(A::Error::invalid_length(vec.len() + 1, &self));
+                    }
+                }
+                Ok(vec)
+            }
+        }
+
+        deserializer.deserialize_seq(VecVisitor::<T, N>(PhantomData))
+    }
+}
+
+impl<K: Serialize + Ord, V: Serialize, const N: usize> Serialize for BTreeMap<K, V, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, K, V, const N: usize> Deserialize<'de> for BTreeMap<K, V, N>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MapVisitor<K, V, const N: usize>(PhantomData<(K, V)>);
+
+        impl<'de, K, V, const N: usize> Visitor<'de> for MapVisitor<K, V, N>
+        where
+            K: Deserialize<'de> + Ord,
+            V: Deserialize<'de>,
+        {
+            type Value = BTreeMap<K, V, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of at most {N} key-value pairs")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut map = BTreeMap::new();
+                let mut len = 0;
+                while let Some((key, value)) = seq.next_element::<(K, V)>()? {
+                    len += 1;
+                    if map.insert(key, value).is_err() {
+                        return Err(A::Error::invalid_length(len, &self));
+                    }
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_seq(MapVisitor::<K, V, N>(PhantomData))
+    }
+}
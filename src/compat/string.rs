@@ -84,6 +84,28 @@ impl<const N: usize> String<N> {
             self.0.push_str(s).map_err(|_| Error::Validation(Error::MAX_NAME_SIZE_EXCEEDED))
         }
     }
+
+    /// Constructs a `String` from `s` without a length check when storage is growable.
+    ///
+    /// With `alloc`, the full string is kept regardless of `N`. With `heapless`, `s` is
+    /// truncated at the last character boundary that fits in `N` bytes.
+    pub fn from_str_truncating(s: &str) -> Self {
+        #[cfg(feature = "alloc")]
+        {
+            Self(alloc::string::String::from(s))
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            let mut end = s.len().min(N);
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+            let mut inner = Inner::new();
+            // Cannot fail: `end <= N`
+            let _ = inner.push_str(&s[..end]);
+            Self(inner)
+        }
+    }
 }
 
 impl<const N: usize> AsRef<str> for String<N> {
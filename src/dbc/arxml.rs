@@ -0,0 +1,281 @@
+//! Minimal AUTOSAR System Description (ARXML) export.
+
+use crate::{ByteOrder, Dbc, Message, Signal};
+use std::fmt::Write;
+
+/// Package paths referenced by the generated elements.
+const PKG_FRAMES: &str = "Frames";
+const PKG_PDUS: &str = "PDUs";
+const PKG_SIGNALS: &str = "Signals";
+const PKG_COMPU_METHODS: &str = "CompuMethods";
+const PKG_BASE_TYPES: &str = "BaseTypes";
+const PKG_CLUSTERS: &str = "Clusters";
+
+/// Short names of the shared base types.
+const BASE_TYPE_UNSIGNED: &str = "uint";
+const BASE_TYPE_SIGNED: &str = "sint";
+
+/// Indenting XML writer producing one element per line.
+struct XmlWriter {
+    out: String,
+    depth: usize,
+}
+
+impl XmlWriter {
+    fn new() -> Self {
+        let mut out = String::with_capacity(4096);
+        out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        Self { out, depth: 0 }
+    }
+
+    fn indent(&mut self) {
+        for _ in 0..self.depth {
+            self.out.push_str("  ");
+        }
+    }
+
+    fn open(&mut self, tag: &str) {
+        self.indent();
+        let _ = writeln!(self.out, "<{tag}>");
+        self.depth += 1;
+    }
+
+    fn close(&mut self, tag: &str) {
+        self.depth -= 1;
+        self.indent();
+        let _ = writeln!(self.out, "</{tag}>");
+    }
+
+    fn leaf(&mut self, tag: &str, value: impl core::fmt::Display) {
+        self.indent();
+        let _ = writeln!(self.out, "<{tag}>{value}</{tag}>");
+    }
+
+    fn reference(&mut self, tag: &str, dest: &str, package: &str, name: &str) {
+        self.indent();
+        let _ = writeln!(self.out, "<{tag} DEST=\"{dest}\">/{package}/{name}</{tag}>");
+    }
+
+    fn package(&mut self, name: &str, elements: impl FnOnce(&mut Self)) {
+        self.open("AR-PACKAGE");
+        self.leaf("SHORT-NAME", name);
+        self.open("ELEMENTS");
+        elements(self);
+        self.close("ELEMENTS");
+        self.close("AR-PACKAGE");
+    }
+}
+
+impl Dbc {
+    /// Export the messages and signals as a minimal AUTOSAR System Description (ARXML).
+    ///
+    /// The output covers what is needed to import frames and signals:
+    /// - each message becomes a `CAN-FRAME`, an `I-SIGNAL-I-PDU` mapped into it, and a
+    ///   `CAN-FRAME-TRIGGERING` carrying the CAN ID and addressing mode
+    /// - each signal becomes an `I-SIGNAL` with its length, mapped into the PDU with its
+    ///   start bit and byte order, and a `LINEAR` `COMPU-METHOD` for factor and offset
+    ///
+    /// Signal elements are named `<Message>_<Signal>` because AUTOSAR short names must be
+    /// unique within a package. Start positions are the DBC start bits, i.e. the most
+    /// significant bit for big-endian signals. Comments, value descriptions, attributes,
+    /// multiplexing and the `VECTOR__INDEPENDENT_SIG_MSG` pseudo-message are not exported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let arxml = dbc.to_arxml();
+    /// assert!(arxml.contains("<CAN-FRAME>"));
+    /// assert!(arxml.contains("<SHORT-NAME>Engine_RPM</SHORT-NAME>"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn to_arxml(&self) -> String {
+        let messages: Vec<&Message> = self
+            .messages()
+            .iter()
+            .filter(|m| m.id_with_flag() != Message::PSEUDO_MESSAGE_ID)
+            .collect();
+
+        let mut w = XmlWriter::new();
+        w.open("AUTOSAR xmlns=\"http://autosar.org/schema/r4.0\"");
+        w.open("AR-PACKAGES");
+
+        w.package(PKG_CLUSTERS, |w| {
+            w.open("CAN-CLUSTER");
+            w.leaf("SHORT-NAME", "CanCluster");
+            w.open("CAN-CLUSTER-VARIANTS");
+            w.open("CAN-CLUSTER-CONDITIONAL");
+            w.open("PHYSICAL-CHANNELS");
+            w.open("CAN-PHYSICAL-CHANNEL");
+            w.leaf("SHORT-NAME", "CanChannel");
+            w.open("FRAME-TRIGGERINGS");
+            for message in &messages {
+                write_frame_triggering(w, message);
+            }
+            w.close("FRAME-TRIGGERINGS");
+            w.close("CAN-PHYSICAL-CHANNEL");
+            w.close("PHYSICAL-CHANNELS");
+            w.close("CAN-CLUSTER-CONDITIONAL");
+            w.close("CAN-CLUSTER-VARIANTS");
+            w.close("CAN-CLUSTER");
+        });
+
+        w.package(PKG_FRAMES, |w| {
+            for message in &messages {
+                write_frame(w, message);
+            }
+        });
+
+        w.package(PKG_PDUS, |w| {
+            for message in &messages {
+                write_pdu(w, message);
+            }
+        });
+
+        w.package(PKG_SIGNALS, |w| {
+            for message in &messages {
+                for signal in message.signals().iter() {
+                    write_signal(w, message, signal);
+                }
+            }
+        });
+
+        w.package(PKG_COMPU_METHODS, |w| {
+            for message in &messages {
+                for signal in message.signals().iter() {
+                    write_compu_method(w, message, signal);
+                }
+            }
+        });
+
+        w.package(PKG_BASE_TYPES, |w| {
+            for (name, encoding) in [(BASE_TYPE_UNSIGNED, "NONE"), (BASE_TYPE_SIGNED, "2C")] {
+                w.open("SW-BASE-TYPE");
+                w.leaf("SHORT-NAME", name);
+                w.leaf("BASE-TYPE-ENCODING", encoding);
+                w.close("SW-BASE-TYPE");
+            }
+        });
+
+        w.close("AR-PACKAGES");
+        w.close("AUTOSAR");
+        w.out
+    }
+}
+
+fn packing_byte_order(byte_order: ByteOrder) -> &'static str {
+    match byte_order {
+        ByteOrder::LittleEndian => "MOST-SIGNIFICANT-BYTE-LAST",
+        ByteOrder::BigEndian => "MOST-SIGNIFICANT-BYTE-FIRST",
+    }
+}
+
+fn write_frame_triggering(w: &mut XmlWriter, message: &Message) {
+    w.open("CAN-FRAME-TRIGGERING");
+    w.leaf("SHORT-NAME", message.name());
+    w.reference("FRAME-REF", "CAN-FRAME", PKG_FRAMES, message.name());
+    let addressing_mode = if message.is_extended() {
+        "EXTENDED"
+    } else {
+        "STANDARD"
+    };
+    w.leaf("CAN-ADDRESSING-MODE", addressing_mode);
+    w.leaf("IDENTIFIER", message.id());
+    w.close("CAN-FRAME-TRIGGERING");
+}
+
+fn write_frame(w: &mut XmlWriter, message: &Message) {
+    w.open("CAN-FRAME");
+    w.leaf("SHORT-NAME", message.name());
+    w.leaf("FRAME-LENGTH", message.dlc());
+    w.open("PDU-TO-FRAME-MAPPINGS");
+    w.open("PDU-TO-FRAME-MAPPING");
+    w.leaf("SHORT-NAME", message.name());
+    w.leaf(
+        "PACKING-BYTE-ORDER",
+        packing_byte_order(ByteOrder::LittleEndian),
+    );
+    w.reference("PDU-REF", "I-SIGNAL-I-PDU", PKG_PDUS, message.name());
+    w.leaf("START-POSITION", 0);
+    w.close("PDU-TO-FRAME-MAPPING");
+    w.close("PDU-TO-FRAME-MAPPINGS");
+    w.close("CAN-FRAME");
+}
+
+fn write_pdu(w: &mut XmlWriter, message: &Message) {
+    w.open("I-SIGNAL-I-PDU");
+    w.leaf("SHORT-NAME", message.name());
+    w.leaf("LENGTH", message.dlc());
+    w.open("I-SIGNAL-TO-PDU-MAPPINGS");
+    for signal in message.signals().iter() {
+        let name = format!("{}_{}", message.name(), signal.name());
+        w.open("I-SIGNAL-TO-I-PDU-MAPPING");
+        w.leaf("SHORT-NAME", &name);
+        w.reference("I-SIGNAL-REF", "I-SIGNAL", PKG_SIGNALS, &name);
+        w.leaf(
+            "PACKING-BYTE-ORDER",
+            packing_byte_order(signal.byte_order()),
+        );
+        w.leaf("START-POSITION", signal.start_bit());
+        w.close("I-SIGNAL-TO-I-PDU-MAPPING");
+    }
+    w.close("I-SIGNAL-TO-PDU-MAPPINGS");
+    w.close("I-SIGNAL-I-PDU");
+}
+
+fn write_signal(w: &mut XmlWriter, message: &Message, signal: &Signal) {
+    let name = format!("{}_{}", message.name(), signal.name());
+    let base_type = if signal.is_unsigned() {
+        BASE_TYPE_UNSIGNED
+    } else {
+        BASE_TYPE_SIGNED
+    };
+    w.open("I-SIGNAL");
+    w.leaf("SHORT-NAME", &name);
+    w.leaf("LENGTH", signal.length());
+    w.open("NETWORK-REPRESENTATION-PROPS");
+    w.open("SW-DATA-DEF-PROPS-VARIANTS");
+    w.open("SW-DATA-DEF-PROPS-CONDITIONAL");
+    w.reference("BASE-TYPE-REF", "SW-BASE-TYPE", PKG_BASE_TYPES, base_type);
+    w.reference("COMPU-METHOD-REF", "COMPU-METHOD", PKG_COMPU_METHODS, &name);
+    w.close("SW-DATA-DEF-PROPS-CONDITIONAL");
+    w.close("SW-DATA-DEF-PROPS-VARIANTS");
+    w.close("NETWORK-REPRESENTATION-PROPS");
+    w.close("I-SIGNAL");
+}
+
+fn write_compu_method(w: &mut XmlWriter, message: &Message, signal: &Signal) {
+    w.open("COMPU-METHOD");
+    w.leaf(
+        "SHORT-NAME",
+        format_args!("{}_{}", message.name(), signal.name()),
+    );
+    w.leaf("CATEGORY", "LINEAR");
+    w.open("COMPU-INTERNAL-TO-PHYS");
+    w.open("COMPU-SCALES");
+    w.open("COMPU-SCALE");
+    w.open("COMPU-RATIONAL-COEFFS");
+    // physical = (offset + factor * raw) / 1
+    w.open("COMPU-NUMERATOR");
+    w.leaf("V", signal.offset());
+    w.leaf("V", signal.factor());
+    w.close("COMPU-NUMERATOR");
+    w.open("COMPU-DENOMINATOR");
+    w.leaf("V", 1);
+    w.close("COMPU-DENOMINATOR");
+    w.close("COMPU-RATIONAL-COEFFS");
+    w.close("COMPU-SCALE");
+    w.close("COMPU-SCALES");
+    w.close("COMPU-INTERNAL-TO-PHYS");
+    w.close("COMPU-METHOD");
+}
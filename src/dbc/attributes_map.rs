@@ -25,6 +25,11 @@ type ValuesMap = BTreeMap<(Name, AttributeTarget), AttributeValue, MAX_ATTRIBUTE
 /// Attribute definitions specify the name, object type, and value constraints
 /// for attributes that can be assigned to DBC objects.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct AttributeDefinitionsMap {
     definitions: AttrDefsVec,
 }
@@ -91,6 +96,11 @@ impl AttributeDefinitionsMap {
 /// Default values are used when an attribute is not explicitly assigned
 /// to an object.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct AttributeDefaultsMap {
     defaults: DefaultsMap,
 }
@@ -142,6 +152,11 @@ impl AttributeDefaultsMap {
 ///
 /// Attribute values are the actual values assigned to specific DBC objects.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct AttributeValuesMap {
     values: ValuesMap,
 }
@@ -160,6 +175,97 @@ impl AttributeValuesMap {
         Self { values }
     }
 
+    /// Copy the values assigned to message `src_id` and its signals to `new_id`.
+    #[cfg(feature = "std")]
+    pub(crate) fn copy_message(&mut self, src_id: u32, new_id: u32) -> crate::Result<()> {
+        let copies: std::vec::Vec<_> = self
+            .values
+            .iter()
+            .filter_map(|((name, target), value)| {
+                let target = match target {
+                    AttributeTarget::Message(id) if *id == src_id => {
+                        AttributeTarget::Message(new_id)
+                    }
+                    AttributeTarget::Signal(id, signal) if *id == src_id => {
+                        AttributeTarget::Signal(new_id, signal.clone())
+                    }
+                    _ => return None,
+                };
+                Some(((name.clone(), target), value.clone()))
+            })
+            .collect();
+        for (key, value) in copies {
+            self.values.insert(key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Move the values assigned to signal `old` of message `message_id` to `new`.
+    #[cfg(feature = "std")]
+    pub(crate) fn rename_signal(
+        &mut self,
+        message_id: u32,
+        old: &str,
+        new: &Name,
+    ) -> crate::Result<()> {
+        let keys: std::vec::Vec<_> = self
+            .values
+            .iter()
+            .filter(|((_, target), _)| {
+                matches!(target, AttributeTarget::Signal(id, signal)
+                    if *id == message_id && signal.as_str() == old)
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in keys {
+            if let Some(value) = self.values.remove(&key) {
+                let target = AttributeTarget::Signal(message_id, new.clone());
+                self.values.insert((key.0, target), value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the values assigned to message `message_id` and its signals.
+    #[cfg(feature = "std")]
+    pub(crate) fn remove_message(&mut self, message_id: u32) {
+        self.remove_where(|target| match target {
+            AttributeTarget::Message(id) | AttributeTarget::Signal(id, _) => *id == message_id,
+            _ => false,
+        });
+    }
+
+    /// Remove the values assigned to signal `name` of message `message_id`.
+    #[cfg(feature = "std")]
+    pub(crate) fn remove_signal(&mut self, message_id: u32, name: &str) {
+        self.remove_where(|target| {
+            matches!(target, AttributeTarget::Signal(id, signal)
+                if *id == message_id && signal.as_str() == name)
+        });
+    }
+
+    /// Remove the values whose target matches `remove`.
+    #[cfg(feature = "std")]
+    fn remove_where(&mut self, remove: impl Fn(&AttributeTarget) -> bool) {
+        let keys: std::vec::Vec<_> = self
+            .values
+            .iter()
+            .filter(|((_, target), _)| remove(target))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in keys {
+            self.values.remove(&key);
+        }
+    }
+
+    /// Remove the values assigned to node `node_name`.
+    #[cfg(feature = "std")]
+    pub(crate) fn remove_node(&mut self, node_name: &str) {
+        self.remove_where(
+            |target| matches!(target, AttributeTarget::Node(name) if name.as_str() == node_name),
+        );
+    }
+
     /// Get an iterator over all attribute values.
     #[inline]
     #[must_use = "iterator is lazy and does nothing unless consumed"]
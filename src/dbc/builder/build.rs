@@ -3,7 +3,7 @@ use super::DbcBuilder;
 use crate::dbc::{AttributeDefaultsMap, AttributeDefinitionsMap, AttributeValuesMap};
 use crate::{
     BitTiming, Dbc, ExtendedMultiplexing, MAX_EXTENDED_MULTIPLEXING, MAX_MESSAGES, MAX_NAME_SIZE,
-    Message, Nodes, Result, Version,
+    Message, Nodes, Result, SignalGroup, Version,
     compat::{BTreeMap, String, Vec as CompatVec},
     dbc::{Messages, Validate},
 };
@@ -11,9 +11,15 @@ use crate::{
 impl DbcBuilder {
     /// Validates the builder without constructing the `Dbc`.
     ///
-    /// This method performs all validation checks. Note that this consumes
-    /// the builder. If you want to keep the builder after validation, call
-    /// `build()` instead and check the result.
+    /// This runs the same checks as [`build`](Self::build): all messages, value
+    /// descriptions and extended multiplexing entries are built, then duplicate message
+    /// IDs, senders missing from the nodes list and cross-references are checked.
+    /// The builder is left untouched, so it can be validated again after further edits.
+    ///
+    /// # Note
+    ///
+    /// This method clones and builds all fields internally for validation.
+    /// If you only need the final `Dbc`, call `build()` directly.
     ///
     /// # Examples
     ///
@@ -24,58 +30,35 @@ impl DbcBuilder {
     /// if builder.validate().is_err() {
     ///     // Handle validation error
     /// }
+    /// let dbc = builder.build()?;
+    /// # Ok::<(), dbc_rs::Error>(())
     /// ```
     #[must_use = "validation result should be checked"]
-    pub fn validate(self) -> Result<()> {
-        // Build and validate (extract_fields builds everything)
-        // We need to call extract_fields from the impl<'a> block
-        // Since validate doesn't need the lifetime, we can just build and drop
-        let (_version, nodes, messages, value_descriptions, extended_multiplexing) = {
-            let version = self.version.build()?;
-            let nodes = self.nodes.build()?;
-            let messages: std::vec::Vec<Message> = self
-                .messages
-                .into_iter()
-                .map(|builder| builder.build())
-                .collect::<Result<std::vec::Vec<_>>>()?;
-            let mut value_descriptions_map: BTreeMap<
-                (Option<u32>, String<{ MAX_NAME_SIZE }>),
-                crate::value_descriptions::ValueDescriptions,
-                { MAX_MESSAGES },
-            > = BTreeMap::new();
-            for ((message_id, signal_name), vd_builder) in self.value_descriptions {
-                let vd: crate::value_descriptions::ValueDescriptions = vd_builder.build()?;
-                let compat_signal_name = String::try_from(signal_name.as_str())
-                    .map_err(|_| crate::Error::Validation(crate::Error::MAX_NAME_SIZE_EXCEEDED))?;
-                let _ = value_descriptions_map.insert((message_id, compat_signal_name), vd);
-            }
-            let value_descriptions = crate::dbc::ValueDescriptionsMap::new(value_descriptions_map);
-
-            // Build extended multiplexing entries
-            let extended_multiplexing_vec: std::vec::Vec<ExtendedMultiplexing> = self
-                .extended_multiplexing
-                .into_iter()
-                .map(|builder| builder.build())
-                .collect::<Result<std::vec::Vec<_>>>()?;
-
-            (
-                version,
-                nodes,
-                messages,
-                value_descriptions,
-                extended_multiplexing_vec,
-            )
-        };
-
-        // Validate messages and extended multiplexing
-        Validate::validate(
+    pub fn validate(&self) -> Result<()> {
+        let (_, _, nodes, messages, value_descriptions, extended_multiplexing, _, _, _) =
+            self.clone().extract_fields()?;
+        Self::validate_fields(
             &nodes,
             &messages,
-            Some(&value_descriptions),
-            Some(&extended_multiplexing),
-        )?;
+            &value_descriptions,
+            &extended_multiplexing,
+        )
+    }
 
-        Ok(())
+    /// Runs the database-level validation shared by [`validate`](Self::validate) and
+    /// [`build`](Self::build).
+    fn validate_fields(
+        nodes: &Nodes,
+        messages: &Messages,
+        value_descriptions: &crate::dbc::ValueDescriptionsMap,
+        extended_multiplexing: &CompatVec<ExtendedMultiplexing, { MAX_EXTENDED_MULTIPLEXING }>,
+    ) -> Result<()> {
+        Validate::validate(
+            nodes,
+            messages.as_slice(),
+            Some(value_descriptions),
+            Some(extended_multiplexing.as_slice()),
+        )
     }
 
     #[allow(clippy::type_complexity)]
@@ -88,6 +71,8 @@ impl DbcBuilder {
         Messages,
         crate::dbc::ValueDescriptionsMap,
         CompatVec<ExtendedMultiplexing, { MAX_EXTENDED_MULTIPLEXING }>,
+        crate::dbc::SignalGroups,
+        crate::dbc::EnvironmentVariables,
         Option<std::string::String>,
     )> {
         // Build version
@@ -146,6 +131,24 @@ impl DbcBuilder {
                 .map_err(|_| crate::Error::expected("too many extended multiplexing entries"))?;
         }
 
+        // Build signal groups
+        let mut signal_groups = crate::dbc::SignalGroups::new();
+        for builder in self.signal_groups {
+            let group: SignalGroup = builder.build()?;
+            signal_groups
+                .push(group)
+                .map_err(|_| crate::Error::expected("too many signal groups"))?;
+        }
+
+        // Build environment variables
+        let mut environment_variables = crate::dbc::EnvironmentVariables::new();
+        for builder in self.environment_variables {
+            environment_variables.push(builder.build()?).map_err(|_| {
+                crate::Error::Validation(crate::Error::ENVIRONMENT_VARIABLES_TOO_MANY)
+            })?;
+        }
+        Validate::validate_environment_variables(&nodes, environment_variables.as_slice())?;
+
         Ok((
             version,
             bit_timing,
@@ -153,6 +156,8 @@ impl DbcBuilder {
             messages,
             value_descriptions,
             extended_multiplexing,
+            signal_groups,
+            environment_variables,
             self.comment,
         ))
     }
@@ -181,18 +186,16 @@ impl DbcBuilder {
             messages,
             value_descriptions,
             extended_multiplexing,
+            signal_groups,
+            environment_variables,
             comment,
         ) = self.extract_fields()?;
         // Validate before construction
-        // Get slice from Messages for validation
-        let messages_slice: std::vec::Vec<Message> = messages.iter().cloned().collect();
-        let extended_multiplexing_slice: std::vec::Vec<ExtendedMultiplexing> =
-            extended_multiplexing.iter().cloned().collect();
-        Validate::validate(
+        Self::validate_fields(
             &nodes,
-            &messages_slice,
-            Some(&value_descriptions),
-            Some(&extended_multiplexing_slice),
+            &messages,
+            &value_descriptions,
+            &extended_multiplexing,
         )?;
         #[cfg(feature = "attributes")]
         return Ok(Dbc::new(
@@ -203,6 +206,10 @@ impl DbcBuilder {
             value_descriptions,
             extended_multiplexing,
             comment.map(|c| c.into()),
+            CompatVec::new(),
+            CompatVec::new(),
+            signal_groups,
+            environment_variables,
             AttributeDefinitionsMap::default(),
             AttributeDefaultsMap::default(),
             AttributeValuesMap::default(),
@@ -216,6 +223,10 @@ impl DbcBuilder {
             value_descriptions,
             extended_multiplexing,
             comment.map(|c| c.into()),
+            CompatVec::new(),
+            CompatVec::new(),
+            signal_groups,
+            environment_variables,
         ))
     }
 }
@@ -225,8 +236,9 @@ mod tests {
     #![allow(clippy::float_cmp)]
     use super::DbcBuilder;
     use crate::{
-        ByteOrder, ExtendedMultiplexingBuilder, MessageBuilder, NodesBuilder, ReceiversBuilder,
-        SignalBuilder, ValueDescriptionsBuilder, VersionBuilder,
+        ByteOrder, EnvironmentVariableAccess, EnvironmentVariableBuilder, EnvironmentVariableType,
+        Error, ExtendedMultiplexingBuilder, MessageBuilder, NodesBuilder, ReceiversBuilder,
+        SignalBuilder, SignalGroupBuilder, ValueDescriptionsBuilder, VersionBuilder,
     };
 
     #[test]
@@ -262,6 +274,45 @@ mod tests {
         assert_eq!(dbc.messages().at(0).unwrap().id(), 256);
     }
 
+    #[test]
+    fn test_dbc_builder_message_by_name() {
+        let message = |id, name| MessageBuilder::new().id(id).name(name).dlc(8).sender("ECM");
+
+        let dbc = DbcBuilder::new()
+            .version(VersionBuilder::new().version("1.0"))
+            .nodes(NodesBuilder::new().add_nodes(["ECM"]))
+            .add_messages([message(512, "Brake"), message(256, "Engine")])
+            .build()
+            .unwrap();
+
+        assert_eq!(dbc.message_by_name("Engine").map(|m| m.id()), Some(256));
+        assert_eq!(dbc.message_by_name("Brake").map(|m| m.id()), Some(512));
+        assert!(dbc.message_by_name("Body").is_none());
+    }
+
+    #[test]
+    fn test_dbc_builder_comments_round_trip() {
+        let dbc = DbcBuilder::new()
+            .version(VersionBuilder::new().version("1.0"))
+            .nodes(
+                NodesBuilder::new()
+                    .add_node_with_comment("ECM", "Engine Control Module")
+                    .add_node("TCM"),
+            )
+            .comment("Powertrain CAN")
+            .build()
+            .unwrap();
+
+        let serialized = dbc.to_dbc_string();
+        assert!(serialized.contains("CM_ \"Powertrain CAN\";"));
+        assert!(serialized.contains("CM_ BU_ ECM \"Engine Control Module\";"));
+
+        let reparsed = crate::Dbc::parse(&serialized).unwrap();
+        assert_eq!(reparsed.comment(), Some("Powertrain CAN"));
+        assert_eq!(reparsed.node_comment("ECM"), Some("Engine Control Module"));
+        assert_eq!(reparsed.node_comment("TCM"), None);
+    }
+
     #[test]
     fn test_dbc_builder_missing_version() {
         let nodes = NodesBuilder::new().add_nodes(["ECM"]);
@@ -359,11 +410,45 @@ mod tests {
             .sender("ECM")
             .add_signal(signal);
 
-        // validate() consumes the builder, so we can't use it after
-        // But we can check it doesn't error
         let builder = DbcBuilder::new().version(version).nodes(nodes).add_message(message);
         let result = builder.validate();
         assert!(result.is_ok());
+        // The builder is still usable after validation
+        assert_eq!(builder.build().unwrap().messages().len(), 1);
+    }
+
+    #[test]
+    fn test_dbc_builder_validate_duplicate_message_id() {
+        let message = |name: &str| {
+            MessageBuilder::new().id(256).name(name).dlc(8).sender("ECM").add_signal(
+                SignalBuilder::new()
+                    .name("RPM")
+                    .start_bit(0)
+                    .length(16)
+                    .byte_order(ByteOrder::LittleEndian)
+                    .unsigned(true)
+                    .factor(1.0)
+                    .offset(0.0)
+                    .min(0.0)
+                    .max(100.0)
+                    .receivers(ReceiversBuilder::new().none()),
+            )
+        };
+
+        let builder = DbcBuilder::new()
+            .nodes(NodesBuilder::new().add_nodes(["ECM"]))
+            .add_message(message("EngineData"));
+        assert!(builder.validate().is_ok());
+
+        let builder = builder.add_message(message("EngineData2"));
+        assert_eq!(
+            builder.validate(),
+            Err(Error::Validation(Error::DUPLICATE_MESSAGE_ID))
+        );
+        assert_eq!(
+            builder.build().unwrap_err(),
+            Error::Validation(Error::DUPLICATE_MESSAGE_ID)
+        );
     }
 
     // ========================================================================
@@ -894,6 +979,108 @@ mod tests {
         assert_eq!(dbc.extended_multiplexing()[1].signal_name(), "SignalB");
     }
 
+    #[test]
+    fn test_dbc_builder_with_signal_groups() {
+        let dbc = DbcBuilder::new()
+            .version(VersionBuilder::new().version("1.0"))
+            .nodes(NodesBuilder::new().add_node("ECM"))
+            .add_message(
+                MessageBuilder::new().id(256).name("Engine").dlc(8).sender("ECM").add_signal(
+                    SignalBuilder::new()
+                        .name("RPM")
+                        .start_bit(0)
+                        .length(16)
+                        .byte_order(ByteOrder::LittleEndian)
+                        .unsigned(true)
+                        .factor(1.0)
+                        .offset(0.0)
+                        .min(0.0)
+                        .max(8000.0)
+                        .receivers(ReceiversBuilder::new().none()),
+                ),
+            )
+            .add_signal_group(
+                SignalGroupBuilder::new().message_id(256).name("EngineGroup").add_signal("RPM"),
+            )
+            .build()
+            .unwrap();
+
+        let group = dbc.signal_group(256, "EngineGroup").unwrap();
+        assert_eq!(group.repetitions(), 1);
+        assert!(group.contains("RPM"));
+
+        let rebuilt = DbcBuilder::from_dbc(&dbc).build().unwrap();
+        assert_eq!(rebuilt.signal_groups(), dbc.signal_groups());
+        assert!(
+            DbcBuilder::from_dbc(&dbc)
+                .clear_signal_groups()
+                .build()
+                .unwrap()
+                .signal_groups()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_dbc_builder_with_environment_variables() {
+        let dbc = DbcBuilder::new()
+            .version(VersionBuilder::new().version("1.0"))
+            .nodes(NodesBuilder::new().add_node("ECM"))
+            .add_environment_variable(
+                EnvironmentVariableBuilder::new()
+                    .name("EnvKL15")
+                    .max(1.0)
+                    .ev_id(1)
+                    .access_type(EnvironmentVariableAccess::ReadWrite)
+                    .add_access_node("ECM"),
+            )
+            .add_environment_variable(
+                EnvironmentVariableBuilder::new()
+                    .name("EnvBlob")
+                    .var_type(EnvironmentVariableType::Data)
+                    .data_size(8),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(dbc.environment_variables().count(), 2);
+        assert_eq!(
+            dbc.environment_variable("EnvBlob").unwrap().data_size(),
+            Some(8)
+        );
+
+        let rebuilt = DbcBuilder::from_dbc(&dbc).build().unwrap();
+        assert!(rebuilt.environment_variables().eq(dbc.environment_variables()));
+        let reparsed = crate::Dbc::parse(&dbc.to_dbc_string()).unwrap();
+        assert!(reparsed.environment_variables().eq(dbc.environment_variables()));
+    }
+
+    #[test]
+    fn test_dbc_builder_environment_variable_validation() {
+        let env_var = |name: &str, node: &str| {
+            EnvironmentVariableBuilder::new().name(name).add_access_node(node)
+        };
+        let builder = DbcBuilder::new()
+            .version(VersionBuilder::new().version("1.0"))
+            .nodes(NodesBuilder::new().add_node("ECM"));
+
+        let result = builder
+            .clone()
+            .add_environment_variable(env_var("EnvKL15", "ECM"))
+            .add_environment_variable(env_var("EnvKL15", "ECM"))
+            .build();
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Validation(Error::ENVIRONMENT_VARIABLE_DUPLICATE_NAME)
+        );
+
+        let result = builder.add_environment_variable(env_var("EnvKL15", "TCM")).build();
+        assert_eq!(
+            result.unwrap_err(),
+            Error::Validation(Error::ENVIRONMENT_VARIABLE_NODE_NOT_IN_NODES)
+        );
+    }
+
     #[test]
     fn test_dbc_builder_clear_extended_multiplexing() {
         let version = VersionBuilder::new().version("1.0");
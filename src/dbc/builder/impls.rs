@@ -1,7 +1,8 @@
 use super::DbcBuilder;
 use crate::{
-    BitTimingBuilder, Dbc, ExtendedMultiplexingBuilder, MessageBuilder, NodesBuilder, Receivers,
-    ReceiversBuilder, SignalBuilder, ValueDescriptionsBuilder, VersionBuilder,
+    BitTimingBuilder, Dbc, EnvironmentVariableBuilder, ExtendedMultiplexingBuilder, MessageBuilder,
+    NodesBuilder, Receivers, ReceiversBuilder, SignalBuilder, SignalGroupBuilder,
+    ValueDescriptionsBuilder, VersionBuilder,
 };
 use std::collections::BTreeMap;
 
@@ -32,6 +33,8 @@ impl DbcBuilder {
             messages: Vec::new(),
             value_descriptions: BTreeMap::new(),
             extended_multiplexing: Vec::new(),
+            signal_groups: Vec::new(),
+            environment_variables: Vec::new(),
             comment: None,
         }
     }
@@ -310,6 +313,106 @@ impl DbcBuilder {
         self
     }
 
+    /// Adds a signal group (SIG_GROUP_) to the DBC file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{DbcBuilder, SignalGroupBuilder};
+    ///
+    /// let group = SignalGroupBuilder::new()
+    ///     .message_id(256)
+    ///     .name("EngineGroup")
+    ///     .add_signal("RPM");
+    ///
+    /// let builder = DbcBuilder::new()
+    ///     .add_signal_group(group);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "builder method returns modified builder"]
+    pub fn add_signal_group(mut self, group: SignalGroupBuilder) -> Self {
+        self.signal_groups.push(group);
+        self
+    }
+
+    /// Adds multiple signal groups to the DBC file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{DbcBuilder, SignalGroupBuilder};
+    ///
+    /// let engine = SignalGroupBuilder::new().message_id(256).name("Engine").add_signal("RPM");
+    /// let thermal = SignalGroupBuilder::new().message_id(256).name("Thermal").add_signal("Temp");
+    ///
+    /// let builder = DbcBuilder::new()
+    ///     .add_signal_groups(vec![engine, thermal]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "builder method returns modified builder"]
+    pub fn add_signal_groups(
+        mut self,
+        groups: impl IntoIterator<Item = SignalGroupBuilder>,
+    ) -> Self {
+        self.signal_groups.extend(groups);
+        self
+    }
+
+    /// Clears all signal groups from the builder.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::DbcBuilder;
+    ///
+    /// let builder = DbcBuilder::new()
+    ///     .clear_signal_groups();
+    /// ```
+    #[must_use = "builder method returns modified builder"]
+    pub fn clear_signal_groups(mut self) -> Self {
+        self.signal_groups.clear();
+        self
+    }
+
+    /// Adds an environment variable (EV_) to the DBC file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{DbcBuilder, EnvironmentVariableBuilder};
+    ///
+    /// let env_var = EnvironmentVariableBuilder::new()
+    ///     .name("EnvKL15")
+    ///     .max(1.0)
+    ///     .add_access_node("ECM");
+    ///
+    /// let builder = DbcBuilder::new()
+    ///     .add_environment_variable(env_var);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "builder method returns modified builder"]
+    pub fn add_environment_variable(mut self, env_var: EnvironmentVariableBuilder) -> Self {
+        self.environment_variables.push(env_var);
+        self
+    }
+
+    /// Adds multiple environment variables to the DBC file.
+    #[must_use = "builder method returns modified builder"]
+    pub fn add_environment_variables(
+        mut self,
+        env_vars: impl IntoIterator<Item = EnvironmentVariableBuilder>,
+    ) -> Self {
+        self.environment_variables.extend(env_vars);
+        self
+    }
+
+    /// Clears all environment variables from the builder.
+    #[must_use = "builder method returns modified builder"]
+    pub fn clear_environment_variables(mut self) -> Self {
+        self.environment_variables.clear();
+        self
+    }
+
     /// Sets the database-level comment (general CM_ entry).
     ///
     /// # Examples
@@ -392,6 +495,9 @@ impl DbcBuilder {
                     .name(msg.name())
                     .dlc(msg.dlc())
                     .sender(msg.sender());
+                if msg.effective_dlc() != msg.dlc() {
+                    msg_builder = msg_builder.effective_dlc(msg.effective_dlc());
+                }
 
                 // Convert signals using SignalBuilder
                 for sig in msg.signals().iter() {
@@ -465,6 +571,44 @@ impl DbcBuilder {
             })
             .collect();
 
+        // Convert signal groups to builders
+        let signal_groups: Vec<SignalGroupBuilder> = dbc
+            .signal_groups()
+            .iter()
+            .map(|group| {
+                SignalGroupBuilder::new()
+                    .message_id(group.message_id())
+                    .name(group.name())
+                    .repetitions(group.repetitions())
+                    .add_signals(group.signal_names())
+            })
+            .collect();
+
+        // Convert environment variables to builders
+        let environment_variables: Vec<EnvironmentVariableBuilder> = dbc
+            .environment_variables()
+            .map(|env_var| {
+                let mut builder = EnvironmentVariableBuilder::new()
+                    .name(env_var.name())
+                    .var_type(env_var.var_type())
+                    .min(env_var.min())
+                    .max(env_var.max())
+                    .initial_value(env_var.initial_value())
+                    .ev_id(env_var.ev_id())
+                    .access_type(env_var.access_type());
+                if let Some(unit) = env_var.unit() {
+                    builder = builder.unit(unit);
+                }
+                for node in env_var.access_nodes() {
+                    builder = builder.add_access_node(node);
+                }
+                if let Some(data_size) = env_var.data_size() {
+                    builder = builder.data_size(data_size);
+                }
+                builder
+            })
+            .collect();
+
         // Copy comment if present
         let comment = dbc.comment().map(|c| c.to_string());
 
@@ -475,11 +619,44 @@ impl DbcBuilder {
             messages,
             value_descriptions,
             extended_multiplexing,
+            signal_groups,
+            environment_variables,
             comment,
         }
     }
 }
 
+impl Extend<MessageBuilder> for DbcBuilder {
+    /// Appends messages, like [`add_messages`](DbcBuilder::add_messages) on a `&mut` builder.
+    fn extend<I: IntoIterator<Item = MessageBuilder>>(&mut self, messages: I) {
+        self.messages.extend(messages);
+    }
+}
+
+impl FromIterator<MessageBuilder> for DbcBuilder {
+    /// Collects messages into a new builder.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dbc_rs::{DbcBuilder, MessageBuilder, VersionBuilder};
+    ///
+    /// let dbc = [(256, "Engine"), (512, "Brake"), (768, "Gearbox")]
+    ///     .into_iter()
+    ///     .map(|(id, name)| MessageBuilder::new().id(id).name(name).dlc(8).sender("ECM"))
+    ///     .collect::<DbcBuilder>()
+    ///     .version(VersionBuilder::new().version("1.0"))
+    ///     .build()?;
+    /// assert_eq!(dbc.messages().len(), 3);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    fn from_iter<I: IntoIterator<Item = MessageBuilder>>(messages: I) -> Self {
+        let mut builder = Self::new();
+        builder.extend(messages);
+        builder
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::float_cmp)]
@@ -658,6 +835,33 @@ BO_ 256 Engine : 8 ECM
         assert_eq!(engine_msg.signals().at(0).unwrap().name(), "RPM");
     }
 
+    #[cfg(feature = "attributes")]
+    #[test]
+    fn test_dbc_builder_from_dbc_keeps_effective_dlc() {
+        let dbc_content = r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Reserved : 48|16@1+ (1,0) [0|65535] "" *
+
+BA_DEF_ BO_ "GenMsgLength" INT 0 64;
+BA_ "GenMsgLength" BO_ 256 2;
+"#;
+        let original_dbc = Dbc::parse(dbc_content).unwrap();
+        let rebuilt = DbcBuilder::from_dbc(&original_dbc).build().unwrap();
+
+        let engine = rebuilt.messages().find_by_id(256).unwrap();
+        assert_eq!(engine.dlc(), 8);
+        assert_eq!(engine.effective_dlc(), 2);
+        assert_eq!(rebuilt.decode(256, &[0x40, 0x1F], false).unwrap().len(), 1);
+
+        let too_large =
+            MessageBuilder::new().id(1).name("M").dlc(8).sender("ECM").effective_dlc(65);
+        assert!(too_large.build().is_err());
+    }
+
     #[test]
     fn test_dbc_builder_from_dbc_empty() {
         // Parse a minimal DBC
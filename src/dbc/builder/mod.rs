@@ -1,6 +1,6 @@
 use crate::{
-    BitTimingBuilder, ExtendedMultiplexingBuilder, MessageBuilder, NodesBuilder,
-    ValueDescriptionsBuilder, VersionBuilder,
+    BitTimingBuilder, EnvironmentVariableBuilder, ExtendedMultiplexingBuilder, MessageBuilder,
+    NodesBuilder, SignalGroupBuilder, ValueDescriptionsBuilder, VersionBuilder,
 };
 use std::collections::BTreeMap;
 
@@ -45,6 +45,8 @@ pub struct DbcBuilder {
     messages: Vec<MessageBuilder>,
     value_descriptions: BTreeMap<(Option<u32>, String), ValueDescriptionsBuilder>,
     extended_multiplexing: Vec<ExtendedMultiplexingBuilder>,
+    signal_groups: Vec<SignalGroupBuilder>,
+    environment_variables: Vec<EnvironmentVariableBuilder>,
     comment: Option<String>,
 }
 
@@ -0,0 +1,238 @@
+use crate::{Dbc, Error, Message, Result};
+
+/// Algorithm used to compute an 8-bit frame checksum.
+///
+/// See [`Dbc::verify_checksum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChecksumAlgorithm {
+    /// XOR of all covered bytes.
+    Xor8,
+    /// Sum of all covered bytes, modulo 256.
+    Sum8,
+    /// CRC-8/SAE-J1850 (polynomial `0x1D`, initial value `0xFF`, final XOR `0xFF`),
+    /// as used by AUTOSAR `Crc_CalculateCRC8`.
+    Crc8,
+}
+
+impl ChecksumAlgorithm {
+    /// Compute the checksum of `bytes`.
+    pub(crate) fn compute(self, bytes: impl Iterator<Item = u8>) -> u8 {
+        match self {
+            Self::Xor8 => bytes.fold(0, |acc, b| acc ^ b),
+            Self::Sum8 => bytes.fold(0, |acc, b| acc.wrapping_add(b)),
+            Self::Crc8 => {
+                let crc = bytes.fold(0xFF, |mut crc: u8, b| {
+                    crc ^= b;
+                    for _ in 0..8 {
+                        crc = if crc & 0x80 != 0 {
+                            (crc << 1) ^ 0x1D
+                        } else {
+                            crc << 1
+                        };
+                    }
+                    crc
+                });
+                crc ^ 0xFF
+            }
+        }
+    }
+}
+
+impl Dbc {
+    /// Verify the checksum carried by a received CAN frame.
+    ///
+    /// The checksum is recomputed with `algorithm` over every byte of the frame up to the
+    /// [`effective_dlc`](Message::effective_dlc), except the bytes holding
+    /// `checksum_signal`, and compared against the raw value of `checksum_signal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The raw CAN message ID (without extended flag)
+    /// * `payload` - The CAN message payload bytes
+    /// * `is_extended` - Whether this is an extended (29-bit) CAN ID
+    /// * `checksum_signal` - Name of the signal carrying the checksum
+    /// * `algorithm` - Checksum algorithm used by the transmitter
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - The checksum matches
+    /// * `Ok(false)` - The frame is corrupted
+    /// * `Err(Error)` - If the message or signal is not found, or the payload is shorter
+    ///   than the message
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{ChecksumAlgorithm, Dbc};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 4 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///  SG_ Counter : 16|8@1+ (1,0) [0|255] "" *
+    ///  SG_ Checksum : 24|8@1+ (1,0) [0|255] "" *
+    /// "#)?;
+    ///
+    /// // 0x40 ^ 0x1F ^ 0x01 = 0x5E
+    /// let payload = [0x40, 0x1F, 0x01, 0x5E];
+    /// assert!(dbc.verify_checksum(256, &payload, false, "Checksum", ChecksumAlgorithm::Xor8)?);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn verify_checksum(
+        &self,
+        id: u32,
+        payload: &[u8],
+        is_extended: bool,
+        checksum_signal: &str,
+        algorithm: ChecksumAlgorithm,
+    ) -> Result<bool> {
+        let id = if is_extended {
+            id | Message::EXTENDED_ID_FLAG
+        } else {
+            id
+        };
+
+        let message = self
+            .messages()
+            .find_by_id(id)
+            .ok_or(Error::Decoding(Error::MESSAGE_NOT_FOUND))?;
+        let signal = message
+            .signals()
+            .find(checksum_signal)
+            .ok_or(Error::Decoding(Error::SIGNAL_NOT_FOUND))?;
+
+        let len = message.effective_dlc() as usize;
+        if payload.len() < len {
+            return Err(Error::Decoding(Error::PAYLOAD_LENGTH_MISMATCH));
+        }
+        let expected = signal
+            .decode_raw(payload)
+            .ok_or(Error::Decoding(Error::PAYLOAD_LENGTH_MISMATCH))?;
+
+        let (lsb, msb) =
+            Message::bit_range(signal.start_bit(), signal.length(), signal.byte_order());
+        let checksum_bytes = (lsb.min(msb) / 8) as usize..=(lsb.max(msb) / 8) as usize;
+        let covered = payload[..len]
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !checksum_bytes.contains(idx))
+            .map(|(_, &b)| b);
+
+        Ok(expected == i64::from(algorithm.compute(covered)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChecksumAlgorithm;
+    use crate::{Dbc, Error};
+
+    const DBC: &str = r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 4 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Counter : 16|8@1+ (1,0) [0|255] "" *
+ SG_ Checksum : 24|8@1+ (1,0) [0|255] "" *
+
+BO_ 2147484672 Brake : 3 ECM
+ SG_ Checksum : 7|8@0+ (1,0) [0|255] "" *
+ SG_ Pressure : 15|16@0+ (0.1,0) [0|6553.5] "bar" *
+"#;
+
+    #[test]
+    fn test_checksum_algorithms() {
+        let check = b"123456789";
+        assert_eq!(ChecksumAlgorithm::Xor8.compute(check.iter().copied()), 0x31);
+        assert_eq!(ChecksumAlgorithm::Sum8.compute(check.iter().copied()), 0xDD);
+        // CRC-8/SAE-J1850 check value
+        assert_eq!(ChecksumAlgorithm::Crc8.compute(check.iter().copied()), 0x4B);
+    }
+
+    #[test]
+    fn test_verify_checksum_good_and_corrupted() {
+        let dbc = Dbc::parse(DBC).unwrap();
+        let verify = |payload: &[u8], algorithm| {
+            dbc.verify_checksum(256, payload, false, "Checksum", algorithm)
+        };
+
+        // 0x40 ^ 0x1F ^ 0x01 = 0x5E
+        assert_eq!(
+            verify(&[0x40, 0x1F, 0x01, 0x5E], ChecksumAlgorithm::Xor8),
+            Ok(true)
+        );
+        assert_eq!(
+            verify(&[0x40, 0x1E, 0x01, 0x5E], ChecksumAlgorithm::Xor8),
+            Ok(false)
+        );
+
+        // 0x40 + 0x1F + 0x01 = 0x60
+        assert_eq!(
+            verify(&[0x40, 0x1F, 0x01, 0x60], ChecksumAlgorithm::Sum8),
+            Ok(true)
+        );
+        assert_eq!(
+            verify(&[0x40, 0x1F, 0x02, 0x60], ChecksumAlgorithm::Sum8),
+            Ok(false)
+        );
+
+        let crc = ChecksumAlgorithm::Crc8.compute([0x40, 0x1F, 0x01].into_iter());
+        assert_eq!(
+            verify(&[0x40, 0x1F, 0x01, crc], ChecksumAlgorithm::Crc8),
+            Ok(true)
+        );
+        assert_eq!(
+            verify(&[0x41, 0x1F, 0x01, crc], ChecksumAlgorithm::Crc8),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn test_verify_checksum_big_endian_leading_byte() {
+        let dbc = Dbc::parse(DBC).unwrap();
+
+        // Checksum in byte 0 covers bytes 1-2: 0x12 ^ 0x34 = 0x26
+        assert_eq!(
+            dbc.verify_checksum(
+                0x400,
+                &[0x26, 0x12, 0x34],
+                true,
+                "Checksum",
+                ChecksumAlgorithm::Xor8
+            ),
+            Ok(true)
+        );
+        assert_eq!(
+            dbc.verify_checksum(
+                0x400,
+                &[0x26, 0x12, 0x35],
+                true,
+                "Checksum",
+                ChecksumAlgorithm::Xor8
+            ),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn test_verify_checksum_errors() {
+        let dbc = Dbc::parse(DBC).unwrap();
+        let algorithm = ChecksumAlgorithm::Xor8;
+
+        assert_eq!(
+            dbc.verify_checksum(512, &[0; 4], false, "Checksum", algorithm),
+            Err(Error::Decoding(Error::MESSAGE_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.verify_checksum(256, &[0; 4], false, "Crc", algorithm),
+            Err(Error::Decoding(Error::SIGNAL_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.verify_checksum(256, &[0; 3], false, "Checksum", algorithm),
+            Err(Error::Decoding(Error::PAYLOAD_LENGTH_MISMATCH))
+        );
+    }
+}
@@ -1,11 +1,16 @@
-use crate::{Dbc, Error, MAX_SIGNALS_PER_MESSAGE, Message, Result, compat::Vec};
+use crate::{
+    Dbc, Error, MAX_SIGNALS_PER_MESSAGE, Message, Result, Signal,
+    compat::{Name, Vec},
+};
 #[cfg(feature = "embedded-can")]
 use embedded_can::{Frame, Id};
 
 /// A decoded signal from a CAN message.
 ///
 /// Contains the signal name, its decoded physical value, unit, and optional value description.
-#[derive(Debug, Clone, PartialEq)]
+/// The raw value is extracted once and kept alongside the physical value, so a single
+/// [`Dbc::decode`] call provides both (see [`DecodedSignal::raw`]).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct DecodedSignal<'a> {
     /// The name of the signal as defined in the DBC file.
     pub name: &'a str,
@@ -71,6 +76,23 @@ impl<'a> DecodedSignal<'a> {
         }
     }
 
+    /// Returns the raw integer value the physical [`value`](Self::value) was computed from.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::DecodedSignal;
+    ///
+    /// let signal = DecodedSignal::new("Temp", 50.0, 90, -40.0, 215.0, Some("C"), None);
+    /// assert_eq!(signal.raw(), 90);
+    /// assert_eq!(signal.value, 50.0);
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn raw(&self) -> i64 {
+        self.raw_value
+    }
+
     /// Returns `true` if the decoded value is within the valid range [min, max].
     ///
     /// # Examples
@@ -90,6 +112,27 @@ impl<'a> DecodedSignal<'a> {
     }
 }
 
+/// Per-frame context carried through [`Dbc::decode_with_meta`] unchanged.
+///
+/// The crate does not interpret these fields; their units and numbering are up to the caller
+/// (e.g., microseconds since capture start and a logger channel index).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FrameMeta {
+    /// The frame timestamp.
+    pub timestamp: u64,
+    /// The bus channel the frame was received on.
+    pub channel: u8,
+}
+
+/// The decoded signals of one CAN frame together with its [`FrameMeta`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedFrame<'a> {
+    /// The decoded signals, as returned by [`Dbc::decode`].
+    pub signals: Vec<DecodedSignal<'a>, { MAX_SIGNALS_PER_MESSAGE }>,
+    /// The metadata passed to [`Dbc::decode_with_meta`].
+    pub meta: FrameMeta,
+}
+
 /// Maximum number of multiplexer switches in a single message.
 /// Most CAN messages have 0-2 switches; 8 is generous.
 const MAX_SWITCHES: usize = 8;
@@ -206,6 +249,77 @@ impl Dbc {
         payload: &[u8],
         is_extended: bool,
     ) -> Result<Vec<DecodedSignal<'_>, { MAX_SIGNALS_PER_MESSAGE }>> {
+        let mut decoded_signals: Vec<DecodedSignal<'_>, { MAX_SIGNALS_PER_MESSAGE }> = Vec::new();
+        self.decode_each(id, payload, is_extended, |signal| {
+            decoded_signals
+                .push(signal)
+                .map_err(|_| Error::Decoding(Error::MESSAGE_TOO_MANY_SIGNALS))
+        })?;
+        Ok(decoded_signals)
+    }
+
+    /// Decode a CAN message payload into a caller-provided buffer.
+    ///
+    /// Behaves like [`decode`](Self::decode), but writes the decoded signals to the start of
+    /// `out` and returns how many were written, so the output needs no collection of
+    /// [`MAX_SIGNALS_PER_MESSAGE`] capacity. Entries after the returned count are left
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`decode`](Self::decode), and
+    /// [`Error::Decoding`] with [`Error::DECODE_BUFFER_TOO_SMALL`] if `out` cannot hold all
+    /// decoded signals. In that case the contents of `out` are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, DecodedSignal};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///  SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" *
+    /// "#)?;
+    ///
+    /// let mut out = [DecodedSignal::default(); 4];
+    /// let payload = [0x40, 0x1F, 0x5A, 0x00, 0x00, 0x00, 0x00, 0x00];
+    /// let count = dbc.decode_into(256, &payload, false, &mut out)?;
+    /// assert_eq!(count, 2);
+    /// assert_eq!(out[0].value, 2000.0);
+    /// assert_eq!(out[1].name, "Temp");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    pub fn decode_into<'a>(
+        &'a self,
+        id: u32,
+        payload: &[u8],
+        is_extended: bool,
+        out: &mut [DecodedSignal<'a>],
+    ) -> Result<usize> {
+        let mut count = 0;
+        self.decode_each(id, payload, is_extended, |signal| {
+            let slot = out.get_mut(count).ok_or(Error::Decoding(Error::DECODE_BUFFER_TOO_SMALL))?;
+            *slot = signal;
+            count += 1;
+            Ok(())
+        })?;
+        Ok(count)
+    }
+
+    /// Decodes the active signals of a message, switches first, passing each to `emit`.
+    #[inline]
+    fn decode_each<'a>(
+        &'a self,
+        id: u32,
+        payload: &[u8],
+        is_extended: bool,
+        emit: impl FnMut(DecodedSignal<'a>) -> Result<()>,
+    ) -> Result<()> {
         // If it's an extended ID, add the extended ID flag
         let id = if is_extended {
             id | Message::EXTENDED_ID_FLAG
@@ -219,17 +333,28 @@ impl Dbc {
             .find_by_id(id)
             .ok_or(Error::Decoding(Error::MESSAGE_NOT_FOUND))?;
 
+        self.decode_message_each(message, payload, emit)
+    }
+
+    /// Decodes the active signals of `message`, switches first, passing each to `emit`.
+    #[inline]
+    pub(crate) fn decode_message_each<'a>(
+        &'a self,
+        message: &'a Message,
+        payload: &[u8],
+        mut emit: impl FnMut(DecodedSignal<'a>) -> Result<()>,
+    ) -> Result<()> {
+        let id = message.id_with_flag();
+
         // Validate payload has enough bytes to decode all signals
         // We check against min_bytes_required (actual signal coverage) rather than DLC
         // because DLC may be larger than needed (e.g., DLC=8 but signals only use 3 bytes)
+        // Signals beyond the effective DLC (GenMsgLength override) are not required
         let min_bytes = message.min_bytes_required() as usize;
         if payload.len() < min_bytes {
             return Err(Error::Decoding(Error::PAYLOAD_LENGTH_MISMATCH));
         }
 
-        // Pre-allocate result vector
-        let mut decoded_signals: Vec<DecodedSignal<'_>, { MAX_SIGNALS_PER_MESSAGE }> = Vec::new();
-
         // Stack-allocated switch values (no heap allocation)
         let mut switch_values = SwitchValues::<'_>::new();
 
@@ -248,9 +373,9 @@ impl Dbc {
         // PASS 1: Decode multiplexer switches first (needed before multiplexed signals)
         // This is necessary because multiplexed signals depend on switch values
         for signal in signals.iter() {
-            if signal.is_multiplexer_switch() {
-                // decode_raw() returns (raw_value, physical_value) in one pass
-                let (raw_value, physical_value) = signal.decode_raw(payload)?;
+            if signal.is_multiplexer_switch() && message.is_transmitted(signal) {
+                // decode_raw_and_physical() returns (raw_value, physical_value) in one pass
+                let (raw_value, physical_value) = signal.decode_raw_and_physical(payload)?;
 
                 // Multiplexer switch values must be non-negative
                 if raw_value < 0 {
@@ -269,24 +394,23 @@ impl Dbc {
                 };
 
                 // Add to decoded signals
-                decoded_signals
-                    .push(DecodedSignal::new(
-                        signal.name(),
-                        physical_value,
-                        raw_value,
-                        signal.min(),
-                        signal.max(),
-                        signal.unit(),
-                        description,
-                    ))
-                    .map_err(|_| Error::Decoding(Error::MESSAGE_TOO_MANY_SIGNALS))?;
+                emit(DecodedSignal::new(
+                    signal.name(),
+                    physical_value,
+                    raw_value,
+                    signal.min(),
+                    signal.max(),
+                    signal.unit(),
+                    description,
+                ))?;
             }
         }
 
         // PASS 2: Decode non-switch signals based on multiplexing rules
         for signal in signals.iter() {
-            // Skip multiplexer switches (already decoded in pass 1)
-            if signal.is_multiplexer_switch() {
+            // Skip multiplexer switches (already decoded in pass 1) and signals beyond
+            // the transmitted length
+            if signal.is_multiplexer_switch() || !message.is_transmitted(signal) {
                 continue;
             }
 
@@ -295,11 +419,13 @@ impl Dbc {
                 // This is a multiplexed signal (m0, m1, etc.)
                 if message_has_extended_mux {
                     // Check extended multiplexing only if message has any
-                    self.check_extended_multiplexing(id, signal.name(), &switch_values)
-                        .unwrap_or_else(|| {
-                            // No extended entries for this signal - use basic multiplexing
-                            switch_values.any_has_value(mux_value)
-                        })
+                    self.check_extended_multiplexing(id, signal.name(), |name| {
+                        switch_values.get_by_name(name)
+                    })
+                    .unwrap_or_else(|| {
+                        // No extended entries for this signal - use basic multiplexing
+                        switch_values.any_has_value(mux_value)
+                    })
                 } else {
                     // No extended multiplexing for this message - use basic check
                     switch_values.any_has_value(mux_value)
@@ -310,8 +436,8 @@ impl Dbc {
             };
 
             if should_decode {
-                // decode_raw() returns (raw_value, physical_value) in one pass
-                let (raw_value, physical_value) = signal.decode_raw(payload)?;
+                // decode_raw_and_physical() returns (raw_value, physical_value) in one pass
+                let (raw_value, physical_value) = signal.decode_raw_and_physical(payload)?;
 
                 // Lookup value description only if any exist (skip O(n) scan otherwise)
                 let description = if has_any_value_descriptions {
@@ -321,21 +447,340 @@ impl Dbc {
                     None
                 };
 
-                decoded_signals
-                    .push(DecodedSignal::new(
-                        signal.name(),
-                        physical_value,
-                        raw_value,
-                        signal.min(),
-                        signal.max(),
-                        signal.unit(),
-                        description,
-                    ))
-                    .map_err(|_| Error::Decoding(Error::MESSAGE_TOO_MANY_SIGNALS))?;
+                emit(DecodedSignal::new(
+                    signal.name(),
+                    physical_value,
+                    raw_value,
+                    signal.min(),
+                    signal.max(),
+                    signal.unit(),
+                    description,
+                ))?;
             }
         }
 
-        Ok(decoded_signals)
+        Ok(())
+    }
+
+    /// Decode a CAN message payload and attach the frame's [`FrameMeta`] to the result.
+    ///
+    /// Behaves like [`decode`](Self::decode); `meta` is passed through unchanged so that
+    /// timestamp and channel stay attached to the signals through a decode pipeline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, FrameMeta};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let meta = FrameMeta { timestamp: 1_500_000, channel: 1 };
+    /// let payload = [0x40, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    /// let frame = dbc.decode_with_meta(256, &payload, false, meta)?;
+    /// assert_eq!(frame.meta, meta);
+    /// assert_eq!(frame.signals[0].value, 2000.0);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    pub fn decode_with_meta(
+        &self,
+        id: u32,
+        payload: &[u8],
+        is_extended: bool,
+        meta: FrameMeta,
+    ) -> Result<DecodedFrame<'_>> {
+        let signals = self.decode(id, payload, is_extended)?;
+        Ok(DecodedFrame { signals, meta })
+    }
+
+    /// Decode a CAN message payload and check each physical value against its signal's range.
+    ///
+    /// Signals are decoded as by [`Dbc::decode`]; afterwards every value must lie within the
+    /// `[min, max]` range declared in the DBC file. Signals declared with `[0|0]` have no
+    /// range and are not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Dbc::decode`], or [`Error::DecodedValueOutOfRange`] naming
+    /// the first signal whose value lies outside its range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, Error};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let payload = [0x40, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    /// assert_eq!(dbc.decode_checked(256, &payload, false)?[0].value, 2000.0);
+    ///
+    /// // Raw 0xFFFF decodes to 16383.75 rpm, above the declared maximum
+    /// let payload = [0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    /// assert!(matches!(
+    ///     dbc.decode_checked(256, &payload, false),
+    ///     Err(Error::DecodedValueOutOfRange { .. })
+    /// ));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn decode_checked(
+        &self,
+        id: u32,
+        payload: &[u8],
+        is_extended: bool,
+    ) -> Result<Vec<DecodedSignal<'_>, { MAX_SIGNALS_PER_MESSAGE }>> {
+        let decoded = self.decode(id, payload, is_extended)?;
+        let out_of_range = decoded
+            .iter()
+            .find(|signal| !(signal.min == 0.0 && signal.max == 0.0) && !signal.is_in_range());
+        if let Some(signal) = out_of_range {
+            return Err(Error::DecodedValueOutOfRange {
+                message_id: if is_extended {
+                    id | Message::EXTENDED_ID_FLAG
+                } else {
+                    id
+                },
+                signal: Name::try_from(signal.name)?,
+                value: signal.value,
+                min: signal.min,
+                max: signal.max,
+            });
+        }
+        Ok(decoded)
+    }
+
+    /// Decode a CAN message payload, returning only the signals of one signal group.
+    ///
+    /// Signals are decoded as by [`Dbc::decode`] and filtered to the members of the
+    /// `SIG_GROUP_` named `group_name` for this message, in message order. Multiplexed
+    /// members that are not active in this payload are omitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The raw CAN message ID (without extended flag)
+    /// * `payload` - The CAN message payload bytes
+    /// * `is_extended` - Whether this is an extended (29-bit) CAN ID
+    /// * `group_name` - Name of the signal group to decode
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Dbc::decode`], or `Error::Decoding(SIGNAL_GROUP_NOT_FOUND)`
+    /// if the message has no signal group named `group_name`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///  SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" *
+    ///
+    /// SIG_GROUP_ 256 Speed 1 : RPM;
+    /// "#)?;
+    ///
+    /// let payload = [0x40, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    /// let decoded = dbc.decode_group(256, &payload, false, "Speed")?;
+    /// assert_eq!(decoded.len(), 1);
+    /// assert_eq!(decoded[0].name, "RPM");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn decode_group(
+        &self,
+        id: u32,
+        payload: &[u8],
+        is_extended: bool,
+        group_name: &str,
+    ) -> Result<Vec<DecodedSignal<'_>, { MAX_SIGNALS_PER_MESSAGE }>> {
+        let decoded = self.decode(id, payload, is_extended)?;
+        let id = if is_extended {
+            id | Message::EXTENDED_ID_FLAG
+        } else {
+            id
+        };
+        let group = self
+            .signal_group(id, group_name)
+            .ok_or(Error::Decoding(Error::SIGNAL_GROUP_NOT_FOUND))?;
+        Ok(decoded.into_iter().filter(|signal| group.contains(signal.name)).collect())
+    }
+
+    /// Decode a sequence of frames lazily, e.g. when replaying a log.
+    ///
+    /// Each `(id, payload)` pair is decoded with [`decode`](Self::decode) and yielded with
+    /// its ID. IDs use the DBC file convention: extended IDs carry bit 31 (`0x80000000`).
+    /// Frames that cannot be decoded are not skipped: an unknown ID yields
+    /// [`Error::MESSAGE_NOT_FOUND`], so callers can count misses.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let log: [(u32, &[u8]); 2] = [(256, &[0x40, 0x1F, 0, 0, 0, 0, 0, 0]), (512, &[0; 8])];
+    /// let misses = dbc.decode_frames(log).filter(|(_, decoded)| decoded.is_err()).count();
+    /// assert_eq!(misses, 1);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "iterator is lazy and does nothing unless consumed"]
+    pub fn decode_frames<'a, I>(
+        &'a self,
+        frames: I,
+    ) -> impl Iterator<
+        Item = (
+            u32,
+            Result<Vec<DecodedSignal<'a>, { MAX_SIGNALS_PER_MESSAGE }>>,
+        ),
+    > + 'a
+    where
+        I: IntoIterator<Item = (u32, &'a [u8])>,
+        I::IntoIter: 'a,
+    {
+        frames.into_iter().map(move |(id, payload)| {
+            let is_extended = id & Message::EXTENDED_ID_FLAG != 0;
+            (
+                id,
+                self.decode(id & Message::MAX_EXTENDED_ID, payload, is_extended),
+            )
+        })
+    }
+
+    /// Decode a single signal of a CAN message.
+    ///
+    /// Only the bits of `signal_name` are extracted and scaled, which avoids decoding the
+    /// whole message when a single value is needed. The payload only has to cover the
+    /// bytes of that signal, not the full message length. Multiplexing is not evaluated.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The message ID (extended IDs carry bit 31, as in the DBC file)
+    /// * `signal_name` - Name of the signal to decode
+    /// * `payload` - The CAN message payload bytes
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Decoding`] with [`Error::MESSAGE_NOT_FOUND`] or
+    /// [`Error::SIGNAL_NOT_FOUND`] if the message or signal does not exist, or with
+    /// [`Error::PAYLOAD_LENGTH_MISMATCH`] if the payload is too short for the signal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///  SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" *
+    /// "#)?;
+    ///
+    /// // Two bytes are enough for RPM
+    /// assert_eq!(dbc.decode_signal(256, "RPM", &[0x40, 0x1F])?, 2000.0);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    pub fn decode_signal(&self, msg_id: u32, signal_name: &str, payload: &[u8]) -> Result<f64> {
+        let signal = self
+            .messages()
+            .find_by_id(msg_id)
+            .ok_or(Error::Decoding(Error::MESSAGE_NOT_FOUND))?
+            .signals()
+            .find(signal_name)
+            .ok_or(Error::Decoding(Error::SIGNAL_NOT_FOUND))?;
+        signal
+            .decode_raw_and_physical(payload)
+            .map(|(_, physical)| physical)
+            .map_err(|_| Error::Decoding(Error::PAYLOAD_LENGTH_MISMATCH))
+    }
+
+    /// Returns the signals of a message that are active for the given switch values.
+    ///
+    /// `switch_values` pairs multiplexer switch names with their current raw values. Signals
+    /// without a multiplexer indicator and the top-level switch are always yielded. A
+    /// signal with `SG_MUL_VAL_` entries is yielded only if every switch it references has
+    /// a value within one of its ranges (AND logic across switches); other multiplexed
+    /// signals are yielded if their `m<value>` marker matches the top-level switch.
+    /// Signals are yielded in declaration order; unknown message IDs yield nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_id` - The message ID (extended IDs carry bit 31, as in the DBC file)
+    /// * `switch_values` - `(switch_name, raw_value)` pairs
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 500 Diagnostics : 8 ECM
+    ///  SG_ Service M : 0|8@1+ (1,0) [0|255] "" *
+    ///  SG_ Pid m1M : 8|8@1+ (1,0) [0|255] "" *
+    ///  SG_ VehicleSpeed m13 : 16|16@1+ (0.01,0) [0|655.35] "km/h" *
+    ///
+    /// SG_MUL_VAL_ 500 Pid Service 1-1 ;
+    /// SG_MUL_VAL_ 500 VehicleSpeed Pid 13-13 ;
+    /// "#)?;
+    ///
+    /// let active: Vec<&str> = dbc
+    ///     .multiplexed_signals(500, &[("Service", 1), ("Pid", 13)])
+    ///     .map(|s| s.name())
+    ///     .collect();
+    /// assert_eq!(active, ["Service", "Pid", "VehicleSpeed"]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "iterator is lazy and does nothing unless consumed"]
+    pub fn multiplexed_signals<'a: 'b, 'b>(
+        &'a self,
+        message_id: u32,
+        switch_values: &'b [(&'b str, u64)],
+    ) -> impl Iterator<Item = &'a Signal> + 'b {
+        let lookup = move |name: &str| {
+            switch_values
+                .iter()
+                .find(|(switch, _)| *switch == name)
+                .map(|&(_, value)| value)
+        };
+        let message = self.messages().find_by_id(message_id);
+        let top_level_value = message
+            .and_then(Message::multiplexer_switch)
+            .and_then(|switch| lookup(switch.name()));
+
+        message.into_iter().flat_map(|m| m.signals().iter()).filter(move |signal| {
+            let Some(mux_value) = signal.multiplexer_switch_value() else {
+                return true;
+            };
+            self.check_extended_multiplexing(message_id, signal.name(), lookup)
+                .unwrap_or(top_level_value == Some(mux_value))
+        })
     }
 
     /// Check extended multiplexing rules for a signal.
@@ -346,7 +791,7 @@ impl Dbc {
         &self,
         message_id: u32,
         signal_name: &str,
-        switch_values: &SwitchValues,
+        switch_value: impl Fn(&str) -> Option<u64>,
     ) -> Option<bool> {
         // Get extended entries for this signal
         let indices = self.ext_mux_index.get(message_id, signal_name)?;
@@ -382,28 +827,17 @@ impl Dbc {
             };
 
             // Get the current switch value
-            let switch_val = match switch_values.get_by_name(switch_name) {
+            let switch_val = match switch_value(switch_name) {
                 Some(v) => v,
                 None => return Some(false), // Switch not found, signal not active
             };
 
             // Check if any extended entry for this switch has a matching value range
-            let mut has_match = false;
-            for &idx in indices {
-                if let Some(entry) = self.extended_multiplexing.get(idx) {
-                    if entry.multiplexer_switch() == switch_name {
-                        for &(min, max) in entry.value_ranges() {
-                            if switch_val >= min && switch_val <= max {
-                                has_match = true;
-                                break;
-                            }
-                        }
-                        if has_match {
-                            break;
-                        }
-                    }
-                }
-            }
+            let has_match = indices.iter().any(|&idx| {
+                self.extended_multiplexing.get(idx).is_some_and(|entry| {
+                    entry.multiplexer_switch() == switch_name && entry.applies_to(switch_val)
+                })
+            });
 
             if !has_match {
                 return Some(false); // This switch doesn't match, signal not active
@@ -484,7 +918,49 @@ impl Dbc {
 
 #[cfg(test)]
 mod tests {
-    use crate::Dbc;
+    use super::{DecodedSignal, FrameMeta};
+    use crate::{Dbc, Error};
+
+    #[test]
+    fn test_decode_frames() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+
+BO_ 2147484672 Brake : 8 ECM
+ SG_ Pressure : 0|16@1+ (0.1,0) [0|1000] "bar" *
+"#,
+        )
+        .unwrap();
+
+        let frames: [(u32, &[u8]); 3] = [
+            (256, &[0x40, 0x1F, 0, 0, 0, 0, 0, 0]),
+            (512, &[0; 8]),
+            (0x8000_0400, &[0xE8, 0x03, 0, 0, 0, 0, 0, 0]),
+        ];
+        let mut decoded = dbc.decode_frames(frames);
+
+        let (id, signals) = decoded.next().unwrap();
+        assert_eq!(id, 256);
+        assert_eq!(signals.unwrap()[0].value, 2000.0);
+
+        let (id, signals) = decoded.next().unwrap();
+        assert_eq!(id, 512);
+        assert_eq!(
+            signals.unwrap_err(),
+            Error::Decoding(Error::MESSAGE_NOT_FOUND)
+        );
+
+        let (id, signals) = decoded.next().unwrap();
+        assert_eq!(id, 0x8000_0400);
+        assert_eq!(signals.unwrap()[0].value, 100.0);
+
+        assert!(decoded.next().is_none());
+    }
 
     #[test]
     fn test_decode_basic() {
@@ -512,6 +988,213 @@ BO_ 256 Engine : 8 ECM
         assert_eq!(decoded[0].unit, Some("rpm"));
     }
 
+    #[test]
+    fn test_decode_raw_and_physical_in_one_pass() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ Temp : 0|8@1- (0.5,-40) [-104|23.5] "C" *
+"#,
+        )
+        .unwrap();
+
+        // raw = -20 (0xEC), physical = -20 * 0.5 - 40 = -50
+        let payload = [0xEC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let decoded = dbc.decode(256, &payload, false).unwrap();
+        assert_eq!(decoded[0].raw(), -20);
+        assert_eq!(decoded[0].raw_value, -20);
+        assert_eq!(decoded[0].value, -50.0);
+    }
+
+    #[test]
+    fn test_decode_into_matches_decode() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ Mode M : 0|8@1+ (1,0) [0|3] "" *
+ SG_ RPM m0 : 8|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp m1 : 8|8@1- (1,-40) [-40|215] "degC" *
+ SG_ Status : 24|8@1+ (1,0) [0|255] "" *
+
+VAL_ 256 Status 1 "Ok" ;
+"#,
+        )
+        .unwrap();
+        let payload = [0x00, 0x40, 0x1F, 0x01, 0x00, 0x00, 0x00, 0x00];
+
+        let mut out = [DecodedSignal::default(); 4];
+        let count = dbc.decode_into(256, &payload, false, &mut out).unwrap();
+        let decoded = dbc.decode(256, &payload, false).unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(&out[..count], decoded.as_slice());
+        assert_eq!(out[2].description, Some("Ok"));
+        // Unused entries are left untouched
+        assert_eq!(out[3], DecodedSignal::default());
+
+        // An exactly sized buffer is enough
+        let mut exact = [DecodedSignal::default(); 3];
+        assert_eq!(dbc.decode_into(256, &payload, false, &mut exact), Ok(3));
+    }
+
+    #[test]
+    fn test_decode_into_errors() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" *
+"#,
+        )
+        .unwrap();
+        let payload = [0u8; 8];
+
+        let mut small = [DecodedSignal::default(); 1];
+        assert_eq!(
+            dbc.decode_into(256, &payload, false, &mut small),
+            Err(Error::Decoding(Error::DECODE_BUFFER_TOO_SMALL))
+        );
+
+        let mut out = [DecodedSignal::default(); 4];
+        assert_eq!(
+            dbc.decode_into(512, &payload, false, &mut out),
+            Err(Error::Decoding(Error::MESSAGE_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.decode_into(256, &payload[..2], false, &mut out),
+            Err(Error::Decoding(Error::PAYLOAD_LENGTH_MISMATCH))
+        );
+        assert_eq!(
+            dbc.decode_into(256, &payload, false, &mut []),
+            Err(Error::Decoding(Error::DECODE_BUFFER_TOO_SMALL))
+        );
+    }
+
+    #[test]
+    fn test_decode_checked_range() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Counter : 16|8@1+ (1,0) [0|0] "" *
+
+BO_ 2147484672 Diag : 8 ECM
+ SG_ Temp : 0|8@1- (1,0) [-40|100] "degC" *
+"#,
+        )
+        .unwrap();
+
+        // In range; the unbounded Counter is not checked
+        let payload = [0x40, 0x1F, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let decoded = dbc.decode_checked(256, &payload, false).unwrap();
+        assert_eq!(decoded, dbc.decode(256, &payload, false).unwrap());
+
+        // RPM = 0xFFFF * 0.25 = 16383.75 exceeds 8000; decode still accepts it
+        let payload = [0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        assert!(!dbc.decode(256, &payload, false).unwrap()[0].is_in_range());
+        match dbc.decode_checked(256, &payload, false) {
+            Err(Error::DecodedValueOutOfRange {
+                message_id,
+                signal,
+                value,
+                min,
+                max,
+            }) => {
+                assert_eq!(message_id, 256);
+                assert_eq!(signal.as_str(), "RPM");
+                assert_eq!(value, 16383.75);
+                assert_eq!((min, max), (0.0, 8000.0));
+            }
+            other => panic!("expected DecodedValueOutOfRange, got {other:?}"),
+        }
+
+        // Temp = -50 is below -40; the error carries the extended flag
+        let payload = [0xCE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        assert!(matches!(
+            dbc.decode_checked(0x400, &payload, true),
+            Err(Error::DecodedValueOutOfRange {
+                message_id: 2147484672,
+                ..
+            })
+        ));
+
+        assert_eq!(
+            dbc.decode_checked(512, &payload, false),
+            Err(Error::Decoding(Error::MESSAGE_NOT_FOUND))
+        );
+    }
+
+    #[test]
+    fn test_decode_group_returns_members_only() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" *
+ SG_ Load : 24|8@1+ (1,0) [0|100] "%" *
+
+SIG_GROUP_ 256 Powertrain 1 : Load RPM;
+"#,
+        )
+        .unwrap();
+
+        let payload = [0x40, 0x1F, 0x5A, 0x32, 0x00, 0x00, 0x00, 0x00];
+        let decoded = dbc.decode_group(256, &payload, false, "Powertrain").unwrap();
+        assert!(decoded.iter().map(|s| s.name).eq(["RPM", "Load"]));
+        assert_eq!(decoded[0].value, 2000.0);
+        assert_eq!(decoded[1].value, 50.0);
+
+        assert_eq!(
+            dbc.decode_group(256, &payload, false, "Chassis"),
+            Err(Error::Decoding(Error::SIGNAL_GROUP_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.decode_group(512, &payload, false, "Powertrain"),
+            Err(Error::Decoding(Error::MESSAGE_NOT_FOUND))
+        );
+    }
+
+    #[test]
+    fn test_decode_with_meta_passes_meta_through() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+"#,
+        )
+        .unwrap();
+
+        let payload = [0x40, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let meta = FrameMeta {
+            timestamp: 1_234_567_890,
+            channel: 3,
+        };
+        let frame = dbc.decode_with_meta(256, &payload, false, meta).unwrap();
+
+        assert_eq!(frame.meta, meta);
+        assert_eq!(frame.signals, dbc.decode(256, &payload, false).unwrap());
+
+        // Errors are reported the same as for `decode`
+        assert!(dbc.decode_with_meta(512, &payload, false, meta).is_err());
+    }
+
     #[test]
     fn test_decode_message_not_found() {
         let dbc = Dbc::parse(
@@ -558,7 +1241,6 @@ BO_ 256 Engine : 8 ECM
 
     #[test]
     fn test_decode_payload_length_mismatch() {
-        use crate::Error;
         let data = r#"VERSION "1.0"
 
 BU_: ECM
@@ -931,6 +1613,92 @@ SG_MUL_VAL_ 502 Signal_C Mux2 20-25 ;
         assert!(find3("Signal_C").is_none());
     }
 
+    #[test]
+    fn test_decode_signal() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 23|8@0- (1,-40) [-40|215] "degC" *
+
+BO_ 2147484672 Extended : 8 ECM
+ SG_ Pressure : 8|16@1+ (0.1,0) [0|1000] "bar" *
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(dbc.decode_signal(256, "RPM", &[0x40, 0x1F]), Ok(2000.0));
+        // Only the signal's bytes are required
+        assert_eq!(dbc.decode_signal(256, "Temp", &[0, 0, 0x50]), Ok(40.0));
+        assert_eq!(
+            dbc.decode_signal(0x8000_0400, "Pressure", &[0, 0x10, 0x27]),
+            Ok(1000.0)
+        );
+
+        assert_eq!(
+            dbc.decode_signal(512, "RPM", &[0; 8]),
+            Err(Error::Decoding(Error::MESSAGE_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.decode_signal(256, "Speed", &[0; 8]),
+            Err(Error::Decoding(Error::SIGNAL_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.decode_signal(256, "Temp", &[0, 0]),
+            Err(Error::Decoding(Error::PAYLOAD_LENGTH_MISMATCH))
+        );
+    }
+
+    #[test]
+    fn test_multiplexed_signals_combines_basic_and_extended() {
+        use crate::compat::Vec;
+
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 505 Diagnostics : 8 ECM
+ SG_ Service M : 0|8@1+ (1,0) [0|255] ""
+ SG_ Pid m1M : 8|8@1+ (1,0) [0|255] ""
+ SG_ VehicleSpeed m13 : 16|16@1+ (0.01,0) [0|655.35] "km/h" *
+ SG_ CoolantTemp m5 : 16|8@1- (1,0) [-128|127] "degC" *
+ SG_ Dtc m2 : 16|16@1+ (1,0) [0|65535] "" *
+ SG_ Counter : 56|8@1+ (1,0) [0|255] "" *
+
+SG_MUL_VAL_ 505 Pid Service 1-1 ;
+SG_MUL_VAL_ 505 VehicleSpeed Pid 13-13 ;
+SG_MUL_VAL_ 505 VehicleSpeed Service 1-1 ;
+SG_MUL_VAL_ 505 CoolantTemp Pid 5-5,100-110 ;
+"#,
+        )
+        .unwrap();
+        let active = |switches: &[(&str, u64)]| {
+            dbc.multiplexed_signals(505, switches).map(|s| s.name()).collect::<Vec<_, 8>>()
+        };
+
+        // Extended ranges across both switches (AND logic)
+        assert!(active(&[("Service", 1), ("Pid", 13)]).iter().eq(&[
+            "Service",
+            "Pid",
+            "VehicleSpeed",
+            "Counter"
+        ]));
+        assert!(active(&[("Service", 2), ("Pid", 13)]).iter().eq(&["Service", "Dtc", "Counter"]));
+        assert!(active(&[("Service", 1), ("Pid", 105)]).iter().eq(&[
+            "Service",
+            "Pid",
+            "CoolantTemp",
+            "Counter"
+        ]));
+        // A referenced switch without a value deactivates the signal
+        assert!(active(&[("Service", 1)]).iter().eq(&["Service", "Pid", "Counter"]));
+        assert_eq!(dbc.multiplexed_signals(999, &[]).count(), 0);
+    }
+
     /// Test that extended multiplexing takes precedence over basic m0/m1 values.
     /// Note: Depends on SG_MUL_VAL_ parsing working correctly.
     #[test]
@@ -1009,7 +1777,6 @@ SG_MUL_VAL_ 504 Signal_E Mux2 10-15 ;
 
     #[test]
     fn test_decode_negative_multiplexer_switch() {
-        use crate::Error;
         // Create a DBC with a signed multiplexer switch signal
         // 8-bit signed signal: values -128 to 127
         let dbc = Dbc::parse(
@@ -1054,7 +1821,7 @@ BO_ 256 MuxMessage : 8 ECM
 
 BU_: ECM
 
-BO_ 600 TooManySwitches : 18 ECM
+BO_ 600 TooManySwitches : 20 ECM
  SG_ Mux1 M : 0|8@1+ (1,0) [0|255] ""
  SG_ Mux2 M : 8|8@1+ (1,0) [0|255] ""
  SG_ Mux3 M : 16|8@1+ (1,0) [0|255] ""
@@ -0,0 +1,383 @@
+use crate::{Dbc, Message, Signal};
+use std::{
+    fmt::{Display, Formatter, Result},
+    vec::Vec,
+};
+
+/// Structured changeset between two databases, produced by [`Dbc::diff`].
+///
+/// Messages are matched by ID (including the extended ID flag) and signals by name, so
+/// reordering messages or signals is not a change. The `Display` implementation prints
+/// a git-style summary: `+`/`-` lines for added and removed definitions and the
+/// affected `BO_` line as context for changes inside a message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbcDiff<'a> {
+    /// Messages only present in the new database, in its message order.
+    pub added_messages: Vec<&'a Message>,
+    /// Messages only present in the old database, in its message order.
+    pub removed_messages: Vec<&'a Message>,
+    /// Messages present in both databases whose definition or signals differ.
+    pub modified_messages: Vec<MessageDiff<'a>>,
+    diff_comments: bool,
+}
+
+/// Changes to a message present in both databases.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageDiff<'a> {
+    /// The message in the old database.
+    pub old: &'a Message,
+    /// The message in the new database.
+    pub new: &'a Message,
+    /// Signals only present in the new message.
+    pub added_signals: Vec<&'a Signal>,
+    /// Signals only present in the old message.
+    pub removed_signals: Vec<&'a Signal>,
+    /// Signals present in both messages whose definition differs.
+    pub changed_signals: Vec<SignalDiff<'a>>,
+}
+
+/// A signal whose definition differs between two databases.
+///
+/// Both versions are kept, so old and new values of any property (for example
+/// [`Signal::start_bit`], [`Signal::length`], [`Signal::factor`] and
+/// [`Signal::offset`]) can be compared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalDiff<'a> {
+    /// The signal in the old message.
+    pub old: &'a Signal,
+    /// The signal in the new message.
+    pub new: &'a Signal,
+}
+
+impl DbcDiff<'_> {
+    /// Returns `true` if the two databases have no differences.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn is_empty(&self) -> bool {
+        self.added_messages.is_empty()
+            && self.removed_messages.is_empty()
+            && self.modified_messages.is_empty()
+    }
+}
+
+impl MessageDiff<'_> {
+    /// Returns `true` if the message's own definition (name, DLC or sender) changed.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn header_changed(&self) -> bool {
+        self.old.name() != self.new.name()
+            || self.old.dlc() != self.new.dlc()
+            || self.old.sender() != self.new.sender()
+    }
+}
+
+impl Dbc {
+    /// Compares this database (old) with `other` (new), ignoring comment-only changes.
+    ///
+    /// Equivalent to [`Dbc::diff_with`] with `diff_comments` set to `false`. Only message
+    /// and signal definitions are compared; value descriptions, attributes and other
+    /// sections are not part of the changeset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let old = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    /// let new = Dbc::parse(r#"VERSION "1.1"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.5,0) [0|8000] "rpm" *
+    ///  SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" *
+    /// "#)?;
+    ///
+    /// let diff = old.diff(&new);
+    /// let engine = &diff.modified_messages[0];
+    /// assert_eq!(engine.added_signals[0].name(), "Temp");
+    /// assert_eq!(engine.changed_signals[0].old.factor(), 0.25);
+    /// assert_eq!(engine.changed_signals[0].new.factor(), 0.5);
+    /// println!("{diff}");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn diff<'a>(&'a self, other: &'a Dbc) -> DbcDiff<'a> {
+        self.diff_with(other, false)
+    }
+
+    /// Compares this database (old) with `other` (new).
+    ///
+    /// Like [`Dbc::diff`]; when `diff_comments` is `true`, changed `CM_` comments on
+    /// messages and signals also count as modifications.
+    #[must_use = "return value should be used"]
+    pub fn diff_with<'a>(&'a self, other: &'a Dbc, diff_comments: bool) -> DbcDiff<'a> {
+        let mut removed_messages = Vec::new();
+        let mut modified_messages = Vec::new();
+        for old in self.messages().iter() {
+            match other.messages().find_by_id(old.id_with_flag()) {
+                Some(new) => {
+                    if let Some(message_diff) = diff_message(old, new, diff_comments) {
+                        modified_messages.push(message_diff);
+                    }
+                }
+                None => removed_messages.push(old),
+            }
+        }
+
+        let added_messages = other
+            .messages()
+            .iter()
+            .filter(|new| self.messages().find_by_id(new.id_with_flag()).is_none())
+            .collect();
+
+        DbcDiff {
+            added_messages,
+            removed_messages,
+            modified_messages,
+            diff_comments,
+        }
+    }
+}
+
+fn diff_message<'a>(
+    old: &'a Message,
+    new: &'a Message,
+    diff_comments: bool,
+) -> Option<MessageDiff<'a>> {
+    let mut removed_signals = Vec::new();
+    let mut changed_signals = Vec::new();
+    for old_signal in old.signals().iter() {
+        match new.signals().find(old_signal.name()) {
+            Some(new_signal) => {
+                if signal_changed(old_signal, new_signal, diff_comments) {
+                    changed_signals.push(SignalDiff {
+                        old: old_signal,
+                        new: new_signal,
+                    });
+                }
+            }
+            None => removed_signals.push(old_signal),
+        }
+    }
+    let added_signals: Vec<&Signal> = new
+        .signals()
+        .iter()
+        .filter(|signal| old.signals().find(signal.name()).is_none())
+        .collect();
+
+    let message_diff = MessageDiff {
+        old,
+        new,
+        added_signals,
+        removed_signals,
+        changed_signals,
+    };
+    let comment_changed = diff_comments && old.comment() != new.comment();
+    if message_diff.header_changed()
+        || comment_changed
+        || !message_diff.added_signals.is_empty()
+        || !message_diff.removed_signals.is_empty()
+        || !message_diff.changed_signals.is_empty()
+    {
+        Some(message_diff)
+    } else {
+        None
+    }
+}
+
+fn signal_changed(old: &Signal, new: &Signal, diff_comments: bool) -> bool {
+    old.start_bit() != new.start_bit()
+        || old.length() != new.length()
+        || old.byte_order() != new.byte_order()
+        || old.is_unsigned() != new.is_unsigned()
+        || old.factor() != new.factor()
+        || old.offset() != new.offset()
+        || old.min() != new.min()
+        || old.max() != new.max()
+        || old.unit() != new.unit()
+        || old.receivers() != new.receivers()
+        || old.is_multiplexer_switch() != new.is_multiplexer_switch()
+        || old.multiplexer_switch_value() != new.multiplexer_switch_value()
+        || (diff_comments && old.comment() != new.comment())
+}
+
+fn write_comment(
+    f: &mut Formatter<'_>,
+    prefix: char,
+    target: &str,
+    comment: Option<&str>,
+) -> Result {
+    match comment {
+        Some(comment) => writeln!(f, "{prefix}CM_ {target} \"{comment}\";"),
+        None => Ok(()),
+    }
+}
+
+impl Display for DbcDiff<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for message in &self.removed_messages {
+            writeln!(f, "-{}", message.to_dbc_string())?;
+        }
+        for message in &self.added_messages {
+            writeln!(f, "+{}", message.to_dbc_string())?;
+        }
+        for message in &self.modified_messages {
+            if message.header_changed() {
+                writeln!(f, "-{}", message.old.to_dbc_string())?;
+                writeln!(f, "+{}", message.new.to_dbc_string())?;
+            } else {
+                writeln!(f, " {}", message.new.to_dbc_string())?;
+            }
+            if self.diff_comments && message.old.comment() != message.new.comment() {
+                let target = format!("BO_ {}", message.new.id_with_flag());
+                write_comment(f, '-', &target, message.old.comment())?;
+                write_comment(f, '+', &target, message.new.comment())?;
+            }
+            for signal in &message.removed_signals {
+                writeln!(f, "-{}", signal.to_dbc_string())?;
+            }
+            for signal in &message.added_signals {
+                writeln!(f, "+{}", signal.to_dbc_string())?;
+            }
+            for signal in &message.changed_signals {
+                let old_line = signal.old.to_dbc_string();
+                let new_line = signal.new.to_dbc_string();
+                if old_line != new_line {
+                    writeln!(f, "-{old_line}")?;
+                    writeln!(f, "+{new_line}")?;
+                }
+                if self.diff_comments && signal.old.comment() != signal.new.comment() {
+                    let target =
+                        format!("SG_ {} {}", message.new.id_with_flag(), signal.new.name());
+                    write_comment(f, '-', &target, signal.old.comment())?;
+                    write_comment(f, '+', &target, signal.new.comment())?;
+                }
+            }
+        }
+        write!(
+            f,
+            "{} message(s) added, {} removed, {} modified",
+            self.added_messages.len(),
+            self.removed_messages.len(),
+            self.modified_messages.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Dbc;
+
+    const OLD: &str = r#"VERSION "1.0"
+
+BU_: ECM TCM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" TCM
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" TCM
+ SG_ Load : 24|8@1+ (1,0) [0|100] "%" TCM
+
+BO_ 512 Brake : 4 ECM
+ SG_ Pressure : 0|16@1+ (0.1,0) [0|6553.5] "bar" TCM
+
+BO_ 768 Legacy : 2 TCM
+ SG_ Flag : 0|1@1+ (1,0) [0|1] "" ECM
+
+CM_ SG_ 512 Pressure "Brake line pressure";
+"#;
+
+    const NEW: &str = r#"VERSION "1.1"
+
+BU_: ECM TCM
+
+BO_ 512 Brake : 4 ECM
+ SG_ Pressure : 0|16@1+ (0.1,0) [0|6553.5] "bar" TCM
+
+BO_ 256 Engine : 8 ECM
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" TCM
+ SG_ RPM : 0|12@1+ (0.5,0) [0|8000] "rpm" TCM
+ SG_ Gear : 24|4@1+ (1,0) [0|8] "" TCM
+
+BO_ 1024 Body : 8 ECM
+ SG_ Door : 0|1@1+ (1,0) [0|1] "" TCM
+
+CM_ SG_ 512 Pressure "Front brake line pressure";
+"#;
+
+    #[test]
+    fn test_diff_is_order_independent() {
+        let old = Dbc::parse(OLD).unwrap();
+        let new = Dbc::parse(NEW).unwrap();
+        let diff = old.diff(&new);
+
+        assert_eq!(diff.added_messages.len(), 1);
+        assert_eq!(diff.added_messages[0].name(), "Body");
+        assert_eq!(diff.removed_messages.len(), 1);
+        assert_eq!(diff.removed_messages[0].name(), "Legacy");
+
+        // Brake only has a comment change, Engine's reordered Temp is unchanged
+        assert_eq!(diff.modified_messages.len(), 1);
+        let engine = &diff.modified_messages[0];
+        assert_eq!(engine.new.name(), "Engine");
+        assert!(!engine.header_changed());
+        assert_eq!(engine.added_signals.len(), 1);
+        assert_eq!(engine.added_signals[0].name(), "Gear");
+        assert_eq!(engine.removed_signals.len(), 1);
+        assert_eq!(engine.removed_signals[0].name(), "Load");
+        assert_eq!(engine.changed_signals.len(), 1);
+        let rpm = &engine.changed_signals[0];
+        assert_eq!((rpm.old.start_bit(), rpm.new.start_bit()), (0, 0));
+        assert_eq!((rpm.old.length(), rpm.new.length()), (16, 12));
+        assert_eq!((rpm.old.factor(), rpm.new.factor()), (0.25, 0.5));
+        assert_eq!((rpm.old.offset(), rpm.new.offset()), (0.0, 0.0));
+
+        assert!(old.diff(&old).is_empty());
+    }
+
+    #[test]
+    fn test_diff_comments() {
+        let old = Dbc::parse(OLD).unwrap();
+        let new = Dbc::parse(NEW).unwrap();
+        let diff = old.diff_with(&new, true);
+
+        assert_eq!(diff.modified_messages.len(), 2);
+        let brake = &diff.modified_messages[1];
+        assert_eq!(brake.new.name(), "Brake");
+        assert_eq!(brake.changed_signals.len(), 1);
+        assert_eq!(
+            brake.changed_signals[0].new.comment(),
+            Some("Front brake line pressure")
+        );
+    }
+
+    #[test]
+    fn test_diff_display() {
+        let old = Dbc::parse(OLD).unwrap();
+        let new = Dbc::parse(NEW).unwrap();
+
+        let expected = "-BO_ 768 Legacy : 2 TCM
++BO_ 1024 Body : 8 ECM
+ BO_ 256 Engine : 8 ECM
+- SG_ Load : 24|8@1+ (1,0) [0|100] \"%\" TCM
++ SG_ Gear : 24|4@1+ (1,0) [0|8] \"\" TCM
+- SG_ RPM : 0|16@1+ (0.25,0) [0|8000] \"rpm\" TCM
++ SG_ RPM : 0|12@1+ (0.5,0) [0|8000] \"rpm\" TCM
+1 message(s) added, 1 removed, 1 modified";
+        assert_eq!(old.diff(&new).to_string(), expected);
+
+        let with_comments = old.diff_with(&new, true).to_string();
+        assert!(with_comments.contains(
+            " BO_ 512 Brake : 4 ECM
+-CM_ SG_ 512 Pressure \"Brake line pressure\";
++CM_ SG_ 512 Pressure \"Front brake line pressure\";
+"
+        ));
+    }
+}
@@ -1,7 +1,7 @@
-use crate::{Dbc, Error, Message, Result, compat::Vec};
+use crate::{Dbc, EncodeMode, Error, Message, Result, Signal, compat::Vec};
 
 /// Maximum CAN FD payload size (64 bytes)
-const MAX_PAYLOAD_SIZE: usize = 64;
+const MAX_PAYLOAD_SIZE: usize = Message::MAX_PAYLOAD_SIZE;
 
 impl Dbc {
     /// Encode signal values into a CAN message payload.
@@ -71,6 +71,43 @@ impl Dbc {
         id: u32,
         signals: &[(&str, f64)],
         is_extended: bool,
+    ) -> Result<Vec<u8, MAX_PAYLOAD_SIZE>> {
+        self.encode_with(id, signals, is_extended, EncodeMode::Reject)
+    }
+
+    /// Encode signal values into a CAN message payload, handling out-of-range values
+    /// per `mode`.
+    ///
+    /// Like [`Dbc::encode()`], which always uses [`EncodeMode::Reject`], but values
+    /// outside a signal's `[min, max]` range can be clamped or wrapped instead. See
+    /// [`Signal::encode_raw_with`] for the details of each mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, EncodeMode};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// assert!(dbc.encode(256, &[("RPM", 9000.0)], false).is_err());
+    ///
+    /// // Clamped to 8000 rpm: raw 32000 = 0x7D00
+    /// let payload = dbc.encode_with(256, &[("RPM", 9000.0)], false, EncodeMode::Clamp)?;
+    /// assert_eq!(&payload.as_slice()[..2], &[0x00, 0x7D]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn encode_with(
+        &self,
+        id: u32,
+        signals: &[(&str, f64)],
+        is_extended: bool,
+        mode: EncodeMode,
     ) -> Result<Vec<u8, MAX_PAYLOAD_SIZE>> {
         // If it's an extended ID, add the extended ID flag
         let id = if is_extended {
@@ -86,11 +123,7 @@ impl Dbc {
             .ok_or(Error::Encoding(Error::MESSAGE_NOT_FOUND))?;
 
         // Create zero-initialized payload of size DLC
-        let dlc = message.dlc() as usize;
-        let mut payload: Vec<u8, MAX_PAYLOAD_SIZE> = Vec::new();
-        for _ in 0..dlc {
-            payload.push(0).map_err(|_| Error::Encoding(Error::MESSAGE_DLC_TOO_LARGE))?;
-        }
+        let mut payload = message.payload_template();
 
         // Encode each signal
         for &(signal_name, physical_value) in signals {
@@ -102,12 +135,76 @@ impl Dbc {
                 .ok_or(Error::Encoding(Error::ENCODING_SIGNAL_NOT_FOUND))?;
 
             // Encode and insert into payload (get mutable slice for heapless compatibility)
-            signal.encode_to(physical_value, payload.as_mut_slice())?;
+            signal.encode_to_with(physical_value, payload.as_mut_slice(), mode)?;
         }
 
         Ok(payload)
     }
 
+    /// Build the payload a transmitter sends before any signal has been set.
+    ///
+    /// Starts from a zeroed payload of the message length and writes the raw
+    /// [`start_value`](crate::Signal::start_value) (`GenSigStartValue`) of every signal
+    /// that has one. In multiplexed messages only the signals selected by the
+    /// multiplexer switch's own start value (or 0) are written. Returns `None` if no
+    /// message has ID `msg_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg_id` - The message ID (extended IDs carry bit 31, as in the DBC file)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 4 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///  SG_ Temp : 16|8@1+ (1,-40) [-40|215] "degC" *
+    ///
+    /// BA_DEF_ SG_ "GenSigStartValue" INT 0 65535;
+    /// BA_ "GenSigStartValue" SG_ 256 Temp 40;
+    /// "#)?;
+    ///
+    /// let payload = dbc.initial_payload(256).unwrap();
+    /// assert_eq!(payload.as_slice(), &[0x00, 0x00, 0x28, 0x00]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn initial_payload(&self, msg_id: u32) -> Option<Vec<u8, MAX_PAYLOAD_SIZE>> {
+        let message = self.messages().find_by_id(msg_id)?;
+        let mut payload = message.payload_template();
+
+        let switch_value = message
+            .multiplexer_switch()
+            .and_then(Signal::start_value)
+            .and_then(|v| u64::try_from(v).ok())
+            .unwrap_or(0);
+        for signal in message.multiplexed_signals(switch_value) {
+            let Some(start_value) = signal.start_value() else {
+                continue;
+            };
+            let length = usize::from(signal.length());
+            let mask = if length >= 64 {
+                u64::MAX
+            } else {
+                (1u64 << length) - 1
+            };
+            signal.byte_order().insert_bits(
+                payload.as_mut_slice(),
+                usize::from(signal.start_bit()),
+                length,
+                start_value as u64 & mask,
+            );
+        }
+
+        Some(payload)
+    }
+
     /// Encode signal values into a CAN frame using embedded-can types.
     ///
     /// This is a convenience method that encodes signals and returns the result
@@ -167,6 +264,38 @@ BO_ 256 Engine : 8 ECM
         assert_eq!(payload[1], 0x1F);
     }
 
+    #[test]
+    fn test_encode_with_modes_above_max() {
+        use crate::{EncodeMode, Error};
+
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Load : 16|8@1+ (1,0) [0|100] "%" *
+"#,
+        )
+        .unwrap();
+        let signals = [("RPM", 2000.0), ("Load", 300.0)];
+
+        // Reject (the default of encode) fails the whole payload
+        let out_of_range = Err(Error::Encoding(Error::ENCODING_VALUE_OUT_OF_RANGE));
+        assert_eq!(dbc.encode(256, &signals, false), out_of_range);
+        assert_eq!(
+            dbc.encode_with(256, &signals, false, EncodeMode::Reject),
+            out_of_range
+        );
+
+        // Clamp writes max (100), Wrap the low 8 bits of 300 (44)
+        let clamped = dbc.encode_with(256, &signals, false, EncodeMode::Clamp).unwrap();
+        assert_eq!(clamped.as_slice()[..3], [0x40, 0x1F, 100]);
+        let wrapped = dbc.encode_with(256, &signals, false, EncodeMode::Wrap).unwrap();
+        assert_eq!(wrapped.as_slice()[..3], [0x40, 0x1F, 44]);
+    }
+
     #[test]
     fn test_encode_multiple_signals() {
         let dbc = Dbc::parse(
@@ -230,6 +359,43 @@ BO_ 256 Engine : 8 ECM
         assert!((find_value("Throttle").unwrap() - throttle).abs() < 0.5);
     }
 
+    #[test]
+    fn test_encode_decode_float_signals() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ Torque : 0|32@1- (0.5,10) [-1000|1000] "Nm" *
+ SG_ Mode : 32|8@1+ (1,0) [0|3] "" *
+
+BO_ 257 Precise : 8 ECM
+ SG_ Position : 0|64@1- (1,0) [-1000000|1000000] "m" *
+
+SIG_VALTYPE_ 256 Torque : 1;
+SIG_VALTYPE_ 257 Position : 2;
+"#,
+        )
+        .unwrap();
+
+        // Factor and offset apply to the IEEE-754 value: (13.0 - 10) / 0.5 = 6.0
+        let payload = dbc.encode(256, &[("Torque", 13.0), ("Mode", 2.0)], false).unwrap();
+        assert_eq!(payload.as_slice()[..4], 6.0f32.to_le_bytes());
+        assert_eq!(payload[4], 2);
+
+        let decoded = dbc.decode(256, &payload, false).unwrap();
+        assert_eq!(decoded[0].name, "Torque");
+        assert_eq!(decoded[0].value, 13.0);
+        assert_eq!(decoded[0].raw_value, i64::from(6.0f32.to_bits()));
+        assert_eq!(decoded[1].value, 2.0);
+
+        let payload = dbc.encode(257, &[("Position", -123.456)], false).unwrap();
+        assert_eq!(payload.as_slice(), (-123.456f64).to_le_bytes());
+        let decoded = dbc.decode(257, &payload, false).unwrap();
+        assert_eq!(decoded[0].value, -123.456);
+    }
+
     #[test]
     fn test_encode_message_not_found() {
         let dbc = Dbc::parse(
@@ -422,6 +588,43 @@ BO_ 256 Engine : 8 ECM
         assert_eq!(payload[1], 200);
     }
 
+    #[cfg(feature = "attributes")]
+    #[test]
+    fn test_initial_payload_from_start_values() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ Mode M : 0|8@1+ (1,0) [0|255] "" *
+ SG_ Pressure m1 : 15|16@0+ (1,0) [0|65535] "kPa" *
+ SG_ Level m2 : 8|8@1+ (1,0) [0|255] "" *
+ SG_ Offset : 32|8@1- (1,0) [-128|127] "" *
+
+BO_ 512 Idle : 2 ECM
+ SG_ Flag : 0|1@1+ (1,0) [0|1] "" *
+
+BA_DEF_ SG_ "GenSigStartValue" FLOAT -1000 100000;
+BA_ "GenSigStartValue" SG_ 256 Mode 1;
+BA_ "GenSigStartValue" SG_ 256 Pressure 4660;
+BA_ "GenSigStartValue" SG_ 256 Level 7;
+BA_ "GenSigStartValue" SG_ 256 Offset -2;
+"#,
+        )
+        .unwrap();
+
+        // Switch start value selects Pressure (big-endian 0x1234); Level is skipped
+        let payload = dbc.initial_payload(256).unwrap();
+        assert!(payload.iter().eq([0x01, 0x12, 0x34, 0x00, 0xFE, 0x00, 0x00, 0x00].iter()));
+
+        // No start values: zeroed payload of the message length
+        let payload = dbc.initial_payload(512).unwrap();
+        assert!(payload.iter().eq([0x00, 0x00].iter()));
+
+        assert!(dbc.initial_payload(0x999).is_none());
+    }
+
     #[cfg(feature = "embedded-can")]
     mod embedded_can_tests {
         use super::*;
@@ -0,0 +1,70 @@
+//! Character encodings accepted by [`Dbc::parse_bytes_with_encoding`].
+
+extern crate alloc;
+
+use crate::{Dbc, Result};
+
+/// Character encoding of a DBC file.
+///
+/// DBC files have no encoding declaration, so the encoding must be known by the caller.
+/// Legacy tools commonly wrote Latin-1, e.g. for German umlauts in comments and units.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// Strict UTF-8, as used by [`Dbc::parse_bytes`].
+    #[default]
+    Utf8,
+    /// ISO-8859-1, where every byte maps to the Unicode code point of the same value.
+    Latin1,
+}
+
+impl Dbc {
+    /// Parse a DBC file from a byte slice in the given [`Encoding`]
+    ///
+    /// Non-UTF-8 input is transcoded to UTF-8 before parsing, so all strings in the
+    /// resulting [`Dbc`] are UTF-8. [`Encoding::Utf8`] behaves like [`Dbc::parse_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, Encoding};
+    ///
+    /// // "Öltemperatur" with 'Ö' encoded as the single Latin-1 byte 0xD6
+    /// let dbc_bytes = b"VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n\nCM_ BO_ 256 \"\xD6ltemperatur\";";
+    /// let dbc = Dbc::parse_bytes_with_encoding(dbc_bytes, Encoding::Latin1)?;
+    /// let message = dbc.messages().find_by_id(256).unwrap();
+    /// assert_eq!(message.comment(), Some("Öltemperatur"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn parse_bytes_with_encoding(data: &[u8], encoding: Encoding) -> Result<Self> {
+        match encoding {
+            Encoding::Utf8 => Self::parse_bytes(data),
+            Encoding::Latin1 => {
+                let content: alloc::string::String = data.iter().map(|&b| char::from(b)).collect();
+                Self::parse(&content)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Encoding;
+    use crate::Dbc;
+
+    const LATIN1_DBC: &[u8] =
+        b"VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n SG_ Temp : 0|8@1+ (1,-40) [-40|215] \"\xB0C\" *\n";
+
+    #[test]
+    fn test_parse_bytes_with_encoding_latin1() {
+        let dbc = Dbc::parse_bytes_with_encoding(LATIN1_DBC, Encoding::Latin1).unwrap();
+        let message = dbc.messages().find_by_id(256).unwrap();
+        assert_eq!(message.signals().find("Temp").unwrap().unit(), Some("°C"));
+    }
+
+    #[test]
+    fn test_parse_bytes_with_encoding_utf8_is_strict() {
+        assert!(Dbc::parse_bytes(LATIN1_DBC).is_err());
+        assert!(Dbc::parse_bytes_with_encoding(LATIN1_DBC, Encoding::Utf8).is_err());
+        assert!(Dbc::parse_bytes_with_encoding(LATIN1_DBC, Encoding::default()).is_err());
+    }
+}
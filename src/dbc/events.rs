@@ -0,0 +1,311 @@
+use crate::{
+    BO_, BS_, BU_, BitTiming, Dbc, Error, Message, Parser, Result, SG_, Signal, VERSION, Version,
+};
+
+/// An item emitted by [`DbcEvents`] while streaming through a DBC file.
+///
+/// Names are borrowed from the input where the item is emitted as-is (nodes and message
+/// headers). Signals are emitted as parsed [`Signal`]s tagged with their message ID.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum ParseEvent<'a> {
+    /// The `VERSION` statement.
+    Version(Version),
+    /// A non-empty `BS_` bit timing section.
+    BitTiming(BitTiming),
+    /// A node from the `BU_` list, in declaration order.
+    Node(&'a str),
+    /// A `BO_` message header. Its signals follow as [`ParseEvent::Signal`] events.
+    Message {
+        /// The message ID as written in the file (bit 31 set for extended IDs).
+        id: u32,
+        /// The message name.
+        name: &'a str,
+        /// The data length code.
+        dlc: u8,
+        /// The transmitting node.
+        sender: &'a str,
+    },
+    /// A `SG_` signal of the preceding message.
+    Signal {
+        /// ID of the message the signal belongs to, as in [`ParseEvent::Message::id`].
+        message_id: u32,
+        /// The parsed signal.
+        signal: Signal,
+    },
+}
+
+/// Streaming DBC parser yielding [`ParseEvent`]s, created by [`Dbc::parse_events`].
+///
+/// Unlike [`Dbc::parse`], nothing is collected: each item is parsed when the iterator
+/// is advanced, so a caller can pick out a few messages of a very large file without
+/// storing the rest. Only the syntax of each item is checked; validation across items
+/// (senders against nodes, signal layout against the DLC, duplicate IDs) is left to
+/// [`Dbc::parse`]. Sections other than `VERSION`, `BS_`, `BU_`, `BO_` and `SG_` are
+/// skipped. After an error the iterator is exhausted.
+#[derive(Debug)]
+pub struct DbcEvents<'a> {
+    data: &'a [u8],
+    parser: Parser<'a>,
+    /// Remaining node names of the current `BU_` line
+    nodes: Option<Parser<'a>>,
+    /// ID of the message whose signals are being read
+    message_id: Option<u32>,
+    done: bool,
+}
+
+impl Dbc {
+    /// Parse a DBC file as a stream of [`ParseEvent`]s without building a [`Dbc`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty. Errors in the content are reported by the
+    /// iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, ParseEvent};
+    ///
+    /// let data = r#"VERSION "1.0"
+    ///
+    /// BU_: ECM TCM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" TCM
+    ///
+    /// BO_ 512 Brake : 8 TCM
+    ///  SG_ Pressure : 0|16@1+ (0.1,0) [0|6553.5] "bar" ECM
+    /// "#;
+    ///
+    /// // Collect only the signals of message 512
+    /// let mut brake_signals = 0;
+    /// for event in Dbc::parse_events(data)? {
+    ///     if let ParseEvent::Signal { message_id: 512, signal } = event? {
+    ///         assert_eq!(signal.name(), "Pressure");
+    ///         brake_signals += 1;
+    ///     }
+    /// }
+    /// assert_eq!(brake_signals, 1);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn parse_events(data: &str) -> Result<DbcEvents<'_>> {
+        Ok(DbcEvents {
+            data: data.as_bytes(),
+            parser: Parser::new(data.as_bytes())?,
+            nodes: None,
+            message_id: None,
+            done: false,
+        })
+    }
+}
+
+impl<'a> DbcEvents<'a> {
+    /// Create a parser over the rest of the current line, leaving `self.parser` after it.
+    fn take_line(&mut self) -> Result<Parser<'a>> {
+        let (start, line) = (self.parser.pos(), self.parser.line());
+        self.parser.skip_to_end_of_line();
+        Parser::new_at_line(&self.data[start..self.parser.pos()], line)
+    }
+
+    fn next_event(&mut self) -> Result<Option<ParseEvent<'a>>> {
+        // Emit the remaining nodes of a BU_ line one at a time
+        if let Some(nodes) = self.nodes.as_mut() {
+            nodes.skip_whitespace_optional();
+            if let Ok(node) = nodes.parse_identifier() {
+                return Ok(Some(ParseEvent::Node(node)));
+            }
+            self.nodes = None;
+        }
+
+        loop {
+            // Skip comments (lines starting with //)
+            self.parser.skip_newlines_and_spaces();
+            if self.parser.starts_with(b"//") {
+                self.parser.skip_to_end_of_line();
+                continue;
+            }
+
+            let keyword = match self.parser.peek_next_keyword() {
+                Ok(keyword) => keyword,
+                Err(Error::UnexpectedEof { .. }) => return Ok(None),
+                Err(e) => return Err(e),
+            };
+            if keyword != SG_ {
+                self.message_id = None;
+            }
+
+            match keyword {
+                VERSION => {
+                    return Version::parse(&mut self.parser).map(|v| Some(ParseEvent::Version(v)));
+                }
+                BS_ => {
+                    let bit_timing = BitTiming::parse(&mut self.parser)?;
+                    self.parser.skip_to_end_of_line();
+                    if !bit_timing.is_empty() {
+                        return Ok(Some(ParseEvent::BitTiming(bit_timing)));
+                    }
+                }
+                BU_ => {
+                    let mut nodes = self.take_line()?;
+                    nodes.expect(BU_.as_bytes())?;
+                    nodes.expect_with_msg(b":", "Expected ':' after BU_")?;
+                    self.nodes = Some(nodes);
+                    if let Some(event) = self.next_event()? {
+                        return Ok(Some(event));
+                    }
+                }
+                BO_ => {
+                    let mut header = self.take_line()?;
+                    let (id, name, dlc, sender) = Message::parse_header(&mut header)?;
+                    Message::validate(id, name, dlc, sender, &[]).map_err(|e| {
+                        crate::error::map_val_error_with_line(
+                            e,
+                            |msg| header.err_message(msg),
+                            || header.err_message(Error::MESSAGE_ERROR_PREFIX),
+                        )
+                    })?;
+                    self.message_id = Some(id);
+                    return Ok(Some(ParseEvent::Message {
+                        id,
+                        name,
+                        dlc,
+                        sender,
+                    }));
+                }
+                SG_ => {
+                    // Orphaned signals (not inside a message) are skipped, as by Dbc::parse
+                    let Some(message_id) = self.message_id else {
+                        self.parser.skip_to_end_of_line();
+                        continue;
+                    };
+                    match Signal::parse(&mut self.parser) {
+                        Ok(signal) => {
+                            self.parser.skip_trailing_comment();
+                            if self.parser.at_newline() {
+                                self.parser.skip_to_end_of_line();
+                            }
+                            return Ok(Some(ParseEvent::Signal { message_id, signal }));
+                        }
+                        Err(_) => {
+                            // Malformed signals end the message, as in Dbc::parse
+                            self.parser.skip_to_end_of_line();
+                            self.message_id = None;
+                        }
+                    }
+                }
+                _ => self.parser.skip_to_end_of_line(),
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for DbcEvents<'a> {
+    type Item = Result<ParseEvent<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let event = self.next_event().transpose();
+        if !matches!(event, Some(Ok(_))) {
+            self.done = true;
+        }
+        event
+    }
+}
+
+impl core::iter::FusedIterator for DbcEvents<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseEvent;
+    use crate::{ByteOrder, Dbc, Error};
+
+    const DATA: &str = r#"VERSION "2.0"
+
+NS_ :
+    CM_
+    BA_
+
+BS_: 500000 : 1,2
+
+BU_: ECM TCM
+
+// Engine message
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" TCM
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" TCM
+
+BO_ 2147484672 Diag : 8 TCM
+ SG_ Service : 7|8@0+ (1,0) [0|255] "" ECM
+
+CM_ BO_ 256 "Engine data";
+SG_ Orphan : 0|8@1+ (1,0) [0|255] "" ECM
+"#;
+
+    #[test]
+    fn test_parse_events_order() {
+        let mut events = Dbc::parse_events(DATA).unwrap();
+
+        match events.next() {
+            Some(Ok(ParseEvent::Version(version))) => assert_eq!(version.as_str(), "2.0"),
+            other => panic!("expected version, got {other:?}"),
+        }
+        match events.next() {
+            Some(Ok(ParseEvent::BitTiming(bit_timing))) => {
+                assert_eq!(bit_timing.baudrate(), Some(500000))
+            }
+            other => panic!("expected bit timing, got {other:?}"),
+        }
+        assert_eq!(events.next(), Some(Ok(ParseEvent::Node("ECM"))));
+        assert_eq!(events.next(), Some(Ok(ParseEvent::Node("TCM"))));
+        assert_eq!(
+            events.next(),
+            Some(Ok(ParseEvent::Message {
+                id: 256,
+                name: "Engine",
+                dlc: 8,
+                sender: "ECM"
+            }))
+        );
+        for expected in ["RPM", "Temp"] {
+            match events.next() {
+                Some(Ok(ParseEvent::Signal { message_id, signal })) => {
+                    assert_eq!(message_id, 256);
+                    assert_eq!(signal.name(), expected);
+                }
+                other => panic!("expected signal, got {other:?}"),
+            }
+        }
+        assert!(matches!(
+            events.next(),
+            Some(Ok(ParseEvent::Message {
+                id: 2147484672,
+                name: "Diag",
+                ..
+            }))
+        ));
+        match events.next() {
+            Some(Ok(ParseEvent::Signal { message_id, signal })) => {
+                assert_eq!(message_id, 2147484672);
+                assert_eq!(signal.byte_order(), ByteOrder::BigEndian);
+            }
+            other => panic!("expected signal, got {other:?}"),
+        }
+        // The orphaned signal is skipped
+        assert_eq!(events.next(), None);
+        assert_eq!(events.next(), None);
+    }
+
+    #[test]
+    fn test_parse_events_error_ends_stream() {
+        let data = "BU_: ECM\n\nBO_ 256 Engine : 99 ECM\n SG_ A : 0|8@1+ (1,0) [0|255] \"\" *\n";
+        let mut events = Dbc::parse_events(data).unwrap();
+        assert_eq!(events.next(), Some(Ok(ParseEvent::Node("ECM"))));
+        assert!(matches!(events.next(), Some(Err(Error::Message { .. }))));
+        assert_eq!(events.next(), None);
+
+        assert!(Dbc::parse_events("").is_err());
+    }
+}
@@ -1,11 +1,16 @@
 #[cfg(feature = "attributes")]
 use super::{AttributeDefaultsMap, AttributeDefinitionsMap, AttributeValuesMap};
-use super::{ExtMuxIndex, ExtendedMultiplexings, Messages, ValueDescriptionsMap};
+use super::{
+    EnvironmentVariables, ExtMuxIndex, ExtendedMultiplexings, Messages, SignalGroups,
+    SignalTypeRefs, SignalTypes, ValueDescriptionsMap,
+};
 #[cfg(feature = "attributes")]
-use crate::{AttributeDefinition, AttributeValue};
+use crate::{AttributeDefinition, AttributeObjectType, AttributeTarget, AttributeValue};
 use crate::{
-    BitTiming, Dbc, ExtendedMultiplexing, Nodes, ValueDescriptions, Version, compat::Comment,
+    BitTiming, Dbc, EnvironmentVariable, ExtendedMultiplexing, Message, Nodes, SignalGroup,
+    SignalType, SignalTypeRef, ValueDescriptions, Version, compat::Comment,
 };
+use core::ops::RangeInclusive;
 
 impl Dbc {
     #[cfg(feature = "attributes")]
@@ -18,6 +23,10 @@ impl Dbc {
         value_descriptions: ValueDescriptionsMap,
         extended_multiplexing: ExtendedMultiplexings,
         comment: Option<Comment>,
+        signal_types: SignalTypes,
+        signal_type_refs: SignalTypeRefs,
+        signal_groups: SignalGroups,
+        environment_variables: EnvironmentVariables,
         attribute_definitions: AttributeDefinitionsMap,
         attribute_defaults: AttributeDefaultsMap,
         attribute_values: AttributeValuesMap,
@@ -34,6 +43,10 @@ impl Dbc {
             extended_multiplexing,
             ext_mux_index,
             comment,
+            signal_types,
+            signal_type_refs,
+            signal_groups,
+            environment_variables,
             attribute_definitions,
             attribute_defaults,
             attribute_values,
@@ -41,6 +54,7 @@ impl Dbc {
     }
 
     #[cfg(not(feature = "attributes"))]
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         version: Option<Version>,
         bit_timing: Option<BitTiming>,
@@ -49,6 +63,10 @@ impl Dbc {
         value_descriptions: ValueDescriptionsMap,
         extended_multiplexing: ExtendedMultiplexings,
         comment: Option<Comment>,
+        signal_types: SignalTypes,
+        signal_type_refs: SignalTypeRefs,
+        signal_groups: SignalGroups,
+        environment_variables: EnvironmentVariables,
     ) -> Self {
         // Build index for fast extended multiplexing lookup
         let ext_mux_index = ExtMuxIndex::build(extended_multiplexing.as_slice());
@@ -62,6 +80,10 @@ impl Dbc {
             extended_multiplexing,
             ext_mux_index,
             comment,
+            signal_types,
+            signal_type_refs,
+            signal_groups,
+            environment_variables,
         }
     }
 
@@ -152,6 +174,69 @@ impl Dbc {
         &self.messages
     }
 
+    /// Find a message by name.
+    ///
+    /// Shorthand for `messages().find(name)`, which looks the name up in an index built
+    /// when the database is parsed or built. Message names are normally unique; if several
+    /// messages share a name, the first one in definition order is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    /// BO_ 512 Brake : 8 ECM
+    /// "#)?;
+    ///
+    /// assert_eq!(dbc.message_by_name("Brake").map(|m| m.id()), Some(512));
+    /// assert!(dbc.message_by_name("Body").is_none());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn message_by_name(&self, name: &str) -> Option<&Message> {
+        self.messages.find(name)
+    }
+
+    /// Get the messages whose IDs fall within `range`, in ascending ID order.
+    ///
+    /// IDs follow the DBC file convention used by `messages().find_by_id()`: extended IDs
+    /// carry bit 31 (`0x80000000`), so a standard range such as `0x000..=0x7FF` never
+    /// matches extended messages. The lookup uses the sorted message index, which makes
+    /// this suitable for splitting a large database into address blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 768 Brake : 8 ECM
+    /// BO_ 256 Engine : 8 ECM
+    /// BO_ 1024 Body : 8 ECM
+    /// "#)?;
+    ///
+    /// let names: Vec<_> = dbc.messages_in_id_range(0x100..=0x300).map(|m| m.name()).collect();
+    /// assert_eq!(names, ["Engine", "Brake"]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "iterator is lazy and does nothing unless consumed"]
+    pub fn messages_in_id_range(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> impl Iterator<Item = &Message> + '_ {
+        self.messages.in_id_range(range)
+    }
+
     /// Get value descriptions for a specific signal
     ///
     /// Value descriptions map numeric signal values to human-readable text.
@@ -282,6 +367,59 @@ impl Dbc {
             .filter(move |ext_mux| ext_mux.message_id() == message_id)
     }
 
+    /// Get extended multiplexing entries for a specific signal
+    ///
+    /// Returns the SG_MUL_VAL_ entries controlling `signal_name` in the given message,
+    /// in file order. A signal may have one entry per multiplexer switch it depends on.
+    /// Together with [`ExtendedMultiplexing::applies_to`] this allows evaluating the
+    /// activation rules without reimplementing the range checks.
+    ///
+    /// # Performance
+    ///
+    /// Uses the pre-built extended multiplexing index (O(log n) lookup, zero allocation).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 500 ComplexMux : 8 ECM
+    ///  SG_ Mux1 M : 0|8@1+ (1,0) [0|255] ""
+    ///  SG_ Mux2 M : 8|8@1+ (1,0) [0|255] ""
+    ///  SG_ Signal_A m0 : 16|16@1+ (0.1,0) [0|100] ""
+    ///
+    /// SG_MUL_VAL_ 500 Signal_A Mux1 0-5,10-15 ;
+    /// SG_MUL_VAL_ 500 Signal_A Mux2 20-25 ;
+    /// "#)?;
+    ///
+    /// // Signal_A is active only when every referenced switch matches
+    /// let switches = [("Mux1", 12), ("Mux2", 22)];
+    /// let active = dbc.ext_mux_entries_for_signal(500, "Signal_A").all(|entry| {
+    ///     switches
+    ///         .iter()
+    ///         .any(|&(name, value)| name == entry.multiplexer_switch() && entry.applies_to(value))
+    /// });
+    /// assert!(active);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "iterator is lazy and does nothing unless consumed"]
+    pub fn ext_mux_entries_for_signal(
+        &self,
+        message_id: u32,
+        signal_name: &str,
+    ) -> impl Iterator<Item = &ExtendedMultiplexing> + '_ {
+        self.ext_mux_index
+            .get(message_id, signal_name)
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(|&idx| self.extended_multiplexing.get(idx))
+    }
+
     /// Returns the database-level comment from CM_ (general comment), if present.
     ///
     /// This is the general comment for the entire DBC file, not associated with
@@ -330,6 +468,196 @@ impl Dbc {
     pub fn node_comment(&self, node_name: &str) -> Option<&str> {
         self.nodes.node_comment(node_name)
     }
+
+    /// Get all signal types (SGTYPE_ entries).
+    ///
+    /// Value descriptions declared via `SGTYPE_VAL_` are available on each
+    /// [`SignalType`] through [`SignalType::value_descriptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// SGTYPE_ TempType : 8@1- (1,-40) [-40|215] "degC" 0, TempTable;
+    /// "#)?;
+    /// assert_eq!(dbc.signal_types().len(), 1);
+    /// assert!(dbc.signal_type("TempType").is_some());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn signal_types(&self) -> &[SignalType] {
+        self.signal_types.as_slice()
+    }
+
+    /// Get a signal type by name.
+    #[must_use = "return value should be used"]
+    pub fn signal_type(&self, name: &str) -> Option<&SignalType> {
+        self.signal_types.iter().find(|t| t.name() == name)
+    }
+
+    /// Get all signal type references (SIG_TYPE_REF_ entries).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ Temp : 0|8@1- (1,-40) [-40|215] "degC" *
+    ///
+    /// SGTYPE_ TempType : 8@1- (1,-40) [-40|215] "degC" 0, TempTable;
+    ///
+    /// SIG_TYPE_REF_ 256 Temp : TempType;
+    /// "#)?;
+    /// let type_ref = &dbc.signal_type_refs()[0];
+    /// assert_eq!(dbc.signal_type(type_ref.type_name()).unwrap().size(), 8);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn signal_type_refs(&self) -> &[SignalTypeRef] {
+        self.signal_type_refs.as_slice()
+    }
+
+    /// Get all signal groups (SIG_GROUP_ entries).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///
+    /// SIG_GROUP_ 256 EngineGroup 1 : RPM;
+    /// "#)?;
+    /// assert_eq!(dbc.signal_groups().len(), 1);
+    /// assert_eq!(dbc.signal_groups()[0].name(), "EngineGroup");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn signal_groups(&self) -> &[SignalGroup] {
+        self.signal_groups.as_slice()
+    }
+
+    /// Get the signal groups (SIG_GROUP_) of a message, in file order
+    ///
+    /// `message_id` includes the extended ID flag for 29-bit IDs, as written in the DBC file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///  SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" *
+    ///
+    /// SIG_GROUP_ 256 Speed 1 : RPM;
+    /// SIG_GROUP_ 256 Thermal 1 : Temp;
+    /// "#)?;
+    /// let names: Vec<&str> = dbc.signal_groups_for_message(256).map(|g| g.name()).collect();
+    /// assert_eq!(names, ["Speed", "Thermal"]);
+    /// assert_eq!(dbc.signal_groups_for_message(512).count(), 0);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "iterator is lazy and does nothing unless consumed"]
+    pub fn signal_groups_for_message(&self, id: u32) -> impl Iterator<Item = &SignalGroup> + '_ {
+        self.signal_groups.iter().filter(move |group| group.message_id() == id)
+    }
+
+    /// Get the signal group (SIG_GROUP_) with the given name in a message
+    ///
+    /// `message_id` includes the extended ID flag for 29-bit IDs, as written in the DBC file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///
+    /// SIG_GROUP_ 256 EngineGroup 1 : RPM;
+    /// "#)?;
+    /// assert!(dbc.signal_group(256, "EngineGroup").unwrap().contains("RPM"));
+    /// assert!(dbc.signal_group(256, "Missing").is_none());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn signal_group(&self, message_id: u32, name: &str) -> Option<&SignalGroup> {
+        self.signal_groups
+            .iter()
+            .find(|group| group.message_id() == message_id && group.name() == name)
+    }
+
+    /// Get all environment variables (EV_ entries), in file order
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// EV_ EnvKL15 : 0 [0|1] "" 0 1 DUMMY_NODE_VECTOR3 ECM;
+    /// EV_ EnvSpeed : 1 [0|250] "km/h" 0 2 DUMMY_NODE_VECTOR1 ECM;
+    /// "#)?;
+    /// let names: Vec<&str> = dbc.environment_variables().map(|ev| ev.name()).collect();
+    /// assert_eq!(names, ["EnvKL15", "EnvSpeed"]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "iterator is lazy and does nothing unless consumed"]
+    pub fn environment_variables(&self) -> impl Iterator<Item = &EnvironmentVariable> + '_ {
+        self.environment_variables.iter()
+    }
+
+    /// Get the environment variable (EV_) with the given name
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// EV_ EnvSpeed : 1 [0|250] "km/h" 0 2 DUMMY_NODE_VECTOR1 ECM;
+    /// "#)?;
+    /// assert_eq!(dbc.environment_variable("EnvSpeed").unwrap().unit(), Some("km/h"));
+    /// assert!(dbc.environment_variable("Missing").is_none());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn environment_variable(&self, name: &str) -> Option<&EnvironmentVariable> {
+        self.environment_variables.iter().find(|env_var| env_var.name() == name)
+    }
 }
 
 // ============================================================================
@@ -451,6 +779,29 @@ impl Dbc {
     }
 
     /// Get a signal attribute value by message ID, signal name, and attribute name.
+    ///
+    /// Looks up values assigned with `BA_ "<name>" SG_ <message_id> <signal> <value>;`.
+    /// The message ID is matched as written in the file (including the extended ID flag).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ Temp : 0|8@1+ (1,-40) [-40|215] "degC" *
+    ///
+    /// BA_DEF_ SG_ "GenSigStartValue" FLOAT 0 100000;
+    /// BA_DEF_DEF_ "GenSigStartValue" 0.0;
+    /// BA_ "GenSigStartValue" SG_ 256 Temp 40.0;"#)?;
+    /// let value = dbc.signal_attribute(256, "Temp", "GenSigStartValue");
+    /// assert_eq!(value.and_then(|v| v.as_float()), Some(40.0));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
     #[inline]
     #[must_use = "return value should be used"]
     pub fn signal_attribute(
@@ -508,6 +859,131 @@ impl Dbc {
         self.signal_attribute(message_id, signal_name, attr_name)
             .or_else(|| self.attribute_default(attr_name))
     }
+
+    /// Get the value of attribute `name` for `target`, falling back to its default.
+    ///
+    /// Generalizes the `*_attribute_or_default` methods: the value assigned with `BA_` is
+    /// returned if present, otherwise the `BA_DEF_DEF_` default, provided the `BA_DEF_`
+    /// definition applies to the kind of object `target` refers to. Message IDs in
+    /// `target` are matched as written in the file (including the extended ID flag).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{AttributeTarget, Dbc};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    /// BO_ 512 Brake : 8 ECM
+    ///
+    /// BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+    /// BA_DEF_DEF_ "GenMsgCycleTime" 100;
+    /// BA_ "GenMsgCycleTime" BO_ 256 10;"#)?;
+    ///
+    /// let cycle_time = |id| dbc.attribute_value(&AttributeTarget::Message(id), "GenMsgCycleTime");
+    /// assert_eq!(cycle_time(256).and_then(|v| v.as_int()), Some(10));
+    /// assert_eq!(cycle_time(512).and_then(|v| v.as_int()), Some(100));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn attribute_value(&self, target: &AttributeTarget, name: &str) -> Option<&AttributeValue> {
+        if let Some(value) = self.attribute_values.get(name, target) {
+            return Some(value);
+        }
+        let object_type = match target {
+            AttributeTarget::Network => AttributeObjectType::Network,
+            AttributeTarget::Node(_) => AttributeObjectType::Node,
+            AttributeTarget::Message(_) => AttributeObjectType::Message,
+            AttributeTarget::Signal(..) => AttributeObjectType::Signal,
+        };
+        if self.attribute_definition(name)?.object_type() != object_type {
+            return None;
+        }
+        self.attribute_default(name)
+    }
+
+    /// Get the label of enum attribute `name` for `target`, falling back to its default.
+    ///
+    /// `BA_` assignments of enum attributes hold the index of the label, while defaults
+    /// usually spell out the label itself; both are resolved against the `ENUM` values of
+    /// the definition. Returns `None` if the attribute is not an enum, has no value, or the
+    /// value is not one of its labels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{AttributeTarget, Dbc};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    /// BO_ 512 Brake : 8 ECM
+    ///
+    /// BA_DEF_ BO_ "GenMsgSendType" ENUM "Cyclic","Spontaneous","IfActive";
+    /// BA_DEF_DEF_ "GenMsgSendType" "Cyclic";
+    /// BA_ "GenMsgSendType" BO_ 512 1;"#)?;
+    ///
+    /// let send_type = |id| dbc.attribute_enum_label(&AttributeTarget::Message(id), "GenMsgSendType");
+    /// assert_eq!(send_type(256), Some("Cyclic"));
+    /// assert_eq!(send_type(512), Some("Spontaneous"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn attribute_enum_label(&self, target: &AttributeTarget, name: &str) -> Option<&str> {
+        let value_type = self.attribute_definition(name)?.value_type();
+        let labels = value_type.enum_values()?;
+        match self.attribute_value(target, name)? {
+            AttributeValue::Int(index) => value_type.enum_label(*index),
+            AttributeValue::String(label) => {
+                labels.iter().map(|l| l.as_str()).find(|l| *l == label.as_str())
+            }
+            AttributeValue::Float(_) => None,
+        }
+    }
+
+    /// Get the cycle time of a message in milliseconds (`GenMsgCycleTime` attribute).
+    ///
+    /// Falls back to the attribute default. Returns `None` if no message has this ID, or
+    /// the attribute is not defined or not an integer. `id` is matched as written in the
+    /// file (including the extended ID flag).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///
+    /// BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+    /// BA_ "GenMsgCycleTime" BO_ 256 20;"#)?;
+    ///
+    /// assert_eq!(dbc.message_cycle_time(256), Some(20));
+    /// assert_eq!(dbc.message_cycle_time(512), None);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn message_cycle_time(&self, id: u32) -> Option<i64> {
+        self.messages().find_by_id(id)?;
+        self.attribute_value(&AttributeTarget::Message(id), "GenMsgCycleTime")?.as_int()
+    }
+}
+
+impl AsRef<Dbc> for Dbc {
+    #[inline]
+    fn as_ref(&self) -> &Dbc {
+        self
+    }
 }
 
 #[cfg(test)]
@@ -542,6 +1018,146 @@ SG_MUL_VAL_ 500 Signal_A Mux1 5-10 ;
         assert_eq!(ext_entries[0].value_ranges(), [(5, 10)]);
     }
 
+    #[test]
+    fn test_extended_multiplexing_across_messages() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 500 ComplexMux : 8 ECM
+ SG_ Mux1 M : 0|8@1+ (1,0) [0|255] ""
+ SG_ Signal_A m0 : 16|16@1+ (0.1,0) [0|100] "unit" *
+ SG_ Signal_B m1 : 16|16@1+ (0.1,0) [0|100] "unit" *
+
+BO_ 2147484160 ExtendedMux : 8 ECM
+ SG_ Mux2 M : 0|8@1+ (1,0) [0|255] ""
+ SG_ Signal_C m0 : 8|8@1+ (1,0) [0|255] "" *
+
+BO_ 600 Plain : 8 ECM
+ SG_ Value : 0|8@1+ (1,0) [0|255] "" *
+
+SG_MUL_VAL_ 500 Signal_A Mux1 0-5 ;
+SG_MUL_VAL_ 2147484160 Signal_C Mux2 1-1 ;
+SG_MUL_VAL_ 500 Signal_B Mux1 6-10 ;
+"#,
+        )
+        .unwrap();
+
+        // The slice spans all messages, in file order
+        let all = dbc.extended_multiplexing();
+        assert_eq!(all.len(), 3);
+        assert!(all.iter().map(|ext_mux| ext_mux.message_id()).eq([500, 0x8000_0200, 500]));
+        assert_eq!(dbc.extended_multiplexing_for_message(500).count(), 2);
+        assert_eq!(dbc.extended_multiplexing_for_message(600).count(), 0);
+    }
+
+    #[test]
+    fn test_message_by_name() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 768 Zeta : 8 ECM
+BO_ 256 Alpha : 8 ECM
+BO_ 512 Dup : 8 ECM
+BO_ 1024 Mid : 8 ECM
+BO_ 2048 Dup : 8 ECM
+BO_ 128 alpha : 8 ECM
+"#,
+        )
+        .unwrap();
+
+        for (name, id) in [("Zeta", 768), ("Alpha", 256), ("Mid", 1024), ("alpha", 128)] {
+            assert_eq!(dbc.message_by_name(name).map(|m| m.id()), Some(id));
+        }
+        // Duplicate names resolve to the first definition
+        assert_eq!(dbc.message_by_name("Dup").map(|m| m.id()), Some(512));
+        assert_eq!(dbc.messages().find("Dup").map(|m| m.id()), Some(512));
+        for missing in ["", "ALPHA", "Du", "Dupe", "Zz"] {
+            assert!(dbc.message_by_name(missing).is_none());
+        }
+
+        let empty = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n").unwrap();
+        assert!(empty.message_by_name("Alpha").is_none());
+    }
+
+    #[test]
+    fn test_messages_in_id_range() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 1792 Diag : 8 ECM
+BO_ 256 Engine : 8 ECM
+BO_ 2147483904 EngineExt : 8 ECM
+BO_ 2047 Last : 8 ECM
+BO_ 0 First : 8 ECM
+BO_ 512 Brake : 8 ECM
+BO_ 2566848512 J1939 : 8 ECM
+"#,
+        )
+        .unwrap();
+
+        let ids = |lo: u32, hi: u32| dbc.messages_in_id_range(lo..=hi).map(|m| m.id_with_flag());
+
+        // Inclusive on both ends, ascending regardless of file order
+        assert!(ids(0x000, 0x7FF).eq([0, 256, 512, 1792, 2047]));
+        assert!(ids(0x100, 0x200).eq([256, 512]));
+        assert!(ids(0x101, 0x1FF).eq([]));
+
+        // Extended IDs carry bit 31
+        assert!(ids(0x8000_0000, 0x9FFF_FFFF).eq([0x8000_0100, 0x98FF_0000]));
+        assert_eq!(dbc.messages_in_id_range(0..=u32::MAX).count(), 7);
+    }
+
+    #[test]
+    fn test_ext_mux_entries_for_signal() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 500 ComplexMux : 8 ECM
+ SG_ Mux1 M : 0|8@1+ (1,0) [0|255] ""
+ SG_ Mux2 M : 8|8@1+ (1,0) [0|255] ""
+ SG_ Signal_A m0 : 16|16@1+ (0.1,0) [0|100] "unit" *
+ SG_ Signal_B m0 : 32|16@1+ (1,0) [0|100] "unit" *
+
+SG_MUL_VAL_ 500 Signal_A Mux1 0-5,10-15,200-200 ;
+SG_MUL_VAL_ 500 Signal_B Mux1 7-7 ;
+SG_MUL_VAL_ 500 Signal_A Mux2 20-25 ;
+"#,
+        )
+        .unwrap();
+
+        let mut entries = dbc.ext_mux_entries_for_signal(500, "Signal_A");
+        let mux1 = entries.next().unwrap();
+        let mux2 = entries.next().unwrap();
+        assert!(entries.next().is_none());
+
+        assert_eq!(mux1.multiplexer_switch(), "Mux1");
+        assert!(mux1.applies_to(0));
+        assert!(mux1.applies_to(5));
+        assert!(!mux1.applies_to(6));
+        assert!(!mux1.applies_to(9));
+        assert!(mux1.applies_to(10));
+        assert!(mux1.applies_to(15));
+        assert!(!mux1.applies_to(16));
+        assert!(mux1.applies_to(200));
+        assert!(!mux1.applies_to(201));
+
+        assert_eq!(mux2.multiplexer_switch(), "Mux2");
+        assert!(mux2.applies_to(22));
+        assert!(!mux2.applies_to(3));
+
+        assert_eq!(dbc.ext_mux_entries_for_signal(500, "Signal_B").count(), 1);
+        assert_eq!(dbc.ext_mux_entries_for_signal(500, "Mux1").count(), 0);
+        assert_eq!(dbc.ext_mux_entries_for_signal(501, "Signal_A").count(), 0);
+    }
+
     #[test]
     fn test_version() {
         let dbc = Dbc::parse(
@@ -556,6 +1172,79 @@ BO_ 256 Engine : 8 ECM
         assert_eq!(dbc.version().map(|v| v.as_str()), Some("1.0"));
     }
 
+    #[cfg(feature = "attributes")]
+    #[test]
+    fn test_attribute_value_queries() {
+        use crate::AttributeTarget;
+
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM TCM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+BO_ 512 Brake : 8 ECM
+BO_ 2147484672 Diag : 8 TCM
+
+BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+BA_DEF_ BO_ "GenMsgSendType" ENUM "Cyclic","Spontaneous","IfActive";
+BA_DEF_ BU_ "NodeLayerModules" STRING;
+BA_DEF_ SG_ "GenSigStartValue" FLOAT 0 100000;
+BA_DEF_DEF_ "GenMsgCycleTime" 100;
+BA_DEF_DEF_ "GenMsgSendType" "Cyclic";
+BA_ "GenMsgCycleTime" BO_ 256 10;
+BA_ "GenMsgCycleTime" BO_ 2147484672 1000;
+BA_ "GenMsgSendType" BO_ 512 2;
+BA_ "GenMsgSendType" BO_ 2147484672 7;
+BA_ "NodeLayerModules" BU_ ECM "CANoeILNLVector.dll";
+BA_ "GenSigStartValue" SG_ 256 RPM 400.0;
+"#,
+        )
+        .unwrap();
+
+        // Assigned values, defaults, and nothing for attributes without either
+        let ecm = AttributeTarget::Node("ECM".try_into().unwrap());
+        let tcm = AttributeTarget::Node("TCM".try_into().unwrap());
+        let rpm = AttributeTarget::Signal(256, "RPM".try_into().unwrap());
+        assert_eq!(
+            dbc.attribute_value(&ecm, "NodeLayerModules").and_then(|v| v.as_str()),
+            Some("CANoeILNLVector.dll")
+        );
+        assert_eq!(dbc.attribute_value(&tcm, "NodeLayerModules"), None);
+        assert_eq!(
+            dbc.attribute_value(&rpm, "GenSigStartValue").and_then(|v| v.as_float()),
+            Some(400.0)
+        );
+        assert_eq!(
+            dbc.attribute_value(&AttributeTarget::Network, "Missing"),
+            None
+        );
+
+        assert_eq!(dbc.message_cycle_time(256), Some(10));
+        assert_eq!(dbc.message_cycle_time(512), Some(100));
+        assert_eq!(dbc.message_cycle_time(2147484672), Some(1000));
+        assert_eq!(dbc.message_cycle_time(1024), None);
+
+        // Defaults only apply to the object type of the definition
+        assert_eq!(dbc.attribute_value(&ecm, "GenMsgCycleTime"), None);
+        assert_eq!(
+            dbc.attribute_value(&AttributeTarget::Network, "GenMsgSendType"),
+            None
+        );
+
+        // Enum indices and spelled-out defaults resolve to labels
+        let send_type =
+            |id| dbc.attribute_enum_label(&AttributeTarget::Message(id), "GenMsgSendType");
+        assert_eq!(send_type(256), Some("Cyclic"));
+        assert_eq!(send_type(512), Some("IfActive"));
+        assert_eq!(send_type(2147484672), None);
+        assert_eq!(
+            dbc.attribute_enum_label(&AttributeTarget::Message(256), "GenMsgCycleTime"),
+            None
+        );
+    }
+
     #[test]
     fn test_nodes() {
         let dbc = Dbc::parse(
@@ -0,0 +1,302 @@
+//! Kayak CAN Definition (KCD) export.
+
+use crate::{ByteOrder, Dbc, Message, Signal, SignalValueType};
+use std::fmt::Write;
+
+/// KCD schema namespace.
+const KCD_NAMESPACE: &str = "http://kayak.2codeornot2code.org/1.0";
+
+/// Name of the single bus the messages are exported to.
+const BUS_NAME: &str = "CAN";
+
+/// Indenting XML writer producing one element per line, with escaped attributes.
+struct XmlWriter {
+    out: String,
+    depth: usize,
+}
+
+impl XmlWriter {
+    fn new() -> Self {
+        let mut out = String::with_capacity(4096);
+        out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        Self { out, depth: 0 }
+    }
+
+    fn start(&mut self, tag: &str, attributes: &[(&str, &str)]) {
+        for _ in 0..self.depth {
+            self.out.push_str("  ");
+        }
+        self.out.push('<');
+        self.out.push_str(tag);
+        for (name, value) in attributes {
+            let _ = write!(self.out, " {name}=\"");
+            escape_into(&mut self.out, value);
+            self.out.push('"');
+        }
+    }
+
+    fn open(&mut self, tag: &str, attributes: &[(&str, &str)]) {
+        self.start(tag, attributes);
+        self.out.push_str(">\n");
+        self.depth += 1;
+    }
+
+    fn close(&mut self, tag: &str) {
+        self.depth -= 1;
+        for _ in 0..self.depth {
+            self.out.push_str("  ");
+        }
+        let _ = writeln!(self.out, "</{tag}>");
+    }
+
+    fn empty(&mut self, tag: &str, attributes: &[(&str, &str)]) {
+        self.start(tag, attributes);
+        self.out.push_str("/>\n");
+    }
+
+    fn text(&mut self, tag: &str, text: &str) {
+        self.start(tag, &[]);
+        self.out.push('>');
+        escape_into(&mut self.out, text);
+        let _ = writeln!(self.out, "</{tag}>");
+    }
+}
+
+/// Append `text` with the XML special characters escaped.
+fn escape_into(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+impl Dbc {
+    /// Export the database as a Kayak CAN Definition (KCD) XML document.
+    ///
+    /// The mapping follows the KCD schema used by Kayak, SocketCAN tools and cantools:
+    /// - each node of `BU_` becomes a `<Node>`, numbered from 1 in declaration order
+    /// - each message becomes a `<Message>` on a single `<Bus>`, with its sender as
+    ///   `<Producer>`; extended IDs set `format="extended"`
+    /// - each signal becomes a `<Signal>` with offset, length and endianness, its
+    ///   receivers as `<Consumer>`, a `<Value>` carrying slope (factor), intercept
+    ///   (offset), unit and range, and its `VAL_` descriptions as a `<LabelSet>`
+    /// - message and signal comments become `<Notes>`
+    ///
+    /// Messages with a single multiplexer switch export it as a `<Multiplex>` with one
+    /// `<MuxGroup>` per `mN` value. Extended multiplexing (`SG_MUL_VAL_`) has no KCD
+    /// equivalent; such messages are exported with flat signals. Big-endian offsets are
+    /// converted to KCD's bit numbering, which counts bits from the most significant bit
+    /// of each byte. Attributes and the `VECTOR__INDEPENDENT_SIG_MSG` pseudo-message are not
+    /// exported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let kcd = dbc.to_kcd();
+    /// assert!(kcd.contains("<Message id=\"0x100\" name=\"Engine\" length=\"8\">"));
+    /// assert!(kcd.contains("<Signal name=\"RPM\" offset=\"0\" length=\"16\">"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn to_kcd(&self) -> String {
+        let mut w = XmlWriter::new();
+        w.open(
+            "NetworkDefinition",
+            &[
+                ("xmlns", KCD_NAMESPACE),
+                ("xmlns:xsi", "http://www.w3.org/2001/XMLSchema-instance"),
+            ],
+        );
+
+        let version = self.version().map(|v| v.as_str()).unwrap_or_default();
+        if version.is_empty() {
+            w.empty("Document", &[]);
+        } else {
+            w.empty("Document", &[("version", version)]);
+        }
+
+        for (index, node) in self.nodes().iter().enumerate() {
+            let id = (index + 1).to_string();
+            w.empty("Node", &[("id", &id), ("name", node)]);
+        }
+
+        let baudrate = self
+            .bit_timing()
+            .and_then(|bit_timing| bit_timing.baudrate())
+            .map(|kbps| (u64::from(kbps) * 1000).to_string());
+        match &baudrate {
+            Some(baudrate) => w.open("Bus", &[("name", BUS_NAME), ("baudrate", baudrate)]),
+            None => w.open("Bus", &[("name", BUS_NAME)]),
+        }
+        for message in self.messages().iter() {
+            if message.id_with_flag() != Message::PSEUDO_MESSAGE_ID {
+                self.write_kcd_message(&mut w, message);
+            }
+        }
+        w.close("Bus");
+
+        w.close("NetworkDefinition");
+        w.out
+    }
+
+    fn write_kcd_message(&self, w: &mut XmlWriter, message: &Message) {
+        let id = format!("{:#X}", message.id());
+        let length = message.dlc().to_string();
+        let mut attributes = vec![
+            ("id", id.as_str()),
+            ("name", message.name()),
+            ("length", length.as_str()),
+        ];
+        if message.is_extended() {
+            attributes.push(("format", "extended"));
+        }
+        w.open("Message", &attributes);
+
+        if let Some(comment) = message.comment() {
+            w.text("Notes", comment);
+        }
+        if let Some(producer) = self.kcd_node_id(message.sender()) {
+            w.open("Producer", &[]);
+            w.empty("NodeRef", &[("id", &producer)]);
+            w.close("Producer");
+        }
+
+        let signals = message.signals();
+        let switches = signals.iter().filter(|s| s.is_multiplexer_switch()).count();
+        let has_ext_mux =
+            self.extended_multiplexing_for_message(message.id_with_flag()).next().is_some();
+        for signal in signals.iter() {
+            if switches != 1 || has_ext_mux {
+                self.write_kcd_signal(w, message, signal);
+            } else if signal.is_multiplexer_switch() {
+                self.write_kcd_multiplex(w, message, signal);
+            } else if signal.multiplexer_switch_value().is_none() {
+                self.write_kcd_signal(w, message, signal);
+            }
+        }
+
+        w.close("Message");
+    }
+
+    /// Write the multiplexer `switch` of `message` with the signals it selects.
+    fn write_kcd_multiplex(&self, w: &mut XmlWriter, message: &Message, switch: &Signal) {
+        open_kcd_signal(w, "Multiplex", switch);
+        self.write_kcd_signal_content(w, message, switch);
+
+        let mut values: Vec<u64> =
+            message.signals().iter().filter_map(|s| s.multiplexer_switch_value()).collect();
+        values.sort_unstable();
+        values.dedup();
+        for value in values {
+            let count = value.to_string();
+            w.open("MuxGroup", &[("count", &count)]);
+            for signal in message.signals().iter() {
+                if signal.multiplexer_switch_value() == Some(value) {
+                    self.write_kcd_signal(w, message, signal);
+                }
+            }
+            w.close("MuxGroup");
+        }
+        w.close("Multiplex");
+    }
+
+    fn write_kcd_signal(&self, w: &mut XmlWriter, message: &Message, signal: &Signal) {
+        open_kcd_signal(w, "Signal", signal);
+        self.write_kcd_signal_content(w, message, signal);
+        w.close("Signal");
+    }
+
+    /// Write the notes, consumers, value and labels of `signal`.
+    fn write_kcd_signal_content(&self, w: &mut XmlWriter, message: &Message, signal: &Signal) {
+        if let Some(comment) = signal.comment() {
+            w.text("Notes", comment);
+        }
+
+        let consumers: Vec<String> =
+            signal.receivers().iter().filter_map(|node| self.kcd_node_id(node)).collect();
+        if !consumers.is_empty() {
+            w.open("Consumer", &[]);
+            for consumer in &consumers {
+                w.empty("NodeRef", &[("id", consumer)]);
+            }
+            w.close("Consumer");
+        }
+
+        let value_type = match signal.value_type() {
+            SignalValueType::Float => "single",
+            SignalValueType::Double => "double",
+            SignalValueType::Integer if signal.is_unsigned() => "unsigned",
+            SignalValueType::Integer => "signed",
+        };
+        let slope = signal.factor().to_string();
+        let intercept = signal.offset().to_string();
+        let min = signal.min().to_string();
+        let max = signal.max().to_string();
+        let mut value = vec![
+            ("type", value_type),
+            ("slope", slope.as_str()),
+            ("intercept", intercept.as_str()),
+        ];
+        if let Some(unit) = signal.unit().filter(|unit| !unit.is_empty()) {
+            value.push(("unit", unit));
+        }
+        value.push(("min", min.as_str()));
+        value.push(("max", max.as_str()));
+        w.empty("Value", &value);
+
+        if let Some(descriptions) =
+            self.value_descriptions_for_signal(message.id_with_flag(), signal.name())
+        {
+            w.open("LabelSet", &[]);
+            for (raw, label) in descriptions.iter() {
+                let raw = raw.to_string();
+                w.empty("Label", &[("name", label), ("value", &raw)]);
+            }
+            w.close("LabelSet");
+        }
+    }
+
+    /// KCD id of the node named `name`, or `None` for `Vector__XXX` and unknown nodes.
+    fn kcd_node_id(&self, name: &str) -> Option<String> {
+        self.nodes()
+            .iter()
+            .position(|node| node == name)
+            .map(|index| (index + 1).to_string())
+    }
+}
+
+/// Open the `tag` element of `signal` with its position attributes.
+fn open_kcd_signal(w: &mut XmlWriter, tag: &str, signal: &Signal) {
+    let start_bit = signal.start_bit();
+    let offset = match signal.byte_order() {
+        ByteOrder::LittleEndian => start_bit,
+        ByteOrder::BigEndian => start_bit / 8 * 8 + (7 - start_bit % 8),
+    }
+    .to_string();
+    let length = signal.length().to_string();
+    let mut attributes = vec![
+        ("name", signal.name()),
+        ("offset", offset.as_str()),
+        ("length", length.as_str()),
+    ];
+    if signal.byte_order() == ByteOrder::BigEndian {
+        // Spelled as in the KCD schema
+        attributes.push(("endianess", "big"));
+    }
+    w.open(tag, &attributes);
+}
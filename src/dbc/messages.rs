@@ -1,19 +1,29 @@
 use crate::{Error, MAX_MESSAGES, Message, Result, compat::Vec};
+use core::ops::RangeInclusive;
 #[cfg(feature = "heapless")]
 use heapless::index_map::FnvIndexMap;
 
+#[cfg(all(feature = "std", not(feature = "heapless")))]
+use crate::hasher::FxHashMap;
+
 /// Encapsulates the messages array and count for a DBC
 ///
 /// Uses `Vec<Message>` for dynamic sizing.
-/// Optionally includes an index for O(1) or O(log n) message lookup by ID.
+/// Includes an ID index built once at construction, which serves message lookup by ID
+/// and rejects duplicate message IDs, and a name index for lookup by name.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Messages {
     messages: Vec<Message, { MAX_MESSAGES }>,
-    // Optional index for fast ID lookup (feature-flagged)
+    // Hash index for O(1) ID lookup (feature-flagged)
     #[cfg(feature = "heapless")]
-    id_index: Option<FnvIndexMap<u32, usize, { MAX_MESSAGES }>>,
+    id_index: FnvIndexMap<u32, usize, { MAX_MESSAGES }>,
+    #[cfg(all(feature = "std", not(feature = "heapless")))]
+    id_index: FxHashMap<u32, usize>,
+    // (id, index) pairs sorted by id, for range queries (and lookup without std)
     #[cfg(all(feature = "alloc", not(feature = "heapless")))]
-    sorted_indices: Option<alloc::vec::Vec<(u32, usize)>>, // (id, index) pairs sorted by id
+    sorted_indices: alloc::vec::Vec<(u32, usize)>,
+    // Message indices sorted by name, ties in definition order, for O(log n) name lookup
+    name_index: Vec<usize, { MAX_MESSAGES }>,
 }
 
 impl Messages {
@@ -32,6 +42,9 @@ impl Messages {
     }
 
     /// Create Messages from an owned Vec (avoids cloning)
+    ///
+    /// Returns `Error::Validation(DUPLICATE_MESSAGE_ID)` if two messages share an ID
+    /// (compared with the extended flag, so standard 0x100 and extended 0x100 differ).
     pub(crate) fn from_vec(messages: Vec<Message, { MAX_MESSAGES }>) -> Result<Self> {
         if let Some(err) = crate::error::check_max_limit(
             messages.len(),
@@ -41,47 +54,74 @@ impl Messages {
             return Err(err);
         }
 
-        // Build index for fast lookup (if features allow)
-        #[cfg(feature = "heapless")]
-        let id_index = Self::build_heapless_index(&messages);
+        // Build the lookup index; this is where duplicate IDs are detected
+        #[cfg(any(feature = "heapless", feature = "std"))]
+        let id_index = Self::build_hash_index(&messages)?;
         #[cfg(all(feature = "alloc", not(feature = "heapless")))]
-        let sorted_indices = Self::build_sorted_index(&messages);
+        let sorted_indices = Self::build_sorted_index(&messages)?;
+        let name_index = Self::build_name_index(&messages);
 
         Ok(Self {
             messages,
-            #[cfg(feature = "heapless")]
+            name_index,
+            #[cfg(any(feature = "heapless", feature = "std"))]
             id_index,
             #[cfg(all(feature = "alloc", not(feature = "heapless")))]
             sorted_indices,
         })
     }
 
-    /// Build heapless index for O(1) lookup (only with heapless feature)
+    /// Build the heapless hash index for O(1) lookup (only with heapless feature)
     #[cfg(feature = "heapless")]
-    fn build_heapless_index(
-        messages: &[Message],
-    ) -> Option<FnvIndexMap<u32, usize, { MAX_MESSAGES }>> {
+    fn build_hash_index(messages: &[Message]) -> Result<FnvIndexMap<u32, usize, { MAX_MESSAGES }>> {
         let mut index = FnvIndexMap::new();
         for (idx, msg) in messages.iter().enumerate() {
-            if index.insert(msg.id_with_flag(), idx).is_err() {
-                // If we can't insert (capacity full or duplicate), return None
-                // This should not happen in practice if MAX_MESSAGES is correct
-                return None;
+            // Cannot run out of capacity: `messages.len() <= MAX_MESSAGES` was checked
+            if let Ok(Some(_)) = index.insert(msg.id_with_flag(), idx) {
+                return Err(Error::Validation(Error::DUPLICATE_MESSAGE_ID));
+            }
+        }
+        Ok(index)
+    }
+
+    /// Build the hash index for O(1) lookup (std without heapless)
+    #[cfg(all(feature = "std", not(feature = "heapless")))]
+    fn build_hash_index(messages: &[Message]) -> Result<FxHashMap<u32, usize>> {
+        let mut index = FxHashMap::with_capacity_and_hasher(messages.len(), Default::default());
+        for (idx, msg) in messages.iter().enumerate() {
+            if index.insert(msg.id_with_flag(), idx).is_some() {
+                return Err(Error::Validation(Error::DUPLICATE_MESSAGE_ID));
             }
         }
-        Some(index)
+        Ok(index)
     }
 
-    /// Build sorted index for O(log n) lookup (only with alloc feature, no heapless)
+    /// Build sorted index for range queries and O(log n) lookup (alloc without heapless)
     #[cfg(all(feature = "alloc", not(feature = "heapless")))]
-    fn build_sorted_index(messages: &[Message]) -> Option<alloc::vec::Vec<(u32, usize)>> {
+    fn build_sorted_index(messages: &[Message]) -> Result<alloc::vec::Vec<(u32, usize)>> {
         let mut indices = alloc::vec::Vec::with_capacity(messages.len());
         for (idx, msg) in messages.iter().enumerate() {
             indices.push((msg.id_with_flag(), idx));
         }
-        // Sort by message ID for binary search
+        // Sort by message ID for binary search; duplicates end up adjacent
         indices.sort_by_key(|&(id, _)| id);
-        Some(indices)
+        if indices.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(Error::Validation(Error::DUPLICATE_MESSAGE_ID));
+        }
+        Ok(indices)
+    }
+
+    /// Build the name index: message indices sorted by name, then by position
+    fn build_name_index(messages: &[Message]) -> Vec<usize, { MAX_MESSAGES }> {
+        let mut index: Vec<usize, { MAX_MESSAGES }> = (0..messages.len()).collect();
+        index.as_mut_slice().sort_unstable_by_key(|&idx| (messages[idx].name(), idx));
+        index
+    }
+
+    /// Get the messages as a slice
+    #[inline]
+    pub(crate) fn as_slice(&self) -> &[Message] {
+        self.messages.as_slice()
     }
 
     /// Get an iterator over the messages
@@ -105,6 +145,13 @@ impl Messages {
         self.messages.iter()
     }
 
+    /// Get a mutable iterator over the messages.
+    /// IDs and names must not be changed, as the lookup indices are not rebuilt.
+    #[cfg(feature = "std")]
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut Message> + '_ {
+        self.messages.iter_mut()
+    }
+
     /// Get the number of messages
     ///
     /// # Examples
@@ -160,6 +207,9 @@ impl Messages {
 
     /// Find a message by name, or None if not found
     ///
+    /// Uses the name index built at construction (O(log n)). If several messages share
+    /// the name, the first one in definition order is returned.
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
@@ -174,7 +224,12 @@ impl Messages {
     /// ```
     #[must_use = "return value should be used"]
     pub fn find(&self, name: &str) -> Option<&Message> {
-        self.iter().find(|m| m.name() == name)
+        let index = self.name_index.as_slice();
+        let pos = index.partition_point(|&idx| self.messages[idx].name() < name);
+        index
+            .get(pos)
+            .map(|&idx| &self.messages[idx])
+            .filter(|message| message.name() == name)
     }
 
     /// Find a message by CAN ID, or None if not found
@@ -193,36 +248,56 @@ impl Messages {
     /// ```
     /// Find a message by CAN ID with optimized lookup based on available features.
     ///
-    /// - With `heapless` feature: O(1) lookup using FnvIndexMap
-    /// - With `alloc` feature (no heapless): O(log n) lookup using binary search on sorted indices
-    /// - Otherwise: O(n) linear search
+    /// - With `heapless` or `std` feature: O(1) hash lookup
+    /// - With `alloc` only: O(log n) binary search on sorted indices
     #[inline]
     #[must_use = "return value should be used"]
     pub fn find_by_id(&self, id: u32) -> Option<&Message> {
-        #[cfg(feature = "heapless")]
-        {
-            if let Some(ref index) = self.id_index {
-                if let Some(&idx) = index.get(&id) {
-                    return self.messages.get(idx);
-                }
-                return None;
-            }
-        }
+        #[cfg(any(feature = "heapless", feature = "std"))]
+        let idx = self.id_index.get(&id).copied();
 
-        #[cfg(all(feature = "alloc", not(feature = "heapless")))]
-        {
-            if let Some(ref sorted) = self.sorted_indices {
-                // Binary search for O(log n) lookup
-                if let Ok(pos) = sorted.binary_search_by_key(&id, |&(msg_id, _)| msg_id) {
-                    let &(_, idx) = sorted.get(pos)?;
-                    return self.messages.get(idx);
-                }
-                return None;
-            }
-        }
+        #[cfg(all(feature = "alloc", not(feature = "std"), not(feature = "heapless")))]
+        let idx = self
+            .sorted_indices
+            .binary_search_by_key(&id, |&(msg_id, _)| msg_id)
+            .ok()
+            .map(|pos| self.sorted_indices[pos].1);
+
+        self.messages.get(idx?)
+    }
 
-        // Fallback: linear search O(n)
-        // This is used when no alloc/heapless features are enabled
-        self.messages.iter().find(|m| m.id_with_flag() == id)
+    /// Messages whose ID (with extended flag) lies in `range`, in ascending ID order.
+    ///
+    /// Uses the sorted index: O(log n) to find the start, then one step per match.
+    #[cfg(all(feature = "alloc", not(feature = "heapless")))]
+    pub(crate) fn in_id_range(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> impl Iterator<Item = &Message> + '_ {
+        let sorted = self.sorted_indices.as_slice();
+        let start = sorted.partition_point(|&(id, _)| id < *range.start());
+        sorted[start..]
+            .iter()
+            .take_while(move |&&(id, _)| id <= *range.end())
+            .filter_map(|&(_, idx)| self.messages.get(idx))
+    }
+
+    /// Messages whose ID (with extended flag) lies in `range`, in ascending ID order.
+    ///
+    /// The hash index is unordered, so matches are collected and sorted on the stack.
+    #[cfg(feature = "heapless")]
+    pub(crate) fn in_id_range(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> impl Iterator<Item = &Message> + '_ {
+        let mut matches: Vec<(u32, usize), { MAX_MESSAGES }> = self
+            .messages
+            .iter()
+            .enumerate()
+            .map(|(idx, msg)| (msg.id_with_flag(), idx))
+            .filter(|(id, _)| range.contains(id))
+            .collect();
+        matches.as_mut_slice().sort_unstable_by_key(|&(id, _)| id);
+        matches.into_iter().filter_map(|(_, idx)| self.messages.get(idx))
     }
 }
@@ -1,9 +1,15 @@
 use crate::{
-    BitTiming, ExtendedMultiplexing, MAX_EXTENDED_MULTIPLEXING, Nodes, Version,
+    BitTiming, EnvironmentVariable, ExtendedMultiplexing, MAX_ENVIRONMENT_VARIABLES,
+    MAX_EXTENDED_MULTIPLEXING, MAX_SIGNAL_GROUPS, MAX_SIGNAL_TYPE_REFS, MAX_SIGNAL_TYPES, Nodes,
+    SignalGroup, SignalType, SignalTypeRef, Version,
     compat::{Comment, Vec},
 };
 
 type ExtendedMultiplexings = Vec<ExtendedMultiplexing, { MAX_EXTENDED_MULTIPLEXING }>;
+type SignalTypes = Vec<SignalType, { MAX_SIGNAL_TYPES }>;
+type SignalTypeRefs = Vec<SignalTypeRef, { MAX_SIGNAL_TYPE_REFS }>;
+type SignalGroups = Vec<SignalGroup, { MAX_SIGNAL_GROUPS }>;
+type EnvironmentVariables = Vec<EnvironmentVariable, { MAX_ENVIRONMENT_VARIABLES }>;
 
 // Index for fast extended multiplexing lookup by (message_id, signal_name)
 // Maps to indices into the extended_multiplexing vec
@@ -14,17 +20,37 @@ use ext_mux_index::ExtMuxIndex;
 #[cfg(feature = "attributes")]
 mod attributes_map;
 mod messages;
+mod parse_options;
 mod value_descriptions_map;
 
 // Include modules for additional functionality
+#[cfg(feature = "arxml")]
+mod arxml;
 #[cfg(feature = "std")]
 mod builder;
+mod checksum;
 mod decode;
+#[cfg(feature = "std")]
+mod diff;
 mod encode;
+#[cfg(feature = "encoding")]
+mod encoding;
+mod events;
 mod impls;
+#[cfg(feature = "kcd")]
+mod kcd;
 mod parse;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "std")]
+mod serialize_options;
+#[cfg(feature = "std")]
+mod signal_ref;
+mod statistics;
 #[cfg(feature = "std")]
 mod std;
+#[cfg(feature = "std")]
+mod trace;
 mod validate;
 
 // Re-exports
@@ -32,8 +58,20 @@ mod validate;
 pub use attributes_map::{AttributeDefaultsMap, AttributeDefinitionsMap, AttributeValuesMap};
 #[cfg(feature = "std")]
 pub use builder::DbcBuilder;
-pub use decode::DecodedSignal;
+pub use checksum::ChecksumAlgorithm;
+pub use decode::{DecodedFrame, DecodedSignal, FrameMeta};
+#[cfg(feature = "std")]
+pub use diff::{DbcDiff, MessageDiff, SignalDiff};
+#[cfg(feature = "encoding")]
+pub use encoding::Encoding;
+pub use events::{DbcEvents, ParseEvent};
 use messages::Messages;
+pub use parse_options::ParseOptions;
+#[cfg(feature = "std")]
+pub use serialize_options::SerializeOptions;
+#[cfg(feature = "std")]
+pub use signal_ref::SignalRef;
+pub use statistics::DbcStats;
 use validate::Validate;
 use value_descriptions_map::ValueDescriptionsMap;
 
@@ -62,6 +100,7 @@ use value_descriptions_map::ValueDescriptionsMap;
 /// # Ok::<(), dbc_rs::Error>(())
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 pub struct Dbc {
     version: Option<Version>,
     bit_timing: Option<BitTiming>,
@@ -70,9 +109,18 @@ pub struct Dbc {
     value_descriptions: ValueDescriptionsMap,
     extended_multiplexing: ExtendedMultiplexings,
     /// Index for O(1) extended multiplexing lookup by (message_id, signal_name)
+    #[cfg_attr(feature = "serde", serde(skip))]
     ext_mux_index: ExtMuxIndex,
     /// Database-level comment from CM_ (general comment)
     comment: Option<Comment>,
+    /// Signal types (SGTYPE_, with SGTYPE_VAL_ value descriptions attached)
+    signal_types: SignalTypes,
+    /// Signal type references (SIG_TYPE_REF_)
+    signal_type_refs: SignalTypeRefs,
+    /// Signal groups (SIG_GROUP_)
+    signal_groups: SignalGroups,
+    /// Environment variables (EV_, with ENVVAR_DATA_ sizes attached)
+    environment_variables: EnvironmentVariables,
     /// Attribute definitions (BA_DEF_)
     #[cfg(feature = "attributes")]
     attribute_definitions: AttributeDefinitionsMap,
@@ -1,9 +1,13 @@
 use crate::{
-    BitTiming, Dbc, Error, ExtendedMultiplexing, MAX_EXTENDED_MULTIPLEXING, MAX_MESSAGES,
-    MAX_NODES, MAX_SIGNALS_PER_MESSAGE, Message, Nodes, Parser, Result, Signal, ValueDescriptions,
-    Version,
-    compat::{BTreeMap, Comment, Name, ValueDescEntries, Vec},
-    dbc::{Messages, Validate, ValueDescriptionsMap},
+    BitTiming, ByteOrder, Dbc, EnvironmentVariable, Error, ExtendedMultiplexing,
+    MAX_ENVIRONMENT_VARIABLES, MAX_EXTENDED_MULTIPLEXING, MAX_MESSAGES, MAX_NODES,
+    MAX_SIGNAL_TYPES, MAX_SIGNALS_PER_MESSAGE, Message, Nodes, Parser, Result, Signal, SignalGroup,
+    SignalType, SignalTypeRef, SignalValueType, ValueDescriptions, Version,
+    compat::{BTreeMap, Comment, MAX_COMMENT_SIZE, Name, ValueDescEntries, Vec, validate_name},
+    dbc::{
+        EnvironmentVariables, Messages, ParseOptions, SignalGroups, SignalTypeRefs, SignalTypes,
+        Validate, ValueDescriptionsMap,
+    },
 };
 #[cfg(feature = "attributes")]
 use crate::{
@@ -35,7 +39,61 @@ impl Dbc {
     /// # Ok::<(), dbc_rs::Error>(())
     /// ```
     pub fn parse(data: &str) -> Result<Self> {
+        Self::parse_with_options(data, ParseOptions::default())
+    }
+
+    /// Parse a DBC file from a string slice, rejecting sections the crate does not model
+    ///
+    /// [`parse`](Self::parse) skips keywords such as `BA_REL_`, `BU_SG_REL_` or `CAT_`;
+    /// this fails with [`Error::UNSUPPORTED_KEYWORD`] instead. Malformed lines of modeled
+    /// sections are handled as by `parse`. Equivalent to
+    /// [`parse_with_options`](Self::parse_with_options) with [`ParseOptions::strict`] set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc_content = r#"VERSION "1.0"
+    ///
+    /// BU_: ECM TCM
+    ///
+    /// BO_ 256 EngineData : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" TCM
+    ///
+    /// BU_SG_REL_ "Relevance" SG_ TCM 256 RPM 1;"#;
+    ///
+    /// assert!(Dbc::parse(dbc_content).is_ok());
+    /// assert!(Dbc::parse_strict(dbc_content).is_err());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn parse_strict(data: &str) -> Result<Self> {
+        Self::parse_with_options(data, ParseOptions::new().strict(true))
+    }
+
+    /// Parse a DBC file from a string slice with custom [`ParseOptions`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, ParseOptions};
+    ///
+    /// let dbc_content = r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 EngineData : 8 ECM
+    ///
+    /// CM_ BO_ 256 "A very long comment ...";"#;
+    ///
+    /// let options = ParseOptions::new().reject_long_comments(true);
+    /// let dbc = Dbc::parse_with_options(dbc_content, options)?;
+    /// assert_eq!(dbc.messages().len(), 1);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn parse_with_options(data: &str, options: ParseOptions) -> Result<Self> {
         let mut parser = Parser::new(data.as_bytes())?;
+        parser.set_hex_message_ids(options.hex_message_ids);
 
         let mut messages_buffer: Vec<Message, { MAX_MESSAGES }> = Vec::new();
 
@@ -43,8 +101,9 @@ impl Dbc {
 
         // Parse version, nodes, and messages
         use crate::{
-            BA_, BA_DEF_, BA_DEF_DEF_, BO_, BO_TX_BU_, BS_, BU_, CM_, EV_, NS_, SG_, SG_MUL_VAL_,
-            SIG_GROUP_, SIG_VALTYPE_, VAL_, VAL_TABLE_, VERSION,
+            BA_, BA_DEF_, BA_DEF_DEF_, BO_, BO_TX_BU_, BS_, BU_, CM_, ENVVAR_DATA_, EV_, EV_DATA_,
+            NS_, SG_, SG_MUL_VAL_, SGTYPE_, SGTYPE_VAL_, SIG_GROUP_, SIG_TYPE_REF_, SIG_VALTYPE_,
+            VAL_, VAL_TABLE_, VERSION,
         };
 
         let mut version: Option<Version> = None;
@@ -65,6 +124,21 @@ impl Dbc {
         let mut value_descriptions_buffer: ValueDescBuffer = ValueDescBuffer::new();
         let mut extended_multiplexing_buffer: ExtMuxBuffer = ExtMuxBuffer::new();
 
+        // Signal type and group buffers
+        // SGTYPE_VAL_ entries: (signal_type_name, entries), applied to signal types after parsing
+        type SignalTypeValueDescBuffer = Vec<(Name, ValueDescEntries), { MAX_SIGNAL_TYPES }>;
+        let mut signal_types_buffer: SignalTypes = SignalTypes::new();
+        let mut signal_type_value_descriptions_buffer: SignalTypeValueDescBuffer =
+            SignalTypeValueDescBuffer::new();
+        let mut signal_type_refs_buffer: SignalTypeRefs = SignalTypeRefs::new();
+        let mut signal_groups_buffer: SignalGroups = SignalGroups::new();
+
+        // Environment variable buffers
+        // ENVVAR_DATA_ entries: (variable_name, data_size), applied to variables after parsing
+        type EnvVarDataBuffer = Vec<(Name, u32), { MAX_ENVIRONMENT_VARIABLES }>;
+        let mut environment_variables_buffer: EnvironmentVariables = EnvironmentVariables::new();
+        let mut environment_variable_data_buffer: EnvVarDataBuffer = EnvVarDataBuffer::new();
+
         // Comment buffers
         let mut db_comment: Option<Comment> = None;
         // Node comments: (node_name, comment)
@@ -73,6 +147,11 @@ impl Dbc {
         let mut message_comments_buffer: MessageCommentBuffer = MessageCommentBuffer::new();
         let mut signal_comments_buffer: SignalCommentBuffer = SignalCommentBuffer::new();
 
+        // SIG_VALTYPE_ entries preceding their BO_: (message_id, signal_name, value_type),
+        // applied after parsing
+        type SignalValueTypeBuffer = Vec<(u32, Name, SignalValueType), { MAX_MESSAGES * 4 }>;
+        let mut signal_value_types_buffer: SignalValueTypeBuffer = SignalValueTypeBuffer::new();
+
         // Attribute buffers - BA_DEF_, BA_DEF_DEF_, BA_ entries can appear anywhere
         #[cfg(feature = "attributes")]
         type AttrDefBuffer = Vec<AttributeDefinition, { MAX_ATTRIBUTE_DEFINITIONS }>;
@@ -191,23 +270,150 @@ impl Dbc {
                 #[cfg(not(feature = "attributes"))]
                 BA_DEF_ | BA_DEF_DEF_ | BA_ => {
                     // Skip attribute entries when feature is disabled
+                    if options.strict {
+                        return Err(parser.err_expected(Error::UNSUPPORTED_KEYWORD));
+                    }
+                    let _ = parser.expect(keyword.as_bytes()).ok();
+                    parser.skip_to_end_of_line();
+                    continue;
+                }
+                SGTYPE_ => {
+                    // Parse signal type: SGTYPE_ name : size@byte_order sign (f,o) [min|max] "unit" default, value_table ;
+                    let _ = parser.expect(SGTYPE_.as_bytes()).ok();
+                    if let Some(signal_type) = SignalType::parse(&mut parser) {
+                        if signal_types_buffer.push(signal_type).is_err() {
+                            return Err(Error::Validation(Error::SIGNAL_TYPES_TOO_MANY));
+                        }
+                    }
+                    parser.skip_to_end_of_line();
+                    continue;
+                }
+                SGTYPE_VAL_ => {
+                    // Parse signal type value descriptions: SGTYPE_VAL_ type_name value "desc" ... ;
+                    let _ = parser.expect(SGTYPE_VAL_.as_bytes()).ok();
+                    parser.skip_newlines_and_spaces();
+                    let Some(type_name) =
+                        parser.parse_identifier().ok().and_then(|n| validate_name(n).ok())
+                    else {
+                        parser.skip_to_end_of_line();
+                        continue;
+                    };
+                    let entries = ValueDescriptions::parse_entries(&mut parser);
+                    if !entries.is_empty()
+                        && signal_type_value_descriptions_buffer.push((type_name, entries)).is_err()
+                    {
+                        return Err(Error::Validation(Error::SIGNAL_TYPES_TOO_MANY));
+                    }
+                    continue;
+                }
+                SIG_TYPE_REF_ => {
+                    // Parse signal type reference: SIG_TYPE_REF_ message_id signal_name : type_name ;
+                    let _ = parser.expect(SIG_TYPE_REF_.as_bytes()).ok();
+                    if let Some(type_ref) = SignalTypeRef::parse(&mut parser) {
+                        if signal_type_refs_buffer.push(type_ref).is_err() {
+                            return Err(Error::Validation(Error::SIGNAL_TYPE_REFS_TOO_MANY));
+                        }
+                    }
+                    parser.skip_to_end_of_line();
+                    continue;
+                }
+                SIG_GROUP_ => {
+                    // Parse signal group: SIG_GROUP_ message_id name repetitions : signals... ;
+                    let _ = parser.expect(SIG_GROUP_.as_bytes()).ok();
+                    if let Some(group) = SignalGroup::parse(&mut parser) {
+                        if signal_groups_buffer.push(group).is_err() {
+                            return Err(Error::Validation(Error::SIGNAL_GROUPS_TOO_MANY));
+                        }
+                    }
+                    parser.skip_to_end_of_line();
+                    continue;
+                }
+                EV_ => {
+                    // Parse environment variable:
+                    // EV_ name : type [min|max] "unit" initial ev_id access nodes ;
+                    let line = parser.line();
+                    let _ = parser.expect(EV_.as_bytes()).ok();
+                    let env_var = EnvironmentVariable::parse(&mut parser).ok_or_else(|| {
+                        Error::expected_at(Error::ENVIRONMENT_VARIABLE_INVALID, line)
+                    })?;
+                    if environment_variables_buffer.iter().any(|ev| ev.name() == env_var.name()) {
+                        return Err(Error::expected_at(
+                            Error::ENVIRONMENT_VARIABLE_DUPLICATE_NAME,
+                            line,
+                        ));
+                    }
+                    if environment_variables_buffer.push(env_var).is_err() {
+                        return Err(Error::Validation(Error::ENVIRONMENT_VARIABLES_TOO_MANY));
+                    }
+                    parser.skip_to_end_of_line();
+                    continue;
+                }
+                ENVVAR_DATA_ | EV_DATA_ => {
+                    // Parse environment variable data size: ENVVAR_DATA_ name : data_size ;
+                    let line = parser.line();
                     let _ = parser.expect(keyword.as_bytes()).ok();
+                    let data = EnvironmentVariable::parse_data(&mut parser).ok_or_else(|| {
+                        Error::expected_at(Error::ENVIRONMENT_VARIABLE_INVALID, line)
+                    })?;
+                    if environment_variable_data_buffer.push(data).is_err() {
+                        return Err(Error::Validation(Error::ENVIRONMENT_VARIABLES_TOO_MANY));
+                    }
                     parser.skip_to_end_of_line();
                     continue;
                 }
-                VAL_TABLE_ | SIG_GROUP_ | SIG_VALTYPE_ | EV_ | BO_TX_BU_ => {
+                SIG_VALTYPE_ => {
+                    // Parse signal value type: SIG_VALTYPE_ message_id signal_name : type ;
+                    let _ = parser.expect(SIG_VALTYPE_.as_bytes()).ok();
+                    parser.skip_newlines_and_spaces();
+                    if let Ok(message_id) = parser.parse_message_id() {
+                        parser.skip_newlines_and_spaces();
+                        if let Ok(signal_name_bytes) = parser.parse_identifier() {
+                            parser.skip_newlines_and_spaces();
+                            // The colon is omitted by some writers
+                            let _ = parser.expect(b":").ok();
+                            parser.skip_newlines_and_spaces();
+                            let value_type = parser
+                                .parse_i64()
+                                .ok()
+                                .and_then(|code| u64::try_from(code).ok())
+                                .and_then(SignalValueType::from_dbc_code);
+                            if let (Ok(signal_name), Some(value_type)) =
+                                (Name::try_from(signal_name_bytes), value_type)
+                            {
+                                // Entries usually follow their BO_ and apply right away;
+                                // the others wait until all messages are parsed
+                                if !Self::apply_signal_value_type(
+                                    &mut messages_buffer,
+                                    message_id,
+                                    &signal_name,
+                                    value_type,
+                                ) && signal_value_types_buffer
+                                    .push((message_id, signal_name, value_type))
+                                    .is_err()
+                                {
+                                    return Err(Error::Validation(
+                                        Error::SIGNAL_VALUE_TYPES_TOO_MANY,
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                    parser.skip_to_end_of_line();
+                    continue;
+                }
+                VAL_TABLE_ | BO_TX_BU_ => {
                     // TODO: These DBC sections are recognized but not parsed:
                     //   VAL_TABLE_   - Global value tables (rarely used)
-                    //   SIG_GROUP_   - Signal groups (rarely used)
-                    //   SIG_VALTYPE_ - Signal extended value types: float/double (medium priority)
-                    //   EV_          - Environment variables (rarely used)
                     //   BO_TX_BU_    - Multiple message transmitters (rarely used)
                     //
                     // Not yet recognized (rarely used):
-                    //   ENVVAR_DATA_, SGTYPE_, BA_DEF_SGTYPE_, BA_SGTYPE_, SIG_TYPE_REF_,
+                    //   BA_DEF_SGTYPE_, BA_SGTYPE_,
                     //   BA_DEF_REL_, BA_REL_, BA_DEF_DEF_REL_, BU_SG_REL_, BU_EV_REL_, BU_BO_REL_
                     //
                     // Consume keyword then skip to end of line
+                    if options.strict {
+                        return Err(parser.err_expected(Error::UNSUPPORTED_KEYWORD));
+                    }
                     let _ = parser.expect(keyword.as_bytes()).ok();
                     parser.skip_to_end_of_line();
                     continue;
@@ -225,14 +431,8 @@ impl Dbc {
                     // Determine comment type by peeking next token
                     if parser.starts_with(b"\"") {
                         // General database comment: CM_ "string";
-                        if parser.expect(b"\"").is_ok() {
-                            if let Ok(comment_bytes) = parser.take_until_quote(false, 1024) {
-                                if let Ok(comment_str) = core::str::from_utf8(comment_bytes) {
-                                    if let Ok(comment) = Comment::try_from(comment_str) {
-                                        db_comment = Some(comment);
-                                    }
-                                }
-                            }
+                        if let Some(comment) = Self::parse_comment_text(&mut parser, options)? {
+                            db_comment = Some(comment);
                         }
                         parser.skip_to_end_of_line();
                     } else if parser.starts_with(BU_.as_bytes()) {
@@ -242,17 +442,10 @@ impl Dbc {
                         if let Ok(node_name_bytes) = parser.parse_identifier() {
                             if let Ok(node_name) = Name::try_from(node_name_bytes) {
                                 parser.skip_newlines_and_spaces();
-                                if parser.expect(b"\"").is_ok() {
-                                    if let Ok(comment_bytes) = parser.take_until_quote(false, 1024)
-                                    {
-                                        if let Ok(comment_str) = core::str::from_utf8(comment_bytes)
-                                        {
-                                            if let Ok(comment) = Comment::try_from(comment_str) {
-                                                let _ =
-                                                    node_comments_buffer.push((node_name, comment));
-                                            }
-                                        }
-                                    }
+                                if let Some(comment) =
+                                    Self::parse_comment_text(&mut parser, options)?
+                                {
+                                    let _ = node_comments_buffer.push((node_name, comment));
                                 }
                             }
                         }
@@ -261,17 +454,10 @@ impl Dbc {
                         // Message comment: CM_ BO_ message_id "string";
                         let _ = parser.expect(BO_.as_bytes()).ok();
                         parser.skip_newlines_and_spaces();
-                        if let Ok(message_id) = parser.parse_u32() {
+                        if let Ok(message_id) = parser.parse_message_id() {
                             parser.skip_newlines_and_spaces();
-                            if parser.expect(b"\"").is_ok() {
-                                if let Ok(comment_bytes) = parser.take_until_quote(false, 1024) {
-                                    if let Ok(comment_str) = core::str::from_utf8(comment_bytes) {
-                                        if let Ok(comment) = Comment::try_from(comment_str) {
-                                            let _ =
-                                                message_comments_buffer.push((message_id, comment));
-                                        }
-                                    }
-                                }
+                            if let Some(comment) = Self::parse_comment_text(&mut parser, options)? {
+                                let _ = message_comments_buffer.push((message_id, comment));
                             }
                         }
                         parser.skip_to_end_of_line();
@@ -279,28 +465,19 @@ impl Dbc {
                         // Signal comment: CM_ SG_ message_id signal_name "string";
                         let _ = parser.expect(SG_.as_bytes()).ok();
                         parser.skip_newlines_and_spaces();
-                        if let Ok(message_id) = parser.parse_u32() {
+                        if let Ok(message_id) = parser.parse_message_id() {
                             parser.skip_newlines_and_spaces();
                             if let Ok(signal_name_bytes) = parser.parse_identifier() {
                                 if let Ok(signal_name) = Name::try_from(signal_name_bytes) {
                                     parser.skip_newlines_and_spaces();
-                                    if parser.expect(b"\"").is_ok() {
-                                        if let Ok(comment_bytes) =
-                                            parser.take_until_quote(false, 1024)
-                                        {
-                                            if let Ok(comment_str) =
-                                                core::str::from_utf8(comment_bytes)
-                                            {
-                                                if let Ok(comment) = Comment::try_from(comment_str)
-                                                {
-                                                    let _ = signal_comments_buffer.push((
-                                                        message_id,
-                                                        signal_name,
-                                                        comment,
-                                                    ));
-                                                }
-                                            }
-                                        }
+                                    if let Some(comment) =
+                                        Self::parse_comment_text(&mut parser, options)?
+                                    {
+                                        let _ = signal_comments_buffer.push((
+                                            message_id,
+                                            signal_name,
+                                            comment,
+                                        ));
                                     }
                                 }
                             }
@@ -338,7 +515,11 @@ impl Dbc {
                     // Note: message_id of -1 (0xFFFFFFFF) means the value descriptions apply to
                     // all signals with this name in ANY message (global value descriptions)
                     parser.skip_newlines_and_spaces();
-                    let message_id = match parser.parse_i64() {
+                    let message_id = match parser
+                        .parse_message_id()
+                        .map(i64::from)
+                        .or_else(|_| parser.parse_i64())
+                    {
                         Ok(id) => {
                             // -1 (0xFFFFFFFF) is the magic number for global value descriptions
                             if id == -1 {
@@ -370,52 +551,7 @@ impl Dbc {
                         }
                     };
                     // Parse value-description pairs
-                    let mut entries: ValueDescEntries = ValueDescEntries::new();
-                    loop {
-                        parser.skip_newlines_and_spaces();
-                        // Check for semicolon (end of VAL_ statement)
-                        if parser.starts_with(b";") {
-                            parser.expect(b";").ok();
-                            break;
-                        }
-                        // Parse value (as i64 first to handle negative values like -1, then convert to u64)
-                        // Note: -1 (0xFFFFFFFF) is the magic number for global value descriptions in message_id,
-                        // but values in VAL_ can also be negative
-                        let value = match parser.parse_i64() {
-                            Ok(v) => {
-                                // Handle -1 specially: convert to 0xFFFFFFFF (u32::MAX) instead of large u64
-                                if v == -1 { 0xFFFF_FFFFu64 } else { v as u64 }
-                            }
-                            Err(_) => {
-                                parser.skip_to_end_of_line();
-                                break;
-                            }
-                        };
-                        parser.skip_newlines_and_spaces();
-                        // Parse description string (expect quote, then take until quote)
-                        if parser.expect(b"\"").is_err() {
-                            parser.skip_to_end_of_line();
-                            break;
-                        }
-                        let description_bytes = match parser.take_until_quote(false, 1024) {
-                            Ok(bytes) => bytes,
-                            Err(_) => {
-                                parser.skip_to_end_of_line();
-                                break;
-                            }
-                        };
-                        let description = match core::str::from_utf8(description_bytes)
-                            .ok()
-                            .and_then(|s| Name::try_from(s).ok())
-                        {
-                            Some(desc) => desc,
-                            None => {
-                                parser.skip_to_end_of_line();
-                                break;
-                            }
-                        };
-                        let _ = entries.push((value, description));
-                    }
+                    let entries = ValueDescriptions::parse_entries(&mut parser);
                     if !entries.is_empty() {
                         let _ = value_descriptions_buffer.push((message_id, signal_name, entries));
                     }
@@ -428,9 +564,10 @@ impl Dbc {
                 }
                 BU_ => {
                     // Nodes::parse expects BU_ keyword, create parser from original input including it
+                    let bu_line = parser.line();
                     parser.skip_to_end_of_line();
                     let bu_input = &data.as_bytes()[pos_at_keyword..parser.pos()];
-                    let mut bu_parser = Parser::new(bu_input)?;
+                    let mut bu_parser = Parser::new_at_line(bu_input, bu_line)?;
                     nodes = Some(Nodes::parse(&mut bu_parser)?);
                     continue;
                 }
@@ -442,25 +579,30 @@ impl Dbc {
 
                     // Save parser position (at BO_ keyword, so Message::parse can consume it)
                     let message_start_pos = pos_at_keyword;
+                    let message_line = parser.line();
+                    #[cfg(feature = "spans")]
+                    let message_start = (message_line, parser.column());
 
                     // Don't manually parse - just find where the header ends by looking for the colon and sender
                     // We need to find the end of the header line to separate it from signals
-                    let header_line_end = {
+                    // ID and DLC are kept so signals can be bounds-checked as they are parsed
+                    let (header_line_end, message_id, message_dlc) = {
                         // Skip to end of line to find where header ends
                         let mut temp_parser = Parser::new(&data.as_bytes()[pos_at_keyword..])?;
+                        temp_parser.set_hex_message_ids(options.hex_message_ids);
                         // Skip BO_ keyword
                         temp_parser.expect(crate::BO_.as_bytes()).ok();
                         temp_parser.skip_whitespace().ok();
-                        temp_parser.parse_u32().ok(); // ID
+                        let id = temp_parser.parse_message_id().ok(); // ID
                         temp_parser.skip_whitespace().ok();
                         temp_parser.parse_identifier().ok(); // name
                         temp_parser.skip_whitespace().ok();
                         temp_parser.expect(b":").ok(); // colon
                         temp_parser.skip_whitespace().ok();
-                        temp_parser.parse_u8().ok(); // DLC
+                        let dlc = temp_parser.parse_u8().ok(); // DLC
                         temp_parser.skip_whitespace().ok();
                         temp_parser.parse_identifier().ok(); // sender
-                        pos_at_keyword + temp_parser.pos()
+                        (pos_at_keyword + temp_parser.pos(), id, dlc)
                     };
 
                     // Now parse signals from the original parser
@@ -492,13 +634,59 @@ impl Dbc {
                         }
 
                         // Parse signal - Signal::parse consumes SG_ itself
+                        let signal_line = parser.line();
+                        #[cfg(feature = "spans")]
+                        let signal_column = parser.column();
                         match Signal::parse(&mut parser) {
-                            Ok(signal) => {
+                            #[allow(unused_mut)]
+                            Ok(mut signal) => {
+                                #[cfg(feature = "spans")]
+                                signal.set_span((signal_line, signal_column, parser.line()));
+                                // Reject signals overrunning the DLC here, where the
+                                // offending SG_ line is still known
+                                if let (Some(id), Some(dlc)) = (message_id, message_dlc) {
+                                    let end_bit = Message::signal_end_bit(&signal);
+                                    let available_bits = u16::from(dlc) * 8;
+                                    if id != Message::PSEUDO_MESSAGE_ID
+                                        && dlc <= 64
+                                        && end_bit >= available_bits
+                                    {
+                                        // A start bit numbered for the other byte order is
+                                        // a common authoring error: point it out
+                                        let byte_order = signal.byte_order();
+                                        let opposite = match byte_order {
+                                            ByteOrder::LittleEndian => ByteOrder::BigEndian,
+                                            ByteOrder::BigEndian => ByteOrder::LittleEndian,
+                                        };
+                                        let (lsb, msb) = Message::bit_range(
+                                            signal.start_bit(),
+                                            signal.length(),
+                                            opposite,
+                                        );
+                                        if lsb.max(msb) < available_bits {
+                                            return Err(Error::SignalByteOrderMismatch {
+                                                message_id: id,
+                                                signal: Name::try_from(signal.name())?,
+                                                byte_order,
+                                                line: Some(signal_line),
+                                            });
+                                        }
+                                        return Err(Error::SignalOutOfBounds {
+                                            message_id: id,
+                                            signal: Name::try_from(signal.name())?,
+                                            end_bit,
+                                            available_bits,
+                                            line: Some(signal_line),
+                                        });
+                                    }
+                                }
                                 signals_array.push(signal).map_err(|_| {
                                     parser.err_receivers(Error::SIGNAL_RECEIVERS_TOO_MANY)
                                 })?;
-                                // Receivers::parse stops at newline but doesn't consume it
-                                // Consume it so next iteration starts at the next line
+                                // Receivers::parse stops at newline or at a trailing `//` comment
+                                // without consuming it. Consume both so the next iteration starts
+                                // at the next line
+                                parser.skip_trailing_comment();
                                 if parser.at_newline() {
                                     parser.skip_to_end_of_line();
                                 }
@@ -515,10 +703,24 @@ impl Dbc {
                     // Create a new parser from the original input, but only up to the end of the header
                     // (not including signals, so Message::parse doesn't complain about extra content)
                     let message_input = &data.as_bytes()[message_start_pos..header_line_end];
-                    let mut message_parser = Parser::new(message_input)?;
+                    // Errors in the header report the line of the BO_ keyword
+                    let mut message_parser = Parser::new_at_line(message_input, message_line)?;
+                    message_parser.set_hex_message_ids(options.hex_message_ids);
 
                     // Use Message::parse which will parse the header and use our signals
-                    let message = Message::parse(&mut message_parser, signals_array.as_slice())?;
+                    #[allow(unused_mut)]
+                    let mut message =
+                        Message::parse(&mut message_parser, signals_array.as_slice())?;
+                    #[cfg(feature = "spans")]
+                    {
+                        let (line, column) = message_start;
+                        let end_line = signals_array
+                            .as_slice()
+                            .last()
+                            .and_then(Signal::span)
+                            .map_or(line, |(_, _, end_line)| end_line);
+                        message.set_span((line, column, end_line));
+                    }
 
                     messages_buffer
                         .push(message)
@@ -532,6 +734,10 @@ impl Dbc {
                     continue;
                 }
                 _ => {
+                    // Unmodeled sections (BA_REL_, BU_SG_REL_, CAT_, FILTER, ...)
+                    if options.strict {
+                        return Err(parser.err_expected(Error::UNSUPPORTED_KEYWORD));
+                    }
                     parser.skip_to_end_of_line();
                     continue;
                 }
@@ -565,6 +771,24 @@ impl Dbc {
             ValueDescriptionsMap::new(map)
         };
 
+        // Attach SGTYPE_VAL_ value descriptions to their signal types (consume buffer to avoid cloning)
+        for (type_name, entries) in signal_type_value_descriptions_buffer {
+            if let Some(signal_type) =
+                signal_types_buffer.iter_mut().find(|t| t.name() == type_name.as_str())
+            {
+                signal_type.set_value_descriptions(ValueDescriptions::new(entries));
+            }
+        }
+
+        // Attach ENVVAR_DATA_ sizes to their environment variables
+        for (name, data_size) in environment_variable_data_buffer {
+            if let Some(env_var) =
+                environment_variables_buffer.iter_mut().find(|ev| ev.name() == name.as_str())
+            {
+                env_var.set_data_size(data_size);
+            }
+        }
+
         // Build attribute maps from buffers (consume buffers to avoid cloning)
         #[cfg(feature = "attributes")]
         let (attribute_definitions, attribute_defaults, attribute_values) = {
@@ -625,21 +849,83 @@ impl Dbc {
             }
         }
 
-        // Validate messages (duplicate IDs, sender in nodes, etc.)
+        // Signal value types are applied by matching (message_id, signal_name)
+        for (message_id, signal_name, value_type) in signal_value_types_buffer {
+            Self::apply_signal_value_type(
+                &mut messages_buffer,
+                message_id,
+                &signal_name,
+                value_type,
+            );
+        }
+
+        // Apply transmitted length overrides (BA_ "GenMsgLength" BO_ <id> <len>;)
+        #[cfg(feature = "attributes")]
+        for ((name, target), value) in attribute_values.iter() {
+            let AttributeTarget::Message(message_id) = *target else {
+                continue;
+            };
+            if name != Message::DLC_OVERRIDE_ATTRIBUTE {
+                continue;
+            }
+            let Some(dlc) = value.as_int().and_then(|v| u8::try_from(v).ok()) else {
+                continue;
+            };
+            if dlc > 64 {
+                continue;
+            }
+            for msg in messages_buffer.iter_mut() {
+                if msg.id() == message_id || msg.id_with_flag() == message_id {
+                    msg.set_dlc_override(dlc);
+                    break;
+                }
+            }
+        }
+
+        // Apply signal initial values (BA_ "GenSigStartValue" SG_ <id> <signal> <raw>;)
+        #[cfg(feature = "attributes")]
+        for ((name, target), value) in attribute_values.iter() {
+            let AttributeTarget::Signal(message_id, signal_name) = target else {
+                continue;
+            };
+            if name != Signal::START_VALUE_ATTRIBUTE {
+                continue;
+            }
+            let start_value = match *value {
+                AttributeValue::Int(v) => v,
+                AttributeValue::Float(v) if v.is_finite() && v == (v as i64) as f64 => v as i64,
+                _ => continue,
+            };
+            for msg in messages_buffer.iter_mut() {
+                if msg.id() == *message_id || msg.id_with_flag() == *message_id {
+                    if let Some(signal) = msg.signals_mut().find_mut(signal_name.as_str()) {
+                        signal.set_start_value(start_value);
+                    }
+                    break;
+                }
+            }
+        }
+
+        let map_validation_error = |e| match e {
+            Error::DuplicateMultiplexerSwitch { .. } => e,
+            e => crate::error::map_val_error(e, Error::message, || {
+                Error::message(Error::MESSAGE_ERROR_PREFIX)
+            }),
+        };
+
+        // Construct directly from owned buffer (avoids cloning all messages)
+        // Building the ID index rejects duplicate message IDs
+        let messages = Messages::from_vec(messages_buffer).map_err(map_validation_error)?;
+
+        // Validate messages (sender in nodes, multiplexing, etc.)
         Validate::validate(
             &nodes,
-            messages_buffer.as_slice(),
+            messages.as_slice(),
             Some(&value_descriptions_map),
             Some(extended_multiplexing_buffer.as_slice()),
         )
-        .map_err(|e| {
-            crate::error::map_val_error(e, Error::message, || {
-                Error::message(Error::MESSAGE_ERROR_PREFIX)
-            })
-        })?;
-
-        // Construct directly from owned buffer (avoids cloning all messages)
-        let messages = Messages::from_vec(messages_buffer)?;
+        .map_err(map_validation_error)?;
+        Validate::validate_environment_variables(&nodes, environment_variables_buffer.as_slice())?;
 
         #[cfg(feature = "attributes")]
         return Ok(Dbc::new(
@@ -650,6 +936,10 @@ impl Dbc {
             value_descriptions_map,
             extended_multiplexing_buffer,
             db_comment,
+            signal_types_buffer,
+            signal_type_refs_buffer,
+            signal_groups_buffer,
+            environment_variables_buffer,
             attribute_definitions,
             attribute_defaults,
             attribute_values,
@@ -664,11 +954,64 @@ impl Dbc {
             value_descriptions_map,
             extended_multiplexing_buffer,
             db_comment,
+            signal_types_buffer,
+            signal_type_refs_buffer,
+            signal_groups_buffer,
+            environment_variables_buffer,
         ))
     }
 
+    /// Set the value type of a `SIG_VALTYPE_` entry on its signal
+    ///
+    /// Returns `false` if no message in `messages` has `message_id`.
+    fn apply_signal_value_type(
+        messages: &mut Vec<Message, { MAX_MESSAGES }>,
+        message_id: u32,
+        signal_name: &Name,
+        value_type: SignalValueType,
+    ) -> bool {
+        let Some(msg) = messages
+            .iter_mut()
+            .find(|msg| msg.id() == message_id || msg.id_with_flag() == message_id)
+        else {
+            return false;
+        };
+        if let Some(signal) = msg.signals_mut().find_mut(signal_name.as_str()) {
+            signal.set_value_type(value_type);
+        }
+        true
+    }
+
+    /// Read a quoted comment string, returning `None` if it is malformed
+    ///
+    /// Expects the parser to be positioned at the opening quote. Comments longer than
+    /// `MAX_COMMENT_SIZE` are kept in full with `alloc`. With `heapless` they are
+    /// truncated, or rejected if [`ParseOptions::reject_long_comments`] is set.
+    fn parse_comment_text(parser: &mut Parser, options: ParseOptions) -> Result<Option<Comment>> {
+        if parser.expect(b"\"").is_err() {
+            return Ok(None);
+        }
+        let max_len = parser.remaining().len();
+        let Ok(comment_bytes) = parser.take_until_quote(false, max_len) else {
+            return Ok(None);
+        };
+        let Ok(comment_str) = core::str::from_utf8(comment_bytes) else {
+            return Ok(None);
+        };
+        if comment_str.len() > MAX_COMMENT_SIZE
+            && cfg!(not(feature = "alloc"))
+            && options.reject_long_comments
+        {
+            return Err(parser.err_max_str_length(MAX_COMMENT_SIZE));
+        }
+        Ok(Some(Comment::from_str_truncating(comment_str)))
+    }
+
     /// Parse a DBC file from a byte slice
     ///
+    /// A leading UTF-8 byte order mark and trailing NUL bytes (e.g. from a zero-padded
+    /// fixed-size buffer) are ignored. The remaining bytes must be valid UTF-8.
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
@@ -680,15 +1023,16 @@ impl Dbc {
     /// # Ok::<(), dbc_rs::Error>(())
     /// ```
     pub fn parse_bytes(data: &[u8]) -> Result<Self> {
-        let content =
-            core::str::from_utf8(data).map_err(|_e| Error::expected(Error::INVALID_UTF8))?;
+        let end = data.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+        let content = core::str::from_utf8(&data[..end])
+            .map_err(|_e| Error::expected(Error::INVALID_UTF8))?;
         Dbc::parse(content)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Dbc;
+    use crate::{Dbc, Error};
 
     #[test]
     fn test_parse_basic() {
@@ -714,6 +1058,116 @@ BO_ 256 Engine : 8 ECM
         assert_eq!(dbc.messages().len(), 1);
     }
 
+    #[test]
+    fn test_parse_bytes_bom_and_nul_padding() {
+        let mut buffer = [0u8; 128];
+        let dbc_bytes = b"\xEF\xBB\xBFVERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n";
+        buffer[..dbc_bytes.len()].copy_from_slice(dbc_bytes);
+
+        let dbc = Dbc::parse_bytes(&buffer).unwrap();
+        assert_eq!(dbc.version().map(|v| v.as_str()), Some("1.0"));
+        assert_eq!(dbc.messages().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_signal_groups_for_message() {
+        let data = r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ Sig1 : 0|8@1+ (1,0) [0|255] "" *
+ SG_ Sig2 : 8|8@1+ (1,0) [0|255] "" *
+
+BO_ 512 Brake : 8 ECM
+ SG_ Sig3 : 0|8@1+ (1,0) [0|255] "" *
+
+SIG_GROUP_ 256 Group1 1 : Sig1 Sig2 ;
+SIG_GROUP_ 512 Group2 1 : Sig3;
+"#;
+        let dbc = Dbc::parse(data).unwrap();
+        let mut groups = dbc.signal_groups_for_message(256);
+        let group = groups.next().unwrap();
+        assert!(groups.next().is_none());
+        assert_eq!(group.message_id(), 256);
+        assert_eq!(group.name(), "Group1");
+        assert_eq!(group.repetitions(), 1);
+        let mut names = group.signal_names();
+        assert_eq!(names.next(), Some("Sig1"));
+        assert_eq!(names.next(), Some("Sig2"));
+        assert_eq!(names.next(), None);
+
+        assert_eq!(dbc.signal_groups_for_message(512).count(), 1);
+        assert_eq!(dbc.signal_groups_for_message(768).count(), 0);
+    }
+
+    #[test]
+    fn test_parse_environment_variables() {
+        let data = r#"VERSION "1.0"
+
+BU_: ECM TCM
+
+EV_ EnvKL15 : 0 [0|1] "" 0 1 DUMMY_NODE_VECTOR3 ECM, TCM;
+EV_ EnvSpeed : 1 [0|250] "km/h" 10 2 DUMMY_NODE_VECTOR1 ECM;
+EV_ EnvBlob : 0 [0|0] "" 0 3 DUMMY_NODE_VECTOR0 Vector__XXX;
+
+ENVVAR_DATA_ EnvBlob : 16;
+EV_DATA_ Unknown : 4;
+"#;
+        let dbc = Dbc::parse(data).unwrap();
+        assert_eq!(dbc.environment_variables().count(), 3);
+
+        let speed = dbc.environment_variable("EnvSpeed").unwrap();
+        assert_eq!(speed.var_type(), crate::EnvironmentVariableType::Float);
+        assert_eq!(speed.unit(), Some("km/h"));
+        assert_eq!(speed.ev_id(), 2);
+        assert_eq!(speed.data_size(), None);
+
+        let blob = dbc.environment_variable("EnvBlob").unwrap();
+        assert_eq!(blob.var_type(), crate::EnvironmentVariableType::Data);
+        assert_eq!(blob.data_size(), Some(16));
+        assert_eq!(blob.access_nodes().count(), 0);
+    }
+
+    #[test]
+    fn test_parse_environment_variable_errors() {
+        // Unknown variable type
+        let malformed = "VERSION \"1.0\"\n\nBU_: ECM\n\nEV_ Broken : 7 [0|0] \"\" 0 4 DUMMY_NODE_VECTOR0 ECM;\n";
+        assert_eq!(
+            Dbc::parse(malformed).unwrap_err(),
+            Error::expected_at(Error::ENVIRONMENT_VARIABLE_INVALID, 5)
+        );
+
+        let malformed_data = "VERSION \"1.0\"\n\nBU_: ECM\n\nENVVAR_DATA_ EnvBlob 16;\n";
+        assert_eq!(
+            Dbc::parse(malformed_data).unwrap_err(),
+            Error::expected_at(Error::ENVIRONMENT_VARIABLE_INVALID, 5)
+        );
+
+        let duplicate = r#"VERSION "1.0"
+
+BU_: ECM
+
+EV_ EnvKL15 : 0 [0|1] "" 0 1 DUMMY_NODE_VECTOR3 ECM;
+EV_ EnvKL15 : 0 [0|1] "" 0 2 DUMMY_NODE_VECTOR3 ECM;
+"#;
+        assert_eq!(
+            Dbc::parse(duplicate).unwrap_err(),
+            Error::expected_at(Error::ENVIRONMENT_VARIABLE_DUPLICATE_NAME, 6)
+        );
+
+        let unknown_node = r#"VERSION "1.0"
+
+BU_: ECM
+
+EV_ EnvKL15 : 0 [0|1] "" 0 1 DUMMY_NODE_VECTOR3 ECM, TCM;
+"#;
+        assert_eq!(
+            Dbc::parse(unknown_node).unwrap_err(),
+            Error::Validation(Error::ENVIRONMENT_VARIABLE_NODE_NOT_IN_NODES)
+        );
+    }
+
     #[test]
     fn test_parse_empty_nodes() {
         let dbc_content = r#"VERSION "1.0"
@@ -788,6 +1242,29 @@ BO_ 256 EngineData2 : 8 ECM
         }
     }
 
+    #[test]
+    fn test_parse_standard_and_extended_same_base_id() {
+        // The ID index keys on the extended flag, so these are not duplicates
+        let data = r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Standard : 8 ECM
+ SG_ A : 0|8@1+ (1,0) [0|255] ""
+
+BO_ 2147483904 Extended : 8 ECM
+ SG_ B : 0|8@1+ (1,0) [0|255] ""
+"#;
+
+        let dbc = Dbc::parse(data).unwrap();
+        assert_eq!(dbc.messages().find_by_id(256).unwrap().name(), "Standard");
+        assert_eq!(
+            dbc.messages().find_by_id(0x8000_0100).unwrap().name(),
+            "Extended"
+        );
+        assert!(dbc.messages().find_by_id(257).is_none());
+    }
+
     #[test]
     fn test_parse_sender_not_in_nodes() {
         use crate::Error;
@@ -860,12 +1337,29 @@ BO_ 256 Engine : 8 ECM
 BU_: ECM
 
 BO_ 256 Test : 8 ECM
- SG_ CHECKSUM : 63|8@1+ (1,0) [0|255] ""
+ SG_ CHECKSUM : 60|8@1+ (1,0) [0|255] ""
 "#;
 
         // Default (strict) mode should fail
         let result = Dbc::parse(data);
         assert!(result.is_err());
+
+        // The error points at the offending SG_ line
+        match result.unwrap_err() {
+            Error::SignalOutOfBounds {
+                message_id,
+                end_bit,
+                available_bits,
+                line,
+                ..
+            } => {
+                assert_eq!(message_id, 256);
+                assert_eq!(end_bit, 67);
+                assert_eq!(available_bits, 64);
+                assert_eq!(line, Some(6));
+            }
+            other => panic!("Expected SignalOutOfBounds error, got {:?}", other),
+        }
     }
 
     #[cfg(feature = "std")]
@@ -1146,6 +1640,30 @@ BO_ invalid EngineData : 8 ECM
         assert!(err.line().is_some(), "Error should include line number");
     }
 
+    #[test]
+    fn test_parse_bom_and_crlf() {
+        // UTF-8 BOM and Windows line endings, as written by Windows tools
+        let data = "\u{feff}VERSION \"1.0\"\r\n\r\nBU_: ECM TCM\r\n\r\nBO_ 256 Engine : 8 ECM\r\n SG_ RPM : 0|16@1+ (0.25,0) [0|8000] \"rpm\" TCM\r\n\r\nCM_ BO_ 256 \"Engine data\";\r\n";
+
+        let dbc = Dbc::parse(data).unwrap();
+        assert_eq!(dbc.version().map(|v| v.as_str()), Some("1.0"));
+        assert_eq!(dbc.nodes().len(), 2);
+        let engine = dbc.messages().find_by_id(256).unwrap();
+        assert_eq!(engine.signals().len(), 1);
+        assert_eq!(engine.comment(), Some("Engine data"));
+    }
+
+    #[test]
+    fn test_error_line_number_with_bom_and_crlf() {
+        let lf = "VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ invalid EngineData : 8 ECM\n";
+        let bom_crlf =
+            "\u{feff}VERSION \"1.0\"\r\n\r\nBU_: ECM\r\n\r\nBO_ invalid EngineData : 8 ECM\r\n";
+
+        let expected = Dbc::parse(lf).unwrap_err();
+        assert_eq!(expected.line(), Some(5));
+        assert_eq!(Dbc::parse(bom_crlf).unwrap_err().line(), Some(5));
+    }
+
     // ============================================================================
     // CM_ Comment Parsing Tests (Section 14 of SPECIFICATIONS.md)
     // ============================================================================
@@ -1359,5 +1877,316 @@ CM_ SG_ 256 RPM "RPM signal";
         assert!(serialized.contains("CM_ BU_ ECM \"Engine Control Module\";"));
         assert!(serialized.contains("CM_ BO_ 256 \"Engine status\";"));
         assert!(serialized.contains("CM_ SG_ 256 RPM \"RPM signal\";"));
+
+        // Signal comments survive a round trip
+        let reparsed = Dbc::parse(&serialized).unwrap();
+        let message = reparsed.messages().find_by_id(256).unwrap();
+        assert_eq!(
+            message.signals().find("RPM").unwrap().comment(),
+            Some("RPM signal")
+        );
+    }
+
+    /// Writes a DBC with a `len`-byte message comment into `buf` and returns it as `&str`.
+    fn long_comment_dbc(buf: &mut [u8], len: usize) -> &str {
+        const HEAD: &[u8] =
+            b"VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n\nCM_ BO_ 256 \"";
+        const TAIL: &[u8] = b"\";\n";
+        let total = HEAD.len() + len + TAIL.len();
+        buf[..HEAD.len()].copy_from_slice(HEAD);
+        buf[HEAD.len()..HEAD.len() + len].fill(b'x');
+        buf[HEAD.len() + len..total].copy_from_slice(TAIL);
+        core::str::from_utf8(&buf[..total]).unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_parse_long_comment_unbounded_with_alloc() {
+        let len = crate::compat::MAX_COMMENT_SIZE * 4;
+        let mut buf = [0u8; 2048];
+        let data = long_comment_dbc(&mut buf, len);
+
+        let dbc = Dbc::parse(data).unwrap();
+        let msg = dbc.messages().find_by_id(256).unwrap();
+        assert_eq!(msg.comment().map(str::len), Some(len));
+
+        // The option has no effect when comments are heap-allocated
+        let options = crate::ParseOptions::new().reject_long_comments(true);
+        let dbc = Dbc::parse_with_options(data, options).unwrap();
+        let msg = dbc.messages().find_by_id(256).unwrap();
+        assert_eq!(msg.comment().map(str::len), Some(len));
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc"))]
+    fn test_parse_long_comment_rejected_with_heapless() {
+        let mut buf = [0u8; 2048];
+        let data = long_comment_dbc(&mut buf, crate::compat::MAX_COMMENT_SIZE + 1);
+        let options = crate::ParseOptions::new().reject_long_comments(true);
+        assert!(Dbc::parse_with_options(data, options).is_err());
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc"))]
+    fn test_parse_long_comment_truncated_with_heapless() {
+        let mut buf = [0u8; 2048];
+        let data = long_comment_dbc(&mut buf, crate::compat::MAX_COMMENT_SIZE + 1);
+        let dbc = Dbc::parse(data).unwrap();
+        let msg = dbc.messages().find_by_id(256).unwrap();
+        assert_eq!(
+            msg.comment().map(str::len),
+            Some(crate::compat::MAX_COMMENT_SIZE)
+        );
+    }
+
+    #[test]
+    fn test_parse_hex_message_ids() {
+        let hex = r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 0x100 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" Vector__XXX
+
+BO_ 0x80000400 Diag : 8 ECM
+ SG_ Mode : 0|8@1+ (1,0) [0|3] "" Vector__XXX
+
+CM_ BO_ 0x100 "Engine data";
+VAL_ 0x100 RPM 0 "Stopped" ;
+"#;
+        let dec = r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" Vector__XXX
+
+BO_ 2147484672 Diag : 8 ECM
+ SG_ Mode : 0|8@1+ (1,0) [0|3] "" Vector__XXX
+
+CM_ BO_ 256 "Engine data";
+VAL_ 256 RPM 0 "Stopped" ;
+"#;
+        // Strict parsing follows the spec and rejects hexadecimal IDs
+        assert!(Dbc::parse(hex).is_err());
+
+        let options = crate::ParseOptions::new().hex_message_ids(true);
+        let from_hex = Dbc::parse_with_options(hex, options).unwrap();
+
+        let engine = from_hex.messages().find_by_id(256).unwrap();
+        assert_eq!(engine.name(), "Engine");
+        assert_eq!(engine.comment(), Some("Engine data"));
+        assert!(from_hex.value_descriptions_for_signal(256, "RPM").is_some());
+        let diag = from_hex.messages().find_by_id(0x400 | 0x8000_0000).unwrap();
+        assert!(diag.is_extended());
+
+        // Both spellings describe the same messages
+        let from_dec = Dbc::parse_with_options(dec, options).unwrap();
+        for (a, b) in from_hex.messages().iter().zip(from_dec.messages().iter()) {
+            assert_eq!((a.id_with_flag(), a.name()), (b.id_with_flag(), b.name()));
+        }
+        #[cfg(feature = "std")]
+        assert_eq!(from_hex.to_dbc_string(), from_dec.to_dbc_string());
+    }
+
+    #[test]
+    fn test_parse_strict_rejects_unmodeled_keywords() {
+        let data = r#"VERSION "1.0"
+
+BU_: ECM TCM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" TCM
+
+BU_SG_REL_ "Relevance" SG_ TCM 256 RPM 1;
+"#;
+        let lenient = Dbc::parse(data).unwrap();
+        assert_eq!(lenient.messages().len(), 1);
+
+        match Dbc::parse_strict(data) {
+            Err(Error::Expected { msg, line }) => {
+                assert_eq!(msg, Error::UNSUPPORTED_KEYWORD);
+                assert_eq!(line, Some(8));
+            }
+            other => panic!("expected UNSUPPORTED_KEYWORD, got {other:?}"),
+        }
+
+        // Recognized-but-skipped sections are rejected too
+        let val_table = "VERSION \"1.0\"\n\nBU_: ECM\n\nVAL_TABLE_ Gears 0 \"P\" 1 \"D\" ;\n";
+        assert!(Dbc::parse(val_table).is_ok());
+        assert!(Dbc::parse_strict(val_table).is_err());
+
+        // Everything the crate models still parses in strict mode
+        let modeled = r#"VERSION "1.0"
+
+NS_ :
+    NS_DESC_
+    BA_REL_
+
+BS_:
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" Vector__XXX
+
+CM_ BO_ 256 "Engine data";
+VAL_ 256 RPM 0 "Stopped" ;
+"#;
+        assert!(Dbc::parse_strict(modeled).is_ok());
+    }
+
+    #[test]
+    fn test_parse_strict_attributes_need_feature() {
+        // Attribute sections are unmodeled without the `attributes` feature
+        let attributes = "VERSION \"1.0\"\n\nBU_: ECM\n\nBA_DEF_ BU_ \"Layer\" STRING;\n";
+        assert!(Dbc::parse(attributes).is_ok());
+        assert_eq!(
+            Dbc::parse_strict(attributes).is_ok(),
+            cfg!(feature = "attributes")
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_signal_value_types_order_and_capacity() {
+        use crate::SignalValueType;
+        use std::fmt::Write;
+
+        // An entry ahead of its BO_ is applied once the message is parsed
+        let mut data = std::string::String::from(
+            "VERSION \"1.0\"\n\nBU_: ECM\n\nSIG_VALTYPE_ 512 Late : 1;\n\n\
+             BO_ 256 Engine : 8 ECM\n SG_ Torque : 0|32@1- (1,0) [-1000|1000] \"Nm\" *\n\n\
+             BO_ 512 Brake : 8 ECM\n SG_ Late : 0|32@1- (1,0) [-1000|1000] \"\" *\n\n",
+        );
+        // Entries following their BO_ are not limited by the pending-entry buffer
+        for _ in 0..=crate::MAX_MESSAGES * 4 {
+            writeln!(data, "SIG_VALTYPE_ 256 Torque : 1;").unwrap();
+        }
+
+        let dbc = Dbc::parse(&data).unwrap();
+        let value_type = |message: &str, signal: &str| {
+            let message = dbc.messages().find(message).unwrap();
+            message.signals().find(signal).unwrap().value_type()
+        };
+        assert_eq!(value_type("Engine", "Torque"), SignalValueType::Float);
+        assert_eq!(value_type("Brake", "Late"), SignalValueType::Float);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_signal_type_value_descriptions_capacity() {
+        use std::fmt::Write;
+
+        let mut data = std::string::String::from("VERSION \"1.0\"\n\nBU_: ECM\n\n");
+        for i in 0..=crate::MAX_SIGNAL_TYPES {
+            writeln!(data, "SGTYPE_VAL_ Type{i} 0 \"Off\" 1 \"On\" ;").unwrap();
+        }
+
+        assert_eq!(
+            Dbc::parse(&data).unwrap_err(),
+            Error::Validation(Error::SIGNAL_TYPES_TOO_MANY)
+        );
+    }
+
+    #[test]
+    fn test_parse_signal_groups_without_semicolon() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+
+SIG_GROUP_ 256 EngineGroup 1 : RPM
+SIG_GROUP_ 256 SecondGroup 1 : RPM;
+"#,
+        )
+        .unwrap();
+
+        let mut groups = dbc.signal_groups().iter();
+        assert_eq!(groups.next().map(|g| g.name()), Some("EngineGroup"));
+        assert_eq!(groups.next().map(|g| g.name()), Some("SecondGroup"));
+        assert!(groups.next().is_none());
+    }
+
+    #[test]
+    fn test_parse_signal_value_types() {
+        use crate::SignalValueType;
+
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ Torque : 0|32@1- (1,0) [-1000|1000] "Nm" *
+ SG_ Mode : 32|8@1+ (1,0) [0|3] "" *
+
+BO_ 2147484672 Precise : 8 ECM
+ SG_ Position : 0|64@1- (1,0) [-1000000|1000000] "m" *
+
+SIG_VALTYPE_ 256 Torque : 1;
+SIG_VALTYPE_ 2147484672 Position 2;
+SIG_VALTYPE_ 256 Mode : 7;
+SIG_VALTYPE_ 999 Unknown : 1;
+"#,
+        )
+        .unwrap();
+
+        let engine = dbc.messages().find("Engine").unwrap();
+        assert_eq!(
+            engine.signals().find("Torque").unwrap().value_type(),
+            SignalValueType::Float
+        );
+        // Unknown type codes are skipped
+        assert!(!engine.signals().find("Mode").unwrap().is_float());
+        // The colon is optional and extended IDs carry bit 31
+        let precise = dbc.messages().find("Precise").unwrap();
+        assert_eq!(
+            precise.signals().find("Position").unwrap().value_type(),
+            SignalValueType::Double
+        );
+
+        // Float types on signals that are not 32 bits long are ignored
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ Torque : 0|16@1- (1,0) [-1000|1000] "Nm" *
+
+SIG_VALTYPE_ 256 Torque : 1;
+"#,
+        )
+        .unwrap();
+        let engine = dbc.messages().find("Engine").unwrap();
+        assert!(!engine.signals().find("Torque").unwrap().is_float());
+    }
+
+    #[test]
+    #[cfg(feature = "long-names")]
+    fn test_parse_long_signal_name() {
+        let name = "ExhaustGasRecirculationValvePositionDiag";
+        assert_eq!(name.len(), 40);
+
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ ExhaustGasRecirculationValvePositionDiag : 0|8@1+ (1,0) [0|255] "" *
+
+CM_ SG_ 256 ExhaustGasRecirculationValvePositionDiag "EGR valve position";
+"#,
+        )
+        .unwrap();
+
+        let signal = dbc.messages().find("Engine").unwrap().signals().find(name).unwrap();
+        assert_eq!(signal.name(), name);
+        assert_eq!(signal.comment(), Some("EGR valve position"));
     }
 }
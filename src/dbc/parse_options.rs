@@ -0,0 +1,75 @@
+/// Options controlling how [`Dbc::parse_with_options`](crate::Dbc::parse_with_options)
+/// handles input that exceeds the configured limits or departs from the DBC format.
+///
+/// The default options match [`Dbc::parse`](crate::Dbc::parse).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use dbc_rs::{Dbc, ParseOptions};
+///
+/// let options = ParseOptions::new().hex_message_ids(true).strict(true);
+/// let dbc = Dbc::parse_with_options("VERSION \"1.0\"\n\nBU_: ECM\n", options)?;
+/// # Ok::<(), dbc_rs::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// Fail the parse on `CM_` comments that exceed the fixed comment capacity instead
+    /// of truncating them.
+    ///
+    /// Only relevant with the `heapless` feature, where comments are stored in
+    /// fixed-capacity strings. With `alloc`, comments are never truncated.
+    pub reject_long_comments: bool,
+
+    /// Accept hexadecimal message IDs with a `0x`/`0X` prefix, e.g. `BO_ 0x100 ...`.
+    ///
+    /// The DBC format requires decimal IDs, but some hand-written files use hexadecimal.
+    /// The prefix is accepted wherever a message ID is expected (`BO_`, `CM_`, `BA_`,
+    /// `VAL_`, `SIG_GROUP_`, `SIG_TYPE_REF_`, `SIG_VALTYPE_` and `SG_MUL_VAL_`). Extended
+    /// IDs still carry bit 31, e.g. `0x80000400`. Serialization always writes decimal IDs.
+    pub hex_message_ids: bool,
+
+    /// Reject sections the crate does not model instead of skipping them.
+    ///
+    /// By default, keywords such as `BA_REL_`, `BU_SG_REL_`, `CAT_`, `FILTER`,
+    /// `VAL_TABLE_` and `BO_TX_BU_` are skipped to the end of the line, as are `BA_DEF_`,
+    /// `BA_DEF_DEF_` and `BA_` without the `attributes` feature. In strict mode they fail
+    /// the parse with [`Error::UNSUPPORTED_KEYWORD`](crate::Error::UNSUPPORTED_KEYWORD).
+    ///
+    /// Strict mode only concerns keywords: malformed lines of modeled sections are handled
+    /// the same way in both modes. See also [`Dbc::parse_strict`](crate::Dbc::parse_strict).
+    pub strict: bool,
+}
+
+impl ParseOptions {
+    /// Create the default options, equivalent to [`ParseOptions::default`].
+    pub const fn new() -> Self {
+        Self {
+            reject_long_comments: false,
+            hex_message_ids: false,
+            strict: false,
+        }
+    }
+
+    /// Set [`reject_long_comments`](Self::reject_long_comments).
+    #[must_use = "builder method returns modified options"]
+    pub const fn reject_long_comments(mut self, reject_long_comments: bool) -> Self {
+        self.reject_long_comments = reject_long_comments;
+        self
+    }
+
+    /// Set [`hex_message_ids`](Self::hex_message_ids).
+    #[must_use = "builder method returns modified options"]
+    pub const fn hex_message_ids(mut self, hex_message_ids: bool) -> Self {
+        self.hex_message_ids = hex_message_ids;
+        self
+    }
+
+    /// Set [`strict`](Self::strict).
+    #[must_use = "builder method returns modified options"]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(feature = "attributes")]
+use super::{AttributeDefaultsMap, AttributeDefinitionsMap, AttributeValuesMap};
+use super::{
+    EnvironmentVariables, ExtendedMultiplexings, Messages, SignalGroups, SignalTypeRefs,
+    SignalTypes, Validate, ValueDescriptionsMap,
+};
+use crate::{
+    BitTiming, Dbc, MAX_MESSAGES, Message, Nodes, Signal, Version,
+    compat::{Comment, Vec},
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+// Messages serialize as a plain sequence; the ID index is rebuilt on deserialization
+impl Serialize for Messages {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+impl<'de> Deserialize<'de> for Messages {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let messages = Vec::<Message, { MAX_MESSAGES }>::deserialize(deserializer)?;
+        Messages::from_vec(messages).map_err(D::Error::custom)
+    }
+}
+
+/// Field-for-field mirror of [`Dbc`] without the derived extended multiplexing index.
+///
+/// Messages are read as a plain sequence and indexed only once validated: with `heapless`
+/// the ID index is a large inline map, and moving it through the visitor frames would
+/// exhaust the stack.
+#[derive(Deserialize)]
+#[serde(rename = "Dbc")]
+struct DbcFields {
+    version: Option<Version>,
+    bit_timing: Option<BitTiming>,
+    nodes: Nodes,
+    messages: Vec<Message, { MAX_MESSAGES }>,
+    value_descriptions: ValueDescriptionsMap,
+    extended_multiplexing: ExtendedMultiplexings,
+    comment: Option<Comment>,
+    signal_types: SignalTypes,
+    signal_type_refs: SignalTypeRefs,
+    signal_groups: SignalGroups,
+    environment_variables: EnvironmentVariables,
+    #[cfg(feature = "attributes")]
+    attribute_definitions: AttributeDefinitionsMap,
+    #[cfg(feature = "attributes")]
+    attribute_defaults: AttributeDefaultsMap,
+    #[cfg(feature = "attributes")]
+    attribute_values: AttributeValuesMap,
+}
+
+/// Deserializes a [`Dbc`], applying the same validation as [`Dbc::parse`].
+impl<'de> Deserialize<'de> for Dbc {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = DbcFields::deserialize(deserializer)?;
+
+        for message in fields.messages.iter() {
+            for signal in message.signals().iter() {
+                Signal::validate(signal.name(), signal.length(), signal.min(), signal.max())
+                    .map_err(D::Error::custom)?;
+            }
+            Message::validate(
+                message.id_with_flag(),
+                message.name(),
+                message.dlc(),
+                message.sender(),
+                message.signals().as_slice(),
+            )
+            .map_err(D::Error::custom)?;
+        }
+        Validate::validate(
+            &fields.nodes,
+            fields.messages.as_slice(),
+            Some(&fields.value_descriptions),
+            Some(fields.extended_multiplexing.as_slice()),
+        )
+        .map_err(D::Error::custom)?;
+        let messages = Messages::from_vec(fields.messages).map_err(D::Error::custom)?;
+
+        #[cfg(feature = "attributes")]
+        return Ok(Dbc::new(
+            fields.version,
+            fields.bit_timing,
+            fields.nodes,
+            messages,
+            fields.value_descriptions,
+            fields.extended_multiplexing,
+            fields.comment,
+            fields.signal_types,
+            fields.signal_type_refs,
+            fields.signal_groups,
+            fields.environment_variables,
+            fields.attribute_definitions,
+            fields.attribute_defaults,
+            fields.attribute_values,
+        ));
+
+        #[cfg(not(feature = "attributes"))]
+        Ok(Dbc::new(
+            fields.version,
+            fields.bit_timing,
+            fields.nodes,
+            messages,
+            fields.value_descriptions,
+            fields.extended_multiplexing,
+            fields.comment,
+            fields.signal_types,
+            fields.signal_type_refs,
+            fields.signal_groups,
+            fields.environment_variables,
+        ))
+    }
+}
@@ -0,0 +1,30 @@
+/// Options controlling how [`Dbc::to_string_with`](crate::Dbc::to_string_with) writes
+/// a DBC file.
+///
+/// The default options match [`Dbc::to_dbc_string`](crate::Dbc::to_dbc_string).
+/// Big-endian start bits are always written in the Vector MSB numbering they are stored
+/// in, so the output of any options parses back to the same signal layout.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use dbc_rs::{Dbc, SerializeOptions};
+///
+/// let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM")?;
+/// let options = SerializeOptions {
+///     sort_signals_by_start_bit: true,
+///     ..SerializeOptions::default()
+/// };
+/// let dbc_string = dbc.to_string_with(&options);
+/// # Ok::<(), dbc_rs::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SerializeOptions {
+    /// Write the `SG_` lines of each message in ascending start bit order instead of
+    /// definition order.
+    ///
+    /// Start bits are compared as written (the MSB for big-endian signals). Signals with
+    /// the same start bit keep their definition order. Other sections, such as `CM_` and
+    /// `VAL_`, are not reordered.
+    pub sort_signals_by_start_bit: bool,
+}
@@ -0,0 +1,224 @@
+use crate::{Dbc, DecodedSignal, Error, Message, Result, Signal};
+
+/// A stable, owned handle to a signal, identified by message ID and signal name.
+///
+/// Unlike a borrowed `&Signal`, a `SignalRef` does not borrow the [`Dbc`] it came from.
+/// It can be stored across rebuilds of the database (for example a selection in a UI)
+/// and resolved again with [`Dbc::resolve`]. Resolution fails only if the message or
+/// signal it names no longer exists.
+///
+/// `message_id` is the ID as written in the DBC file, including the extended ID flag
+/// (bit 31) for 29-bit IDs, the same form accepted by `dbc.messages().find_by_id()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SignalRef {
+    /// The message ID, including the extended ID flag for 29-bit IDs.
+    pub message_id: u32,
+    /// The signal name.
+    pub name: String,
+}
+
+impl SignalRef {
+    /// Creates a handle for the signal `name` in message `message_id`.
+    ///
+    /// The handle is not checked against any [`Dbc`]; use [`Dbc::signal_path`] to create
+    /// a handle only for signals that exist.
+    pub fn new(message_id: u32, name: impl Into<String>) -> Self {
+        Self {
+            message_id,
+            name: name.into(),
+        }
+    }
+}
+
+impl Dbc {
+    /// Returns a [`SignalRef`] handle for a signal, or `None` if it does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let handle = dbc.signal_path(256, "RPM").unwrap();
+    /// assert_eq!(dbc.resolve(&handle).unwrap().name(), "RPM");
+    /// assert!(dbc.signal_path(256, "Missing").is_none());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn signal_path(&self, message_id: u32, signal: &str) -> Option<SignalRef> {
+        let signal = self.messages().find_by_id(message_id)?.signals().find(signal)?;
+        Some(SignalRef::new(message_id, signal.name()))
+    }
+
+    /// Resolves a [`SignalRef`] handle to the signal it names in this DBC.
+    ///
+    /// Returns `None` if the message or signal does not exist.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn resolve(&self, signal_ref: &SignalRef) -> Option<&Signal> {
+        self.messages()
+            .find_by_id(signal_ref.message_id)?
+            .signals()
+            .find(&signal_ref.name)
+    }
+
+    /// Returns the qualified name `"MessageName.SignalName"` of a signal, or `None` if it
+    /// does not exist.
+    ///
+    /// Signal names are only unique within a message; qualified names are unique within
+    /// the database. `message_id` includes the extended ID flag for 29-bit IDs, as for
+    /// [`Dbc::signal_path`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// assert_eq!(dbc.qualified_signal_name(256, "RPM").as_deref(), Some("Engine.RPM"));
+    /// assert!(dbc.qualified_signal_name(256, "Missing").is_none());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn qualified_signal_name(&self, message_id: u32, signal: &str) -> Option<String> {
+        let message = self.messages().find_by_id(message_id)?;
+        let signal = message.signals().find(signal)?;
+        Some(format!("{}.{}", message.name(), signal.name()))
+    }
+
+    /// Decode a CAN message payload like [`Dbc::decode`], pairing each signal with its
+    /// qualified name `"MessageName.SignalName"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The raw CAN message ID (without extended flag)
+    /// * `payload` - The CAN message payload bytes
+    /// * `is_extended` - Whether this is an extended (29-bit) CAN ID
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let payload = [0x40, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    /// let decoded = dbc.decode_qualified(256, &payload, false)?;
+    /// assert_eq!(decoded[0].0, "Engine.RPM");
+    /// assert_eq!(decoded[0].1.value, 2000.0);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn decode_qualified(
+        &self,
+        id: u32,
+        payload: &[u8],
+        is_extended: bool,
+    ) -> Result<Vec<(String, DecodedSignal<'_>)>> {
+        let decoded = self.decode(id, payload, is_extended)?;
+        let id = if is_extended {
+            id | Message::EXTENDED_ID_FLAG
+        } else {
+            id
+        };
+        let message = self
+            .messages()
+            .find_by_id(id)
+            .ok_or(Error::Decoding(Error::MESSAGE_NOT_FOUND))?;
+        Ok(decoded
+            .into_iter()
+            .map(|signal| (format!("{}.{}", message.name(), signal.name), signal))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SignalRef;
+    use crate::{Dbc, DbcBuilder, MessageBuilder};
+
+    #[test]
+    fn test_signal_ref_survives_unrelated_edit() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+"#,
+        )
+        .unwrap();
+
+        let handle = dbc.signal_path(256, "Temp").unwrap();
+        assert_eq!(handle, SignalRef::new(256, "Temp"));
+        assert_eq!(dbc.resolve(&handle).unwrap().offset(), -40.0);
+
+        // Add another message; the handle still resolves
+        let edited = DbcBuilder::from_dbc(&dbc)
+            .add_message(MessageBuilder::new().id(128).name("Brake").dlc(8).sender("ECM"))
+            .build()
+            .unwrap();
+        assert_eq!(edited.messages().len(), 2);
+        assert_eq!(edited.resolve(&handle).unwrap().name(), "Temp");
+
+        // Removing the message invalidates the handle
+        let removed = DbcBuilder::from_dbc(&dbc).clear_messages().build().unwrap();
+        assert!(removed.resolve(&handle).is_none());
+    }
+
+    #[test]
+    fn test_qualified_names_disambiguate_shared_signal() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ Counter : 0|8@1+ (1,0) [0|255] "" *
+
+BO_ 512 Brake : 8 ECM
+ SG_ Counter : 0|8@1+ (1,0) [0|255] "" *
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            dbc.qualified_signal_name(256, "Counter").as_deref(),
+            Some("Engine.Counter")
+        );
+        assert_eq!(
+            dbc.qualified_signal_name(512, "Counter").as_deref(),
+            Some("Brake.Counter")
+        );
+        assert!(dbc.qualified_signal_name(1024, "Counter").is_none());
+
+        let payload = [7, 0, 0, 0, 0, 0, 0, 0];
+        let engine = dbc.decode_qualified(256, &payload, false).unwrap();
+        let brake = dbc.decode_qualified(512, &payload, false).unwrap();
+        assert_eq!(engine[0].0, "Engine.Counter");
+        assert_eq!(brake[0].0, "Brake.Counter");
+        assert_eq!(engine[0].1.value, brake[0].1.value);
+        assert_eq!(engine[0].1.name, "Counter");
+    }
+}
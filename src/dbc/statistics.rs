@@ -0,0 +1,331 @@
+use crate::Dbc;
+#[cfg(feature = "attributes")]
+use crate::Message;
+use core::fmt::{Display, Formatter, Result};
+
+/// Summary counts of a database, produced by [`Dbc::statistics`].
+///
+/// The `Display` implementation prints the counts as a two-column table, which is handy
+/// for a quick sanity check of a freshly parsed file.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DbcStats {
+    /// Number of messages (`BO_`).
+    pub messages: usize,
+    /// Number of signals (`SG_`) over all messages.
+    pub signals: usize,
+    /// Largest number of signals in a single message.
+    pub max_signals_per_message: usize,
+    /// Messages with a multiplexer switch signal.
+    pub multiplexed_messages: usize,
+    /// Messages with a 29-bit extended ID.
+    pub extended_messages: usize,
+    /// Signals declaring the unspecified `[0|0]` range.
+    pub unspecified_range_signals: usize,
+    /// Estimated bus traffic in bits per second of the messages with a cycle time
+    /// (`GenMsgCycleTime`), or `None` if no message has one.
+    ///
+    /// Each frame counts with its worst-case length on a classic CAN bus, including bit
+    /// stuffing. CAN FD frames are approximated the same way. Always `None` without the
+    /// `attributes` feature.
+    pub bits_per_second: Option<f64>,
+    /// Bus bit rate in bits per second, taken from the `BS_` baudrate if present.
+    pub bitrate: Option<u32>,
+}
+
+impl DbcStats {
+    /// Estimated bus load at `bitrate` bits per second, as a fraction (`0.25` = 25%).
+    ///
+    /// Returns `None` if [`bits_per_second`](Self::bits_per_second) is unknown or
+    /// `bitrate` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///
+    /// BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+    /// BA_ "GenMsgCycleTime" BO_ 256 10;"#)?;
+    ///
+    /// let load = dbc.statistics().bus_load(500_000).unwrap();
+    /// assert!(load > 0.0 && load < 0.05);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn bus_load(&self, bitrate: u32) -> Option<f64> {
+        if bitrate == 0 {
+            return None;
+        }
+        Some(self.bits_per_second? / f64::from(bitrate))
+    }
+}
+
+impl Display for DbcStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "{:<22}{:>10}", "Messages", self.messages)?;
+        writeln!(f, "{:<22}{:>10}", "  extended ID", self.extended_messages)?;
+        writeln!(
+            f,
+            "{:<22}{:>10}",
+            "  multiplexed", self.multiplexed_messages
+        )?;
+        writeln!(f, "{:<22}{:>10}", "Signals", self.signals)?;
+        writeln!(
+            f,
+            "{:<22}{:>10}",
+            "  max per message", self.max_signals_per_message
+        )?;
+        writeln!(
+            f,
+            "{:<22}{:>10}",
+            "  unspecified range", self.unspecified_range_signals
+        )?;
+        match self.bits_per_second {
+            Some(bits) => writeln!(f, "{:<22}{:>10.0} bit/s", "Bus traffic", bits)?,
+            None => writeln!(f, "{:<22}{:>10}", "Bus traffic", "-")?,
+        }
+        if let Some(bitrate) = self.bitrate {
+            if let Some(load) = self.bus_load(bitrate) {
+                writeln!(
+                    f,
+                    "{:<22}{:>9.1}% at {} bit/s",
+                    "Bus load",
+                    load * 100.0,
+                    bitrate
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Dbc {
+    /// Summarize the database: message and signal counts, multiplexed and extended-ID
+    /// messages, signals without a declared range, and a bus load estimate.
+    ///
+    /// The bus load estimate needs cycle times (`GenMsgCycleTime`, falling back to its
+    /// default) and is therefore only available with the `attributes` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///  SG_ Temp : 16|8@1- (1,-40) [0|0] "degC" *
+    /// "#)?;
+    ///
+    /// let stats = dbc.statistics();
+    /// assert_eq!(stats.signals, 2);
+    /// assert_eq!(stats.unspecified_range_signals, 1);
+    /// println!("{stats}");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn statistics(&self) -> DbcStats {
+        let mut stats = DbcStats {
+            bitrate: self
+                .bit_timing()
+                .and_then(|bit_timing| bit_timing.baudrate())
+                .map(|kbps| kbps.saturating_mul(1000)),
+            ..DbcStats::default()
+        };
+        for message in self.messages().iter() {
+            let signals = message.signals();
+            stats.messages += 1;
+            stats.signals += signals.len();
+            stats.max_signals_per_message = stats.max_signals_per_message.max(signals.len());
+            if message.is_extended() {
+                stats.extended_messages += 1;
+            }
+            if signals.iter().any(|s| s.is_multiplexer_switch()) {
+                stats.multiplexed_messages += 1;
+            }
+            stats.unspecified_range_signals +=
+                signals.iter().filter(|s| s.min() == 0.0 && s.max() == 0.0).count();
+
+            #[cfg(feature = "attributes")]
+            if let Some(cycle_time) =
+                self.message_cycle_time(message.id_with_flag()).filter(|&ms| ms > 0)
+            {
+                let bits = f64::from(Self::frame_bits(message)) * 1000.0 / cycle_time as f64;
+                stats.bits_per_second = Some(stats.bits_per_second.unwrap_or(0.0) + bits);
+            }
+        }
+        stats
+    }
+
+    /// Estimate the bus load at `baudrate_bps` bits per second, as a percentage.
+    ///
+    /// Each message with a cycle time (`GenMsgCycleTime`, falling back to its default)
+    /// counts with its worst-case classic CAN frame length: start of frame, arbitration,
+    /// control, data, CRC, ACK and end of frame fields, the interframe space, and the
+    /// maximum number of stuff bits. Messages without a cycle time are skipped. Returns
+    /// `0.0` if no message has a cycle time or `baudrate_bps` is zero.
+    ///
+    /// See [`statistics`](Self::statistics) for the underlying traffic estimate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///
+    /// BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+    /// BA_ "GenMsgCycleTime" BO_ 256 10;"#)?;
+    ///
+    /// // 135 bits every 10 ms
+    /// let load = dbc.estimated_bus_load(500_000);
+    /// assert!((load - 2.7).abs() < 1e-9);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[cfg(feature = "attributes")]
+    #[must_use = "return value should be used"]
+    pub fn estimated_bus_load(&self, baudrate_bps: u32) -> f64 {
+        self.statistics().bus_load(baudrate_bps).map_or(0.0, |load| load * 100.0)
+    }
+
+    /// Worst-case length in bits of a classic CAN frame carrying the message, including
+    /// stuff bits.
+    #[cfg(feature = "attributes")]
+    fn frame_bits(message: &Message) -> u32 {
+        let data_bits = u32::from(message.dlc()) * 8;
+        // Bits outside the data field, and those of them subject to bit stuffing
+        let (overhead, stuffed) = if message.is_extended() {
+            (67, 54)
+        } else {
+            (47, 34)
+        };
+        data_bits + overhead + (stuffed + data_bits - 1) / 4
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Dbc;
+
+    #[test]
+    fn test_statistics() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BS_: 500
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [0|0] "degC" *
+
+BO_ 2147484672 Diag : 8 ECM
+ SG_ Page M : 0|8@1+ (1,0) [0|255] "" *
+ SG_ Speed m0 : 8|16@1+ (0.1,0) [0|0] "km/h" *
+ SG_ Temp m1 : 8|8@1- (1,-40) [-40|215] "degC" *
+
+BO_ 512 Status : 2 ECM
+
+BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+BA_ "GenMsgCycleTime" BO_ 256 10;
+BA_ "GenMsgCycleTime" BO_ 2147484672 100;
+"#,
+        )
+        .unwrap();
+
+        let stats = dbc.statistics();
+        assert_eq!(stats.messages, 3);
+        assert_eq!(stats.signals, 5);
+        assert_eq!(stats.max_signals_per_message, 3);
+        assert_eq!(stats.multiplexed_messages, 1);
+        assert_eq!(stats.extended_messages, 1);
+        assert_eq!(stats.unspecified_range_signals, 2);
+        assert_eq!(stats.bitrate, Some(500_000));
+
+        // 135 bits every 10 ms, and 160 bits every 100 ms
+        #[cfg(feature = "attributes")]
+        {
+            assert_eq!(stats.bits_per_second, Some(13_500.0 + 1_600.0));
+            assert_eq!(stats.bus_load(500_000), Some(15_100.0 / 500_000.0));
+        }
+        assert_eq!(stats.bus_load(0), None);
+    }
+
+    #[test]
+    #[cfg(feature = "attributes")]
+    fn test_estimated_bus_load() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+
+BO_ 257 Transmission : 8 ECM
+ SG_ Gear : 0|8@1+ (1,0) [0|8] "" *
+
+BO_ 258 Status : 8 ECM
+ SG_ State : 0|8@1+ (1,0) [0|255] "" *
+
+BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+BA_ "GenMsgCycleTime" BO_ 256 10;
+BA_ "GenMsgCycleTime" BO_ 257 10;
+"#,
+        )
+        .unwrap();
+
+        // Two 135-bit frames every 10 ms; Status has no cycle time
+        assert!((dbc.estimated_bus_load(500_000) - 5.4).abs() < 1e-9);
+        assert!((dbc.estimated_bus_load(250_000) - 10.8).abs() < 1e-9);
+        assert_eq!(dbc.estimated_bus_load(0), 0.0);
+
+        let no_cycle_times = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+"#,
+        )
+        .unwrap();
+        assert_eq!(no_cycle_times.estimated_bus_load(500_000), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_statistics_display() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+"#,
+        )
+        .unwrap();
+
+        let table = dbc.statistics().to_string();
+        assert!(table.starts_with("Messages                       1\n"));
+        assert!(table.contains("Signals                        1\n"));
+        assert!(table.contains("Bus traffic                    -\n"));
+        assert!(!table.contains("Bus load"));
+    }
+}
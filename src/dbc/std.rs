@@ -1,8 +1,33 @@
-use super::Dbc;
-use crate::Result;
+#[cfg(feature = "attributes")]
+use super::{AttributeDefaultsMap, AttributeDefinitionsMap, AttributeValuesMap};
+use super::{
+    Dbc, EnvironmentVariables, ExtMuxIndex, ExtendedMultiplexings, Messages, SerializeOptions,
+    SignalGroups, SignalTypeRefs, SignalTypes, Validate, ValueDescriptionsMap,
+};
+use crate::{
+    Error, MAX_MESSAGES, MAX_NODES, Message, Node, Nodes, Result, Signal, ValueDescriptions,
+    compat::{Comment, MAX_COMMENT_SIZE, Name, Vec},
+    parser::is_keyword,
+};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::path::Path;
 
+/// Optional sections listed in the `NS_` block of serialized DBC files.
+const NEW_SYMBOLS: [&str; 12] = [
+    crate::CM_,
+    crate::BA_DEF_,
+    crate::BA_,
+    crate::VAL_,
+    crate::BA_DEF_DEF_,
+    crate::SGTYPE_,
+    crate::SGTYPE_VAL_,
+    crate::SIG_TYPE_REF_,
+    crate::SIG_VALTYPE_,
+    crate::SIG_GROUP_,
+    crate::SG_MUL_VAL_,
+    crate::ENVVAR_DATA_,
+];
+
 impl Dbc {
     /// Load and parse a DBC file from disk.
     ///
@@ -19,306 +44,2531 @@ impl Dbc {
         let content = std::fs::read_to_string(path.as_ref())?;
         Self::parse(&content)
     }
-    /// Serialize this DBC to a DBC format string
+
+    /// Duplicate a message under a new ID and name.
+    ///
+    /// The copy keeps the signals and comments of the source message. Its message-specific
+    /// value descriptions, extended multiplexing entries, signal groups, signal type
+    /// references and attribute values are duplicated as well, remapped to `new_id`.
+    /// IDs use the DBC file convention: extended IDs carry bit 31 (`0x80000000`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] if `src_id` does not exist, `new_id` is already in use
+    /// or out of range, another message is already named `new_name`
+    /// ([`Error::MESSAGE_NAME_DUPLICATE`]), or the database is full. The database is left
+    /// unchanged on error.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use dbc_rs::Dbc;
     ///
-    /// let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM")?;
-    /// let dbc_string = dbc.to_dbc_string();
-    /// // The string can be written to a file or used elsewhere
-    /// assert!(dbc_string.contains("VERSION"));
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Wheel_FL : 8 ECM
+    ///  SG_ Speed : 0|16@1+ (0.01,0) [0|655.35] "km/h" *
+    /// "#)?;
+    ///
+    /// dbc.clone_message(256, 257, "Wheel_FR")?;
+    /// assert_eq!(dbc.messages().find_by_id(257).unwrap().name(), "Wheel_FR");
     /// # Ok::<(), dbc_rs::Error>(())
     /// ```
-    #[must_use = "return value should be used"]
-    pub fn to_dbc_string(&self) -> String {
-        // Pre-allocate with estimated capacity
-        // Estimate: ~50 chars per message + ~100 chars per signal
-        let signal_count: usize = self.messages().iter().map(|m| m.signals().len()).sum();
-        let estimated_capacity = 200 + (self.messages.len() * 50) + (signal_count * 100);
-        let mut result = String::with_capacity(estimated_capacity);
+    pub fn clone_message(&mut self, src_id: u32, new_id: u32, new_name: &str) -> Result<()> {
+        if self.messages.find_by_id(new_id).is_some() {
+            return Err(Error::Validation(Error::DUPLICATE_MESSAGE_ID));
+        }
+        if self.messages.find(new_name).is_some() {
+            return Err(Error::Validation(Error::MESSAGE_NAME_DUPLICATE));
+        }
+        let source = self
+            .messages
+            .find_by_id(src_id)
+            .ok_or(Error::Validation(Error::MESSAGE_NOT_FOUND))?;
+        let message = source.with_id_and_name(new_id, Name::try_from(new_name)?);
+        let signals: std::vec::Vec<_> = message.signals().iter().cloned().collect();
+        Message::validate(
+            new_id,
+            message.name(),
+            message.dlc(),
+            message.sender(),
+            &signals,
+        )?;
 
-        // VERSION line
-        if let Some(version) = &self.version {
-            result.push_str(&version.to_dbc_string());
-            result.push_str("\n\n");
+        // Build everything first so a failure leaves `self` untouched
+        let mut messages: Vec<Message, { MAX_MESSAGES }> = self.messages.iter().cloned().collect();
+        messages.push(message)?;
+        let messages = Messages::from_vec(messages)?;
+
+        let mut value_descriptions = self.value_descriptions.clone();
+        value_descriptions.copy_message(src_id, new_id)?;
+
+        let mut extended_multiplexing = self.extended_multiplexing.clone();
+        for ext_mux in self.extended_multiplexing_for_message(src_id) {
+            extended_multiplexing.push(ext_mux.with_message_id(new_id))?;
         }
 
-        // BS_ line (bit timing) - only output if present
-        if let Some(ref bit_timing) = self.bit_timing {
-            result.push_str(&bit_timing.to_string());
-            result.push_str("\n\n");
-        } else {
-            // Empty BS_: is always required per DBC spec
-            result.push_str("BS_:\n\n");
+        let mut signal_groups = self.signal_groups.clone();
+        for group in self.signal_groups.iter().filter(|g| g.message_id() == src_id) {
+            signal_groups.push(group.with_message_id(new_id))?;
+        }
+
+        let mut signal_type_refs = self.signal_type_refs.clone();
+        for type_ref in self.signal_type_refs.iter().filter(|r| r.message_id() == src_id) {
+            signal_type_refs.push(type_ref.with_message_id(new_id))?;
+        }
+
+        #[cfg(feature = "attributes")]
+        let attribute_values = {
+            let mut attribute_values = self.attribute_values.clone();
+            attribute_values.copy_message(src_id, new_id)?;
+            attribute_values
+        };
+
+        self.ext_mux_index = ExtMuxIndex::build(extended_multiplexing.as_slice());
+        self.messages = messages;
+        self.value_descriptions = value_descriptions;
+        self.extended_multiplexing = extended_multiplexing;
+        self.signal_groups = signal_groups;
+        self.signal_type_refs = signal_type_refs;
+        #[cfg(feature = "attributes")]
+        {
+            self.attribute_values = attribute_values;
+        }
+        Ok(())
+    }
+
+    /// Rename a signal and every entry that refers to it by name.
+    ///
+    /// Besides the signal itself, the message-specific value descriptions (`VAL_`),
+    /// extended multiplexing entries (`SG_MUL_VAL_`, as signal or as multiplexer switch),
+    /// signal group members (`SIG_GROUP_`), signal type references (`SIG_TYPE_REF_`) and
+    /// attribute values (`BA_ ... SG_`) of the signal are updated. Global value
+    /// descriptions (`VAL_ -1 ...`) apply to every signal of a name and are left as is.
+    /// `msg_id` uses the DBC file convention: extended IDs carry bit 31 (`0x80000000`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] with [`Error::MESSAGE_NOT_FOUND`] or
+    /// [`Error::SIGNAL_NOT_FOUND`] if the message or signal does not exist, or with
+    /// [`Error::SIGNAL_NAME_DUPLICATE`] if the message already has a signal named `new`.
+    /// The name must also fit the maximum name length. The database is left unchanged
+    /// on error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ Gear : 0|8@1+ (1,0) [0|5] "" *
+    ///
+    /// VAL_ 256 Gear 0 "Park" 1 "Drive" ;
+    /// "#)?;
+    ///
+    /// dbc.rename_signal(256, "Gear", "GearSelected")?;
+    /// assert!(dbc.value_descriptions_for_signal(256, "GearSelected").is_some());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn rename_signal(&mut self, msg_id: u32, old: &str, new: &str) -> Result<()> {
+        let message = self
+            .messages
+            .find_by_id(msg_id)
+            .ok_or(Error::Validation(Error::MESSAGE_NOT_FOUND))?;
+        if message.signals().find(old).is_none() {
+            return Err(Error::Validation(Error::SIGNAL_NOT_FOUND));
+        }
+        if old == new {
+            return Ok(());
+        }
+        if message.signals().find(new).is_some() {
+            return Err(Error::Validation(Error::SIGNAL_NAME_DUPLICATE));
+        }
+        let new_name = Name::try_from(new)?;
+        let message_id = message.id_with_flag();
+
+        // The maps may fail to insert, so update copies before touching `self`
+        let mut value_descriptions = self.value_descriptions.clone();
+        value_descriptions.rename_signal(message_id, old, &new_name)?;
+        #[cfg(feature = "attributes")]
+        let attribute_values = {
+            let mut attribute_values = self.attribute_values.clone();
+            attribute_values.rename_signal(message_id, old, &new_name)?;
+            attribute_values
+        };
+
+        for message in self.messages.iter_mut() {
+            if message.id_with_flag() == message_id {
+                if let Some(signal) = message.signals_mut().find_mut(old) {
+                    signal.set_name(new_name.clone());
+                }
+            }
+        }
+        for ext_mux in self.extended_multiplexing.iter_mut() {
+            if ext_mux.message_id() == message_id {
+                ext_mux.rename_signal(old, &new_name);
+            }
+        }
+        for group in self.signal_groups.iter_mut() {
+            if group.message_id() == message_id {
+                group.rename_signal(old, &new_name);
+            }
+        }
+        for type_ref in self.signal_type_refs.iter_mut() {
+            if type_ref.message_id() == message_id {
+                type_ref.rename_signal(old, &new_name);
+            }
+        }
+        self.ext_mux_index = ExtMuxIndex::build(self.extended_multiplexing.as_slice());
+        self.value_descriptions = value_descriptions;
+        #[cfg(feature = "attributes")]
+        {
+            self.attribute_values = attribute_values;
+        }
+        Ok(())
+    }
+
+    /// Rename a message.
+    ///
+    /// Value descriptions, extended multiplexing entries, signal groups, comments and
+    /// attribute values refer to messages by ID, so they stay attached to the renamed
+    /// message; only the lookup by name (see [`message_by_name`](Self::message_by_name))
+    /// changes. `msg_id` uses the DBC file convention: extended IDs carry bit 31
+    /// (`0x80000000`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] with [`Error::MESSAGE_NOT_FOUND`] if the message does
+    /// not exist, or with [`Error::MESSAGE_NAME_DUPLICATE`] if another message is named
+    /// `new`. The name must also fit the maximum name length. The database is left
+    /// unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// dbc.rename_message(256, "EngineStatus")?;
+    /// assert_eq!(dbc.message_by_name("EngineStatus").unwrap().id(), 256);
+    /// assert!(dbc.message_by_name("Engine").is_none());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn rename_message(&mut self, msg_id: u32, new: &str) -> Result<()> {
+        let message = self
+            .messages
+            .find_by_id(msg_id)
+            .ok_or(Error::Validation(Error::MESSAGE_NOT_FOUND))?;
+        let message_id = message.id_with_flag();
+        if self.messages.iter().any(|m| m.name() == new && m.id_with_flag() != message_id) {
+            return Err(Error::Validation(Error::MESSAGE_NAME_DUPLICATE));
+        }
+        let new_name = Name::try_from(new)?;
+
+        let mut messages: Vec<Message, { MAX_MESSAGES }> = self.messages.iter().cloned().collect();
+        for message in messages.iter_mut() {
+            if message.id_with_flag() == message_id {
+                message.set_name(new_name.clone());
+            }
+        }
+        // Rebuilds the name index
+        self.messages = Messages::from_vec(messages)?;
+        Ok(())
+    }
+
+    /// Set the comment of a message, as written to its `CM_ BO_` entry.
+    ///
+    /// Replaces any existing comment. `msg_id` uses the DBC file convention: extended IDs
+    /// carry bit 31 (`0x80000000`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] with [`Error::MESSAGE_NOT_FOUND`] if the message does
+    /// not exist, with [`Error::COMMENT_TOO_LONG`] if `comment` exceeds `MAX_COMMENT_SIZE`
+    /// bytes, or with [`Error::COMMENT_CONTAINS_QUOTE`] if it contains a `"`, which cannot
+    /// be serialized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// dbc.set_message_comment(256, "Engine status, sent every 10 ms")?;
+    /// assert!(dbc.to_dbc_string().contains("CM_ BO_ 256 \"Engine status, sent every 10 ms\";"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn set_message_comment(&mut self, msg_id: u32, comment: &str) -> Result<()> {
+        let message_id = self
+            .messages
+            .find_by_id(msg_id)
+            .ok_or(Error::Validation(Error::MESSAGE_NOT_FOUND))?
+            .id_with_flag();
+        let comment = Self::validate_comment(comment)?;
+
+        for message in self.messages.iter_mut() {
+            if message.id_with_flag() == message_id {
+                message.set_comment(comment.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the comment of a signal, as written to its `CM_ SG_` entry.
+    ///
+    /// Replaces any existing comment. `msg_id` uses the DBC file convention: extended IDs
+    /// carry bit 31 (`0x80000000`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] with [`Error::MESSAGE_NOT_FOUND`] or
+    /// [`Error::SIGNAL_NOT_FOUND`] if the message or signal does not exist, and the
+    /// errors of [`set_message_comment`](Self::set_message_comment) for an invalid comment.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// dbc.set_signal_comment(256, "RPM", "Crankshaft speed")?;
+    /// let engine = dbc.messages().find_by_id(256).unwrap();
+    /// assert_eq!(engine.signals().find("RPM").unwrap().comment(), Some("Crankshaft speed"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn set_signal_comment(&mut self, msg_id: u32, signal: &str, comment: &str) -> Result<()> {
+        let message = self
+            .messages
+            .find_by_id(msg_id)
+            .ok_or(Error::Validation(Error::MESSAGE_NOT_FOUND))?;
+        if message.signals().find(signal).is_none() {
+            return Err(Error::Validation(Error::SIGNAL_NOT_FOUND));
+        }
+        let message_id = message.id_with_flag();
+        let comment = Self::validate_comment(comment)?;
+
+        for message in self.messages.iter_mut() {
+            if message.id_with_flag() == message_id {
+                if let Some(signal) = message.signals_mut().find_mut(signal) {
+                    signal.set_comment(comment.clone());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that `comment` fits a [`Comment`] and can be written as a quoted `CM_` string.
+    fn validate_comment(comment: &str) -> Result<Comment> {
+        if comment.len() > MAX_COMMENT_SIZE {
+            return Err(Error::Validation(Error::COMMENT_TOO_LONG));
+        }
+        if comment.contains('"') {
+            return Err(Error::Validation(Error::COMMENT_CONTAINS_QUOTE));
+        }
+        Ok(Comment::from_str_truncating(comment))
+    }
+
+    /// Remove a message and every entry that refers to it, returning the message.
+    ///
+    /// Besides the message itself, its value descriptions (`VAL_`), extended multiplexing
+    /// entries (`SG_MUL_VAL_`), signal groups (`SIG_GROUP_`), signal type references
+    /// (`SIG_TYPE_REF_`) and attribute values (`BA_ ... BO_` and `BA_ ... SG_`) are
+    /// dropped. `id` uses the DBC file convention: extended IDs carry bit 31
+    /// (`0x80000000`). Returns `None`, leaving the database unchanged, if no message has
+    /// that ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ Gear : 0|8@1+ (1,0) [0|5] "" *
+    ///
+    /// VAL_ 256 Gear 0 "Park" 1 "Drive" ;
+    /// "#)?;
+    ///
+    /// let engine = dbc.remove_message(256).unwrap();
+    /// assert_eq!(engine.name(), "Engine");
+    /// assert!(dbc.messages().find_by_id(256).is_none());
+    /// assert!(dbc.value_descriptions_for_signal(256, "Gear").is_none());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn remove_message(&mut self, id: u32) -> Option<Message> {
+        let message_id = self.messages.find_by_id(id)?.id_with_flag();
+
+        let mut removed = None;
+        let mut messages: Vec<Message, { MAX_MESSAGES }> = Vec::new();
+        for message in self.messages.iter() {
+            if message.id_with_flag() == message_id {
+                removed = Some(message.clone());
+            } else {
+                // Cannot fail: the messages already fit
+                let _ = messages.push(message.clone());
+            }
+        }
+        // Rebuilds the ID and name indices; cannot fail with fewer messages
+        self.messages = Messages::from_vec(messages).ok()?;
+
+        self.value_descriptions.remove_message(message_id);
+        self.extended_multiplexing = self
+            .extended_multiplexing
+            .iter()
+            .filter(|ext_mux| ext_mux.message_id() != message_id)
+            .cloned()
+            .collect();
+        self.signal_groups = self
+            .signal_groups
+            .iter()
+            .filter(|g| g.message_id() != message_id)
+            .cloned()
+            .collect();
+        self.signal_type_refs = self
+            .signal_type_refs
+            .iter()
+            .filter(|r| r.message_id() != message_id)
+            .cloned()
+            .collect();
+        #[cfg(feature = "attributes")]
+        self.attribute_values.remove_message(message_id);
+        self.ext_mux_index = ExtMuxIndex::build(self.extended_multiplexing.as_slice());
+        removed
+    }
+
+    /// Remove a signal and every entry that refers to it by name.
+    ///
+    /// Besides the signal itself, its message-specific value descriptions (`VAL_`),
+    /// extended multiplexing entries (`SG_MUL_VAL_`, as signal or as multiplexer switch),
+    /// signal type references (`SIG_TYPE_REF_`) and attribute values (`BA_ ... SG_`) are
+    /// dropped, and it is taken out of signal groups; groups this leaves empty are dropped.
+    /// A multiplexer switch can only be removed once no signal depends on it. `msg_id` uses
+    /// the DBC file convention: extended IDs carry bit 31 (`0x80000000`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] with [`Error::MESSAGE_NOT_FOUND`] or
+    /// [`Error::SIGNAL_NOT_FOUND`] if the message or signal does not exist, or with
+    /// [`Error::SIGNAL_MULTIPLEXER_HAS_DEPENDANTS`] if the signal is a multiplexer switch
+    /// that still selects multiplexed signals (`mN`). The database is left unchanged on
+    /// error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///  SG_ Gear : 16|8@1+ (1,0) [0|5] "" *
+    ///
+    /// VAL_ 256 Gear 0 "Park" 1 "Drive" ;
+    /// "#)?;
+    ///
+    /// dbc.remove_signal(256, "Gear")?;
+    /// assert_eq!(dbc.messages().find_by_id(256).unwrap().signals().len(), 1);
+    /// assert!(dbc.value_descriptions_for_signal(256, "Gear").is_none());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn remove_signal(&mut self, msg_id: u32, name: &str) -> Result<()> {
+        let message = self
+            .messages
+            .find_by_id(msg_id)
+            .ok_or(Error::Validation(Error::MESSAGE_NOT_FOUND))?;
+        let signal =
+            message.signals().find(name).ok_or(Error::Validation(Error::SIGNAL_NOT_FOUND))?;
+        let message_id = message.id_with_flag();
+
+        // A multiplexed signal depends on this switch if its SG_MUL_VAL_ entries name it,
+        // or if it has no entries and so falls back to the message's switch
+        let has_dependants = signal.is_multiplexer_switch()
+            && message.signals().iter().any(|s| {
+                let mut entries = self.ext_mux_entries_for_signal(message_id, s.name()).peekable();
+                s.multiplexer_switch_value().is_some()
+                    && (entries.peek().is_none()
+                        || entries.any(|ext_mux| ext_mux.multiplexer_switch() == name))
+            });
+        if has_dependants {
+            return Err(Error::Validation(Error::SIGNAL_MULTIPLEXER_HAS_DEPENDANTS));
+        }
+
+        for message in self.messages.iter_mut() {
+            if message.id_with_flag() == message_id {
+                let signals: std::vec::Vec<Signal> =
+                    message.signals().iter().filter(|s| s.name() != name).cloned().collect();
+                *message.signals_mut() = signals.into();
+            }
+        }
+        self.value_descriptions.remove_signal(message_id, name);
+        self.extended_multiplexing = self
+            .extended_multiplexing
+            .iter()
+            .filter(|ext_mux| {
+                ext_mux.message_id() != message_id
+                    || (ext_mux.signal_name() != name && ext_mux.multiplexer_switch() != name)
+            })
+            .cloned()
+            .collect();
+        self.signal_groups = self
+            .signal_groups
+            .iter()
+            .filter_map(|group| {
+                if group.message_id() != message_id || !group.contains(name) {
+                    return Some(group.clone());
+                }
+                let mut group = group.clone();
+                group.remove_signal(name);
+                (!group.is_empty()).then_some(group)
+            })
+            .collect();
+        self.signal_type_refs = self
+            .signal_type_refs
+            .iter()
+            .filter(|r| r.message_id() != message_id || r.signal_name() != name)
+            .cloned()
+            .collect();
+        #[cfg(feature = "attributes")]
+        self.attribute_values.remove_signal(message_id, name);
+        self.ext_mux_index = ExtMuxIndex::build(self.extended_multiplexing.as_slice());
+        Ok(())
+    }
+
+    /// Move all value descriptions out of this DBC.
+    ///
+    /// Returns `(message_id, signal_name, descriptions)` tuples and leaves the
+    /// value description map empty, so [`decode`](Self::decode) no longer resolves enum text.
+    /// Global value descriptions (`VAL_ -1 ...`) are returned with a `message_id` of
+    /// `u32::MAX`, the value the `-1` of the DBC file stands for.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 100 Engine : 8 ECM
+    ///  SG_ Gear : 0|8@1+ (1,0) [0|5] "" *
+    ///
+    /// VAL_ 100 Gear 0 "Park" 1 "Drive" ;"#)?;
+    ///
+    /// let enums = dbc.take_value_descriptions();
+    /// assert_eq!(enums[0].0, 100);
+    /// assert_eq!(enums[0].1, "Gear");
+    /// assert!(dbc.value_descriptions().is_empty());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn take_value_descriptions(&mut self) -> std::vec::Vec<(u32, String, ValueDescriptions)> {
+        self.value_descriptions
+            .take()
+            .map(|((message_id, signal_name), vd)| {
+                (
+                    message_id.unwrap_or(u32::MAX),
+                    signal_name.as_str().to_string(),
+                    vd,
+                )
+            })
+            .collect()
+    }
+
+    /// Import value descriptions from another DBC.
+    ///
+    /// Copies the value descriptions of `other` whose `(message_id, signal)` pair exists in
+    /// this DBC, replacing any descriptions already defined for that signal. Entries for
+    /// unknown messages or signals are ignored. Global value descriptions (`VAL_ -1 ...`)
+    /// are copied if any message in this DBC has a signal of that name.
+    ///
+    /// This allows keeping enum definitions in a shared DBC and layering them over
+    /// message-only files.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 100 Engine : 8 ECM
+    ///  SG_ Gear : 0|8@1+ (1,0) [0|5] "" *
+    /// "#)?;
+    /// let enums = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 100 Engine : 8 ECM
+    ///  SG_ Gear : 0|8@1+ (1,0) [0|5] "" *
+    ///
+    /// VAL_ 100 Gear 0 "Park" 1 "Drive" ;"#)?;
+    ///
+    /// dbc.merge_value_descriptions_from(&enums);
+    /// assert_eq!(dbc.value_descriptions().for_signal(100, "Gear").unwrap().get(1), Some("Drive"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn merge_value_descriptions_from(&mut self, other: &Dbc) {
+        let messages = &self.messages;
+        let has_signal =
+            |message: &Message, signal_name: &str| message.signals().find(signal_name).is_some();
+        // Cannot fail: the map is unbounded with `alloc`
+        let _ = self.value_descriptions.merge_from(
+            &other.value_descriptions,
+            |message_id, signal_name| match message_id {
+                Some(id) => messages.find_by_id(id).is_some_and(|m| has_signal(m, signal_name)),
+                None => messages.iter().any(|m| has_signal(m, signal_name)),
+            },
+        );
+    }
+
+    /// Merge the nodes, messages, value descriptions and extended multiplexing of `other`
+    /// into this DBC.
+    ///
+    /// Nodes are deduplicated by name: a node that already exists is kept as is. Global
+    /// value descriptions (`VAL_ -1 ...`) already defined here take precedence over those
+    /// of `other`. Version, bit timing, comments at database level, signal types and
+    /// groups, and attributes of `other` are not merged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] with [`Error::DUPLICATE_MESSAGE_ID`] if `other`
+    /// defines a message ID that already exists (see [`merge_overwrite`](Self::merge_overwrite)),
+    /// or another validation error if the merged database is invalid or exceeds its
+    /// capacity. The database is left unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut powertrain = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM Gateway
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" Gateway
+    /// "#)?;
+    /// let chassis = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ABS Gateway
+    ///
+    /// BO_ 512 Brake : 8 ABS
+    ///  SG_ Pressure : 0|16@1+ (0.1,0) [0|1000] "bar" Gateway
+    /// "#)?;
+    ///
+    /// powertrain.merge(chassis)?;
+    /// assert_eq!(powertrain.messages().len(), 2);
+    /// assert_eq!(powertrain.nodes().len(), 3);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn merge(&mut self, other: Dbc) -> Result<()> {
+        self.merge_with(other, false)
+    }
+
+    /// Merge `other` into this DBC, replacing messages whose ID already exists.
+    ///
+    /// Behaves like [`merge`](Self::merge), except that a message of `other` replaces the
+    /// message with the same ID in place. The value descriptions and extended multiplexing
+    /// entries of the replaced message are dropped in favour of those of `other`, and
+    /// global value descriptions of `other` replace existing ones. Its signal groups,
+    /// signal type references and attribute values are dropped as well; as with `merge`,
+    /// those of `other` are not carried over, so the replacement message has none.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] if the merged database is invalid or exceeds its
+    /// capacity. The database is left unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    /// let update = Dbc::parse(r#"VERSION "1.1"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 EngineV2 : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.125,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// dbc.merge_overwrite(update)?;
+    /// assert_eq!(dbc.messages().find_by_id(256).unwrap().name(), "EngineV2");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn merge_overwrite(&mut self, other: Dbc) -> Result<()> {
+        self.merge_with(other, true)
+    }
+
+    fn merge_with(&mut self, other: Dbc, overwrite: bool) -> Result<()> {
+        // Build everything first so a failure leaves `self` untouched
+        let mut nodes: Vec<Node, { MAX_NODES }> = self.nodes.iter_nodes().cloned().collect();
+        for node in other.nodes.iter_nodes() {
+            if !self.nodes.contains(node.name()) {
+                nodes.push(node.clone())?;
+            }
+        }
+        let nodes = Nodes::new(nodes);
+
+        let mut messages: Vec<Message, { MAX_MESSAGES }> = self.messages.iter().cloned().collect();
+        let mut value_descriptions = self.value_descriptions.clone();
+        let mut extended_multiplexing = self.extended_multiplexing.clone();
+        let mut replaced_ids = std::vec::Vec::new();
+        for message in other.messages.iter() {
+            let id = message.id_with_flag();
+            match messages.iter().position(|m| m.id_with_flag() == id) {
+                Some(idx) if overwrite => {
+                    messages.as_mut_slice()[idx] = message.clone();
+                    replaced_ids.push(id);
+                    value_descriptions.remove_message(id);
+                    extended_multiplexing = extended_multiplexing
+                        .into_iter()
+                        .filter(|ext_mux| ext_mux.message_id() != id)
+                        .collect();
+                }
+                Some(_) => return Err(Error::Validation(Error::DUPLICATE_MESSAGE_ID)),
+                None => messages.push(message.clone())?,
+            }
         }
+        let messages = Messages::from_vec(messages)?;
+
+        let existing = &self.value_descriptions;
+        value_descriptions.merge_from(&other.value_descriptions, |message_id, signal_name| {
+            overwrite
+                || message_id.is_some()
+                || !existing.iter().any(|((id, name), _)| id.is_none() && name == signal_name)
+        })?;
+        for ext_mux in other.extended_multiplexing.iter() {
+            extended_multiplexing.push(ext_mux.clone())?;
+        }
+
+        Validate::validate(
+            &nodes,
+            messages.as_slice(),
+            Some(&value_descriptions),
+            Some(extended_multiplexing.as_slice()),
+        )?;
+
+        // Drop what referred to the signals of replaced messages, like `remove_message`
+        self.signal_groups = self
+            .signal_groups
+            .iter()
+            .filter(|g| !replaced_ids.contains(&g.message_id()))
+            .cloned()
+            .collect();
+        self.signal_type_refs = self
+            .signal_type_refs
+            .iter()
+            .filter(|r| !replaced_ids.contains(&r.message_id()))
+            .cloned()
+            .collect();
+        #[cfg(feature = "attributes")]
+        for &id in &replaced_ids {
+            self.attribute_values.remove_message(id);
+        }
+
+        self.ext_mux_index = ExtMuxIndex::build(extended_multiplexing.as_slice());
+        self.nodes = nodes;
+        self.messages = messages;
+        self.value_descriptions = value_descriptions;
+        self.extended_multiplexing = extended_multiplexing;
+        Ok(())
+    }
+
+    /// Add a node to the `BU_` list.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] with [`Error::NODES_INVALID_NAME`] if the name is not a
+    /// DBC identifier (empty, containing spaces or other symbols, starting with a digit) or is
+    /// a keyword, with [`Error::NODES_DUPLICATE_NAME`] if a node of that name exists, or with
+    /// [`Error::NODES_TOO_MANY`] if the node list is full. The name must also fit the maximum
+    /// name length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    /// "#)?;
+    ///
+    /// dbc.add_node("TCM")?;
+    /// assert!(dbc.nodes().contains("TCM"));
+    /// assert!(dbc.add_node("ECM").is_err());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn add_node(&mut self, name: &str) -> Result<()> {
+        let is_identifier =
+            name.bytes().next().is_some_and(|b| b.is_ascii_alphabetic() || b == b'_')
+                && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_');
+        if !is_identifier || is_keyword(name) {
+            return Err(Error::Validation(Error::NODES_INVALID_NAME));
+        }
+        self.nodes.push(Node::new(Name::try_from(name)?))
+    }
+
+    /// Remove a node from the `BU_` list, returning `true` if it existed.
+    ///
+    /// References to the node are scrubbed so the database stays valid:
+    /// - messages sent by the node get `Vector__XXX` as sender
+    /// - the node is dropped from signal receiver lists; a signal left without receivers
+    ///   gets `Vector__XXX`
+    /// - the node is dropped from the access lists of environment variables
+    /// - attribute values assigned to the node (`BA_ ... BU_`) are removed
+    ///
+    /// The node's comment is removed along with it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM TCM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" TCM
+    /// "#)?;
+    ///
+    /// assert!(dbc.remove_node("ECM"));
+    /// assert!(!dbc.remove_node("ECM"));
+    /// let engine = dbc.messages().find_by_id(256).unwrap();
+    /// assert_eq!(engine.sender(), "Vector__XXX");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn remove_node(&mut self, name: &str) -> bool {
+        if !self.nodes.remove(name) {
+            return false;
+        }
+
+        for message in self.messages.iter_mut() {
+            if message.sender() == name {
+                // Cannot fail: `Vector__XXX` is shorter than any name limit
+                message.set_sender(Name::try_from(crate::VECTOR_XXX).unwrap_or_default());
+            }
+            for signal in message.signals_mut().iter_mut() {
+                signal.remove_receiver(name);
+            }
+        }
+        for env_var in self.environment_variables.iter_mut() {
+            env_var.remove_access_node(name);
+        }
+        #[cfg(feature = "attributes")]
+        self.attribute_values.remove_node(name);
+        true
+    }
+
+    /// Sort the database into a canonical order, so equivalent DBCs serialize identically.
+    ///
+    /// Messages are sorted by ID (standard IDs before extended ones), signals within a
+    /// message by start bit (then by name), nodes and signal receivers alphabetically, and
+    /// value descriptions (`VAL_`) by value. Two databases that differ only in these
+    /// orderings produce the same [`to_dbc_string`](Self::to_dbc_string) output
+    /// afterwards, which keeps text diffs free of noise. Other sections, such as signal
+    /// groups or environment variables, keep their order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let mut dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: TCM ECM
+    ///
+    /// BO_ 512 Gearbox : 8 TCM
+    ///  SG_ Gear : 0|8@1+ (1,0) [0|5] "" ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ Temp : 16|8@1+ (1,-40) [-40|215] "degC" *
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// dbc.canonicalize();
+    /// assert_eq!(dbc.nodes().iter().collect::<Vec<_>>(), ["ECM", "TCM"]);
+    /// assert_eq!(dbc.messages().at(0).unwrap().name(), "Engine");
+    /// assert_eq!(dbc.messages().at(0).unwrap().signals().at(0).unwrap().name(), "RPM");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn canonicalize(&mut self) {
+        self.nodes.sort();
+
+        let mut messages: Vec<Message, { MAX_MESSAGES }> = self.messages.iter().cloned().collect();
+        messages.as_mut_slice().sort_by_key(Message::id_with_flag);
+        for message in messages.iter_mut() {
+            let signals = message.signals_mut();
+            signals.sort_by_start_bit();
+            for signal in signals.iter_mut() {
+                signal.sort_receivers();
+            }
+        }
+        // Cannot fail: the same messages, reordered
+        if let Ok(messages) = Messages::from_vec(messages) {
+            self.messages = messages;
+        }
+
+        self.value_descriptions.sort();
+    }
+
+    /// Return a copy of this DBC with `f` applied to every signal.
+    ///
+    /// `f` replaces the signal it is given, typically with a modified copy built by
+    /// [`Signal::with_scaling`], [`Signal::with_range`] or [`Signal::with_unit`]. The
+    /// result is re-validated like a parsed DBC; `self` is never modified.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] if a transformed signal is invalid, e.g. an inverted
+    /// range or a signal moved beyond its message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Vehicle : 8 ECM
+    ///  SG_ Speed : 0|16@1+ (0.01,0) [0|655.35] "km/h" *
+    /// "#)?;
+    ///
+    /// const MPH_PER_KMH: f64 = 0.621371;
+    /// let imperial = dbc.map_signals(|signal| {
+    ///     if signal.unit() == Some("km/h") {
+    ///         *signal = signal
+    ///             .with_scaling(signal.factor() * MPH_PER_KMH, signal.offset() * MPH_PER_KMH)
+    ///             .with_range(signal.min() * MPH_PER_KMH, signal.max() * MPH_PER_KMH)
+    ///             .with_unit(Some("mph"))
+    ///             .unwrap();
+    ///     }
+    /// })?;
+    /// let speed = imperial.messages().at(0).unwrap().signals().at(0).unwrap();
+    /// assert_eq!(speed.unit(), Some("mph"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn map_signals<F: Fn(&mut Signal)>(&self, f: F) -> Result<Dbc> {
+        let mut dbc = self.clone();
+        for message in dbc.messages.iter_mut() {
+            for signal in message.signals_mut().iter_mut() {
+                f(signal);
+                Signal::validate(signal.name(), signal.length(), signal.min(), signal.max())?;
+            }
+            Message::validate(
+                message.id_with_flag(),
+                message.name(),
+                message.dlc(),
+                message.sender(),
+                message.signals().as_slice(),
+            )?;
+        }
+        Validate::validate(
+            &dbc.nodes,
+            dbc.messages.as_slice(),
+            Some(&dbc.value_descriptions),
+            Some(dbc.extended_multiplexing.as_slice()),
+        )?;
+        Ok(dbc)
+    }
+
+    /// Serialize this DBC to a DBC format string
+    ///
+    /// Sections are written in the order Vector tools emit them: `VERSION`, `NS_`, `BS_`,
+    /// `BU_`, `BO_`/`SG_`, `EV_`, `ENVVAR_DATA_`, `SGTYPE_`, `CM_`, `BA_DEF_`, `BA_DEF_DEF_`,
+    /// `BA_`, `VAL_`, `SGTYPE_VAL_`, `SIG_TYPE_REF_`, `SIG_VALTYPE_`, `SIG_GROUP_` and
+    /// `SG_MUL_VAL_`. Parsing the result with [`Dbc::parse`] yields an equivalent database.
+    /// The same output is available through [`Display`], i.e. `dbc.to_string()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM")?;
+    /// let dbc_string = dbc.to_dbc_string();
+    /// // The string can be written to a file or used elsewhere
+    /// assert!(dbc_string.contains("VERSION"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn to_dbc_string(&self) -> String {
+        self.to_string_with(&SerializeOptions::default())
+    }
+
+    /// Serialize this DBC to a DBC format string using the given [`SerializeOptions`].
+    ///
+    /// With the default options this is the same as [`to_dbc_string`](Self::to_dbc_string).
+    /// The options only affect layout, so parsing the result with [`Dbc::parse`] yields
+    /// an equivalent database either way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, SerializeOptions};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ Temp : 23|8@0+ (1,-40) [-40|215] "degC" *
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let options = SerializeOptions {
+    ///     sort_signals_by_start_bit: true,
+    ///     ..SerializeOptions::default()
+    /// };
+    /// let dbc_string = dbc.to_string_with(&options);
+    /// assert!(dbc_string.find("SG_ RPM") < dbc_string.find("SG_ Temp"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn to_string_with(&self, options: &SerializeOptions) -> String {
+        // Pre-allocate with estimated capacity
+        // Estimate: ~50 chars per message + ~100 chars per signal
+        let signal_count: usize = self.messages().iter().map(|m| m.signals().len()).sum();
+        let estimated_capacity = 200 + (self.messages.len() * 50) + (signal_count * 100);
+        let mut result = String::with_capacity(estimated_capacity);
+
+        // VERSION line
+        if let Some(version) = &self.version {
+            result.push_str(&version.to_dbc_string());
+            result.push_str("\n\n");
+        }
+
+        // NS_ section (new symbols) - lists the optional sections this writer may emit
+        result.push_str("NS_ :\n");
+        for symbol in NEW_SYMBOLS {
+            result.push_str("    ");
+            result.push_str(symbol);
+            result.push('\n');
+        }
+        result.push('\n');
+
+        // BS_ line (bit timing) - only output if present
+        if let Some(ref bit_timing) = self.bit_timing {
+            result.push_str(&bit_timing.to_string());
+            result.push_str("\n\n");
+        } else {
+            // Empty BS_: is always required per DBC spec
+            result.push_str("BS_:\n\n");
+        }
+
+        // BU_ line
+        result.push_str(&self.nodes.to_dbc_string());
+        result.push('\n');
+
+        // BO_ and SG_ lines for each message
+        for message in self.messages().iter() {
+            result.push('\n');
+            result.push_str(&message.to_string_full_with(options));
+        }
+
+        // EV_ lines (environment variables section)
+        if !self.environment_variables.is_empty() {
+            result.push('\n');
+            for env_var in self.environment_variables.iter() {
+                result.push_str(&env_var.to_dbc_string());
+                result.push('\n');
+            }
+        }
+
+        // ENVVAR_DATA_ lines (sizes of data environment variables)
+        let mut wrote_section = false;
+        for env_var in self.environment_variables.iter() {
+            if let Some(data_size) = env_var.data_size() {
+                if !wrote_section {
+                    result.push('\n');
+                    wrote_section = true;
+                }
+                result.push_str("ENVVAR_DATA_ ");
+                result.push_str(env_var.name());
+                result.push_str(" : ");
+                result.push_str(&data_size.to_string());
+                result.push_str(";\n");
+            }
+        }
+
+        // SGTYPE_ lines (signal types section)
+        if !self.signal_types.is_empty() {
+            result.push('\n');
+            for signal_type in self.signal_types.iter() {
+                result.push_str(&signal_type.to_dbc_string());
+                result.push('\n');
+            }
+        }
+
+        // CM_ lines (comments section)
+        // General database comment
+        if let Some(comment) = self.comment() {
+            result.push_str("\nCM_ \"");
+            result.push_str(comment);
+            result.push_str("\";\n");
+        }
+
+        // Node comments
+        for node in self.nodes.iter_nodes() {
+            if let Some(comment) = node.comment() {
+                result.push_str("CM_ BU_ ");
+                result.push_str(node.name());
+                result.push_str(" \"");
+                result.push_str(comment);
+                result.push_str("\";\n");
+            }
+        }
+
+        // Message and signal comments
+        for message in self.messages().iter() {
+            if let Some(comment) = message.comment() {
+                result.push_str("CM_ BO_ ");
+                result.push_str(&message.id_with_flag().to_string());
+                result.push_str(" \"");
+                result.push_str(comment);
+                result.push_str("\";\n");
+            }
+
+            for signal in message.signals().iter() {
+                if let Some(comment) = signal.comment() {
+                    result.push_str("CM_ SG_ ");
+                    result.push_str(&message.id_with_flag().to_string());
+                    result.push(' ');
+                    result.push_str(signal.name());
+                    result.push_str(" \"");
+                    result.push_str(comment);
+                    result.push_str("\";\n");
+                }
+            }
+        }
+
+        // BA_DEF_, BA_DEF_DEF_ and BA_ lines (attributes section)
+        #[cfg(feature = "attributes")]
+        {
+            if !self.attribute_definitions.is_empty() {
+                result.push('\n');
+                for definition in self.attribute_definitions.iter() {
+                    result.push_str(&definition.to_dbc_string());
+                    result.push('\n');
+                }
+            }
+            for (name, value) in self.attribute_defaults.iter() {
+                result.push_str("BA_DEF_DEF_ \"");
+                result.push_str(name);
+                result.push_str("\" ");
+                result.push_str(&value.to_dbc_string());
+                result.push_str(";\n");
+            }
+            for ((name, target), value) in self.attribute_values.iter() {
+                result.push_str("BA_ \"");
+                result.push_str(name);
+                result.push_str("\" ");
+                result.push_str(&target.to_dbc_string());
+                result.push_str(&value.to_dbc_string());
+                result.push_str(";\n");
+            }
+        }
+
+        // VAL_ lines (value descriptions section)
+        let mut wrote_section = false;
+        for ((message_id, signal_name), value_descriptions) in self.value_descriptions.iter() {
+            if !wrote_section {
+                result.push('\n');
+                wrote_section = true;
+            }
+            result.push_str("VAL_ ");
+            match message_id {
+                Some(id) => result.push_str(&id.to_string()),
+                // Global value descriptions
+                None => result.push_str("-1"),
+            }
+            result.push(' ');
+            result.push_str(signal_name);
+            for (value, description) in value_descriptions.iter() {
+                result.push(' ');
+                // Negative values are stored two's complement
+                result.push_str(&(value as i64).to_string());
+                result.push_str(" \"");
+                result.push_str(description);
+                result.push('"');
+            }
+            result.push_str(" ;\n");
+        }
+
+        // SGTYPE_VAL_ lines (signal type value descriptions, in the value descriptions section)
+        for signal_type in self.signal_types.iter() {
+            if let Some(value_descriptions) = signal_type.value_descriptions() {
+                if !wrote_section {
+                    result.push('\n');
+                    wrote_section = true;
+                }
+                result.push_str("SGTYPE_VAL_ ");
+                result.push_str(signal_type.name());
+                for (value, description) in value_descriptions.iter() {
+                    result.push(' ');
+                    // Negative values are stored two's complement
+                    result.push_str(&(value as i64).to_string());
+                    result.push_str(" \"");
+                    result.push_str(description);
+                    result.push('"');
+                }
+                result.push_str(" ;\n");
+            }
+        }
+
+        // SIG_TYPE_REF_ lines (signal type references section)
+        if !self.signal_type_refs.is_empty() {
+            result.push('\n');
+            for type_ref in self.signal_type_refs.iter() {
+                result.push_str(&type_ref.to_dbc_string());
+                result.push('\n');
+            }
+        }
+
+        // SIG_VALTYPE_ lines (float and double signals; integer is the implied default)
+        let mut wrote_section = false;
+        for message in self.messages().iter() {
+            for signal in message.signals().iter() {
+                if !signal.is_float() {
+                    continue;
+                }
+                if !wrote_section {
+                    result.push('\n');
+                    wrote_section = true;
+                }
+                result.push_str("SIG_VALTYPE_ ");
+                result.push_str(&message.id_with_flag().to_string());
+                result.push(' ');
+                result.push_str(signal.name());
+                result.push_str(" : ");
+                result.push_str(&signal.value_type().dbc_code().to_string());
+                result.push_str(";\n");
+            }
+        }
+
+        // SIG_GROUP_ lines (signal groups section)
+        if !self.signal_groups.is_empty() {
+            result.push('\n');
+            for group in self.signal_groups.iter() {
+                result.push_str(&group.to_dbc_string());
+                result.push('\n');
+            }
+        }
+
+        // SG_MUL_VAL_ lines (extended multiplexing section)
+        if !self.extended_multiplexing.is_empty() {
+            result.push('\n');
+            for ext_mux in self.extended_multiplexing.iter() {
+                result.push_str(&ext_mux.to_dbc_string());
+                result.push('\n');
+            }
+        }
+
+        result
+    }
+
+    /// Strip everything that is not needed to look up and decode messages.
+    ///
+    /// Keeps the version, nodes, messages and signals (names, layout, scaling, units,
+    /// receivers). Drops the database, message and signal comments, bit timing, value
+    /// descriptions, extended multiplexing, signal types and groups, environment variables
+    /// and attributes.
+    pub(crate) fn into_minimal(self) -> Self {
+        let mut messages = self.messages;
+        for message in messages.iter_mut() {
+            message.clear_comments();
+        }
+
+        Self {
+            version: self.version,
+            bit_timing: None,
+            nodes: self.nodes,
+            messages,
+            value_descriptions: ValueDescriptionsMap::default(),
+            extended_multiplexing: ExtendedMultiplexings::new(),
+            ext_mux_index: ExtMuxIndex::empty(),
+            comment: None,
+            signal_types: SignalTypes::new(),
+            signal_type_refs: SignalTypeRefs::new(),
+            signal_groups: SignalGroups::new(),
+            environment_variables: EnvironmentVariables::new(),
+            #[cfg(feature = "attributes")]
+            attribute_definitions: AttributeDefinitionsMap::default(),
+            #[cfg(feature = "attributes")]
+            attribute_defaults: AttributeDefaultsMap::default(),
+            #[cfg(feature = "attributes")]
+            attribute_values: AttributeValuesMap::default(),
+        }
+    }
+}
+
+impl Display for Dbc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_dbc_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Messages;
+    use crate::{Dbc, Error, SerializeOptions, compat::MAX_COMMENT_SIZE};
+
+    #[test]
+    fn test_to_dbc_string() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm"
+"#,
+        )
+        .unwrap();
+
+        let dbc_string = dbc.to_dbc_string();
+        assert!(dbc_string.contains("VERSION"));
+        assert!(dbc_string.contains("BU_"));
+        assert!(dbc_string.contains("BO_"));
+        assert!(dbc_string.contains("SG_"));
+    }
+
+    #[test]
+    fn test_to_string_with_options() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ Pressure : 39|12@0+ (0.1,0) [0|409.5] "bar" *
+ SG_ Temp : 23|8@0- (1,-40) [-40|215] "degC" *
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+
+CM_ SG_ 256 Pressure "Big-endian, MSB in byte 4";
+"#,
+        )
+        .unwrap();
+
+        // Default options reproduce to_dbc_string
+        assert_eq!(
+            dbc.to_string_with(&SerializeOptions::default()),
+            dbc.to_dbc_string()
+        );
+
+        let options = SerializeOptions {
+            sort_signals_by_start_bit: true,
+        };
+        let sorted = dbc.to_string_with(&options);
+        let position = |line: &str| sorted.find(line).unwrap();
+        assert!(position(" SG_ RPM : 0|16@1+") < position(" SG_ Temp : 23|8@0-"));
+        assert!(position(" SG_ Temp : 23|8@0-") < position(" SG_ Pressure : 39|12@0+"));
+
+        // Big-endian signals keep their MSB start bit and layout through the round trip
+        let reparsed = Dbc::parse(&sorted).unwrap();
+        let original = dbc.messages().find("Engine").unwrap();
+        let round_tripped = reparsed.messages().find("Engine").unwrap();
+        for name in ["Pressure", "Temp", "RPM"] {
+            let before = original.signals().find(name).unwrap();
+            let after = round_tripped.signals().find(name).unwrap();
+            assert_eq!(after.start_bit(), before.start_bit());
+            assert_eq!(after.bit_position(), before.bit_position());
+            assert_eq!(after.byte_order(), before.byte_order());
+            assert_eq!(after.comment(), before.comment());
+        }
+        let payload = [0x40, 0x1F, 0x5A, 0x00, 0x12, 0x30, 0x00, 0x00];
+        for name in ["Pressure", "Temp", "RPM"] {
+            assert_eq!(
+                reparsed.decode_signal(256, name, &payload).unwrap(),
+                dbc.decode_signal(256, name, &payload).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+"#,
+        )
+        .unwrap();
+
+        let display_str = format!("{}", dbc);
+        assert!(display_str.contains("VERSION"));
+    }
+
+    #[test]
+    fn test_save_round_trip() {
+        let original = r#"VERSION "1.0"
+
+BU_: ECM TCM
+
+BO_ 256 EngineData : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temperature : 16|8@0- (1,-40) [-40|215] "°C" TCM
+
+BO_ 512 BrakeData : 4 TCM
+ SG_ Pressure : 0|16@0+ (0.1,0) [0|1000] "bar"
+"#;
+
+        let dbc = Dbc::parse(original).unwrap();
+        let saved = dbc.to_dbc_string();
+        let dbc2 = Dbc::parse(&saved).unwrap();
+
+        // Verify round-trip: parsed data should match
+        assert_eq!(
+            dbc.version().map(|v| v.to_string()),
+            dbc2.version().map(|v| v.to_string())
+        );
+        assert_eq!(dbc.messages().len(), dbc2.messages().len());
+
+        for (msg1, msg2) in dbc.messages().iter().zip(dbc2.messages().iter()) {
+            assert_eq!(msg1.id(), msg2.id());
+            assert_eq!(msg1.name(), msg2.name());
+            assert_eq!(msg1.dlc(), msg2.dlc());
+            assert_eq!(msg1.sender(), msg2.sender());
+            assert_eq!(msg1.signals().len(), msg2.signals().len());
+
+            for (sig1, sig2) in msg1.signals().iter().zip(msg2.signals().iter()) {
+                assert_eq!(sig1.name(), sig2.name());
+                assert_eq!(sig1.start_bit(), sig2.start_bit());
+                assert_eq!(sig1.length(), sig2.length());
+                assert_eq!(sig1.byte_order(), sig2.byte_order());
+                assert_eq!(sig1.is_unsigned(), sig2.is_unsigned());
+                assert_eq!(sig1.factor(), sig2.factor());
+                assert_eq!(sig1.offset(), sig2.offset());
+                assert_eq!(sig1.min(), sig2.min());
+                assert_eq!(sig1.max(), sig2.max());
+                assert_eq!(sig1.unit(), sig2.unit());
+                assert_eq!(sig1.receivers(), sig2.receivers());
+            }
+        }
+    }
+
+    #[test]
+    fn test_environment_variables_round_trip() {
+        let original = r#"VERSION "1.0"
+
+BU_: ECM TCM
+
+EV_ EnvKL15 : 0 [0|1] "" 0 1 DUMMY_NODE_VECTOR3 ECM, TCM;
+EV_ EnvName : 0 [0|0] "" 0 2 DUMMY_NODE_VECTOR8000 Vector__XXX;
+EV_ EnvBlob : 0 [0|0] "" 0 3 DUMMY_NODE_VECTOR0 ECM;
+
+ENVVAR_DATA_ EnvBlob : 16;
+"#;
+
+        let dbc = Dbc::parse(original).unwrap();
+        let saved = dbc.to_dbc_string();
+        assert!(saved.contains("EV_ EnvName : 2 [0|0] \"\" 0 2 DUMMY_NODE_VECTOR0 Vector__XXX;\n"));
+        assert!(saved.contains("\nENVVAR_DATA_ EnvBlob : 16;\n"));
+
+        let dbc2 = Dbc::parse(&saved).unwrap();
+        assert!(dbc.environment_variables().eq(dbc2.environment_variables()));
+        assert_eq!(
+            dbc2.environment_variable("EnvBlob").unwrap().data_size(),
+            Some(16)
+        );
+    }
+
+    #[test]
+    fn test_save_basic() {
+        // Use parsing instead of builders
+        // Note: '*' is parsed as None per spec compliance, output is 'Vector__XXX'
+        let dbc_content = r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 EngineData : 8 ECM
+ SG_ RPM : 0|16@0+ (0.25,0) [0|8000] "rpm" *
+"#;
+        let dbc = Dbc::parse(dbc_content).unwrap();
+
+        let saved = dbc.to_dbc_string();
+        assert!(saved.contains("VERSION \"1.0\""));
+        assert!(saved.contains("BU_: ECM"));
+        assert!(saved.contains("BO_ 256 EngineData : 8 ECM"));
+        // Per DBC spec Section 9.5: '*' is not valid, we output 'Vector__XXX' instead
+        assert!(saved.contains("SG_ RPM : 0|16@0+ (0.25,0) [0|8000] \"rpm\" Vector__XXX"));
+    }
+
+    #[test]
+    fn test_save_multiple_messages() {
+        // Use parsing instead of builders
+        let dbc_content = r#"VERSION "1.0"
+
+BU_: ECM TCM
+
+BO_ 256 EngineData : 8 ECM
+ SG_ RPM : 0|16@0+ (0.25,0) [0|8000] "rpm"
+
+BO_ 512 BrakeData : 4 TCM
+ SG_ Pressure : 0|16@1+ (0.1,0) [0|1000] "bar"
+"#;
+        let dbc = Dbc::parse(dbc_content).unwrap();
+        let saved = dbc.to_dbc_string();
+
+        // Verify both messages are present
+        assert!(saved.contains("BO_ 256 EngineData : 8 ECM"));
+        assert!(saved.contains("BO_ 512 BrakeData : 4 TCM"));
+        assert!(saved.contains("SG_ RPM"));
+        assert!(saved.contains("SG_ Pressure"));
+    }
+
+    #[test]
+    fn test_bit_timing_empty() {
+        // Empty BS_: should still be present in output
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BS_:
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+"#,
+        )
+        .unwrap();
+
+        // bit_timing should be None when empty
+        assert!(dbc.bit_timing().is_none());
+
+        // Output should still have BS_:
+        let saved = dbc.to_dbc_string();
+        assert!(saved.contains("BS_:"));
+    }
+
+    #[test]
+    fn test_bit_timing_with_values() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BS_: 500000 : 1,2
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+"#,
+        )
+        .unwrap();
+
+        // bit_timing should be Some with values
+        let bt = dbc.bit_timing().expect("bit timing should be present");
+        assert_eq!(bt.baudrate(), Some(500000));
+        assert_eq!(bt.btr1(), Some(1));
+        assert_eq!(bt.btr2(), Some(2));
+
+        // Output should have full BS_ line
+        let saved = dbc.to_dbc_string();
+        assert!(saved.contains("BS_: 500000 : 1,2"));
+    }
+
+    #[test]
+    fn test_bit_timing_baudrate_only() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BS_: 500000
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+"#,
+        )
+        .unwrap();
+
+        // bit_timing should be Some with baudrate only
+        let bt = dbc.bit_timing().expect("bit timing should be present");
+        assert_eq!(bt.baudrate(), Some(500000));
+        assert_eq!(bt.btr1(), None);
+        assert_eq!(bt.btr2(), None);
+
+        // Output should have BS_ with baudrate
+        let saved = dbc.to_dbc_string();
+        assert!(saved.contains("BS_: 500000"));
+    }
+
+    #[test]
+    fn test_clone_message() {
+        let mut dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Door_FL : 8 ECM
+ SG_ Mux M : 0|8@1+ (1,0) [0|255] "" *
+ SG_ State m0 : 8|8@1+ (1,0) [0|3] "" *
+
+CM_ BO_ 256 "Front left door";
+CM_ SG_ 256 State "Door latch state";
+VAL_ 256 State 0 "Closed" 1 "Open" ;
+SG_MUL_VAL_ 256 State Mux 0-0 ;
+"#,
+        )
+        .unwrap();
+
+        dbc.clone_message(256, 257, "Door_FR").unwrap();
+
+        let copy = dbc.messages().find_by_id(257).unwrap();
+        assert_eq!(copy.name(), "Door_FR");
+        assert_eq!(copy.comment(), Some("Front left door"));
+        assert_eq!(copy.signals().len(), 2);
+        assert_eq!(
+            copy.signals().find("State").unwrap().comment(),
+            Some("Door latch state")
+        );
+
+        // SG_MUL_VAL_ entries are remapped to the new ID
+        let ext_mux: std::vec::Vec<_> = dbc.ext_mux_entries_for_signal(257, "State").collect();
+        assert_eq!(ext_mux.len(), 1);
+        assert_eq!(ext_mux[0].message_id(), 257);
+        assert_eq!(ext_mux[0].multiplexer_switch(), "Mux");
+        assert_eq!(ext_mux[0].value_ranges(), [(0, 0)]);
+        assert_eq!(dbc.ext_mux_entries_for_signal(256, "State").count(), 1);
+
+        // VAL_ entries are copied for the new ID, the original ones are kept
+        assert_eq!(dbc.value_descriptions().len(), 2);
+        let cloned = dbc.value_descriptions_for_signal(257, "State").unwrap();
+        assert_eq!(cloned.get(0), Some("Closed"));
+        assert_eq!(cloned.get(1), Some("Open"));
+        let original = dbc.value_descriptions_for_signal(256, "State").unwrap();
+        assert_eq!(original.get(1), Some("Open"));
+
+        // Round-trips through the DBC format
+        let reparsed = Dbc::parse(&dbc.to_dbc_string()).unwrap();
+        assert_eq!(reparsed.messages().len(), 2);
+        let copy = reparsed.messages().find_by_id(257).unwrap();
+        assert_eq!(
+            copy.signals().find("State").unwrap().comment(),
+            Some("Door latch state")
+        );
+    }
+
+    const MERGE_BASE: &str = r#"VERSION "1.0"
+
+BU_: ECM Gateway
+
+BO_ 256 Engine : 8 ECM
+ SG_ State : 0|8@1+ (1,0) [0|255] "" Gateway
+ SG_ Mode : 8|8@1+ (1,0) [0|255] "" Gateway
+
+VAL_ 256 State 0 "Off" 1 "On" ;
+VAL_ -1 Mode 0 "Normal" ;
+"#;
+
+    const MERGE_OTHER: &str = r#"VERSION "2.0"
+
+BU_: ECM ABS Gateway
+
+BO_ 256 EngineV2 : 8 ECM
+ SG_ Status : 0|8@1+ (1,0) [0|255] "" Gateway
+ SG_ Mode : 8|8@1+ (1,0) [0|255] "" Gateway
+
+BO_ 512 Brake : 8 ABS
+ SG_ Mux M : 0|8@1+ (1,0) [0|255] ""
+ SG_ Pressure m0 : 8|16@1+ (0.1,0) [0|1000] "bar" Gateway
+
+VAL_ 512 Mux 0 "Pressure" ;
+VAL_ -1 Mode 0 "Sport" ;
+
+SG_MUL_VAL_ 512 Pressure Mux 2-3 ;
+"#;
+
+    #[test]
+    fn test_merge() {
+        let mut dbc = Dbc::parse(MERGE_BASE).unwrap();
+        let mut other = Dbc::parse(MERGE_OTHER).unwrap();
+        let original = dbc.to_dbc_string();
+
+        // Message 256 exists on both sides
+        assert_eq!(
+            dbc.merge(other.clone()),
+            Err(Error::Validation(Error::DUPLICATE_MESSAGE_ID))
+        );
+        assert_eq!(dbc.to_dbc_string(), original);
+
+        other.messages =
+            Messages::from_vec(other.messages.iter().filter(|m| m.id() != 256).cloned().collect())
+                .unwrap();
+        dbc.merge(other).unwrap();
+
+        // Gateway is not duplicated
+        assert!(dbc.nodes().iter().eq(["ECM", "Gateway", "ABS"]));
+        assert_eq!(dbc.messages().len(), 2);
+        assert_eq!(
+            dbc.value_descriptions_for_signal(512, "Mux").and_then(|vd| vd.get(0)),
+            Some("Pressure")
+        );
+        // Existing global value descriptions win
+        assert_eq!(
+            dbc.value_descriptions_for_signal(256, "Mode").and_then(|vd| vd.get(0)),
+            Some("Normal")
+        );
+
+        // The extended multiplexing index covers the merged entries
+        assert_eq!(dbc.ext_mux_entries_for_signal(512, "Pressure").count(), 1);
+        let decoded = dbc.decode(512, &[2, 0x10, 0, 0, 0, 0, 0, 0], false).unwrap();
+        assert!(decoded.iter().any(|s| s.name == "Pressure"));
+        let decoded = dbc.decode(512, &[0, 0x10, 0, 0, 0, 0, 0, 0], false).unwrap();
+        assert!(!decoded.iter().any(|s| s.name == "Pressure"));
+    }
+
+    #[test]
+    fn test_merge_overwrite() {
+        let mut dbc = Dbc::parse(MERGE_BASE).unwrap();
+        dbc.merge_overwrite(Dbc::parse(MERGE_OTHER).unwrap()).unwrap();
+
+        assert_eq!(dbc.messages().len(), 2);
+        // Replaced in place
+        let engine = dbc.messages().at(0).unwrap();
+        assert_eq!(engine.name(), "EngineV2");
+        assert!(engine.signals().find("Status").is_some());
+        // Value descriptions of the replaced message are dropped
+        assert!(dbc.value_descriptions_for_signal(256, "State").is_none());
+        assert_eq!(
+            dbc.value_descriptions_for_signal(256, "Mode").and_then(|vd| vd.get(0)),
+            Some("Sport")
+        );
+        assert_eq!(dbc.ext_mux_entries_for_signal(512, "Pressure").count(), 1);
+    }
+
+    #[test]
+    fn test_merge_overwrite_drops_references_to_replaced_signals() {
+        let mut dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ A : 0|8@1+ (1,0) [0|255] "" *
+
+BO_ 512 Brake : 8 ECM
+ SG_ P : 0|8@1+ (1,0) [0|255] "" *
+
+SGTYPE_ T : 8@1+ (1,0) [0|255] "" 0;
+
+BA_DEF_ SG_ "GenSigStartValue" INT 0 100;
+BA_ "GenSigStartValue" SG_ 256 A 1;
+
+SIG_TYPE_REF_ 256 A : T;
+SIG_TYPE_REF_ 512 P : T;
+
+SIG_GROUP_ 256 G 1 : A;
+SIG_GROUP_ 512 K 1 : P;
+"#,
+        )
+        .unwrap();
+        let other = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ B : 0|8@1+ (1,0) [0|255] "" *
+
+SIG_GROUP_ 256 H 1 : B;
+"#,
+        )
+        .unwrap();
+
+        dbc.merge_overwrite(other).unwrap();
+        let serialized = dbc.to_dbc_string();
+
+        assert!(!serialized.contains("SIG_GROUP_ 256 G"));
+        assert!(!serialized.contains("SIG_TYPE_REF_ 256 A"));
+        // Groups of `other` are not carried over
+        assert!(!serialized.contains("SIG_GROUP_ 256 H"));
+        // Entries of other messages are kept
+        assert!(serialized.contains("SIG_GROUP_ 512 K 1 : P;"));
+        assert!(serialized.contains("SIG_TYPE_REF_ 512 P : T;"));
+        #[cfg(feature = "attributes")]
+        assert!(!serialized.contains("SG_ 256 A"));
+        assert!(Dbc::parse(&serialized).is_ok());
+    }
+
+    #[test]
+    fn test_clone_message_errors() {
+        let mut dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+BO_ 512 Brake : 8 ECM
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            dbc.clone_message(256, 512, "Engine2"),
+            Err(Error::Validation(Error::DUPLICATE_MESSAGE_ID))
+        );
+        assert_eq!(
+            dbc.clone_message(300, 301, "Missing"),
+            Err(Error::Validation(Error::MESSAGE_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.clone_message(256, 300, "Brake"),
+            Err(Error::Validation(Error::MESSAGE_NAME_DUPLICATE))
+        );
+        assert!(dbc.clone_message(256, 0x2000_0000, "OutOfRange").is_err());
+        assert_eq!(dbc.messages().len(), 2);
+    }
+
+    #[test]
+    fn test_rename_signal() {
+        let mut dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Door_FL : 8 ECM
+ SG_ Mux M : 0|8@1+ (1,0) [0|255] "" *
+ SG_ State m0 : 8|8@1+ (1,0) [0|3] "" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" *
+
+BO_ 257 Door_FR : 8 ECM
+ SG_ State : 0|8@1+ (1,0) [0|3] "" *
+
+BA_DEF_ SG_ "GenSigStartValue" FLOAT 0 100;
+CM_ SG_ 256 State "Door latch state";
+BA_ "GenSigStartValue" SG_ 256 State 1;
+VAL_ 256 State 0 "Closed" 1 "Open" ;
+VAL_ 257 State 0 "Closed" 1 "Open" ;
+SIG_TYPE_REF_ 256 State : LatchType;
+SIG_GROUP_ 256 Latch 1 : Mux State;
+SG_MUL_VAL_ 256 State Mux 0-0 ;
+"#,
+        )
+        .unwrap();
+
+        dbc.rename_signal(256, "State", "LatchState").unwrap();
+        dbc.rename_signal(256, "Mux", "Page").unwrap();
+
+        let door = dbc.messages().find_by_id(256).unwrap();
+        assert!(door.signals().find("State").is_none());
+        let signal = door.signals().find("LatchState").unwrap();
+        assert_eq!(signal.comment(), Some("Door latch state"));
+
+        // The value descriptions stay reachable under the new name
+        let vd = dbc.value_descriptions_for_signal(256, "LatchState").unwrap();
+        assert_eq!(vd.get(1), Some("Open"));
+        assert!(dbc.value_descriptions_for_signal(256, "State").is_none());
+        // The same signal name in another message is untouched
+        assert!(dbc.value_descriptions_for_signal(257, "State").is_some());
+
+        let ext_mux = dbc.ext_mux_entries_for_signal(256, "LatchState").next().unwrap();
+        assert_eq!(ext_mux.multiplexer_switch(), "Page");
+        let group = &dbc.signal_groups()[0];
+        assert_eq!(
+            group.signal_names().collect::<std::vec::Vec<_>>(),
+            ["Page", "LatchState"]
+        );
+        assert_eq!(dbc.signal_type_refs()[0].signal_name(), "LatchState");
+        #[cfg(feature = "attributes")]
+        assert!(dbc.signal_attribute(256, "LatchState", "GenSigStartValue").is_some());
+
+        // Decoding follows the renamed switch and extended multiplexing entry
+        let decoded = dbc.decode(256, &[0, 1, 0, 0, 0, 0, 0, 0], false).unwrap();
+        let state = decoded.iter().find(|s| s.name == "LatchState").unwrap();
+        assert_eq!(state.description, Some("Open"));
+
+        // Round-trips through the DBC format
+        let reparsed = Dbc::parse(&dbc.to_dbc_string()).unwrap();
+        assert!(reparsed.value_descriptions_for_signal(256, "LatchState").is_some());
+        assert_eq!(
+            reparsed.ext_mux_entries_for_signal(256, "LatchState").count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_rename_signal_errors() {
+        let mut dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" *
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            dbc.rename_signal(300, "RPM", "Speed"),
+            Err(Error::Validation(Error::MESSAGE_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.rename_signal(256, "Missing", "Speed"),
+            Err(Error::Validation(Error::SIGNAL_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.rename_signal(256, "RPM", "Temp"),
+            Err(Error::Validation(Error::SIGNAL_NAME_DUPLICATE))
+        );
+        assert!(dbc.rename_signal(256, "RPM", &"S".repeat(crate::MAX_NAME_SIZE + 1)).is_err());
+        let engine = dbc.messages().find_by_id(256).unwrap();
+        assert!(engine.signals().find("RPM").is_some());
+        assert_eq!(dbc.rename_signal(256, "RPM", "RPM"), Ok(()));
+    }
+
+    #[test]
+    fn test_rename_message() {
+        let mut dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ Gear : 0|8@1+ (1,0) [0|5] "" *
+
+BO_ 2147484672 Brake : 8 ECM
+ SG_ Pressure : 0|16@1+ (0.1,0) [0|1000] "bar" *
+
+CM_ BO_ 256 "Engine data";
+VAL_ 256 Gear 0 "Park" 1 "Drive" ;
+"#,
+        )
+        .unwrap();
+
+        dbc.rename_message(256, "EngineStatus").unwrap();
+        let engine = dbc.message_by_name("EngineStatus").unwrap();
+        assert_eq!(engine.id(), 256);
+        assert_eq!(engine.comment(), Some("Engine data"));
+        assert!(dbc.message_by_name("Engine").is_none());
+        assert!(dbc.value_descriptions_for_signal(256, "Gear").is_some());
+
+        dbc.rename_message(0x8000_0400, "BrakeStatus").unwrap();
+        assert!(dbc.message_by_name("BrakeStatus").unwrap().is_extended());
+
+        assert_eq!(
+            dbc.rename_message(256, "BrakeStatus"),
+            Err(Error::Validation(Error::MESSAGE_NAME_DUPLICATE))
+        );
+        assert_eq!(
+            dbc.rename_message(300, "Missing"),
+            Err(Error::Validation(Error::MESSAGE_NOT_FOUND))
+        );
+        assert_eq!(dbc.rename_message(256, "EngineStatus"), Ok(()));
+        assert_eq!(dbc.message_by_name("EngineStatus").unwrap().id(), 256);
+    }
+
+    #[test]
+    fn test_set_comments_round_trip() {
+        let mut dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+
+BO_ 2147484672 Brake : 8 ECM
+ SG_ Pressure : 0|16@1+ (0.1,0) [0|1000] "bar" *
+
+CM_ BO_ 256 "Old comment";
+"#,
+        )
+        .unwrap();
+
+        dbc.set_message_comment(256, "Engine status").unwrap();
+        dbc.set_signal_comment(256, "RPM", "Crankshaft speed").unwrap();
+        dbc.set_message_comment(0x8000_0400, "Brake status").unwrap();
+        dbc.set_signal_comment(0x8000_0400, "Pressure", "Line pressure").unwrap();
+
+        let reparsed = Dbc::parse(&dbc.to_dbc_string()).unwrap();
+        let engine = reparsed.messages().find_by_id(256).unwrap();
+        assert_eq!(engine.comment(), Some("Engine status"));
+        assert_eq!(
+            engine.signals().find("RPM").unwrap().comment(),
+            Some("Crankshaft speed")
+        );
+        let brake = reparsed.messages().find_by_id(0x8000_0400).unwrap();
+        assert_eq!(brake.comment(), Some("Brake status"));
+        assert_eq!(
+            brake.signals().find("Pressure").unwrap().comment(),
+            Some("Line pressure")
+        );
+    }
+
+    #[test]
+    fn test_set_comments_errors() {
+        let mut dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            dbc.set_message_comment(300, "Missing"),
+            Err(Error::Validation(Error::MESSAGE_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.set_signal_comment(300, "RPM", "Missing"),
+            Err(Error::Validation(Error::MESSAGE_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.set_signal_comment(256, "Speed", "Missing"),
+            Err(Error::Validation(Error::SIGNAL_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.set_message_comment(256, "Say \"hi\""),
+            Err(Error::Validation(Error::COMMENT_CONTAINS_QUOTE))
+        );
+
+        let long = "x".repeat(MAX_COMMENT_SIZE + 1);
+        assert_eq!(
+            dbc.set_signal_comment(256, "RPM", &long),
+            Err(Error::Validation(Error::COMMENT_TOO_LONG))
+        );
+        dbc.set_signal_comment(256, "RPM", &long[1..]).unwrap();
+        let engine = dbc.messages().find_by_id(256).unwrap();
+        assert_eq!(engine.comment(), None);
+        assert_eq!(
+            engine.signals().find("RPM").unwrap().comment(),
+            Some(&long[1..])
+        );
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let a = r#"VERSION "1.0"
+
+BU_: ECM TCM BCM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" TCM,BCM
+ SG_ Temp : 16|8@1+ (1,-40) [-40|215] "degC" BCM
 
-        // BU_ line
-        result.push_str(&self.nodes.to_dbc_string());
-        result.push('\n');
+BO_ 2147484672 Diag : 8 BCM
+ SG_ Mode : 0|8@1+ (1,0) [0|3] "" ECM
 
-        // BO_ and SG_ lines for each message
-        for message in self.messages().iter() {
-            result.push('\n');
-            result.push_str(&message.to_string_full());
-        }
+BO_ 512 Gearbox : 8 TCM
+ SG_ Gear : 8|8@1+ (1,0) [0|5] "" ECM
+ SG_ Mode M : 0|8@1+ (1,0) [0|1] "" ECM
+ SG_ Torque m0 : 16|16@1- (0.1,0) [-1000|1000] "Nm" ECM
+ SG_ Speed m1 : 16|16@1+ (0.1,0) [0|300] "km/h" ECM
 
-        // CM_ lines (comments section)
-        // General database comment
-        if let Some(comment) = self.comment() {
-            result.push_str("\nCM_ \"");
-            result.push_str(comment);
-            result.push_str("\";\n");
-        }
+CM_ BO_ 256 "Engine data";
+VAL_ 512 Gear 3 "Drive" 0 "Park" 1 "Reverse" ;
+"#;
+        let b = r#"VERSION "1.0"
 
-        // Node comments
-        for node in self.nodes.iter_nodes() {
-            if let Some(comment) = node.comment() {
-                result.push_str("CM_ BU_ ");
-                result.push_str(node.name());
-                result.push_str(" \"");
-                result.push_str(comment);
-                result.push_str("\";\n");
-            }
-        }
+BU_: TCM BCM ECM
 
-        // Message and signal comments
-        for message in self.messages().iter() {
-            if let Some(comment) = message.comment() {
-                result.push_str("CM_ BO_ ");
-                result.push_str(&message.id().to_string());
-                result.push_str(" \"");
-                result.push_str(comment);
-                result.push_str("\";\n");
-            }
+BO_ 512 Gearbox : 8 TCM
+ SG_ Speed m1 : 16|16@1+ (0.1,0) [0|300] "km/h" ECM
+ SG_ Mode M : 0|8@1+ (1,0) [0|1] "" ECM
+ SG_ Gear : 8|8@1+ (1,0) [0|5] "" ECM
+ SG_ Torque m0 : 16|16@1- (0.1,0) [-1000|1000] "Nm" ECM
 
-            for signal in message.signals().iter() {
-                if let Some(comment) = signal.comment() {
-                    result.push_str("CM_ SG_ ");
-                    result.push_str(&message.id().to_string());
-                    result.push(' ');
-                    result.push_str(signal.name());
-                    result.push_str(" \"");
-                    result.push_str(comment);
-                    result.push_str("\";\n");
-                }
-            }
-        }
+BO_ 2147484672 Diag : 8 BCM
+ SG_ Mode : 0|8@1+ (1,0) [0|3] "" ECM
 
-        result
-    }
-}
+BO_ 256 Engine : 8 ECM
+ SG_ Temp : 16|8@1+ (1,-40) [-40|215] "degC" BCM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" BCM,TCM
 
-impl Display for Dbc {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{}", self.to_dbc_string())
-    }
-}
+CM_ BO_ 256 "Engine data";
+VAL_ 512 Gear 0 "Park" 1 "Reverse" 3 "Drive" ;
+"#;
+        let mut a = Dbc::parse(a).unwrap();
+        let mut b = Dbc::parse(b).unwrap();
+        assert_ne!(a.to_string(), b.to_string());
 
-#[cfg(test)]
-mod tests {
-    use crate::Dbc;
+        a.canonicalize();
+        b.canonicalize();
+        assert_eq!(a.to_string(), b.to_string());
+
+        assert_eq!(a.nodes().iter().collect::<Vec<_>>(), ["BCM", "ECM", "TCM"]);
+        let ids: Vec<u32> = a.messages().iter().map(|m| m.id_with_flag()).collect();
+        assert_eq!(ids, [256, 512, 0x8000_0400]);
+        let gearbox = a.messages().find_by_id(512).unwrap();
+        let names: Vec<&str> = gearbox.signals().iter().map(|s| s.name()).collect();
+        assert_eq!(names, ["Mode", "Gear", "Speed", "Torque"]);
+        let rpm = a.messages().find_by_id(256).unwrap().signals().find("RPM").unwrap();
+        assert_eq!(rpm.receivers().iter().collect::<Vec<_>>(), ["BCM", "TCM"]);
+        let gear = a.value_descriptions_for_signal(512, "Gear").unwrap();
+        let values: Vec<u64> = gear.iter().map(|(value, _)| value).collect();
+        assert_eq!(values, [0, 1, 3]);
+
+        // Canonicalizing is idempotent and the result still round-trips
+        let once = a.to_string();
+        a.canonicalize();
+        assert_eq!(a.to_string(), once);
+        assert_eq!(Dbc::parse(&once).unwrap().to_string(), once);
+    }
 
     #[test]
-    fn test_to_dbc_string() {
-        let dbc = Dbc::parse(
+    fn test_remove_message() {
+        let mut dbc = Dbc::parse(
             r#"VERSION "1.0"
 
 BU_: ECM
 
 BO_ 256 Engine : 8 ECM
- SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm"
+ SG_ Mode M : 0|8@1+ (1,0) [0|3] "" *
+ SG_ Gear m0 : 8|8@1+ (1,0) [0|5] "" *
+
+BO_ 2147484672 Brake : 8 ECM
+ SG_ Pressure : 0|16@1+ (0.1,0) [0|1000] "bar" *
+
+BA_DEF_ BO_ "GenMsgCycleTime" INT 0 10000;
+BA_ "GenMsgCycleTime" BO_ 256 10;
+VAL_ 256 Gear 0 "Park" 1 "Drive" ;
+VAL_ 2147484672 Pressure 0 "Released" ;
+SIG_GROUP_ 256 EngineGroup 1 : Mode Gear;
+SG_MUL_VAL_ 256 Gear Mode 0-0;
 "#,
         )
         .unwrap();
 
-        let dbc_string = dbc.to_dbc_string();
-        assert!(dbc_string.contains("VERSION"));
-        assert!(dbc_string.contains("BU_"));
-        assert!(dbc_string.contains("BO_"));
-        assert!(dbc_string.contains("SG_"));
+        let removed = dbc.remove_message(256).unwrap();
+        assert_eq!(removed.name(), "Engine");
+        assert_eq!(removed.signals().len(), 2);
+        assert!(dbc.messages().find_by_id(256).is_none());
+        assert!(dbc.message_by_name("Engine").is_none());
+        assert_eq!(dbc.messages().len(), 1);
+        assert!(dbc.value_descriptions_for_signal(256, "Gear").is_none());
+        assert!(dbc.value_descriptions_for_signal(0x8000_0400, "Pressure").is_some());
+        assert_eq!(dbc.extended_multiplexing_for_message(256).count(), 0);
+        assert!(dbc.ext_mux_entries_for_signal(256, "Gear").next().is_none());
+        assert_eq!(dbc.signal_groups().iter().count(), 0);
+        #[cfg(feature = "attributes")]
+        assert_eq!(
+            dbc.attribute_values().get_message(256, "GenMsgCycleTime"),
+            None
+        );
+        assert!(dbc.remove_message(256).is_none());
+
+        // The remaining message still resolves by ID and name, and survives a round trip
+        assert_eq!(
+            dbc.message_by_name("Brake").unwrap().id_with_flag(),
+            0x8000_0400
+        );
+        let reparsed = Dbc::parse(&dbc.to_dbc_string()).unwrap();
+        assert_eq!(reparsed.messages().len(), 1);
+        assert!(dbc.remove_message(0x8000_0400).is_some());
+        assert!(dbc.messages().is_empty());
     }
 
     #[test]
-    fn test_display() {
-        let dbc = Dbc::parse(
+    fn test_remove_signal() {
+        let mut dbc = Dbc::parse(
             r#"VERSION "1.0"
 
 BU_: ECM
 
 BO_ 256 Engine : 8 ECM
+ SG_ Mode M : 0|8@1+ (1,0) [0|3] "" *
+ SG_ Gear m0 : 8|8@1+ (1,0) [0|5] "" *
+ SG_ RPM : 16|16@1+ (0.25,0) [0|8000] "rpm" *
+
+BA_DEF_ SG_ "GenSigStartValue" INT 0 100;
+BA_ "GenSigStartValue" SG_ 256 Gear 1;
+VAL_ 256 Gear 0 "Park" 1 "Drive" ;
+SIG_GROUP_ 256 GearGroup 1 : Gear;
+SIG_GROUP_ 256 EngineGroup 1 : Gear RPM;
+SG_MUL_VAL_ 256 Gear Mode 0-0;
 "#,
         )
         .unwrap();
 
-        let display_str = format!("{}", dbc);
-        assert!(display_str.contains("VERSION"));
+        dbc.remove_signal(256, "Gear").unwrap();
+        let engine = dbc.messages().find_by_id(256).unwrap();
+        assert!(engine.signals().find("Gear").is_none());
+        assert_eq!(engine.signals().len(), 2);
+        assert!(dbc.value_descriptions_for_signal(256, "Gear").is_none());
+        assert!(dbc.ext_mux_entries_for_signal(256, "Gear").next().is_none());
+        assert_eq!(dbc.extended_multiplexing().len(), 0);
+        let groups: std::vec::Vec<_> = dbc.signal_groups().iter().collect();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].name(), "EngineGroup");
+        assert!(!groups[0].contains("Gear"));
+        #[cfg(feature = "attributes")]
+        assert!(dbc.attribute_values().get_signal(256, "Gear", "GenSigStartValue").is_none());
+
+        assert_eq!(
+            dbc.remove_signal(256, "Gear"),
+            Err(Error::Validation(Error::SIGNAL_NOT_FOUND))
+        );
+        assert_eq!(
+            dbc.remove_signal(300, "RPM"),
+            Err(Error::Validation(Error::MESSAGE_NOT_FOUND))
+        );
+
+        let reparsed = Dbc::parse(&dbc.to_dbc_string()).unwrap();
+        assert_eq!(
+            reparsed.messages().find_by_id(256).unwrap().signals().len(),
+            2
+        );
     }
 
     #[test]
-    fn test_save_round_trip() {
-        let original = r#"VERSION "1.0"
+    fn test_remove_signal_multiplexer_switch() {
+        let mut dbc = Dbc::parse(
+            r#"VERSION "1.0"
 
-BU_: ECM TCM
+BU_: ECM
 
-BO_ 256 EngineData : 8 ECM
- SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
- SG_ Temperature : 16|8@0- (1,-40) [-40|215] "°C" TCM
+BO_ 256 Engine : 8 ECM
+ SG_ Mode M : 0|8@1+ (1,0) [0|3] "" *
+ SG_ Gear m0 : 8|8@1+ (1,0) [0|5] "" *
+ SG_ RPM : 16|16@1+ (0.25,0) [0|8000] "rpm" *
 
-BO_ 512 BrakeData : 4 TCM
- SG_ Pressure : 0|16@0+ (0.1,0) [0|1000] "bar"
-"#;
+BO_ 512 Brake : 8 ECM
+ SG_ Pressure : 0|16@1+ (1,0) [0|1000] "bar" *
 
-        let dbc = Dbc::parse(original).unwrap();
-        let saved = dbc.to_dbc_string();
-        let dbc2 = Dbc::parse(&saved).unwrap();
+SIG_GROUP_ 256 ModeGroup 1 : Mode;
+SIG_GROUP_ 512 EmptyGroup 1 :;
+"#,
+        )
+        .unwrap();
 
-        // Verify round-trip: parsed data should match
+        // Gear is still selected by Mode
         assert_eq!(
-            dbc.version().map(|v| v.to_string()),
-            dbc2.version().map(|v| v.to_string())
+            dbc.remove_signal(256, "Mode"),
+            Err(Error::Validation(Error::SIGNAL_MULTIPLEXER_HAS_DEPENDANTS))
         );
-        assert_eq!(dbc.messages().len(), dbc2.messages().len());
+        assert_eq!(dbc.messages().find_by_id(256).unwrap().signals().len(), 3);
+        assert_eq!(dbc.signal_groups().len(), 2);
 
-        for (msg1, msg2) in dbc.messages().iter().zip(dbc2.messages().iter()) {
-            assert_eq!(msg1.id(), msg2.id());
-            assert_eq!(msg1.name(), msg2.name());
-            assert_eq!(msg1.dlc(), msg2.dlc());
-            assert_eq!(msg1.sender(), msg2.sender());
-            assert_eq!(msg1.signals().len(), msg2.signals().len());
+        dbc.remove_signal(256, "Gear").unwrap();
+        dbc.remove_signal(256, "Mode").unwrap();
+        assert_eq!(dbc.messages().find_by_id(256).unwrap().signals().len(), 1);
 
-            for (sig1, sig2) in msg1.signals().iter().zip(msg2.signals().iter()) {
-                assert_eq!(sig1.name(), sig2.name());
-                assert_eq!(sig1.start_bit(), sig2.start_bit());
-                assert_eq!(sig1.length(), sig2.length());
-                assert_eq!(sig1.byte_order(), sig2.byte_order());
-                assert_eq!(sig1.is_unsigned(), sig2.is_unsigned());
-                assert_eq!(sig1.factor(), sig2.factor());
-                assert_eq!(sig1.offset(), sig2.offset());
-                assert_eq!(sig1.min(), sig2.min());
-                assert_eq!(sig1.max(), sig2.max());
-                assert_eq!(sig1.unit(), sig2.unit());
-                assert_eq!(sig1.receivers(), sig2.receivers());
-            }
+        // Only the group emptied by the removal is dropped
+        let groups: std::vec::Vec<_> = dbc.signal_groups().iter().map(|g| g.name()).collect();
+        assert_eq!(groups, ["EmptyGroup"]);
+    }
+
+    #[test]
+    fn test_add_node() {
+        let mut dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n").unwrap();
+
+        dbc.add_node("TCM").unwrap();
+        assert_eq!(
+            dbc.nodes().iter().collect::<std::vec::Vec<_>>(),
+            ["ECM", "TCM"]
+        );
+        assert_eq!(
+            dbc.add_node("ECM"),
+            Err(Error::Validation(Error::NODES_DUPLICATE_NAME))
+        );
+        assert!(dbc.add_node(&"N".repeat(crate::MAX_NAME_SIZE + 1)).is_err());
+        assert_eq!(dbc.nodes().len(), 2);
+
+        // The node is written to the BU_ line
+        assert!(dbc.to_dbc_string().contains("BU_: ECM TCM"));
+    }
+
+    #[test]
+    fn test_add_node_rejects_invalid_names() {
+        let mut dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n").unwrap();
+
+        for name in ["", "Two Words", "BO_", "Vector__XXX", "1ECM", "ECM-2"] {
+            assert_eq!(
+                dbc.add_node(name),
+                Err(Error::Validation(Error::NODES_INVALID_NAME)),
+                "{name:?}"
+            );
         }
+        assert_eq!(dbc.nodes().len(), 1);
+        assert!(Dbc::parse(&dbc.to_dbc_string()).is_ok());
+
+        dbc.add_node("_Gateway2").unwrap();
+        assert!(dbc.nodes().contains("_Gateway2"));
     }
 
     #[test]
-    fn test_save_basic() {
-        // Use parsing instead of builders
-        // Note: '*' is parsed as None per spec compliance, output is 'Vector__XXX'
-        let dbc_content = r#"VERSION "1.0"
+    fn test_remove_node_cascades() {
+        let mut dbc = Dbc::parse(
+            r#"VERSION "1.0"
 
-BU_: ECM
+BU_: ECM TCM BCM
 
-BO_ 256 EngineData : 8 ECM
- SG_ RPM : 0|16@0+ (0.25,0) [0|8000] "rpm" *
-"#;
-        let dbc = Dbc::parse(dbc_content).unwrap();
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" TCM,BCM
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" TCM
 
-        let saved = dbc.to_dbc_string();
-        assert!(saved.contains("VERSION \"1.0\""));
-        assert!(saved.contains("BU_: ECM"));
-        assert!(saved.contains("BO_ 256 EngineData : 8 ECM"));
-        // Per DBC spec Section 9.5: '*' is not valid, we output 'Vector__XXX' instead
-        assert!(saved.contains("SG_ RPM : 0|16@0+ (0.25,0) [0|8000] \"rpm\" Vector__XXX"));
+BO_ 512 Gearbox : 8 TCM
+ SG_ Gear : 0|8@1+ (1,0) [0|5] "" ECM
+
+EV_ EnvGear : 0 [0|5] "" 0 1 DUMMY_NODE_VECTOR0 TCM,BCM;
+
+CM_ BU_ TCM "Transmission";
+BA_DEF_ BU_ "ILUsed" INT 0 1;
+BA_DEF_DEF_ "ILUsed" 0;
+BA_ "ILUsed" BU_ TCM 1;
+BA_ "ILUsed" BU_ BCM 1;
+"#,
+        )
+        .unwrap();
+
+        assert!(dbc.remove_node("TCM"));
+        assert!(!dbc.remove_node("TCM"));
+        assert!(!dbc.remove_node("Missing"));
+
+        assert_eq!(
+            dbc.nodes().iter().collect::<std::vec::Vec<_>>(),
+            ["ECM", "BCM"]
+        );
+        assert_eq!(dbc.nodes().node_comment("TCM"), None);
+
+        // Senders fall back to Vector__XXX, other messages are untouched
+        let gearbox = dbc.messages().find_by_id(512).unwrap();
+        assert_eq!(gearbox.sender(), "Vector__XXX");
+        assert_eq!(dbc.messages().find_by_id(256).unwrap().sender(), "ECM");
+
+        // Receiver lists shrink; an emptied list becomes Vector__XXX
+        let engine = dbc.messages().find_by_id(256).unwrap();
+        let rpm = engine.signals().find("RPM").unwrap();
+        assert_eq!(
+            rpm.receivers().iter().collect::<std::vec::Vec<_>>(),
+            ["BCM"]
+        );
+        let temp = engine.signals().find("Temp").unwrap();
+        assert_eq!(temp.receivers().iter().count(), 0);
+
+        let env_var = dbc.environment_variable("EnvGear").unwrap();
+        assert_eq!(
+            env_var.access_nodes().collect::<std::vec::Vec<_>>(),
+            ["BCM"]
+        );
+
+        #[cfg(feature = "attributes")]
+        {
+            assert!(dbc.node_attribute("TCM", "ILUsed").is_none());
+            assert!(dbc.node_attribute("BCM", "ILUsed").is_some());
+        }
+
+        // The result is a valid database
+        let reparsed = Dbc::parse(&dbc.to_dbc_string()).unwrap();
+        assert_eq!(reparsed.nodes().len(), 2);
+        assert!(!reparsed.to_dbc_string().contains("TCM"));
     }
 
     #[test]
-    fn test_save_multiple_messages() {
-        // Use parsing instead of builders
-        let dbc_content = r#"VERSION "1.0"
+    fn test_take_value_descriptions() {
+        let mut dbc = Dbc::parse(
+            r#"VERSION "1.0"
 
-BU_: ECM TCM
+BU_: ECM
 
-BO_ 256 EngineData : 8 ECM
- SG_ RPM : 0|16@0+ (0.25,0) [0|8000] "rpm"
+BO_ 256 Engine : 8 ECM
+ SG_ Gear : 0|8@1+ (1,0) [0|5] "" *
+ SG_ Mode : 8|8@1+ (1,0) [0|3] "" *
 
-BO_ 512 BrakeData : 4 TCM
- SG_ Pressure : 0|16@1+ (0.1,0) [0|1000] "bar"
-"#;
-        let dbc = Dbc::parse(dbc_content).unwrap();
-        let saved = dbc.to_dbc_string();
+VAL_ 256 Gear 0 "Park" 1 "Drive" ;
+VAL_ -1 Mode 0 "Off" 1 "On" ;
+"#,
+        )
+        .unwrap();
 
-        // Verify both messages are present
-        assert!(saved.contains("BO_ 256 EngineData : 8 ECM"));
-        assert!(saved.contains("BO_ 512 BrakeData : 4 TCM"));
-        assert!(saved.contains("SG_ RPM"));
-        assert!(saved.contains("SG_ Pressure"));
+        let decoded = dbc.decode(256, &[1, 1, 0, 0, 0, 0, 0, 0], false).unwrap();
+        assert_eq!(decoded[0].description, Some("Drive"));
+
+        let taken = dbc.take_value_descriptions();
+        assert_eq!(taken.len(), 2);
+        // Global entries sort first
+        assert_eq!((taken[0].0, taken[0].1.as_str()), (u32::MAX, "Mode"));
+        assert_eq!((taken[1].0, taken[1].1.as_str()), (256, "Gear"));
+        assert_eq!(taken[1].2.get(1), Some("Drive"));
+        assert!(dbc.value_descriptions().is_empty());
+
+        let decoded = dbc.decode(256, &[1, 1, 0, 0, 0, 0, 0, 0], false).unwrap();
+        assert_eq!(decoded[0].raw_value, 1);
+        assert!(decoded.iter().all(|s| s.description.is_none()));
+        assert!(dbc.take_value_descriptions().is_empty());
     }
 
     #[test]
-    fn test_bit_timing_empty() {
-        // Empty BS_: should still be present in output
-        let dbc = Dbc::parse(
+    fn test_merge_value_descriptions_from() {
+        let mut dbc = Dbc::parse(
             r#"VERSION "1.0"
 
-BS_:
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ Gear : 0|8@1+ (1,0) [0|5] "" *
+ SG_ Mode : 8|8@1+ (1,0) [0|3] "" *
+"#,
+        )
+        .unwrap();
+        let enums = Dbc::parse(
+            r#"VERSION "1.0"
 
 BU_: ECM
 
 BO_ 256 Engine : 8 ECM
+ SG_ Gear : 0|8@1+ (1,0) [0|5] "" *
+
+BO_ 512 Brake : 8 ECM
+ SG_ State : 0|8@1+ (1,0) [0|3] "" *
+ SG_ Mode : 8|8@1+ (1,0) [0|3] "" *
+ SG_ Unused : 16|8@1+ (1,0) [0|3] "" *
+
+VAL_ 256 Gear 0 "Park" 1 "Drive" ;
+VAL_ 512 State 0 "Released" 1 "Applied" ;
+VAL_ -1 Mode 0 "Off" 1 "On" ;
+VAL_ -1 Unused 0 "No" ;
 "#,
         )
         .unwrap();
 
-        // bit_timing should be None when empty
-        assert!(dbc.bit_timing().is_none());
+        let decoded = dbc.decode(256, &[1, 1, 0, 0, 0, 0, 0, 0], false).unwrap();
+        assert!(decoded.iter().all(|s| s.description.is_none()));
 
-        // Output should still have BS_:
-        let saved = dbc.to_dbc_string();
-        assert!(saved.contains("BS_:"));
+        dbc.merge_value_descriptions_from(&enums);
+
+        // Brake/State and the global Unused are unknown here
+        assert_eq!(dbc.value_descriptions().len(), 2);
+        let decoded = dbc.decode(256, &[1, 1, 0, 0, 0, 0, 0, 0], false).unwrap();
+        assert_eq!(decoded[0].description, Some("Drive"));
+        assert_eq!(decoded[1].description, Some("On"));
     }
 
     #[test]
-    fn test_bit_timing_with_values() {
+    fn test_map_signals_doubles_factors() {
         let dbc = Dbc::parse(
             r#"VERSION "1.0"
 
-BS_: 500000 : 1,2
-
 BU_: ECM
 
 BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+
+CM_ SG_ 256 RPM "Engine speed";
 "#,
         )
         .unwrap();
+        let payload = [0x40, 0x1F, 0x5A, 0x00, 0x00, 0x00, 0x00, 0x00];
 
-        // bit_timing should be Some with values
-        let bt = dbc.bit_timing().expect("bit timing should be present");
-        assert_eq!(bt.baudrate(), Some(500000));
-        assert_eq!(bt.btr1(), Some(1));
-        assert_eq!(bt.btr2(), Some(2));
+        let doubled = dbc
+            .map_signals(|signal| {
+                *signal = signal.with_scaling(signal.factor() * 2.0, signal.offset())
+            })
+            .unwrap();
 
-        // Output should have full BS_ line
-        let saved = dbc.to_dbc_string();
-        assert!(saved.contains("BS_: 500000 : 1,2"));
+        // RPM = 8000 * 0.5, Temp = 90 * 2 - 40
+        let decoded = doubled.decode(256, &payload, false).unwrap();
+        assert_eq!(decoded[0].value, 4000.0);
+        assert_eq!(decoded[1].value, 140.0);
+        let rpm = doubled.messages().at(0).unwrap().signals().at(0).unwrap();
+        assert_eq!(rpm.comment(), Some("Engine speed"));
+
+        // The original is untouched
+        let decoded = dbc.decode(256, &payload, false).unwrap();
+        assert_eq!(decoded[0].value, 2000.0);
+        assert_eq!(decoded[1].value, 50.0);
+
+        // Transformed signals are re-validated
+        assert_eq!(
+            dbc.map_signals(|signal| *signal = signal.with_range(1.0, 0.0)).unwrap_err(),
+            Error::Validation(Error::INVALID_RANGE)
+        );
+        assert_eq!(
+            dbc.map_signals(|signal| *signal = signal.with_start_bit(60)).unwrap_err(),
+            Error::Validation(Error::SIGNAL_EXTENDS_BEYOND_MESSAGE)
+        );
     }
 
     #[test]
-    fn test_bit_timing_baudrate_only() {
+    fn test_to_dbc_string_signal_value_types() {
         let dbc = Dbc::parse(
             r#"VERSION "1.0"
 
-BS_: 500000
-
 BU_: ECM
 
 BO_ 256 Engine : 8 ECM
+ SG_ Torque : 0|32@1- (0.5,0) [-1000|1000] "Nm" *
+ SG_ Mode : 32|8@1+ (1,0) [0|3] "" *
+
+BO_ 2147484672 Precise : 8 ECM
+ SG_ Position : 0|64@1- (1,0) [-1000000|1000000] "m" *
+
+SIG_VALTYPE_ 256 Torque : 1;
+SIG_VALTYPE_ 2147484672 Position : 2;
 "#,
         )
         .unwrap();
 
-        // bit_timing should be Some with baudrate only
-        let bt = dbc.bit_timing().expect("bit timing should be present");
-        assert_eq!(bt.baudrate(), Some(500000));
-        assert_eq!(bt.btr1(), None);
-        assert_eq!(bt.btr2(), None);
+        let dbc_string = dbc.to_dbc_string();
+        assert!(dbc_string.contains("\nSIG_VALTYPE_ 256 Torque : 1;\n"));
+        assert!(dbc_string.contains("\nSIG_VALTYPE_ 2147484672 Position : 2;\n"));
+        // Integer is the default and is not written
+        assert!(!dbc_string.contains("Mode : 0;"));
 
-        // Output should have BS_ with baudrate
-        let saved = dbc.to_dbc_string();
-        assert!(saved.contains("BS_: 500000"));
+        let reparsed = Dbc::parse(&dbc_string).unwrap();
+        for (message, signal) in [
+            ("Engine", "Torque"),
+            ("Engine", "Mode"),
+            ("Precise", "Position"),
+        ] {
+            let value_type = |dbc: &Dbc| {
+                let message = dbc.messages().find(message).unwrap();
+                message.signals().find(signal).unwrap().value_type()
+            };
+            assert_eq!(value_type(&reparsed), value_type(&dbc));
+        }
+        assert_eq!(reparsed.to_dbc_string(), dbc_string);
     }
 }
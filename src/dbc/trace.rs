@@ -0,0 +1,220 @@
+use super::{DecodedFrame, FrameMeta};
+use crate::{Dbc, Error, Result};
+use std::io::BufRead;
+
+/// Maximum payload length of a CAN FD frame.
+const MAX_PAYLOAD: usize = 64;
+
+/// A frame parsed from one candump log line.
+struct TraceFrame {
+    id: u32,
+    is_extended: bool,
+    payload: [u8; MAX_PAYLOAD],
+    len: usize,
+    meta: FrameMeta,
+}
+
+impl Dbc {
+    /// Decode a candump log lazily, one frame per line.
+    ///
+    /// Each non-empty line must use the `candump -l` format
+    /// `(<seconds>.<fraction>) <interface> <id>#<data>`, e.g.
+    /// `(1436509052.249713) can0 100#401F000000000000`. CAN FD frames (`<id>##<flags><data>`)
+    /// are accepted too. IDs with more than three hex digits are treated as extended IDs.
+    ///
+    /// The timestamp is reported in microseconds in [`FrameMeta::timestamp`], and the
+    /// trailing number of the interface name (`can1` → 1) in [`FrameMeta::channel`].
+    ///
+    /// Lines that cannot be parsed yield an [`Error::Expected`] carrying the line number,
+    /// frames that cannot be decoded (e.g. IDs not in this database) yield the error from
+    /// [`decode`](Self::decode). Iteration continues after an error, so callers can skip them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let trace = "(1436509052.249713) can0 100#401F000000000000\n";
+    /// for frame in dbc.decode_trace(trace.as_bytes()).filter_map(Result::ok) {
+    ///     println!("{} us: RPM = {}", frame.meta.timestamp, frame.signals[0].value);
+    /// }
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn decode_trace<'a, R: BufRead + 'a>(
+        &'a self,
+        reader: R,
+    ) -> impl Iterator<Item = Result<DecodedFrame<'a>>> + 'a {
+        reader.lines().enumerate().filter_map(move |(index, line)| {
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err.into())),
+            };
+            let line = line.trim();
+            if line.is_empty() {
+                return None;
+            }
+
+            let frame = match parse_candump_line(line) {
+                Some(frame) => frame,
+                None => {
+                    return Some(Err(Error::expected_at(
+                        Error::TRACE_LINE_INVALID,
+                        index + 1,
+                    )));
+                }
+            };
+            Some(self.decode_with_meta(
+                frame.id,
+                &frame.payload[..frame.len],
+                frame.is_extended,
+                frame.meta,
+            ))
+        })
+    }
+}
+
+/// Parse a `candump -l` line: `(<seconds>.<fraction>) <interface> <id>#<data>`.
+fn parse_candump_line(line: &str) -> Option<TraceFrame> {
+    let mut fields = line.split_whitespace();
+    let timestamp = parse_timestamp(fields.next()?)?;
+    let interface = fields.next()?;
+    let (id, data) = fields.next()?.split_once('#')?;
+
+    // Standard IDs are written with 3 hex digits, extended IDs with 8
+    if id.is_empty() || id.len() > 8 {
+        return None;
+    }
+    let is_extended = id.len() > 3;
+    let id = u32::from_str_radix(id, 16).ok()?;
+
+    // CAN FD frames use `##` followed by a single flags nibble; remote frames carry no data
+    let data = match data.strip_prefix('#') {
+        Some(fd) => fd.get(1..)?,
+        None if data.starts_with('R') => "",
+        None => data,
+    };
+
+    let mut payload = [0u8; MAX_PAYLOAD];
+    if data.len() % 2 != 0 || data.len() / 2 > MAX_PAYLOAD {
+        return None;
+    }
+    for (byte, hex) in payload.iter_mut().zip(data.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok()?;
+    }
+
+    // Interfaces are usually numbered (`can0`, `vcan1`); use that number as the channel
+    let digits = interface.len() - interface.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    let channel = interface[interface.len() - digits..].parse().unwrap_or(0);
+
+    Some(TraceFrame {
+        id,
+        is_extended,
+        payload,
+        len: data.len() / 2,
+        meta: FrameMeta { timestamp, channel },
+    })
+}
+
+/// Parse a `(<seconds>.<fraction>)` timestamp into microseconds.
+fn parse_timestamp(field: &str) -> Option<u64> {
+    let (seconds, fraction) = field.strip_prefix('(')?.strip_suffix(')')?.split_once('.')?;
+    if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    // Normalize the fraction to exactly 6 digits
+    let mut micros = 0u64;
+    for digit in fraction.bytes().chain(core::iter::repeat(b'0')).take(6) {
+        micros = micros * 10 + u64::from(digit - b'0');
+    }
+    seconds.parse::<u64>().ok()?.checked_mul(1_000_000)?.checked_add(micros)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Dbc, Error, FrameMeta};
+
+    const DBC: &str = r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+
+BO_ 2147484672 ExtendedMsg : 8 ECM
+ SG_ Speed : 0|16@1+ (0.1,0) [0|6553.5] "km/h" *
+"#;
+
+    #[test]
+    fn test_decode_trace() {
+        let dbc = Dbc::parse(DBC).unwrap();
+        let trace = "\
+(1436509052.249713) can0 100#401F5A0000000000
+(1436509052.250000) vcan1 00000400#E803000000000000
+
+(1436509052.3) can0 100#40
+garbage
+(1436509052.400000) can0 200#0000000000000000
+";
+
+        let frames: Vec<_> = dbc.decode_trace(trace.as_bytes()).collect();
+        assert_eq!(frames.len(), 5);
+
+        let engine = frames[0].as_ref().unwrap();
+        assert_eq!(
+            engine.meta,
+            FrameMeta {
+                timestamp: 1_436_509_052_249_713,
+                channel: 0
+            }
+        );
+        assert_eq!(engine.signals[0].value, 2000.0);
+        assert_eq!(engine.signals[1].value, 50.0);
+
+        let extended = frames[1].as_ref().unwrap();
+        assert_eq!(extended.meta.channel, 1);
+        assert_eq!(extended.signals[0].name, "Speed");
+        assert_eq!(extended.signals[0].value, 100.0);
+
+        // Payload too short for the message
+        assert!(frames[2].is_err());
+        // Malformed line, reported with its line number
+        assert_eq!(
+            frames[3],
+            Err(Error::expected_at(Error::TRACE_LINE_INVALID, 5))
+        );
+        // Unknown message ID
+        assert!(frames[4].is_err());
+    }
+
+    #[test]
+    fn test_decode_trace_can_fd_and_timestamps() {
+        let dbc = Dbc::parse(DBC).unwrap();
+        let trace = "(10.5) can2 100##1401F5A0000000000\n(x.1) can0 100#00\n(1.000001) can0 1#0\n";
+
+        let frames: Vec<_> = dbc.decode_trace(trace.as_bytes()).collect();
+        let fd = frames[0].as_ref().unwrap();
+        assert_eq!(fd.meta.timestamp, 10_500_000);
+        assert_eq!(fd.meta.channel, 2);
+        assert_eq!(fd.signals[0].value, 2000.0);
+
+        assert_eq!(
+            frames[1],
+            Err(Error::expected_at(Error::TRACE_LINE_INVALID, 2))
+        );
+        // Odd number of data digits
+        assert_eq!(
+            frames[2],
+            Err(Error::expected_at(Error::TRACE_LINE_INVALID, 3))
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use super::ValueDescriptionsMap;
-use crate::{Error, ExtendedMultiplexing, Message, Nodes, Result, VECTOR_XXX};
+use crate::{
+    Dbc, EnvironmentVariable, Error, ExtendedMultiplexing, Message, Nodes, Result, Signal,
+    VECTOR_XXX,
+};
 
 /// Validation functions for DBC structures
 pub(crate) struct Validate;
@@ -13,6 +16,7 @@ impl Validate {
         extended_multiplexing: Option<&[ExtendedMultiplexing]>,
     ) -> Result<()> {
         Self::validate_common(nodes, messages)?;
+        Self::validate_multiplexer_switches(messages, extended_multiplexing.unwrap_or(&[]))?;
 
         // Validate value descriptions if provided
         if let Some(value_descriptions) = value_descriptions {
@@ -28,18 +32,8 @@ impl Validate {
     }
 
     // Common validation logic
+    // Duplicate message IDs are rejected when the `Messages` ID index is built
     fn validate_common(nodes: &Nodes, messages: &[Message]) -> Result<()> {
-        // Check for duplicate message IDs
-        // Compare using the internal ID representation (with extended flag)
-        // Standard and extended IDs with the same base value are NOT duplicates
-        for (i, msg1) in messages.iter().enumerate() {
-            for msg2 in messages.iter().skip(i + 1) {
-                if msg1.id_with_flag() == msg2.id_with_flag() {
-                    return Err(Error::Validation(Error::DUPLICATE_MESSAGE_ID));
-                }
-            }
-        }
-
         // Validate that all message senders are in the nodes list
         // Skip validation if nodes list is empty (empty nodes allowed per DBC spec)
         // Per DBC spec Section 8.4: Vector__XXX means "no sender / unknown sender"
@@ -56,6 +50,62 @@ impl Validate {
         Ok(())
     }
 
+    /// Validates that environment variable names are unique and that their access nodes
+    /// are in the nodes list (skipped if the nodes list is empty, as for senders).
+    pub fn validate_environment_variables(
+        nodes: &Nodes,
+        environment_variables: &[EnvironmentVariable],
+    ) -> Result<()> {
+        for (index, env_var) in environment_variables.iter().enumerate() {
+            if environment_variables[..index]
+                .iter()
+                .any(|other| other.name() == env_var.name())
+            {
+                return Err(Error::Validation(
+                    Error::ENVIRONMENT_VARIABLE_DUPLICATE_NAME,
+                ));
+            }
+            if !nodes.is_empty() && env_var.access_nodes().any(|node| !nodes.contains(node)) {
+                return Err(Error::Validation(
+                    Error::ENVIRONMENT_VARIABLE_NODE_NOT_IN_NODES,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates that each message has at most one top-level multiplexer switch.
+    ///
+    /// Switches nested under another switch (`m1M`) or referenced by an `SG_MUL_VAL_`
+    /// entry of the message are part of an extended multiplexing hierarchy and don't count.
+    fn validate_multiplexer_switches(
+        messages: &[Message],
+        ext_mux_entries: &[ExtendedMultiplexing],
+    ) -> Result<()> {
+        for message in messages {
+            let message_id = message.id_with_flag();
+            let mut top_level = message.signals().iter().filter(|signal| {
+                signal.is_multiplexer_switch()
+                    && signal.multiplexer_switch_value().is_none()
+                    && !ext_mux_entries.iter().any(|ext_mux| {
+                        ext_mux.message_id() == message_id
+                            && (ext_mux.signal_name() == signal.name()
+                                || ext_mux.multiplexer_switch() == signal.name())
+                    })
+            });
+
+            if let (Some(first), Some(second)) = (top_level.next(), top_level.next()) {
+                return Err(Error::DuplicateMultiplexerSwitch {
+                    message_id,
+                    first: first.name().try_into()?,
+                    second: second.name().try_into()?,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validates value descriptions reference existing messages and signals
     fn validate_value_descriptions(
         messages: &[Message],
@@ -130,6 +180,88 @@ impl Validate {
     }
 }
 
+impl Dbc {
+    /// Returns signals whose declared `[min|max]` range cannot be represented by their raw bits.
+    ///
+    /// A declared range outside [`Signal::representable_range`] usually points to an
+    /// authoring mistake, such as a copy-pasted factor or bit length. These are warnings
+    /// rather than parse errors, so the DBC stays usable. Signals with an unspecified
+    /// `[0|0]` range are skipped, and deviations below half a factor step are tolerated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ Temp : 0|8@1+ (1,-40) [-40|500] "C" *
+    /// "#)?;
+    ///
+    /// for (message, signal) in dbc.validate_signal_ranges_consistent() {
+    ///     println!("warning: {}.{} declares an unreachable range", message.name(), signal.name());
+    /// }
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn validate_signal_ranges_consistent(
+        &self,
+    ) -> impl Iterator<Item = (&Message, &Signal)> + '_ {
+        self.messages().iter().flat_map(|message| {
+            message
+                .signals()
+                .iter()
+                .filter(|signal| !Self::signal_range_consistent(signal))
+                .map(move |signal| (message, signal))
+        })
+    }
+
+    /// Returns signals listing their own message's sender among their receivers.
+    ///
+    /// A node receiving what it transmits is usually an authoring mistake, but loopback
+    /// setups can declare it on purpose, so this is an opt-in warning rather than a
+    /// parse error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM TCM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" ECM,TCM
+    /// "#)?;
+    ///
+    /// for (message, signal) in dbc.validate_no_self_receiving() {
+    ///     println!("warning: {}.{} is received by its sender", message.name(), signal.name());
+    /// }
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn validate_no_self_receiving(&self) -> impl Iterator<Item = (&Message, &Signal)> + '_ {
+        self.messages().iter().flat_map(|message| {
+            message
+                .signals()
+                .iter()
+                .filter(|signal| signal.receivers().contains(message.sender()))
+                .map(move |signal| (message, signal))
+        })
+    }
+
+    fn signal_range_consistent(signal: &Signal) -> bool {
+        if signal.min() == 0.0 && signal.max() == 0.0 {
+            return true;
+        }
+        let (min, max) = signal.representable_range();
+        let tolerance = signal.factor().abs() / 2.0;
+        signal.min() >= min - tolerance && signal.max() <= max + tolerance
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Dbc;
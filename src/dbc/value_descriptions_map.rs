@@ -16,6 +16,11 @@ type Map = BTreeMap<Key, ValueDescriptions, { MAX_VALUE_DESCRIPTION_ENTRIES }>;
 /// They can be message-specific (keyed by message_id and signal_name) or global
 /// (keyed by None and signal_name, applying to all signals with that name).
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct ValueDescriptionsMap {
     value_descriptions: Map,
 }
@@ -34,6 +39,92 @@ impl ValueDescriptionsMap {
         Self { value_descriptions }
     }
 
+    /// Copy the message-specific entries of `src_id` to `new_id`.
+    #[cfg(feature = "std")]
+    pub(crate) fn copy_message(&mut self, src_id: u32, new_id: u32) -> crate::Result<()> {
+        let copies: std::vec::Vec<_> = self
+            .value_descriptions
+            .iter()
+            .filter(|((message_id, _), _)| *message_id == Some(src_id))
+            .map(|((_, signal_name), vd)| ((Some(new_id), signal_name.clone()), vd.clone()))
+            .collect();
+        for (key, vd) in copies {
+            self.value_descriptions.insert(key, vd)?;
+        }
+        Ok(())
+    }
+
+    /// Move the message-specific entry of signal `old` in `message_id` to `new`.
+    #[cfg(feature = "std")]
+    pub(crate) fn rename_signal(
+        &mut self,
+        message_id: u32,
+        old: &str,
+        new: &Name,
+    ) -> crate::Result<()> {
+        let key = (Some(message_id), Name::try_from(old)?);
+        if let Some(vd) = self.value_descriptions.remove(&key) {
+            self.value_descriptions.insert((Some(message_id), new.clone()), vd)?;
+        }
+        Ok(())
+    }
+
+    /// Remove the message-specific entries of `message_id`.
+    #[cfg(feature = "std")]
+    pub(crate) fn remove_message(&mut self, message_id: u32) {
+        let keys: std::vec::Vec<Key> = self
+            .value_descriptions
+            .iter()
+            .filter(|((id, _), _)| *id == Some(message_id))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in keys {
+            self.value_descriptions.remove(&key);
+        }
+    }
+
+    /// Remove the message-specific entry of signal `name` in `message_id`.
+    #[cfg(feature = "std")]
+    pub(crate) fn remove_signal(&mut self, message_id: u32, name: &str) {
+        if let Ok(name) = Name::try_from(name) {
+            self.value_descriptions.remove(&(Some(message_id), name));
+        }
+    }
+
+    /// Copy the entries of `other` accepted by `keep`, replacing entries with the same key.
+    #[cfg(feature = "std")]
+    pub(crate) fn merge_from(
+        &mut self,
+        other: &Self,
+        mut keep: impl FnMut(Option<u32>, &str) -> bool,
+    ) -> crate::Result<()> {
+        for ((message_id, signal_name), vd) in other.value_descriptions.iter() {
+            if keep(*message_id, signal_name.as_str()) {
+                self.value_descriptions.insert((*message_id, signal_name.clone()), vd.clone())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sort the entries of every signal by value, and the map itself by key.
+    #[cfg(feature = "std")]
+    pub(crate) fn sort(&mut self) {
+        let mut entries: std::vec::Vec<(Key, ValueDescriptions)> = self.take().collect();
+        // With heapless, the map iterates in insertion order
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (key, mut value_descriptions) in entries {
+            value_descriptions.sort();
+            // Cannot fail: the entries already fit
+            let _ = self.value_descriptions.insert(key, value_descriptions);
+        }
+    }
+
+    /// Remove all entries, returning them in key order.
+    #[cfg(feature = "std")]
+    pub(crate) fn take(&mut self) -> impl Iterator<Item = (Key, ValueDescriptions)> {
+        core::mem::take(&mut self.value_descriptions).into_iter()
+    }
+
     /// Get an iterator over all value descriptions
     ///
     /// # Examples
@@ -0,0 +1,150 @@
+use super::EnvironmentVariableBuilder;
+use crate::{
+    EnvironmentVariable, EnvironmentVariableType, Error, Result, compat::validate_name,
+    environment_variable::AccessNodes,
+};
+
+impl EnvironmentVariableBuilder {
+    /// Builds the `EnvironmentVariable` from the builder configuration.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(EnvironmentVariable)` if successful, or `Err(Error)` if:
+    /// - name is not set or invalid
+    /// - min exceeds max
+    /// - the type is `Data` without a data size, or another type with one
+    /// - Any name or the unit exceeds MAX_NAME_SIZE
+    /// - More than MAX_NODES access nodes have been added
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{EnvironmentVariableBuilder, EnvironmentVariableType};
+    ///
+    /// let env_var = EnvironmentVariableBuilder::new()
+    ///     .name("EnvSpeed")
+    ///     .var_type(EnvironmentVariableType::Float)
+    ///     .max(250.0)
+    ///     .unit("km/h")
+    ///     .build()?;
+    /// assert_eq!(env_var.unit(), Some("km/h"));
+    ///
+    /// // Data variables need a size
+    /// let result = EnvironmentVariableBuilder::new()
+    ///     .name("EnvBlob")
+    ///     .var_type(EnvironmentVariableType::Data)
+    ///     .build();
+    /// assert!(result.is_err());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn build(self) -> Result<EnvironmentVariable> {
+        let name_str = self.name.ok_or_else(|| Error::expected("name is required"))?;
+        let name =
+            validate_name(&name_str).map_err(|_| Error::expected(Error::MAX_NAME_SIZE_EXCEEDED))?;
+
+        if self.min > self.max {
+            return Err(Error::Validation(Error::INVALID_RANGE));
+        }
+
+        let is_data = self.var_type == EnvironmentVariableType::Data;
+        if is_data != self.data_size.is_some() {
+            return Err(Error::expected(
+                "data_size is required for, and only allowed on, data variables",
+            ));
+        }
+
+        let unit = match self.unit.as_deref() {
+            None | Some("") => None,
+            Some(unit) => Some(
+                validate_name(unit).map_err(|_| Error::expected(Error::MAX_NAME_SIZE_EXCEEDED))?,
+            ),
+        };
+
+        let mut access_nodes = AccessNodes::new();
+        for node in self.access_nodes {
+            let node =
+                validate_name(&node).map_err(|_| Error::expected(Error::MAX_NAME_SIZE_EXCEEDED))?;
+            access_nodes.push(node).map_err(|_| Error::expected(Error::NODES_TOO_MANY))?;
+        }
+
+        let mut env_var = EnvironmentVariable::new(
+            name,
+            self.var_type,
+            self.min,
+            self.max,
+            unit,
+            self.initial_value,
+            self.ev_id,
+            self.access_type,
+            access_nodes,
+        );
+        if let Some(data_size) = self.data_size {
+            env_var.set_data_size(data_size);
+        }
+        Ok(env_var)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+    use super::EnvironmentVariableBuilder;
+    use crate::{EnvironmentVariableAccess, EnvironmentVariableType};
+
+    #[test]
+    fn test_environment_variable_builder_basic() {
+        let env_var = EnvironmentVariableBuilder::new()
+            .name("EnvSpeed")
+            .var_type(EnvironmentVariableType::Float)
+            .min(-10.0)
+            .max(250.0)
+            .unit("km/h")
+            .initial_value(5.0)
+            .ev_id(3)
+            .access_type(EnvironmentVariableAccess::Read)
+            .add_access_node("ECM")
+            .add_access_node("TCM")
+            .build()
+            .unwrap();
+
+        assert_eq!(env_var.name(), "EnvSpeed");
+        assert_eq!(env_var.var_type(), EnvironmentVariableType::Float);
+        assert_eq!(env_var.min(), -10.0);
+        assert_eq!(env_var.max(), 250.0);
+        assert_eq!(env_var.unit(), Some("km/h"));
+        assert_eq!(env_var.initial_value(), 5.0);
+        assert_eq!(env_var.ev_id(), 3);
+        assert_eq!(env_var.access_type(), EnvironmentVariableAccess::Read);
+        assert_eq!(env_var.access_nodes().collect::<Vec<_>>(), ["ECM", "TCM"]);
+        assert_eq!(env_var.data_size(), None);
+    }
+
+    #[test]
+    fn test_environment_variable_builder_invalid() {
+        // Missing name
+        assert!(EnvironmentVariableBuilder::new().build().is_err());
+        // min > max
+        assert!(
+            EnvironmentVariableBuilder::new()
+                .name("EnvX")
+                .min(2.0)
+                .max(1.0)
+                .build()
+                .is_err()
+        );
+        // Data size on a non-data variable
+        assert!(EnvironmentVariableBuilder::new().name("EnvX").data_size(4).build().is_err());
+    }
+
+    #[test]
+    fn test_environment_variable_builder_data() {
+        let env_var = EnvironmentVariableBuilder::new()
+            .name("EnvBlob")
+            .var_type(EnvironmentVariableType::Data)
+            .data_size(16)
+            .build()
+            .unwrap();
+        assert_eq!(env_var.var_type(), EnvironmentVariableType::Data);
+        assert_eq!(env_var.data_size(), Some(16));
+    }
+}
@@ -0,0 +1,130 @@
+use super::EnvironmentVariableBuilder;
+use crate::{EnvironmentVariableAccess, EnvironmentVariableType};
+use std::vec::Vec;
+
+impl EnvironmentVariableBuilder {
+    /// Creates a new `EnvironmentVariableBuilder`.
+    ///
+    /// Defaults to an unrestricted integer variable with range `[0|0]`, no unit, initial
+    /// value and ID `0`, and no access nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::EnvironmentVariableBuilder;
+    ///
+    /// let builder = EnvironmentVariableBuilder::new();
+    /// // Must set name before building
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            var_type: EnvironmentVariableType::Integer,
+            min: 0.0,
+            max: 0.0,
+            unit: None,
+            initial_value: 0.0,
+            ev_id: 0,
+            access_type: EnvironmentVariableAccess::Unrestricted,
+            access_nodes: Vec::new(),
+            data_size: None,
+        }
+    }
+
+    /// Sets the variable name.
+    #[must_use = "builder method returns modified builder"]
+    pub fn name(mut self, name: impl AsRef<str>) -> Self {
+        self.name = Some(name.as_ref().to_string());
+        self
+    }
+
+    /// Sets the value type.
+    ///
+    /// [`EnvironmentVariableType::Data`] requires a [`data_size`](Self::data_size).
+    #[must_use = "builder method returns modified builder"]
+    pub fn var_type(mut self, var_type: EnvironmentVariableType) -> Self {
+        self.var_type = var_type;
+        self
+    }
+
+    /// Sets the minimum value.
+    #[must_use = "builder method returns modified builder"]
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the maximum value.
+    #[must_use = "builder method returns modified builder"]
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the unit.
+    #[must_use = "builder method returns modified builder"]
+    pub fn unit(mut self, unit: impl AsRef<str>) -> Self {
+        self.unit = Some(unit.as_ref().to_string());
+        self
+    }
+
+    /// Sets the initial value.
+    #[must_use = "builder method returns modified builder"]
+    pub fn initial_value(mut self, initial_value: f64) -> Self {
+        self.initial_value = initial_value;
+        self
+    }
+
+    /// Sets the environment variable ID.
+    #[must_use = "builder method returns modified builder"]
+    pub fn ev_id(mut self, ev_id: u32) -> Self {
+        self.ev_id = ev_id;
+        self
+    }
+
+    /// Sets the access type.
+    #[must_use = "builder method returns modified builder"]
+    pub fn access_type(mut self, access_type: EnvironmentVariableAccess) -> Self {
+        self.access_type = access_type;
+        self
+    }
+
+    /// Adds a node with access to the variable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::EnvironmentVariableBuilder;
+    ///
+    /// let builder = EnvironmentVariableBuilder::new()
+    ///     .add_access_node("ECM")
+    ///     .add_access_node("TCM");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "builder method returns modified builder"]
+    pub fn add_access_node(mut self, node: impl AsRef<str>) -> Self {
+        self.access_nodes.push(node.as_ref().to_string());
+        self
+    }
+
+    /// Clears all access nodes.
+    #[must_use = "builder method returns modified builder"]
+    pub fn clear_access_nodes(mut self) -> Self {
+        self.access_nodes.clear();
+        self
+    }
+
+    /// Sets the payload size in bytes of a data variable (`ENVVAR_DATA_`).
+    #[must_use = "builder method returns modified builder"]
+    pub fn data_size(mut self, data_size: u32) -> Self {
+        self.data_size = Some(data_size);
+        self
+    }
+}
+
+impl Default for EnvironmentVariableBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
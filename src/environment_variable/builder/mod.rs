@@ -0,0 +1,54 @@
+use crate::{EnvironmentVariableAccess, EnvironmentVariableType};
+
+/// Builder for creating `EnvironmentVariable` programmatically.
+///
+/// Environment variables (EV_) are added to a DBC with
+/// [`DbcBuilder::add_environment_variable`](crate::DbcBuilder::add_environment_variable).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use dbc_rs::{EnvironmentVariableAccess, EnvironmentVariableBuilder, EnvironmentVariableType};
+///
+/// let env_var = EnvironmentVariableBuilder::new()
+///     .name("EnvKL15")
+///     .var_type(EnvironmentVariableType::Integer)
+///     .min(0.0)
+///     .max(1.0)
+///     .ev_id(1)
+///     .access_type(EnvironmentVariableAccess::ReadWrite)
+///     .add_access_node("ECM")
+///     .build()?;
+///
+/// assert_eq!(env_var.name(), "EnvKL15");
+/// assert_eq!(env_var.max(), 1.0);
+///
+/// // Data variables carry their payload size (ENVVAR_DATA_)
+/// let blob = EnvironmentVariableBuilder::new()
+///     .name("EnvBlob")
+///     .var_type(EnvironmentVariableType::Data)
+///     .data_size(16)
+///     .build()?;
+/// assert_eq!(blob.data_size(), Some(16));
+/// # Ok::<(), dbc_rs::Error>(())
+/// ```
+///
+/// # Feature Requirements
+///
+/// This builder requires the `std` feature to be enabled.
+#[derive(Debug, Clone)]
+pub struct EnvironmentVariableBuilder {
+    name: Option<String>,
+    var_type: EnvironmentVariableType,
+    min: f64,
+    max: f64,
+    unit: Option<String>,
+    initial_value: f64,
+    ev_id: u32,
+    access_type: EnvironmentVariableAccess,
+    access_nodes: std::vec::Vec<String>,
+    data_size: Option<u32>,
+}
+
+mod build;
+mod impls;
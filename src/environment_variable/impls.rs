@@ -0,0 +1,159 @@
+use super::{AccessNodes, EnvironmentVariable, EnvironmentVariableAccess, EnvironmentVariableType};
+use crate::compat::Name;
+
+impl EnvironmentVariable {
+    #[allow(clippy::too_many_arguments)] // Internal method, parse is the public entry point
+    pub(crate) fn new(
+        name: Name,
+        var_type: EnvironmentVariableType,
+        min: f64,
+        max: f64,
+        unit: Option<Name>,
+        initial_value: f64,
+        ev_id: u32,
+        access_type: EnvironmentVariableAccess,
+        access_nodes: AccessNodes,
+    ) -> Self {
+        Self {
+            name,
+            var_type,
+            min,
+            max,
+            unit,
+            initial_value,
+            ev_id,
+            access_type,
+            access_nodes,
+            data_size: None,
+        }
+    }
+
+    /// Attach the payload size declared via `ENVVAR_DATA_`, making this a data variable
+    pub(crate) fn set_data_size(&mut self, data_size: u32) {
+        self.var_type = EnvironmentVariableType::Data;
+        self.data_size = Some(data_size);
+    }
+
+    /// Remove `node` from the nodes with access to this variable
+    #[cfg(feature = "std")]
+    pub(crate) fn remove_access_node(&mut self, node: &str) {
+        self.access_nodes =
+            self.access_nodes.iter().filter(|n| n.as_str() != node).cloned().collect();
+    }
+
+    /// Returns the environment variable name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nEV_ EnvKL15 : 0 [0|1] \"\" 0 1 DUMMY_NODE_VECTOR3 ECM;\n")?;
+    /// let env_var = dbc.environment_variables().next().unwrap();
+    /// assert_eq!(env_var.name(), "EnvKL15");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the value type.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn var_type(&self) -> EnvironmentVariableType {
+        self.var_type
+    }
+
+    /// Returns the minimum value.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the maximum value.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Returns the unit, or `None` if the unit string is empty.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn unit(&self) -> Option<&str> {
+        self.unit.as_ref().map(|u| u.as_str())
+    }
+
+    /// Returns the initial value.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn initial_value(&self) -> f64 {
+        self.initial_value
+    }
+
+    /// Returns the environment variable ID.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn ev_id(&self) -> u32 {
+        self.ev_id
+    }
+
+    /// Returns the access type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::{Dbc, EnvironmentVariableAccess};
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nEV_ EnvKL15 : 0 [0|1] \"\" 0 1 DUMMY_NODE_VECTOR3 ECM;\n")?;
+    /// let env_var = dbc.environment_variables().next().unwrap();
+    /// assert_eq!(env_var.access_type(), EnvironmentVariableAccess::ReadWrite);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn access_type(&self) -> EnvironmentVariableAccess {
+        self.access_type
+    }
+
+    /// Returns an iterator over the nodes with access to this variable.
+    ///
+    /// `Vector__XXX` in the DBC file (no specific node) yields an empty iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM TCM\n\nEV_ EnvKL15 : 0 [0|1] \"\" 0 1 DUMMY_NODE_VECTOR3 ECM, TCM;\n")?;
+    /// let env_var = dbc.environment_variables().next().unwrap();
+    /// let nodes: Vec<&str> = env_var.access_nodes().collect();
+    /// assert_eq!(nodes, ["ECM", "TCM"]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "iterator is lazy and does nothing unless consumed"]
+    pub fn access_nodes(&self) -> impl Iterator<Item = &str> + '_ {
+        self.access_nodes.iter().map(|n| n.as_str())
+    }
+
+    /// Returns the payload size in bytes of a data variable (`ENVVAR_DATA_`).
+    ///
+    /// Returns `None` unless [`var_type`](Self::var_type) is
+    /// [`EnvironmentVariableType::Data`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nEV_ EnvBlob : 0 [0|0] \"\" 0 2 DUMMY_NODE_VECTOR0 Vector__XXX;\n\nENVVAR_DATA_ EnvBlob : 16;\n")?;
+    /// let env_var = dbc.environment_variable("EnvBlob").unwrap();
+    /// assert_eq!(env_var.data_size(), Some(16));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn data_size(&self) -> Option<u32> {
+        self.data_size
+    }
+}
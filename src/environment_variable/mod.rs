@@ -0,0 +1,117 @@
+//! Environment variable definition (EV_)
+//!
+//! Environment variables describe values exchanged between nodes and the simulation or
+//! test environment (for example a terminal 15 state). They are not part of any message.
+//!
+//! # DBC Format
+//!
+//! ```text
+//! EV_ <name> : <type> [<min>|<max>] "<unit>" <initial_value> <ev_id> <access_type> <access_node>, ... ;
+//! ENVVAR_DATA_ <name> : <data_size> ;
+//! ```
+//!
+//! `<type>` is `0` (integer), `1` (float) or `2` (string). `<access_type>` is one of
+//! `DUMMY_NODE_VECTOR0` (unrestricted), `DUMMY_NODE_VECTOR1` (read), `DUMMY_NODE_VECTOR2`
+//! (write) or `DUMMY_NODE_VECTOR3` (read/write). Some tools mark string variables by
+//! setting bit `0x8000` of the access type (`DUMMY_NODE_VECTOR8000`); this is accepted
+//! too. A variable with an `ENVVAR_DATA_` (or `EV_DATA_`) entry is a data variable of
+//! the given size in bytes.
+//!
+//! # Example
+//!
+//! ```text
+//! EV_ EnvKL15 : 0 [0|1] "" 0 1 DUMMY_NODE_VECTOR3 ECM, TCM;
+//! EV_ EnvBlob : 0 [0|0] "" 0 2 DUMMY_NODE_VECTOR0 Vector__XXX;
+//! ENVVAR_DATA_ EnvBlob : 16;
+//! ```
+
+use crate::{
+    MAX_NODES,
+    compat::{Name, Vec},
+};
+
+mod impls;
+mod parse;
+#[cfg(feature = "std")]
+mod std;
+
+#[cfg(feature = "std")]
+mod builder;
+
+#[cfg(feature = "std")]
+pub use builder::EnvironmentVariableBuilder;
+
+/// Nodes with access to an environment variable
+type AccessNodes = Vec<Name, { MAX_NODES }>;
+
+/// Value type of an [`EnvironmentVariable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EnvironmentVariableType {
+    /// Integer variable (type `0`)
+    #[default]
+    Integer,
+    /// Floating point variable (type `1`)
+    Float,
+    /// String variable (type `2`)
+    String,
+    /// Data variable with a size declared by `ENVVAR_DATA_`
+    Data,
+}
+
+/// Access type of an [`EnvironmentVariable`] (`DUMMY_NODE_VECTOR<n>`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EnvironmentVariableAccess {
+    /// No access restriction (`DUMMY_NODE_VECTOR0`)
+    #[default]
+    Unrestricted,
+    /// Read only (`DUMMY_NODE_VECTOR1`)
+    Read,
+    /// Write only (`DUMMY_NODE_VECTOR2`)
+    Write,
+    /// Read and write (`DUMMY_NODE_VECTOR3`)
+    ReadWrite,
+}
+
+/// Environment variable definition (EV_)
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use dbc_rs::{Dbc, EnvironmentVariableAccess, EnvironmentVariableType};
+///
+/// let dbc = Dbc::parse(r#"VERSION "1.0"
+///
+/// BU_: ECM TCM
+///
+/// EV_ EnvKL15 : 0 [0|1] "" 0 1 DUMMY_NODE_VECTOR3 ECM, TCM;
+/// EV_ EnvBlob : 0 [0|0] "" 0 2 DUMMY_NODE_VECTOR0 Vector__XXX;
+///
+/// ENVVAR_DATA_ EnvBlob : 16;
+/// "#)?;
+///
+/// let kl15 = dbc.environment_variable("EnvKL15").unwrap();
+/// assert_eq!(kl15.var_type(), EnvironmentVariableType::Integer);
+/// assert_eq!(kl15.access_type(), EnvironmentVariableAccess::ReadWrite);
+/// assert_eq!(kl15.access_nodes().collect::<Vec<_>>(), ["ECM", "TCM"]);
+///
+/// let blob = dbc.environment_variable("EnvBlob").unwrap();
+/// assert_eq!(blob.var_type(), EnvironmentVariableType::Data);
+/// assert_eq!(blob.data_size(), Some(16));
+/// # Ok::<(), dbc_rs::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnvironmentVariable {
+    name: Name,
+    var_type: EnvironmentVariableType,
+    min: f64,
+    max: f64,
+    unit: Option<Name>,
+    initial_value: f64,
+    ev_id: u32,
+    access_type: EnvironmentVariableAccess,
+    access_nodes: AccessNodes,
+    data_size: Option<u32>,
+}
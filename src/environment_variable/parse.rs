@@ -0,0 +1,179 @@
+use super::{AccessNodes, EnvironmentVariable, EnvironmentVariableAccess, EnvironmentVariableType};
+use crate::{
+    Parser, Signal,
+    compat::{Name, validate_name},
+};
+
+/// Prefix of the access type token (`DUMMY_NODE_VECTOR<hex>`)
+const ACCESS_TYPE_PREFIX: &str = "DUMMY_NODE_VECTOR";
+/// Access type flag used by some tools to mark string variables
+const ACCESS_TYPE_STRING_FLAG: u32 = 0x8000;
+
+impl EnvironmentVariable {
+    /// Parse an EV_ entry
+    ///
+    /// Expects the parser to be positioned after the EV_ keyword.
+    /// Parses: name : type [min|max] "unit" initial_value ev_id access_type access_nodes ;
+    /// Example: EnvKL15 : 0 [0|1] "" 0 1 DUMMY_NODE_VECTOR3 ECM, TCM;
+    ///
+    /// Returns `None` if parsing fails (caller should skip to end of line).
+    pub(crate) fn parse(parser: &mut Parser) -> Option<EnvironmentVariable> {
+        parser.skip_newlines_and_spaces();
+
+        let name = validate_name(parser.parse_identifier().ok()?).ok()?;
+        parser.skip_newlines_and_spaces();
+        parser.expect(b":").ok()?;
+        parser.skip_newlines_and_spaces();
+
+        let mut var_type = match parser.parse_u32().ok()? {
+            0 => EnvironmentVariableType::Integer,
+            1 => EnvironmentVariableType::Float,
+            2 => EnvironmentVariableType::String,
+            _ => return None,
+        };
+        parser.skip_newlines_and_spaces();
+
+        // Range and unit share the SG_ syntax
+        let (min, max) = Signal::parse_range(parser).ok()?;
+        parser.skip_newlines_and_spaces();
+        let unit = Signal::parse_unit(parser).ok()?;
+        parser.skip_newlines_and_spaces();
+
+        let initial_value = parser.parse_f64().ok()?;
+        parser.skip_newlines_and_spaces();
+        let ev_id = parser.parse_u32().ok()?;
+        parser.skip_newlines_and_spaces();
+
+        // Parse access type: DUMMY_NODE_VECTOR<hex>
+        let access = parser.parse_identifier().ok()?.strip_prefix(ACCESS_TYPE_PREFIX)?;
+        let access = u32::from_str_radix(access, 16).ok()?;
+        if access & ACCESS_TYPE_STRING_FLAG != 0 {
+            var_type = EnvironmentVariableType::String;
+        }
+        let access_type = match access & !ACCESS_TYPE_STRING_FLAG {
+            0 => EnvironmentVariableAccess::Unrestricted,
+            1 => EnvironmentVariableAccess::Read,
+            2 => EnvironmentVariableAccess::Write,
+            3 => EnvironmentVariableAccess::ReadWrite,
+            _ => return None,
+        };
+
+        // Parse comma-separated access nodes up to ';' or end of line
+        let mut access_nodes = AccessNodes::new();
+        loop {
+            parser.skip_spaces_and_tabs();
+            if parser.eof() || parser.at_newline() || parser.expect(b";").is_ok() {
+                break;
+            }
+            // Vector__XXX means no specific node. Some writers put a second access type
+            // token in place of the node list; it names no node either.
+            let node = parser.parse_identifier().ok()?;
+            if node != crate::VECTOR_XXX && !node.starts_with(ACCESS_TYPE_PREFIX) {
+                access_nodes.push(validate_name(node).ok()?).ok()?;
+            }
+            parser.skip_spaces_and_tabs();
+            let _ = parser.expect(b",").ok();
+        }
+
+        Some(EnvironmentVariable::new(
+            name,
+            var_type,
+            min,
+            max,
+            unit,
+            initial_value,
+            ev_id,
+            access_type,
+            access_nodes,
+        ))
+    }
+
+    /// Parse an ENVVAR_DATA_ (or EV_DATA_) entry
+    ///
+    /// Expects the parser to be positioned after the keyword.
+    /// Parses: name : data_size ;
+    ///
+    /// Returns `None` if parsing fails (caller should skip to end of line).
+    pub(crate) fn parse_data(parser: &mut Parser) -> Option<(Name, u32)> {
+        parser.skip_newlines_and_spaces();
+
+        let name = validate_name(parser.parse_identifier().ok()?).ok()?;
+        parser.skip_newlines_and_spaces();
+        parser.expect(b":").ok()?;
+        parser.skip_newlines_and_spaces();
+
+        // parse_i64 accepts a size directly followed by ';'
+        let data_size = u32::try_from(parser.parse_i64().ok()?).ok()?;
+        parser.skip_newlines_and_spaces();
+        let _ = parser.expect(b";").ok();
+
+        Some((name, data_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+    use super::EnvironmentVariable;
+    use crate::{EnvironmentVariableAccess, EnvironmentVariableType, Parser};
+
+    #[test]
+    fn test_parse_environment_variable() {
+        let input = b" EnvSpeed : 1 [0|250.5] \"km/h\" 12.5 7 DUMMY_NODE_VECTOR1 ECM, TCM;";
+        let mut parser = Parser::new(input).unwrap();
+        let env_var = EnvironmentVariable::parse(&mut parser).unwrap();
+        assert_eq!(env_var.name(), "EnvSpeed");
+        assert_eq!(env_var.var_type(), EnvironmentVariableType::Float);
+        assert_eq!(env_var.min(), 0.0);
+        assert_eq!(env_var.max(), 250.5);
+        assert_eq!(env_var.unit(), Some("km/h"));
+        assert_eq!(env_var.initial_value(), 12.5);
+        assert_eq!(env_var.ev_id(), 7);
+        assert_eq!(env_var.access_type(), EnvironmentVariableAccess::Read);
+        let mut nodes = env_var.access_nodes();
+        assert_eq!(nodes.next(), Some("ECM"));
+        assert_eq!(nodes.next(), Some("TCM"));
+        assert_eq!(nodes.next(), None);
+        assert_eq!(env_var.data_size(), None);
+    }
+
+    #[test]
+    fn test_parse_environment_variable_string_flag() {
+        let input = b" EnvName : 0 [0|0] \"\" 0 3 DUMMY_NODE_VECTOR8002 Vector__XXX;";
+        let mut parser = Parser::new(input).unwrap();
+        let env_var = EnvironmentVariable::parse(&mut parser).unwrap();
+        assert_eq!(env_var.var_type(), EnvironmentVariableType::String);
+        assert_eq!(env_var.access_type(), EnvironmentVariableAccess::Write);
+        assert_eq!(env_var.unit(), None);
+        assert_eq!(env_var.access_nodes().count(), 0);
+    }
+
+    #[test]
+    fn test_parse_environment_variable_spaced_range_and_access_token() {
+        let input = b" EnvVolt : 0 [ 0 | 20 ] \"V\" 12.0 1 DUMMY_NODE_VECTOR1 DUMMY_NODE_VECTOR2;";
+        let mut parser = Parser::new(input).unwrap();
+        let env_var = EnvironmentVariable::parse(&mut parser).unwrap();
+        assert_eq!(env_var.max(), 20.0);
+        assert_eq!(env_var.access_nodes().count(), 0);
+    }
+
+    #[test]
+    fn test_parse_environment_variable_invalid() {
+        for input in [
+            &b" EnvX : 3 [0|1] \"\" 0 1 DUMMY_NODE_VECTOR0 ECM;"[..],
+            b" EnvX : 0 [0|1] \"\" 0 1 DUMMY_NODE_VECTOR4 ECM;",
+            b" EnvX : 0 [0|1] \"\" 0 1 ACCESS0 ECM;",
+        ] {
+            let mut parser = Parser::new(input).unwrap();
+            assert!(EnvironmentVariable::parse(&mut parser).is_none());
+        }
+    }
+
+    #[test]
+    fn test_parse_environment_variable_data() {
+        let mut parser = Parser::new(b" EnvBlob : 16;").unwrap();
+        let (name, data_size) = EnvironmentVariable::parse_data(&mut parser).unwrap();
+        assert_eq!(name.as_str(), "EnvBlob");
+        assert_eq!(data_size, 16);
+    }
+}
@@ -0,0 +1,98 @@
+use super::{EnvironmentVariable, EnvironmentVariableAccess, EnvironmentVariableType};
+use std::{
+    fmt::{Display, Formatter, Result, Write},
+    string::String,
+};
+
+impl EnvironmentVariable {
+    /// Converts the environment variable to its DBC file representation.
+    ///
+    /// Returns a string in the format:
+    /// `EV_ Name : type [min|max] "unit" initial_value ev_id DUMMY_NODE_VECTORn Node1,Node2;`
+    ///
+    /// Data variables are written with type `0`; their size is written separately as an
+    /// `ENVVAR_DATA_` entry by [`Dbc::to_dbc_string`](crate::Dbc::to_dbc_string).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM TCM\n\nEV_ EnvKL15 : 0 [0|1] \"\" 0 1 DUMMY_NODE_VECTOR3 ECM, TCM;\n")?;
+    /// let env_var = dbc.environment_variables().next().unwrap();
+    /// assert_eq!(
+    ///     env_var.to_dbc_string(),
+    ///     "EV_ EnvKL15 : 0 [0|1] \"\" 0 1 DUMMY_NODE_VECTOR3 ECM,TCM;"
+    /// );
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn to_dbc_string(&self) -> String {
+        let mut result = String::with_capacity(80);
+
+        let var_type = match self.var_type() {
+            EnvironmentVariableType::Integer | EnvironmentVariableType::Data => 0,
+            EnvironmentVariableType::Float => 1,
+            EnvironmentVariableType::String => 2,
+        };
+        let access_type = match self.access_type() {
+            EnvironmentVariableAccess::Unrestricted => 0,
+            EnvironmentVariableAccess::Read => 1,
+            EnvironmentVariableAccess::Write => 2,
+            EnvironmentVariableAccess::ReadWrite => 3,
+        };
+        write!(
+            result,
+            "EV_ {} : {} [{}|{}] \"{}\" {} {} DUMMY_NODE_VECTOR{} ",
+            self.name(),
+            var_type,
+            self.min(),
+            self.max(),
+            self.unit().unwrap_or(""),
+            self.initial_value(),
+            self.ev_id(),
+            access_type
+        )
+        .unwrap();
+
+        if self.access_nodes.is_empty() {
+            result.push_str(crate::VECTOR_XXX);
+        }
+        for (i, node) in self.access_nodes().enumerate() {
+            if i > 0 {
+                result.push(',');
+            }
+            result.push_str(node);
+        }
+        result.push(';');
+
+        result
+    }
+}
+
+impl Display for EnvironmentVariable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.to_dbc_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EnvironmentVariable;
+    use crate::Parser;
+
+    #[test]
+    fn test_environment_variable_to_dbc_string_round_trip() {
+        let test_cases = [
+            "EV_ EnvKL15 : 0 [0|1] \"\" 0 1 DUMMY_NODE_VECTOR3 ECM,TCM;",
+            "EV_ EnvSpeed : 1 [0|250.5] \"km/h\" 12.5 7 DUMMY_NODE_VECTOR1 ECM;",
+            "EV_ EnvName : 2 [0|0] \"\" 0 3 DUMMY_NODE_VECTOR0 Vector__XXX;",
+        ];
+
+        for expected in test_cases {
+            let mut parser = Parser::new(&expected.as_bytes()[3..]).unwrap();
+            let env_var = EnvironmentVariable::parse(&mut parser).unwrap();
+            assert_eq!(env_var.to_dbc_string(), expected);
+        }
+    }
+}
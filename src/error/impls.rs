@@ -1,6 +1,7 @@
 use core::fmt;
 
 use super::Error;
+use crate::ByteOrder;
 
 impl Error {
     // ============================================================================
@@ -156,7 +157,13 @@ impl Error {
             Error::Receivers { line, .. } => *line,
             Error::Nodes { line, .. } => *line,
             Error::Signal { line, .. } => *line,
-            Error::Decoding(_) | Error::Encoding(_) | Error::Validation(_) => None,
+            Error::SignalOutOfBounds { line, .. } => *line,
+            Error::SignalByteOrderMismatch { line, .. } => *line,
+            Error::DuplicateMultiplexerSwitch { .. }
+            | Error::DecodedValueOutOfRange { .. }
+            | Error::Decoding(_)
+            | Error::Encoding(_)
+            | Error::Validation(_) => None,
             #[cfg(feature = "std")]
             Error::Io(_) => None,
         }
@@ -200,10 +207,102 @@ impl Error {
                 msg,
                 line: Some(line),
             },
+            Error::SignalOutOfBounds {
+                message_id,
+                signal,
+                end_bit,
+                available_bits,
+                line: None,
+            } => Error::SignalOutOfBounds {
+                message_id,
+                signal,
+                end_bit,
+                available_bits,
+                line: Some(line),
+            },
+            Error::SignalByteOrderMismatch {
+                message_id,
+                signal,
+                byte_order,
+                line: None,
+            } => Error::SignalByteOrderMismatch {
+                message_id,
+                signal,
+                byte_order,
+                line: Some(line),
+            },
             // Already has line info or doesn't support it - return unchanged
             other => other,
         }
     }
+
+    // ============================================================================
+    // Classification
+    // ============================================================================
+
+    /// Returns `true` if the error is confined to a single line of the input.
+    ///
+    /// Recoverable errors are parse errors carrying a line number, such as a malformed
+    /// `SG_` or `BO_` line: a caller can skip that line and continue. Errors that affect
+    /// the database as a whole are not recoverable:
+    /// - validation errors (duplicate message IDs, unknown senders, ...), which carry no line
+    /// - capacity exhaustion (`Too many ...`), as further entries would fail the same way
+    /// - unexpected end of input
+    /// - decoding, encoding and I/O errors
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Error;
+    ///
+    /// assert!(Error::signal_at(Error::SIGNAL_PARSE_INVALID_START_BIT, 12).is_recoverable());
+    /// assert!(!Error::Validation(Error::DUPLICATE_MESSAGE_ID).is_recoverable());
+    /// assert!(!Error::message_at(Error::MESSAGE_TOO_MANY_SIGNALS, 40).is_recoverable());
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn is_recoverable(&self) -> bool {
+        match self {
+            Error::Expected { msg, .. }
+            | Error::Version { msg, .. }
+            | Error::Message { msg, .. }
+            | Error::Receivers { msg, .. }
+            | Error::Nodes { msg, .. }
+            | Error::Signal { msg, .. } => self.line().is_some() && !Self::is_capacity_msg(msg),
+            Error::InvalidChar { line, .. }
+            | Error::MaxStrLength { line, .. }
+            | Error::SignalOutOfBounds { line, .. }
+            | Error::SignalByteOrderMismatch { line, .. } => line.is_some(),
+            Error::UnexpectedEof { .. }
+            | Error::DuplicateMultiplexerSwitch { .. }
+            | Error::DecodedValueOutOfRange { .. }
+            | Error::Decoding(_)
+            | Error::Encoding(_)
+            | Error::Validation(_) => false,
+            #[cfg(feature = "std")]
+            Error::Io(_) => false,
+        }
+    }
+
+    /// Returns `true` if `msg` reports an exhausted fixed capacity.
+    fn is_capacity_msg(msg: &str) -> bool {
+        [
+            Error::MAX_NAME_SIZE_EXCEEDED,
+            Error::NODES_TOO_MANY,
+            Error::MESSAGE_TOO_MANY_SIGNALS,
+            Error::SIGNAL_RECEIVERS_TOO_MANY,
+            Error::EXTENDED_MULTIPLEXING_TOO_MANY,
+            Error::SIGNAL_TYPES_TOO_MANY,
+            Error::SIGNAL_TYPE_REFS_TOO_MANY,
+            Error::SIGNAL_GROUPS_TOO_MANY,
+            Error::SIGNAL_VALUE_TYPES_TOO_MANY,
+            Error::ENVIRONMENT_VARIABLES_TOO_MANY,
+            Error::VALUE_DESCRIPTIONS_TOO_MANY,
+            Error::ATTRIBUTE_DEFINITIONS_TOO_MANY,
+            Error::ATTRIBUTE_VALUES_TOO_MANY,
+            Error::ATTRIBUTE_ENUM_VALUES_TOO_MANY,
+        ]
+        .contains(&msg)
+    }
 }
 
 impl fmt::Display for Error {
@@ -290,6 +389,84 @@ impl fmt::Display for Error {
                     write!(f, "{}: {}", Error::SIGNAL_ERROR_PREFIX, msg)
                 }
             }
+            Error::SignalOutOfBounds {
+                message_id,
+                signal,
+                end_bit,
+                available_bits,
+                line,
+            } => {
+                if let Some(line) = line {
+                    write!(f, "line {}: ", line)?;
+                }
+                write!(
+                    f,
+                    "{}: {}: signal '{}' in message {} ends at bit {}, message has {} bits",
+                    Error::SIGNAL_ERROR_PREFIX,
+                    Error::SIGNAL_EXTENDS_BEYOND_MESSAGE,
+                    signal,
+                    message_id,
+                    end_bit,
+                    available_bits
+                )
+            }
+            Error::SignalByteOrderMismatch {
+                message_id,
+                signal,
+                byte_order,
+                line,
+            } => {
+                if let Some(line) = line {
+                    write!(f, "line {}: ", line)?;
+                }
+                let declared = match byte_order {
+                    ByteOrder::LittleEndian => "little-endian (@1)",
+                    ByteOrder::BigEndian => "big-endian (@0)",
+                };
+                write!(
+                    f,
+                    "{}: {}: signal '{}' in message {} is declared {}",
+                    Error::SIGNAL_ERROR_PREFIX,
+                    Error::SIGNAL_BYTE_ORDER_MISMATCH,
+                    signal,
+                    message_id,
+                    declared
+                )
+            }
+            Error::DuplicateMultiplexerSwitch {
+                message_id,
+                first,
+                second,
+            } => {
+                write!(
+                    f,
+                    "{}: {}: message {} has switches '{}' and '{}'",
+                    Error::VALIDATION_ERROR_PREFIX,
+                    Error::MULTIPLEXER_SWITCH_DUPLICATE,
+                    message_id,
+                    first,
+                    second
+                )
+            }
+            Error::DecodedValueOutOfRange {
+                message_id,
+                signal,
+                value,
+                min,
+                max,
+            } => {
+                write!(
+                    f,
+                    "{}: {}: signal '{}' in message {} decoded to {}, range is [{}|{}]",
+                    Error::DECODING_ERROR_PREFIX,
+                    Error::DECODED_VALUE_OUT_OF_RANGE,
+                    signal,
+                    message_id,
+                    value,
+                    min,
+                    max
+                )
+            }
             Error::Decoding(msg) => {
                 write!(f, "{}: {}", Error::DECODING_ERROR_PREFIX, msg)
             }
@@ -328,10 +505,40 @@ impl std::error::Error for Error {}
 mod tests {
     #![allow(clippy::float_cmp)]
 
+    #[test]
+    fn test_is_recoverable() {
+        use crate::{Dbc, Error};
+
+        // Line-level parse errors
+        assert!(Error::expected_at(Error::EXPECTED_IDENTIFIER, 3).is_recoverable());
+        assert!(Error::invalid_char_at('\t', 4).is_recoverable());
+        assert!(Error::signal_at(Error::SIGNAL_PARSE_INVALID_START_BIT, 5).is_recoverable());
+        assert!(Error::message_at(Error::MESSAGE_INVALID_DLC, 6).is_recoverable());
+
+        // Structural errors
+        assert!(!Error::Validation(Error::DUPLICATE_MESSAGE_ID).is_recoverable());
+        assert!(!Error::message_at(Error::MESSAGE_TOO_MANY_SIGNALS, 7).is_recoverable());
+        assert!(!Error::nodes_at(Error::NODES_TOO_MANY, 2).is_recoverable());
+        assert!(!Error::unexpected_eof_at(9).is_recoverable());
+        assert!(!Error::Decoding(Error::MESSAGE_NOT_FOUND).is_recoverable());
+
+        // As reported by the parser
+        let bad_signal = Dbc::parse(
+            "VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n SG_ RPM : 60|16@1+ (1,0) [0|1] \"\" *\n",
+        )
+        .unwrap_err();
+        assert!(bad_signal.is_recoverable(), "{:?}", bad_signal);
+
+        let duplicate_id =
+            Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 A : 8 ECM\n\nBO_ 256 B : 8 ECM\n")
+                .unwrap_err();
+        assert!(!duplicate_id.is_recoverable(), "{:?}", duplicate_id);
+    }
+
     // Tests that require std feature (for Display/ToString)
     #[cfg(feature = "std")]
     mod tests_with_std {
-        use crate::Error;
+        use crate::{ByteOrder, Error};
 
         #[test]
         fn test_from_parse_int_error() {
@@ -427,6 +634,75 @@ mod tests {
             assert!(display.contains("\\t"));
         }
 
+        #[test]
+        fn test_signal_out_of_bounds_display() {
+            let error = Error::SignalOutOfBounds {
+                message_id: 256,
+                signal: "Overrun".try_into().unwrap(),
+                end_bit: 71,
+                available_bits: 64,
+                line: None,
+            }
+            .with_line(7);
+            assert_eq!(error.line(), Some(7));
+            assert_eq!(
+                error.to_string(),
+                "line 7: Signal error: Signal extends beyond message boundary: \
+                 signal 'Overrun' in message 256 ends at bit 71, message has 64 bits"
+            );
+        }
+
+        #[test]
+        fn test_signal_byte_order_mismatch_display() {
+            let error = Error::SignalByteOrderMismatch {
+                message_id: 256,
+                signal: "Speed".try_into().unwrap(),
+                byte_order: ByteOrder::BigEndian,
+                line: Some(6),
+            };
+            assert!(error.is_recoverable());
+            assert_eq!(
+                error.to_string(),
+                "line 6: Signal error: Signal only fits the message with the opposite byte order; \
+                 check its start bit and byte order: signal 'Speed' in message 256 is declared \
+                 big-endian (@0)"
+            );
+        }
+
+        #[test]
+        fn test_duplicate_multiplexer_switch_display() {
+            let error = Error::DuplicateMultiplexerSwitch {
+                message_id: 300,
+                first: "ServiceId".try_into().unwrap(),
+                second: "SubFunction".try_into().unwrap(),
+            }
+            .with_line(4);
+            assert_eq!(error.line(), None);
+            assert_eq!(
+                error.to_string(),
+                "Validation error: Message has more than one multiplexer switch: \
+                 message 300 has switches 'ServiceId' and 'SubFunction'"
+            );
+        }
+
+        #[test]
+        fn test_decoded_value_out_of_range_display() {
+            let error = Error::DecodedValueOutOfRange {
+                message_id: 256,
+                signal: "RPM".try_into().unwrap(),
+                value: 9000.0,
+                min: 0.0,
+                max: 8000.0,
+            };
+            assert_eq!(error.line(), None);
+            assert!(!error.is_recoverable());
+            assert_eq!(
+                error.to_string(),
+                "Decoding error: Decoded value outside signal min/max range: \
+                 signal 'RPM' in message 256 decoded to 9000, range is [0|8000]"
+            );
+        }
+
         #[test]
         fn test_max_str_length_display() {
             let error = Error::max_str_length_at(256, 20);
@@ -6,6 +6,7 @@
 pub const EXPECTED_WHITESPACE: &str = "Expected space character";
 pub const EXPECTED_PATTERN: &str = "Expected pattern not found";
 pub const EXPECTED_KEYWORD: &str = "Expected DBC keyword";
+pub const UNSUPPORTED_KEYWORD: &str = "DBC keyword is not supported in strict mode";
 pub const EXPECTED_NUMBER: &str = "Expected numeric value";
 pub const EXPECTED_IDENTIFIER: &str =
     "Expected valid identifier (letter or underscore followed by alphanumerics)";
@@ -39,27 +40,50 @@ pub const NODES_TOO_MANY: &str = "Too many nodes: maximum allowed is 256";
 pub const DUPLICATE_MESSAGE_ID: &str = "Duplicate message ID";
 pub const SENDER_NOT_IN_NODES: &str = "Message sender not defined in nodes list (BU_)";
 pub const SIGNAL_EXTENDS_BEYOND_MESSAGE: &str = "Signal extends beyond message boundary";
+pub const SIGNAL_BYTE_ORDER_MISMATCH: &str =
+    "Signal only fits the message with the opposite byte order; check its start bit and byte order";
 pub const INVALID_RANGE: &str = "Invalid range: minimum value exceeds maximum";
 pub const MESSAGE_TOO_MANY_SIGNALS: &str = "Too many signals: maximum allowed is 256 per message";
 pub const SIGNAL_RECEIVERS_TOO_MANY: &str =
     "Too many receiver nodes: maximum allowed is 255 per signal";
 pub const EXTENDED_MULTIPLEXING_TOO_MANY: &str =
     "Too many extended multiplexing entries: maximum allowed is 512 per DBC file";
+pub const SIGNAL_TYPES_TOO_MANY: &str = "Too many signal types: maximum allowed is 64 per DBC file";
+pub const SIGNAL_TYPE_REFS_TOO_MANY: &str =
+    "Too many signal type references: maximum allowed is 512 per DBC file";
+pub const SIGNAL_GROUPS_TOO_MANY: &str =
+    "Too many signal groups: maximum allowed is 256 per DBC file";
+pub const SIGNAL_VALUE_TYPES_TOO_MANY: &str =
+    "Too many SIG_VALTYPE_ entries preceding their message definitions";
+pub const ENVIRONMENT_VARIABLES_TOO_MANY: &str =
+    "Too many environment variables: maximum allowed is 64 per DBC file";
+pub const ENVIRONMENT_VARIABLE_INVALID: &str =
+    "Invalid environment variable entry (EV_ or ENVVAR_DATA_)";
+pub const ENVIRONMENT_VARIABLE_DUPLICATE_NAME: &str = "Duplicate environment variable name";
+pub const ENVIRONMENT_VARIABLE_NODE_NOT_IN_NODES: &str =
+    "Environment variable access node not defined in nodes list (BU_)";
 pub const SIGNAL_NAME_EMPTY: &str = "Signal name cannot be empty";
 pub const SIGNAL_LENGTH_TOO_SMALL: &str = "Signal length must be at least 1 bit";
 pub const SIGNAL_LENGTH_TOO_LARGE: &str = "Signal length cannot exceed 512 bits (CAN FD maximum)";
 pub const SIGNAL_OVERLAP: &str = "Signals overlap within message";
+pub const SIGNAL_NOT_FOUND: &str = "Signal not found in message";
+pub const SIGNAL_GROUP_NOT_FOUND: &str = "Signal group not found in message";
 pub const SIGNAL_EXTENDS_BEYOND_DATA: &str = "Signal extends beyond message data length";
 pub const MESSAGE_NAME_EMPTY: &str = "Message name cannot be empty";
 pub const MESSAGE_SENDER_EMPTY: &str = "Message sender cannot be empty";
 pub const MESSAGE_DLC_TOO_SMALL: &str = "Message DLC must be at least 0 bytes";
 pub const MESSAGE_DLC_TOO_LARGE: &str = "Message DLC cannot exceed 64 bytes (CAN FD maximum)";
+pub const MESSAGE_DLC_INVALID_FD_LENGTH: &str =
+    "Message DLC above 8 bytes must be a CAN FD length (12, 16, 20, 24, 32, 48 or 64)";
 pub const MESSAGE_ID_OUT_OF_RANGE: &str = "Message ID out of valid CAN range";
 pub const MESSAGE_INVALID_ID: &str = "Invalid message ID";
 pub const MESSAGE_INVALID_DLC: &str = "Invalid DLC value";
 pub const MESSAGE_NOT_FOUND: &str = "Message ID not found in database";
 pub const PAYLOAD_LENGTH_MISMATCH: &str = "Payload too short to decode all signals";
+pub const DECODED_VALUE_OUT_OF_RANGE: &str = "Decoded value outside signal min/max range";
+pub const DECODE_BUFFER_TOO_SMALL: &str = "Output buffer too small for decoded signals";
 pub const MULTIPLEXER_SWITCH_NEGATIVE: &str = "Multiplexer switch value cannot be negative";
+pub const MULTIPLEXER_SWITCH_DUPLICATE: &str = "Message has more than one multiplexer switch";
 
 // Value description error messages
 pub const VALUE_DESCRIPTION_MESSAGE_NOT_FOUND: &str =
@@ -16,8 +16,24 @@ pub const SIGNAL_OFFSET_REQUIRED: &str = "offset is required";
 pub const SIGNAL_MIN_REQUIRED: &str = "min is required";
 pub const SIGNAL_MAX_REQUIRED: &str = "max is required";
 
+pub const SIGNAL_MULTIPLEXER_HAS_DEPENDANTS: &str =
+    "Cannot remove a multiplexer switch that still selects multiplexed signals";
+
+pub const SIGNAL_NAME_DUPLICATE: &str = "Duplicate signal name in message";
+pub const MESSAGE_NAME_DUPLICATE: &str = "Duplicate message name";
+pub const COMMENT_TOO_LONG: &str = "Comment exceeds maximum length (MAX_COMMENT_SIZE bytes)";
+pub const COMMENT_CONTAINS_QUOTE: &str = "Comment cannot contain double quotes";
+
 pub const RECEIVERS_DUPLICATE_NAME: &str = "Duplicate Receiver name";
 
+// Node-related error messages (only used in std contexts)
+pub const NODES_INVALID_NAME: &str =
+    "Invalid node name: expected a DBC identifier that is not a keyword";
+
+// Trace decoding error messages (only used in std contexts)
+pub const TRACE_LINE_INVALID: &str =
+    "Expected candump log line: (<timestamp>) <interface> <id>#<data>";
+
 // Attribute-related error messages (only used in std contexts)
 pub const ATTRIBUTE_NAME_REQUIRED: &str = "Attribute name is required";
 pub const ATTRIBUTE_VALUE_TYPE_REQUIRED: &str = "Attribute value type is required";
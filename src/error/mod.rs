@@ -2,6 +2,8 @@ mod helpers;
 mod impls;
 mod lang;
 
+use crate::{ByteOrder, compat::Name};
+
 pub(crate) use helpers::{check_max_limit, map_val_error, map_val_error_with_line};
 
 /// Error type for DBC operations.
@@ -46,6 +48,54 @@ pub enum Error {
         msg: &'static str,
         line: Option<usize>,
     },
+    /// A signal extends beyond the payload declared by its message's DLC.
+    SignalOutOfBounds {
+        /// ID of the containing message (bit 31 set for extended IDs).
+        message_id: u32,
+        /// Name of the offending signal.
+        signal: Name,
+        /// Highest payload bit occupied by the signal.
+        end_bit: u16,
+        /// Number of payload bits available (DLC * 8).
+        available_bits: u16,
+        line: Option<usize>,
+    },
+    /// A signal extends beyond its message's DLC, but would fit with the opposite byte
+    /// order. This usually means a start bit was computed with the other numbering
+    /// (e.g. an Intel LSB start bit on a Motorola signal).
+    SignalByteOrderMismatch {
+        /// ID of the containing message (bit 31 set for extended IDs).
+        message_id: u32,
+        /// Name of the offending signal.
+        signal: Name,
+        /// Byte order the signal is declared with.
+        byte_order: ByteOrder,
+        line: Option<usize>,
+    },
+    /// A message declares more than one top-level multiplexer switch (`M`) without
+    /// `SG_MUL_VAL_` entries nesting them (post-parse, no line info).
+    DuplicateMultiplexerSwitch {
+        /// ID of the containing message (bit 31 set for extended IDs).
+        message_id: u32,
+        /// Name of the first multiplexer switch.
+        first: Name,
+        /// Name of the second multiplexer switch.
+        second: Name,
+    },
+    /// A decoded physical value lies outside its signal's `[min, max]` range, as reported
+    /// by [`Dbc::decode_checked`](crate::Dbc::decode_checked) (runtime, no line info).
+    DecodedValueOutOfRange {
+        /// ID of the containing message (bit 31 set for extended IDs).
+        message_id: u32,
+        /// Name of the offending signal.
+        signal: Name,
+        /// The decoded physical value.
+        value: f64,
+        /// Minimum of the signal's range.
+        min: f64,
+        /// Maximum of the signal's range.
+        max: f64,
+    },
     /// Decoding-related error (runtime, no line info).
     Decoding(&'static str),
     /// Encoding-related error (runtime, no line info).
@@ -66,6 +116,7 @@ impl Error {
     pub const EXPECTED_WHITESPACE: &'static str = lang::EXPECTED_WHITESPACE;
     pub const EXPECTED_PATTERN: &'static str = lang::EXPECTED_PATTERN;
     pub const EXPECTED_KEYWORD: &'static str = lang::EXPECTED_KEYWORD;
+    pub const UNSUPPORTED_KEYWORD: &'static str = lang::UNSUPPORTED_KEYWORD;
     pub const EXPECTED_NUMBER: &'static str = lang::EXPECTED_NUMBER;
     pub const EXPECTED_IDENTIFIER: &'static str = lang::EXPECTED_IDENTIFIER;
     pub const INVALID_UTF8: &'static str = lang::INVALID_UTF8;
@@ -93,6 +144,8 @@ impl Error {
     pub const SIGNAL_PARSE_INVALID_MAX: &'static str = lang::SIGNAL_PARSE_INVALID_MAX;
     pub const SIGNAL_PARSE_UNIT_TOO_LONG: &'static str = lang::SIGNAL_PARSE_UNIT_TOO_LONG;
     pub const SIGNAL_NAME_EMPTY: &'static str = lang::SIGNAL_NAME_EMPTY;
+    #[cfg(feature = "std")]
+    pub const SIGNAL_NAME_DUPLICATE: &'static str = lang::SIGNAL_NAME_DUPLICATE;
     pub const SIGNAL_LENGTH_TOO_SMALL: &'static str = lang::SIGNAL_LENGTH_TOO_SMALL;
     pub const SIGNAL_LENGTH_TOO_LARGE: &'static str = lang::SIGNAL_LENGTH_TOO_LARGE;
     #[cfg(feature = "std")]
@@ -112,22 +165,47 @@ impl Error {
     #[cfg(feature = "std")]
     pub const SIGNAL_MAX_REQUIRED: &'static str = lang::SIGNAL_MAX_REQUIRED;
     pub const SIGNAL_OVERLAP: &'static str = lang::SIGNAL_OVERLAP;
+    pub const SIGNAL_NOT_FOUND: &'static str = lang::SIGNAL_NOT_FOUND;
+    #[cfg(feature = "std")]
+    pub const SIGNAL_MULTIPLEXER_HAS_DEPENDANTS: &'static str =
+        lang::SIGNAL_MULTIPLEXER_HAS_DEPENDANTS;
+    pub const SIGNAL_GROUP_NOT_FOUND: &'static str = lang::SIGNAL_GROUP_NOT_FOUND;
     pub const SIGNAL_EXTENDS_BEYOND_MESSAGE: &'static str = lang::SIGNAL_EXTENDS_BEYOND_MESSAGE;
+    pub const SIGNAL_BYTE_ORDER_MISMATCH: &'static str = lang::SIGNAL_BYTE_ORDER_MISMATCH;
     pub const SIGNAL_EXTENDS_BEYOND_DATA: &'static str = lang::SIGNAL_EXTENDS_BEYOND_DATA;
     pub const SIGNAL_RECEIVERS_TOO_MANY: &'static str = lang::SIGNAL_RECEIVERS_TOO_MANY;
 
     // Validation and decoding error constants
     pub const NODES_DUPLICATE_NAME: &'static str = lang::NODES_DUPLICATE_NAME;
     pub const NODES_TOO_MANY: &'static str = lang::NODES_TOO_MANY;
+    #[cfg(feature = "std")]
+    pub const NODES_INVALID_NAME: &'static str = lang::NODES_INVALID_NAME;
     pub const DUPLICATE_MESSAGE_ID: &'static str = lang::DUPLICATE_MESSAGE_ID;
     pub const SENDER_NOT_IN_NODES: &'static str = lang::SENDER_NOT_IN_NODES;
     pub const INVALID_RANGE: &'static str = lang::INVALID_RANGE;
     pub const MESSAGE_TOO_MANY_SIGNALS: &'static str = lang::MESSAGE_TOO_MANY_SIGNALS;
     pub const EXTENDED_MULTIPLEXING_TOO_MANY: &'static str = lang::EXTENDED_MULTIPLEXING_TOO_MANY;
+    pub const SIGNAL_TYPES_TOO_MANY: &'static str = lang::SIGNAL_TYPES_TOO_MANY;
+    pub const SIGNAL_TYPE_REFS_TOO_MANY: &'static str = lang::SIGNAL_TYPE_REFS_TOO_MANY;
+    pub const SIGNAL_GROUPS_TOO_MANY: &'static str = lang::SIGNAL_GROUPS_TOO_MANY;
+    pub const SIGNAL_VALUE_TYPES_TOO_MANY: &'static str = lang::SIGNAL_VALUE_TYPES_TOO_MANY;
+    pub const ENVIRONMENT_VARIABLES_TOO_MANY: &'static str = lang::ENVIRONMENT_VARIABLES_TOO_MANY;
+    pub const ENVIRONMENT_VARIABLE_INVALID: &'static str = lang::ENVIRONMENT_VARIABLE_INVALID;
+    pub const ENVIRONMENT_VARIABLE_DUPLICATE_NAME: &'static str =
+        lang::ENVIRONMENT_VARIABLE_DUPLICATE_NAME;
+    pub const ENVIRONMENT_VARIABLE_NODE_NOT_IN_NODES: &'static str =
+        lang::ENVIRONMENT_VARIABLE_NODE_NOT_IN_NODES;
     pub const MESSAGE_NAME_EMPTY: &'static str = lang::MESSAGE_NAME_EMPTY;
+    #[cfg(feature = "std")]
+    pub const MESSAGE_NAME_DUPLICATE: &'static str = lang::MESSAGE_NAME_DUPLICATE;
+    #[cfg(feature = "std")]
+    pub const COMMENT_TOO_LONG: &'static str = lang::COMMENT_TOO_LONG;
+    #[cfg(feature = "std")]
+    pub const COMMENT_CONTAINS_QUOTE: &'static str = lang::COMMENT_CONTAINS_QUOTE;
     pub const MESSAGE_SENDER_EMPTY: &'static str = lang::MESSAGE_SENDER_EMPTY;
     pub const MESSAGE_DLC_TOO_SMALL: &'static str = lang::MESSAGE_DLC_TOO_SMALL;
     pub const MESSAGE_DLC_TOO_LARGE: &'static str = lang::MESSAGE_DLC_TOO_LARGE;
+    pub const MESSAGE_DLC_INVALID_FD_LENGTH: &'static str = lang::MESSAGE_DLC_INVALID_FD_LENGTH;
     #[cfg(feature = "std")]
     pub const MESSAGE_DLC_REQUIRED: &'static str = lang::MESSAGE_DLC_REQUIRED;
     pub const MESSAGE_ID_OUT_OF_RANGE: &'static str = lang::MESSAGE_ID_OUT_OF_RANGE;
@@ -137,9 +215,14 @@ impl Error {
     pub const MESSAGE_INVALID_DLC: &'static str = lang::MESSAGE_INVALID_DLC;
     pub const MESSAGE_NOT_FOUND: &'static str = lang::MESSAGE_NOT_FOUND;
     pub const PAYLOAD_LENGTH_MISMATCH: &'static str = lang::PAYLOAD_LENGTH_MISMATCH;
+    pub const DECODED_VALUE_OUT_OF_RANGE: &'static str = lang::DECODED_VALUE_OUT_OF_RANGE;
+    pub const DECODE_BUFFER_TOO_SMALL: &'static str = lang::DECODE_BUFFER_TOO_SMALL;
     pub const MULTIPLEXER_SWITCH_NEGATIVE: &'static str = lang::MULTIPLEXER_SWITCH_NEGATIVE;
+    pub const MULTIPLEXER_SWITCH_DUPLICATE: &'static str = lang::MULTIPLEXER_SWITCH_DUPLICATE;
     #[cfg(feature = "std")]
     pub const RECEIVERS_DUPLICATE_NAME: &'static str = lang::RECEIVERS_DUPLICATE_NAME;
+    #[cfg(feature = "std")]
+    pub const TRACE_LINE_INVALID: &'static str = lang::TRACE_LINE_INVALID;
 
     // Value description error constants (no_std)
     pub const VALUE_DESCRIPTION_MESSAGE_NOT_FOUND: &'static str =
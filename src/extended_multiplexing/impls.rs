@@ -16,6 +16,26 @@ impl ExtendedMultiplexing {
         }
     }
 
+    /// Copy of this entry for another message.
+    #[cfg(feature = "std")]
+    pub(crate) fn with_message_id(&self, message_id: u32) -> Self {
+        Self {
+            message_id,
+            ..self.clone()
+        }
+    }
+
+    /// Replace `old` by `new` as the signal name and as the multiplexer switch.
+    #[cfg(feature = "std")]
+    pub(crate) fn rename_signal(&mut self, old: &str, new: &Name) {
+        if self.signal_name.as_str() == old {
+            self.signal_name = new.clone();
+        }
+        if self.multiplexer_switch.as_str() == old {
+            self.multiplexer_switch = new.clone();
+        }
+    }
+
     /// Returns the CAN message ID this extended multiplexing entry applies to.
     ///
     /// # Examples
@@ -145,4 +165,41 @@ impl ExtendedMultiplexing {
     pub fn value_ranges(&self) -> &[(u64, u64)] {
         self.value_ranges.as_slice()
     }
+
+    /// Returns `true` if `switch_value` falls within any of this entry's value ranges.
+    ///
+    /// This is the per-entry check used during decoding. A signal with entries for
+    /// several switches is only active when every switch has a matching entry (AND logic),
+    /// which can be evaluated by combining this predicate with
+    /// [`Dbc::ext_mux_entries_for_signal`](crate::Dbc::ext_mux_entries_for_signal).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 500 MuxMessage : 8 ECM
+    ///  SG_ Mux1 M : 0|8@1+ (1,0) [0|255] ""
+    ///  SG_ Signal_A m0 : 16|16@1+ (0.1,0) [0|100] "unit" *
+    ///
+    /// SG_MUL_VAL_ 500 Signal_A Mux1 0-5,10-15 ;
+    /// "#)?;
+    ///
+    /// let entry = dbc.extended_multiplexing_for_message(500).next().unwrap();
+    /// assert!(entry.applies_to(3));
+    /// assert!(!entry.applies_to(7));
+    /// assert!(entry.applies_to(15));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn applies_to(&self, switch_value: u64) -> bool {
+        self.value_ranges
+            .iter()
+            .any(|&(min, max)| switch_value >= min && switch_value <= max)
+    }
 }
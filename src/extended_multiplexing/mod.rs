@@ -26,6 +26,8 @@ use crate::compat::{Name, Vec};
 
 mod impls;
 mod parse;
+#[cfg(feature = "std")]
+mod std;
 
 /// A value range (min, max) for extended multiplexing
 type ValueRange = (u64, u64);
@@ -105,6 +107,7 @@ pub use builder::ExtendedMultiplexingBuilder;
 ///
 /// Here, `Signal_A` is only decoded when `Mux1` is 5-10 AND `Mux2` is 20-25.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExtendedMultiplexing {
     message_id: u32,
     signal_name: Name,
@@ -13,7 +13,7 @@ impl ExtendedMultiplexing {
         parser.skip_newlines_and_spaces();
 
         // Parse message_id
-        let message_id = parser.parse_u32().ok()?;
+        let message_id = parser.parse_message_id().ok()?;
         parser.skip_newlines_and_spaces();
 
         // Parse signal_name
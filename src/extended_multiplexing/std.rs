@@ -0,0 +1,44 @@
+use super::ExtendedMultiplexing;
+use std::{
+    fmt::{Display, Formatter, Result},
+    string::String,
+};
+
+impl ExtendedMultiplexing {
+    /// Converts the extended multiplexing entry to its DBC file representation.
+    ///
+    /// Returns a string in the format: `SG_MUL_VAL_ message_id signal switch min-max,min-max ;`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 500 MuxMessage : 8 ECM\n SG_ Mux1 M : 0|8@1+ (1,0) [0|255] \"\"\n SG_ Signal_A m0 : 16|16@1+ (0.1,0) [0|100] \"unit\" *\n\nSG_MUL_VAL_ 500 Signal_A Mux1 0-5,10-15 ;\n")?;
+    /// let entry = dbc.extended_multiplexing_for_message(500).next().unwrap();
+    /// assert_eq!(entry.to_dbc_string(), "SG_MUL_VAL_ 500 Signal_A Mux1 0-5,10-15 ;");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn to_dbc_string(&self) -> String {
+        let mut result = format!(
+            "SG_MUL_VAL_ {} {} {} ",
+            self.message_id(),
+            self.signal_name(),
+            self.multiplexer_switch()
+        );
+        for (i, (min, max)) in self.value_ranges().iter().enumerate() {
+            if i > 0 {
+                result.push(',');
+            }
+            result.push_str(&format!("{min}-{max}"));
+        }
+        result.push_str(" ;");
+        result
+    }
+}
+
+impl Display for ExtendedMultiplexing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.to_dbc_string())
+    }
+}
@@ -1,6 +1,6 @@
 //! Pre-computed decode structures for fast signal extraction.
 
-use crate::{ByteOrder, Signal};
+use crate::{ByteOrder, Dbc, Message, Signal, SignalValueType};
 
 /// Pre-computed signal decode parameters.
 ///
@@ -13,7 +13,8 @@ pub(super) struct SignalDecode {
     pub bit_offset: u8,
     /// Signal length in bits
     pub length: u8,
-    /// Flags: bit 0 = unsigned, bit 1 = little_endian, bit 2 = identity_transform
+    /// Flags: bit 0 = unsigned, bit 1 = little_endian, bit 2 = identity_transform,
+    /// bit 3 = float, bit 4 = double, bit 5 = beyond the transmitted length
     pub flags: u8,
     /// Scaling factor
     pub factor: f64,
@@ -25,6 +26,9 @@ impl SignalDecode {
     pub const FLAG_UNSIGNED: u8 = 0b0001;
     pub const FLAG_LITTLE_ENDIAN: u8 = 0b0010;
     pub const FLAG_IDENTITY: u8 = 0b0100;
+    pub const FLAG_FLOAT: u8 = 0b1000;
+    pub const FLAG_DOUBLE: u8 = 0b1_0000;
+    pub const FLAG_UNTRANSMITTED: u8 = 0b10_0000;
 
     #[inline(always)]
     pub fn is_unsigned(self) -> bool {
@@ -41,8 +45,24 @@ impl SignalDecode {
         (self.flags & Self::FLAG_IDENTITY) != 0
     }
 
-    /// Create from a Signal reference.
-    pub fn from_signal(signal: &Signal) -> Self {
+    #[inline(always)]
+    pub fn is_transmitted(self) -> bool {
+        (self.flags & Self::FLAG_UNTRANSMITTED) == 0
+    }
+
+    #[inline(always)]
+    pub fn value_type(self) -> SignalValueType {
+        if (self.flags & Self::FLAG_DOUBLE) != 0 {
+            SignalValueType::Double
+        } else if (self.flags & Self::FLAG_FLOAT) != 0 {
+            SignalValueType::Float
+        } else {
+            SignalValueType::Integer
+        }
+    }
+
+    /// Create from a Signal reference of `message`.
+    pub fn from_signal(message: &Message, signal: &Signal) -> Self {
         let start_bit = signal.start_bit() as usize;
         let length = signal.length() as usize;
 
@@ -57,6 +77,14 @@ impl SignalDecode {
         if signal.factor() == 1.0 && signal.offset() == 0.0 {
             flags |= Self::FLAG_IDENTITY;
         }
+        match signal.value_type() {
+            SignalValueType::Integer => {}
+            SignalValueType::Float => flags |= Self::FLAG_FLOAT,
+            SignalValueType::Double => flags |= Self::FLAG_DOUBLE,
+        }
+        if !message.is_transmitted(signal) {
+            flags |= Self::FLAG_UNTRANSMITTED;
+        }
 
         Self {
             byte_start: (start_bit / 8) as u8,
@@ -69,6 +97,50 @@ impl SignalDecode {
     }
 }
 
+/// When a signal of a multiplexed message is decoded.
+pub(super) enum MuxCondition {
+    /// Decoded in every frame (signals without a multiplexer indicator)
+    Always,
+    /// A multiplexer switch, decoded in every frame before all other signals
+    Switch,
+    /// Decoded when a multiplexer switch of the message has this raw value (`mN`)
+    Value(u64),
+    /// Decoded when every referenced switch lies in one of its value ranges (`SG_MUL_VAL_`).
+    /// Each entry pairs the position of a switch in the plan's signals with its ranges.
+    Ranges(Vec<(usize, Vec<(u64, u64)>)>),
+    /// Never decoded: the signal lies beyond the transmitted length, or an `SG_MUL_VAL_`
+    /// entry references a switch missing from the message (or not transmitted)
+    Never,
+}
+
+impl MuxCondition {
+    /// Build the condition of a signal with a multiplexer indicator, as evaluated by
+    /// [`Dbc::decode`]: `SG_MUL_VAL_` entries take precedence over the `mN` value.
+    fn for_signal(dbc: &Dbc, message: &Message, signal: &Signal, mux_value: u64) -> Self {
+        let mut ranges: Vec<(usize, Vec<(u64, u64)>)> = Vec::new();
+        for entry in dbc.ext_mux_entries_for_signal(message.id_with_flag(), signal.name()) {
+            let Some(switch) = message.signals().iter().position(|s| {
+                s.is_multiplexer_switch()
+                    && s.name() == entry.multiplexer_switch()
+                    && message.is_transmitted(s)
+            }) else {
+                return Self::Never;
+            };
+            // Entries for the same switch are alternatives
+            match ranges.iter_mut().find(|(position, _)| *position == switch) {
+                Some((_, switch_ranges)) => switch_ranges.extend_from_slice(entry.value_ranges()),
+                None => ranges.push((switch, entry.value_ranges().to_vec())),
+            }
+        }
+
+        if ranges.is_empty() {
+            Self::Value(mux_value)
+        } else {
+            Self::Ranges(ranges)
+        }
+    }
+}
+
 /// Pre-computed decode plan for a message.
 pub(super) struct DecodePlan {
     /// Message index in the original Dbc
@@ -77,4 +149,55 @@ pub(super) struct DecodePlan {
     pub min_bytes: u8,
     /// Pre-computed signal decode parameters
     pub signals: Vec<SignalDecode>,
+    /// Signal names, in `signals` order
+    pub names: Box<[String]>,
+    /// Positions in `signals` of the transmitted multiplexer switches, decoded before all
+    /// other signals
+    pub switches: Vec<usize>,
+    /// Activation condition of each signal, in `signals` order. Empty for messages without
+    /// multiplexing whose signals are all transmitted, and are therefore always decoded.
+    pub conditions: Vec<MuxCondition>,
+}
+
+impl DecodePlan {
+    /// Build the plan for the message at `message_index` of `dbc`.
+    pub fn new(dbc: &Dbc, message_index: usize, message: &Message) -> Self {
+        let signals = message.signals();
+        let switches: Vec<usize> = signals
+            .iter()
+            .enumerate()
+            .filter(|(_, signal)| signal.is_multiplexer_switch() && message.is_transmitted(signal))
+            .map(|(position, _)| position)
+            .collect();
+
+        let multiplexed = signals.iter().any(|s| s.multiplexer_switch_value().is_some());
+        let truncated = signals.iter().any(|s| !message.is_transmitted(s));
+        let conditions = if switches.is_empty() && !multiplexed && !truncated {
+            Vec::new()
+        } else {
+            signals
+                .iter()
+                .map(|signal| {
+                    if !message.is_transmitted(signal) {
+                        MuxCondition::Never
+                    } else if signal.is_multiplexer_switch() {
+                        MuxCondition::Switch
+                    } else if let Some(mux_value) = signal.multiplexer_switch_value() {
+                        MuxCondition::for_signal(dbc, message, signal, mux_value)
+                    } else {
+                        MuxCondition::Always
+                    }
+                })
+                .collect()
+        };
+
+        Self {
+            message_index,
+            min_bytes: message.min_bytes_required(),
+            signals: signals.iter().map(|s| SignalDecode::from_signal(message, s)).collect(),
+            names: signals.iter().map(|signal| signal.name().to_string()).collect(),
+            switches,
+            conditions,
+        }
+    }
 }
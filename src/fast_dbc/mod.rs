@@ -6,6 +6,9 @@
 //! - **Zero-allocation decoding** with optimized hot paths
 //! - **Identity transform detection** skipping factor/offset math when possible
 //!
+//! With the `heapless` feature, `HeaplessFastDbc` provides the same lookup and decode API
+//! for `no_std` targets without heap allocation.
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -26,14 +29,24 @@
 //! }
 //! ```
 
+#[cfg(feature = "std")]
 mod decode;
-mod hasher;
-
-use crate::{ByteOrder, Dbc, Message, Result};
-use decode::{DecodePlan, SignalDecode};
-use hasher::FxHashMap;
+#[cfg(feature = "heapless")]
+mod no_std;
+
+#[cfg(feature = "std")]
+use crate::hasher::FxHashMap;
+#[cfg(feature = "std")]
+use crate::{ByteOrder, Dbc, Message, Result, SignalValueType};
+#[cfg(feature = "std")]
+use decode::{DecodePlan, MuxCondition, SignalDecode};
+#[cfg(feature = "heapless")]
+pub use no_std::HeaplessFastDbc;
+#[cfg(feature = "std")]
 use std::collections::HashMap;
+#[cfg(feature = "std")]
 use std::path::Path;
+#[cfg(feature = "std")]
 use std::sync::Arc;
 
 /// Maximum standard CAN ID for direct array lookup (11-bit = 2048 values).
@@ -54,11 +67,13 @@ const MAX_STANDARD_ID: usize = 2048;
 /// - **FxHash for extended IDs**: Fast hash function for non-standard IDs
 ///
 /// Cloning is O(1) due to internal `Arc` usage.
+#[cfg(feature = "std")]
 #[derive(Clone)]
 pub struct FastDbc {
     inner: Arc<FastDbcInner>,
 }
 
+#[cfg(feature = "std")]
 struct FastDbcInner {
     /// The underlying DBC
     dbc: Dbc,
@@ -75,6 +90,7 @@ struct FastDbcInner {
     total_signals: usize,
 }
 
+#[cfg(feature = "std")]
 impl std::fmt::Debug for FastDbc {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FastDbc")
@@ -85,6 +101,7 @@ impl std::fmt::Debug for FastDbc {
     }
 }
 
+#[cfg(feature = "std")]
 impl FastDbc {
     /// Load a DBC file from disk and wrap it for fast access.
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
@@ -96,6 +113,11 @@ impl FastDbc {
     ///
     /// This pre-computes all decode parameters for maximum runtime performance.
     pub fn new(dbc: Dbc) -> Self {
+        Self::build(dbc, false)
+    }
+
+    /// Build the decode plans and ID tables, then optionally strip the Dbc.
+    fn build(dbc: Dbc, minimal: bool) -> Self {
         let mut standard_ids = Box::new([usize::MAX; MAX_STANDARD_ID]);
         let mut extended_ids: FxHashMap<u32, usize> =
             HashMap::with_capacity_and_hasher(16, Default::default());
@@ -107,18 +129,13 @@ impl FastDbc {
             let plan_idx = decode_plans.len();
 
             // Build decode plan
-            let signals: Vec<SignalDecode> =
-                msg.signals().iter().map(SignalDecode::from_signal).collect();
+            let plan = DecodePlan::new(&dbc, msg_idx, msg);
 
-            let sig_count = signals.len();
+            let sig_count = plan.signals.len();
             max_signals = max_signals.max(sig_count);
             total_signals += sig_count;
 
-            decode_plans.push(DecodePlan {
-                message_index: msg_idx,
-                min_bytes: msg.min_bytes_required(),
-                signals,
-            });
+            decode_plans.push(plan);
 
             // Index by ID
             let id = msg.id_with_flag();
@@ -129,6 +146,9 @@ impl FastDbc {
             }
         }
 
+        // Plans are built first, as they capture the extended multiplexing dropped here
+        let dbc = if minimal { dbc.into_minimal() } else { dbc };
+
         Self {
             inner: Arc::new(FastDbcInner {
                 dbc,
@@ -141,6 +161,21 @@ impl FastDbc {
         }
     }
 
+    /// Create a FastDbc that keeps only what decoding needs.
+    ///
+    /// Builds the same decode plans as [`new`](Self::new), but first strips the Dbc down
+    /// to its messages and signals. Comments, value descriptions, extended multiplexing,
+    /// signal types and groups, and attributes are dropped, which can substantially
+    /// reduce the resident size of long-running processes using large databases.
+    ///
+    /// Lookup and decoding behave exactly as with `new`, and message and signal names,
+    /// units, and scaling remain available through [`get`](Self::get) and
+    /// [`dbc`](Self::dbc). Queries for the dropped data return nothing, e.g.
+    /// `fast.dbc().value_descriptions_for_signal(..)` is always `None`.
+    pub fn new_minimal(dbc: Dbc) -> Self {
+        Self::build(dbc, true)
+    }
+
     // ========================================================================
     // Message Lookup
     // ========================================================================
@@ -165,6 +200,51 @@ impl FastDbc {
         self.inner.extended_ids.get(&extended_id).copied()
     }
 
+    /// Resolve a CAN ID of unknown type in one call.
+    ///
+    /// Returns the index of the message in [`dbc().messages()`](Dbc::messages) and whether
+    /// it matched as an extended ID. Standard IDs take precedence over extended IDs with
+    /// the same value. IDs with the extended flag (bit 31) set, as written in DBC files,
+    /// are looked up as extended IDs directly. IDs with the reserved bits 29 or 30 set
+    /// never match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, FastDbc};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    /// BO_ 2147484672 Brake : 8 ECM
+    /// "#)?;
+    /// let fast = FastDbc::new(dbc);
+    ///
+    /// assert_eq!(fast.resolve(256), Some((0, false)));
+    /// assert_eq!(fast.resolve(0x400), Some((1, true)));
+    /// assert_eq!(fast.resolve(0x8000_0400), Some((1, true)));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    pub fn resolve(&self, id: u32) -> Option<(usize, bool)> {
+        if id & !(Message::EXTENDED_ID_FLAG | Message::MAX_EXTENDED_ID) != 0 {
+            return None;
+        }
+        let (plan_idx, is_extended) = if id & Message::EXTENDED_ID_FLAG != 0 {
+            (
+                self.get_plan_index_extended(id & Message::MAX_EXTENDED_ID)?,
+                true,
+            )
+        } else if let Some(idx) = self.get_plan_index(id) {
+            (idx, false)
+        } else {
+            (self.get_plan_index_extended(id)?, true)
+        };
+        Some((self.inner.decode_plans[plan_idx].message_index, is_extended))
+    }
+
     /// Get a message by standard (11-bit) CAN ID.
     #[inline]
     pub fn get(&self, id: u32) -> Option<&Message> {
@@ -173,6 +253,89 @@ impl FastDbc {
             .and_then(|plan| self.inner.dbc.messages().at(plan.message_index))
     }
 
+    /// Get the position of a signal in the decode buffer, by standard CAN ID and name.
+    ///
+    /// This is the index accepted by [`decode_signal`](Self::decode_signal) and, for
+    /// messages without multiplexing, the slot [`decode_into`](Self::decode_into) writes
+    /// the signal to. Resolve it once at setup and index the output buffer directly in
+    /// the hot loop.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, FastDbc};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///  SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+    /// "#)?;
+    /// let fast = FastDbc::new(dbc);
+    /// let temp = fast.signal_index(256, "Temp").unwrap();
+    ///
+    /// let mut values = vec![0.0; fast.max_signals()];
+    /// for payload in [[0x40, 0x1F, 0x5A, 0, 0, 0, 0, 0]] {
+    ///     fast.decode_into(256, &payload, &mut values);
+    ///     assert_eq!(values[temp], 50.0);
+    /// }
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    pub fn signal_index(&self, id: u32, name: &str) -> Option<usize> {
+        self.get(id)?.signals().iter().position(|s| s.name() == name)
+    }
+
+    /// Get the names of the signals of a message by standard CAN ID, in decode buffer
+    /// order.
+    ///
+    /// `names[i]` is the signal at index `i` of [`decode_signal`](Self::decode_signal) and
+    /// [`encode_from`](Self::encode_from). For messages without multiplexing it is also the
+    /// signal [`decode_into`](Self::decode_into) writes to `out[i]`, so values can be zipped
+    /// with names directly. Multiplexed messages write only the switches and the active
+    /// signals; map their output through [`active_signals_into`](Self::active_signals_into).
+    /// The names are computed once when the `FastDbc` is built.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, FastDbc};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///  SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+    /// "#)?;
+    /// let fast = FastDbc::new(dbc);
+    /// let names = fast.signal_names(256).unwrap();
+    ///
+    /// let mut values = vec![0.0; fast.max_signals()];
+    /// let count = fast.decode_into(256, &[0x40, 0x1F, 0x5A, 0, 0, 0, 0, 0], &mut values).unwrap();
+    /// for (name, value) in names.iter().zip(&values[..count]) {
+    ///     println!("{name} = {value}");
+    /// }
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    pub fn signal_names(&self, id: u32) -> Option<&[String]> {
+        let plan_idx = self.get_plan_index(id)?;
+        Some(&self.inner.decode_plans[plan_idx].names)
+    }
+
+    /// Get the names of the signals of a message by extended CAN ID.
+    ///
+    /// See [`signal_names`](Self::signal_names).
+    #[inline]
+    pub fn signal_names_extended(&self, id: u32) -> Option<&[String]> {
+        let plan_idx = self.get_plan_index_extended(id)?;
+        Some(&self.inner.decode_plans[plan_idx].names)
+    }
+
     /// Get a message by extended (29-bit) CAN ID.
     #[inline]
     pub fn get_extended(&self, id: u32) -> Option<&Message> {
@@ -184,7 +347,22 @@ impl FastDbc {
     /// Get a message by CAN ID, trying extended if standard not found.
     #[inline]
     pub fn get_any(&self, id: u32) -> Option<&Message> {
-        self.get(id).or_else(|| self.get_extended(id))
+        let (msg_idx, _) = self.resolve(id)?;
+        self.inner.dbc.messages().at(msg_idx)
+    }
+
+    /// Get the `VECTOR__INDEPENDENT_SIG_MSG` pseudo-message (ID `0xC0000000`), if present.
+    ///
+    /// The pseudo-message collects signals not assigned to any real message. Its ID has
+    /// the extended flag set and is indexed with the extended IDs, but it is never sent
+    /// on the bus, so it cannot be reached through a 29-bit ID with [`get_extended`](Self::get_extended).
+    #[inline]
+    pub fn pseudo_message(&self) -> Option<&Message> {
+        self.inner
+            .extended_ids
+            .get(&Message::PSEUDO_MESSAGE_ID)
+            .map(|&idx| &self.inner.decode_plans[idx])
+            .and_then(|plan| self.inner.dbc.messages().at(plan.message_index))
     }
 
     // ========================================================================
@@ -199,13 +377,47 @@ impl FastDbc {
     /// - Identity transform detection (skips math when factor=1, offset=0)
     /// - Zero allocation
     ///
+    /// # Output order
+    ///
+    /// For messages without multiplexing, `out[i]` is the `i`-th signal of the message.
+    /// Multiplexed messages are decoded like [`Dbc::decode`]: the multiplexer switches are
+    /// written first, followed by the signals active for the decoded switch values
+    /// (`mN` markers, or the `SG_MUL_VAL_` ranges where present), both in message order.
+    /// Inactive signals are skipped, as are signals gated by a negative switch value.
+    /// Signals beyond the transmitted length ([`Message::effective_dlc`]) are skipped in
+    /// either case. Decoding stops early when `out` is full.
+    ///
     /// # Arguments
     /// * `id` - Standard (11-bit) CAN ID
     /// * `data` - Raw CAN payload bytes
     /// * `out` - Output buffer for physical values
     ///
     /// # Returns
-    /// Number of signals decoded, or `None` if message not found or payload too short.
+    /// Number of signals written to `out`, or `None` if message not found or payload too
+    /// short.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, FastDbc};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Status : 8 ECM
+    ///  SG_ Page M : 0|8@1+ (1,0) [0|1] "" *
+    ///  SG_ Speed m0 : 8|16@1+ (0.1,0) [0|6553.5] "km/h" *
+    ///  SG_ Temp m1 : 8|8@1- (1,-40) [-40|215] "C" *
+    /// "#)?;
+    /// let fast = FastDbc::new(dbc);
+    /// let mut values = vec![0.0; fast.max_signals()];
+    ///
+    /// // Page 1 selects Temp: [Page, Temp]
+    /// assert_eq!(fast.decode_into(256, &[1, 0x5A, 0, 0, 0, 0, 0, 0], &mut values), Some(2));
+    /// assert_eq!(values[..2], [1.0, 50.0]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
     #[inline]
     pub fn decode_into(&self, id: u32, data: &[u8], out: &mut [f64]) -> Option<usize> {
         let plan_idx = self.get_plan_index(id)?;
@@ -219,6 +431,8 @@ impl FastDbc {
     }
 
     /// Decode a message by extended CAN ID into the output buffer.
+    ///
+    /// Signals are written in the order described for [`decode_into`](Self::decode_into).
     #[inline]
     pub fn decode_extended_into(&self, id: u32, data: &[u8], out: &mut [f64]) -> Option<usize> {
         let plan_idx = self.get_plan_index_extended(id)?;
@@ -232,6 +446,8 @@ impl FastDbc {
     }
 
     /// Decode raw values by standard CAN ID.
+    ///
+    /// Signals are written in the order described for [`decode_into`](Self::decode_into).
     #[inline]
     pub fn decode_raw_into(&self, id: u32, data: &[u8], out: &mut [i64]) -> Option<usize> {
         let plan_idx = self.get_plan_index(id)?;
@@ -244,6 +460,151 @@ impl FastDbc {
         Some(self.decode_raw_with_plan(plan, data, out))
     }
 
+    /// Decode a single signal by standard CAN ID and signal index.
+    ///
+    /// Extracts and scales only the requested signal, skipping all other signals
+    /// in the message. The signal index is the position of the signal within the
+    /// message (see [`signal_index`](Self::signal_index)). Multiplexing is not evaluated.
+    ///
+    /// # Returns
+    /// The physical value, or `None` if the message is not found, the index is out
+    /// of range, the signal lies beyond the transmitted length
+    /// ([`Message::effective_dlc`]), or the payload is too short.
+    #[inline]
+    pub fn decode_signal(&self, id: u32, signal_index: usize, data: &[u8]) -> Option<f64> {
+        let plan_idx = self.get_plan_index(id)?;
+        let plan = &self.inner.decode_plans[plan_idx];
+
+        if data.len() < plan.min_bytes as usize {
+            return None;
+        }
+
+        let sig = *plan.signals.get(signal_index).filter(|sig| sig.is_transmitted())?;
+        Some(self.apply_scaling(sig, self.extract_raw(sig, data)))
+    }
+
+    /// Decode a single signal by standard CAN ID and signal name.
+    ///
+    /// Resolves the signal index by name, then decodes like
+    /// [`decode_signal`](Self::decode_signal). For repeated decoding in a hot loop,
+    /// resolve the index once and use `decode_signal` directly.
+    #[inline]
+    pub fn decode_signal_by_name(&self, id: u32, name: &str, data: &[u8]) -> Option<f64> {
+        self.decode_signal(id, self.signal_index(id, name)?, data)
+    }
+
+    /// Write the signal indices [`decode_into`](Self::decode_into) decodes from `data`,
+    /// by standard CAN ID.
+    ///
+    /// `out[i]` receives the index, into [`signal_names`](Self::signal_names), of the value
+    /// `decode_into` writes to its `out[i]` for the same payload. For messages without
+    /// multiplexing this is `0, 1, 2, ...`; for multiplexed messages it lists the switches
+    /// followed by the active signals.
+    ///
+    /// # Returns
+    /// Number of indices written, or `None` if message not found or payload too short.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, FastDbc};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Status : 8 ECM
+    ///  SG_ Page M : 0|8@1+ (1,0) [0|1] "" *
+    ///  SG_ Speed m0 : 8|16@1+ (0.1,0) [0|6553.5] "km/h" *
+    ///  SG_ Temp m1 : 8|8@1- (1,-40) [-40|215] "C" *
+    /// "#)?;
+    /// let fast = FastDbc::new(dbc);
+    /// let names = fast.signal_names(256).unwrap();
+    /// let mut indices = vec![0; fast.max_signals()];
+    ///
+    /// let payload = [1, 0x5A, 0, 0, 0, 0, 0, 0];
+    /// let count = fast.active_signals_into(256, &payload, &mut indices).unwrap();
+    /// assert_eq!(indices[..count].iter().map(|&i| &names[i]).collect::<Vec<_>>(), ["Page", "Temp"]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    pub fn active_signals_into(&self, id: u32, data: &[u8], out: &mut [usize]) -> Option<usize> {
+        let plan_idx = self.get_plan_index(id)?;
+        let plan = &self.inner.decode_plans[plan_idx];
+
+        if data.len() < plan.min_bytes as usize {
+            return None;
+        }
+
+        let mut count = 0;
+        if plan.conditions.is_empty() {
+            for (out_val, position) in out.iter_mut().zip(0..plan.signals.len()) {
+                *out_val = position;
+                count += 1;
+            }
+        } else {
+            for (out_val, position) in out.iter_mut().zip(self.active_signals(plan, data)) {
+                *out_val = position;
+                count += 1;
+            }
+        }
+        Some(count)
+    }
+
+    // ========================================================================
+    // High-Speed Encode
+    // ========================================================================
+
+    /// Encode physical values into a payload by standard CAN ID.
+    ///
+    /// This is the inverse of [`decode_into`](Self::decode_into): `values` are in the order
+    /// `decode_into` writes them. For messages without multiplexing that is signal order;
+    /// for multiplexed messages the switches come first, followed by the signals selected
+    /// by those switch values, and inactive signals are not written. Each value is
+    /// converted with `(value - offset) / factor`, rounded to the nearest integer, clamped
+    /// to the signal's bit width and written with the pre-computed decode plan. Signals
+    /// without a value (when `values` is shorter) are left zero, as are bytes no signal
+    /// touches, so the payload is deterministic.
+    ///
+    /// # Returns
+    /// Number of bytes written (the bytes covered by the message's signals), or `None`
+    /// if the message is not found or `out` is too short.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, FastDbc};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///  SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+    /// "#)?;
+    /// let fast = FastDbc::new(dbc);
+    ///
+    /// let mut payload = [0xFF; 8];
+    /// assert_eq!(fast.encode_from(256, &[2000.0, 50.0], &mut payload), Some(3));
+    /// assert_eq!(payload[..3], [0x40, 0x1F, 0x5A]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    pub fn encode_from(&self, id: u32, values: &[f64], out: &mut [u8]) -> Option<usize> {
+        let plan_idx = self.get_plan_index(id)?;
+        self.encode_with_plan(&self.inner.decode_plans[plan_idx], values, out)
+    }
+
+    /// Encode physical values into a payload by extended CAN ID.
+    ///
+    /// See [`encode_from`](Self::encode_from).
+    #[inline]
+    pub fn encode_extended_from(&self, id: u32, values: &[f64], out: &mut [u8]) -> Option<usize> {
+        let plan_idx = self.get_plan_index_extended(id)?;
+        self.encode_with_plan(&self.inner.decode_plans[plan_idx], values, out)
+    }
+
     // ========================================================================
     // Internal Decode Implementation
     // ========================================================================
@@ -252,10 +613,18 @@ impl FastDbc {
     #[inline(always)]
     fn decode_with_plan(&self, plan: &DecodePlan, data: &[u8], out: &mut [f64]) -> usize {
         let mut count = 0;
-        for (out_val, sig) in out.iter_mut().zip(plan.signals.iter()) {
-            let raw = self.extract_raw(*sig, data);
-            *out_val = self.apply_scaling(*sig, raw);
-            count += 1;
+        if plan.conditions.is_empty() {
+            for (out_val, sig) in out.iter_mut().zip(plan.signals.iter()) {
+                let raw = self.extract_raw(*sig, data);
+                *out_val = self.apply_scaling(*sig, raw);
+                count += 1;
+            }
+        } else {
+            for (out_val, position) in out.iter_mut().zip(self.active_signals(plan, data)) {
+                let sig = plan.signals[position];
+                *out_val = self.apply_scaling(sig, self.extract_raw(sig, data));
+                count += 1;
+            }
         }
         count
     }
@@ -264,14 +633,60 @@ impl FastDbc {
     #[inline(always)]
     fn decode_raw_with_plan(&self, plan: &DecodePlan, data: &[u8], out: &mut [i64]) -> usize {
         let mut count = 0;
-        for (out_val, sig) in out.iter_mut().zip(plan.signals.iter()) {
-            *out_val = self.extract_raw(*sig, data);
-            count += 1;
+        if plan.conditions.is_empty() {
+            for (out_val, sig) in out.iter_mut().zip(plan.signals.iter()) {
+                *out_val = self.extract_raw(*sig, data);
+                count += 1;
+            }
+        } else {
+            for (out_val, position) in out.iter_mut().zip(self.active_signals(plan, data)) {
+                *out_val = self.extract_raw(plan.signals[position], data);
+                count += 1;
+            }
         }
         count
     }
 
-    /// Extract raw signed value from data.
+    /// Positions of the signals of a multiplexed message to decode from `data`: the
+    /// switches first, then the active signals, both in message order.
+    #[inline(always)]
+    fn active_signals<'a>(
+        &'a self,
+        plan: &'a DecodePlan,
+        data: &'a [u8],
+    ) -> impl Iterator<Item = usize> + 'a {
+        let active = plan.conditions.iter().enumerate().filter_map(move |(position, condition)| {
+            self.is_active(plan, condition, data).then_some(position)
+        });
+        plan.switches.iter().copied().chain(active)
+    }
+
+    /// Whether a non-switch signal with `condition` is decoded from `data`.
+    #[inline(always)]
+    fn is_active(&self, plan: &DecodePlan, condition: &MuxCondition, data: &[u8]) -> bool {
+        match condition {
+            MuxCondition::Always => true,
+            // Switches are decoded up front
+            MuxCondition::Switch | MuxCondition::Never => false,
+            MuxCondition::Value(value) => plan
+                .switches
+                .iter()
+                .any(|&switch| self.switch_value(plan, switch, data) == Some(*value)),
+            MuxCondition::Ranges(ranges) => ranges.iter().all(|(switch, ranges)| {
+                self.switch_value(plan, *switch, data).is_some_and(|value| {
+                    ranges.iter().any(|&(min, max)| value >= min && value <= max)
+                })
+            }),
+        }
+    }
+
+    /// Raw value of the switch at `position`, or `None` if it is negative.
+    #[inline(always)]
+    fn switch_value(&self, plan: &DecodePlan, position: usize, data: &[u8]) -> Option<u64> {
+        u64::try_from(self.extract_raw(plan.signals[position], data)).ok()
+    }
+
+    /// Extract raw signed value from data. Float and double signals return their bits.
     #[inline(always)]
     fn extract_raw(&self, sig: SignalDecode, data: &[u8]) -> i64 {
         let byte_order = if sig.is_little_endian() {
@@ -283,17 +698,95 @@ impl FastDbc {
         let start_bit = sig.byte_start as usize * 8 + sig.bit_offset as usize;
         let raw_bits = byte_order.extract_bits(data, start_bit, sig.length as usize);
 
-        if sig.is_unsigned() {
+        if sig.is_unsigned() || sig.value_type() != SignalValueType::Integer {
             raw_bits as i64
         } else {
             Self::sign_extend(raw_bits, sig.length as usize)
         }
     }
 
+    /// Encode using pre-computed plan.
+    #[inline(always)]
+    fn encode_with_plan(&self, plan: &DecodePlan, values: &[f64], out: &mut [u8]) -> Option<usize> {
+        let len = plan.min_bytes as usize;
+        let out = out.get_mut(..len)?;
+        out.fill(0);
+
+        // No multiplexing and every signal transmitted: values are in signal order
+        if plan.conditions.is_empty() {
+            for (&value, &sig) in values.iter().zip(plan.signals.iter()) {
+                self.encode_signal(sig, value, out);
+            }
+            return Some(len);
+        }
+
+        // Otherwise values follow `active_signals`: the switches first, then the signals
+        // selected by the switch values just written
+        let mut values = values.iter().copied();
+        for &switch in &plan.switches {
+            let Some(value) = values.next() else {
+                return Some(len);
+            };
+            self.encode_signal(plan.signals[switch], value, out);
+        }
+        for (position, condition) in plan.conditions.iter().enumerate() {
+            if !self.is_active(plan, condition, out) {
+                continue;
+            }
+            let Some(value) = values.next() else {
+                break;
+            };
+            self.encode_signal(plan.signals[position], value, out);
+        }
+        Some(len)
+    }
+
+    /// Convert a physical value to raw and write it into `out`.
+    #[inline(always)]
+    fn encode_signal(&self, sig: SignalDecode, value: f64, out: &mut [u8]) {
+        let byte_order = if sig.is_little_endian() {
+            ByteOrder::LittleEndian
+        } else {
+            ByteOrder::BigEndian
+        };
+        let start_bit = sig.byte_start as usize * 8 + sig.bit_offset as usize;
+        let raw = match sig.value_type().f64_to_bits((value - sig.offset) / sig.factor) {
+            Some(bits) => bits,
+            None => Self::clamp_raw(sig, self.remove_scaling(sig, value)),
+        };
+        byte_order.insert_bits(out, start_bit, sig.length as usize, raw);
+    }
+
+    /// Invert factor and offset scaling, rounding to the nearest raw value.
+    #[inline(always)]
+    fn remove_scaling(&self, sig: SignalDecode, value: f64) -> f64 {
+        if sig.is_identity() {
+            value.round()
+        } else {
+            ((value - sig.offset) / sig.factor).round()
+        }
+    }
+
+    /// Clamp a raw value to the signal's bit width, as two's complement bits.
+    #[inline(always)]
+    fn clamp_raw(sig: SignalDecode, raw: f64) -> u64 {
+        let bits = u32::from(sig.length).min(64);
+        let mask = u64::MAX >> (64 - bits);
+        if sig.is_unsigned() {
+            // Float-to-int casts saturate (NaN becomes 0)
+            (raw as u64).min(mask)
+        } else {
+            let max = (mask >> 1) as i64;
+            ((raw as i64).clamp(-max - 1, max) as u64) & mask
+        }
+    }
+
     /// Apply factor and offset scaling.
     #[inline(always)]
     fn apply_scaling(&self, sig: SignalDecode, raw: i64) -> f64 {
-        if sig.is_identity() {
+        if let Some(value) = sig.value_type().bits_to_f64(raw as u64) {
+            value * sig.factor + sig.offset
+        } else if sig.is_identity() {
             raw as f64
         } else {
             (raw as f64) * sig.factor + sig.offset
@@ -383,17 +876,50 @@ impl FastDbc {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<Dbc> for FastDbc {
     fn from(dbc: Dbc) -> Self {
         Self::new(dbc)
     }
 }
 
+/// Lets code written against `impl AsRef<Dbc>` accept both [`Dbc`] and [`FastDbc`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use dbc_rs::{Dbc, FastDbc};
+///
+/// fn message_names(dbc: impl AsRef<Dbc>) -> Vec<String> {
+///     dbc.as_ref().messages().iter().map(|m| m.name().to_string()).collect()
+/// }
+///
+/// let dbc = Dbc::parse(r#"VERSION "1.0"
+///
+/// BU_: ECM
+///
+/// BO_ 256 Engine : 8 ECM
+///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+/// "#)?;
+///
+/// assert_eq!(message_names(&dbc), ["Engine"]);
+/// let fast = FastDbc::new(dbc);
+/// assert_eq!(message_names(&fast), ["Engine"]);
+/// # Ok::<(), dbc_rs::Error>(())
+/// ```
+#[cfg(feature = "std")]
+impl AsRef<Dbc> for FastDbc {
+    #[inline]
+    fn as_ref(&self) -> &Dbc {
+        self.dbc()
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -528,6 +1054,85 @@ BO_ 2147484672 ExtendedMsg : 8 ECM
         assert_eq!(values[0], 100.0); // 1000 * 0.1
     }
 
+    #[test]
+    fn test_fast_dbc_resolve() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (1,0) [0|8000] "rpm" *
+
+BO_ 2147484672 ExtendedMsg : 8 ECM
+ SG_ Speed : 0|16@1+ (0.1,0) [0|6553.5] "km/h" *
+
+BO_ 2147483904 ExtendedSameBase : 8 ECM
+ SG_ Value : 0|8@1+ (1,0) [0|255] "" *
+
+BO_ 3221225472 VECTOR__INDEPENDENT_SIG_MSG: 0 Vector__XXX
+ SG_ Orphan : 0|8@1+ (1,0) [0|255] "" Vector__XXX
+
+BO_ 2147483648 ExtendedZero : 8 ECM
+"#,
+        )
+        .unwrap();
+        let fast = FastDbc::new(dbc);
+
+        // Standard ID
+        assert_eq!(fast.resolve(256), Some((0, false)));
+        // Extended ID without and with the flag bit
+        assert_eq!(fast.resolve(0x400), Some((1, true)));
+        assert_eq!(fast.resolve(0x8000_0400), Some((1, true)));
+        // Standard wins over an extended ID with the same value, unless flagged
+        assert_eq!(fast.resolve(0x100), Some((0, false)));
+        assert_eq!(fast.resolve(0x8000_0100), Some((2, true)));
+
+        assert_eq!(fast.resolve(0x200), None);
+        assert_eq!(fast.resolve(0x8000_0200), None);
+        // The pseudo-message is not on the bus, and reserved bits are not masked away
+        assert_eq!(fast.resolve(0x8000_0000), Some((4, true)));
+        assert_eq!(fast.resolve(0xC000_0000), None);
+        assert_eq!(fast.resolve(0xA000_0000), None);
+        assert_eq!(fast.resolve(0x4000_0400), None);
+
+        let (idx, _) = fast.resolve(0x8000_0400).unwrap();
+        assert_eq!(fast.dbc().messages().at(idx).unwrap().name(), "ExtendedMsg");
+        assert_eq!(fast.get_any(0x400).unwrap().name(), "ExtendedMsg");
+    }
+
+    #[test]
+    fn test_fast_dbc_pseudo_message() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+
+BO_ 3221225472 VECTOR__INDEPENDENT_SIG_MSG: 0 Vector__XXX
+ SG_ TireTemp : 0|16@1+ (0.03125,-273) [-273|1734.96875] "deg C" Vector__XXX
+"#,
+        )
+        .unwrap();
+
+        let fast = FastDbc::new(dbc);
+
+        assert_eq!(fast.message_count(), 2);
+        let msg = fast.pseudo_message().unwrap();
+        assert_eq!(msg.name(), "VECTOR__INDEPENDENT_SIG_MSG");
+        assert_eq!(msg.signals().len(), 1);
+
+        // Not reachable as a regular standard or extended ID
+        assert!(!fast.contains(0));
+        assert!(!fast.contains_extended(0));
+        assert!(fast.ids().any(|id| id == 0xC000_0000));
+
+        let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n").unwrap();
+        assert!(FastDbc::new(dbc).pseudo_message().is_none());
+    }
+
     #[test]
     fn test_fast_dbc_multiple_messages() {
         let dbc = Dbc::parse(
@@ -668,4 +1273,475 @@ BO_ 256 Engine : 8 ECM
         assert_eq!(count, 1);
         assert_eq!(raw_values[0], 8000); // Raw before factor
     }
+
+    #[test]
+    fn test_fast_dbc_decode_signal_matches_full_decode() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+ SG_ Speed : 24|16@1+ (0.1,0) [0|6553.5] "km/h" *
+"#,
+        )
+        .unwrap();
+
+        let fast = FastDbc::new(dbc);
+
+        let payload = [0x40, 0x1F, 0x5A, 0xE8, 0x03, 0x00, 0x00, 0x00];
+        let mut values = vec![0.0f64; fast.max_signals()];
+        let count = fast.decode_into(256, &payload, &mut values).unwrap();
+
+        for (index, expected) in values.iter().take(count).enumerate() {
+            assert_eq!(fast.decode_signal(256, index, &payload), Some(*expected));
+        }
+        assert_eq!(
+            fast.decode_signal_by_name(256, "Speed", &payload),
+            Some(values[2])
+        );
+        assert_eq!(
+            fast.decode_signal_by_name(256, "Temp", &payload),
+            Some(50.0)
+        );
+    }
+
+    #[test]
+    fn test_fast_dbc_signal_index_matches_decode_into() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+ SG_ Speed : 24|16@1+ (0.1,0) [0|6553.5] "km/h" *
+"#,
+        )
+        .unwrap();
+
+        let fast = FastDbc::new(dbc);
+        let payload = [0x40, 0x1F, 0x5A, 0xE8, 0x03, 0x00, 0x00, 0x00];
+        let mut values = vec![0.0f64; fast.max_signals()];
+        fast.decode_into(256, &payload, &mut values).unwrap();
+
+        let full = fast.dbc().decode(256, &payload, false).unwrap();
+        for signal in &full {
+            let index = fast.signal_index(256, signal.name).unwrap();
+            assert_eq!(values[index], signal.value, "{}", signal.name);
+        }
+        assert_eq!(fast.signal_index(256, "Speed"), Some(2));
+        assert_eq!(fast.signal_index(256, "Missing"), None);
+        assert_eq!(fast.signal_index(512, "RPM"), None);
+    }
+
+    #[cfg(feature = "attributes")]
+    #[test]
+    fn test_fast_dbc_skips_untransmitted_signals() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+ SG_ Reserved : 48|16@1+ (1,0) [0|65535] "" *
+
+BA_DEF_ BO_ "GenMsgLength" INT 0 64;
+BA_ "GenMsgLength" BO_ 256 3;
+"#,
+        )
+        .unwrap();
+        let payload = [0x40, 0x1F, 0x5A];
+        let expected = dbc.decode(256, &payload, false).unwrap();
+        assert_eq!(expected.len(), 2);
+
+        let fast = FastDbc::new(dbc);
+        let mut values = [-1.0f64; 4];
+        assert_eq!(fast.decode_into(256, &payload, &mut values), Some(2));
+        assert_eq!(values, [2000.0, 50.0, -1.0, -1.0]);
+        let mut raw = [-1i64; 4];
+        assert_eq!(fast.decode_raw_into(256, &payload, &mut raw), Some(2));
+        assert_eq!(raw[..2], [8000, 90]);
+        let mut indices = [usize::MAX; 4];
+        assert_eq!(
+            fast.active_signals_into(256, &payload, &mut indices),
+            Some(2)
+        );
+        assert_eq!(indices[..2], [0, 1]);
+
+        assert_eq!(fast.decode_signal(256, 1, &payload), Some(50.0));
+        assert_eq!(
+            fast.decode_signal(256, 2, &[0, 0, 0, 0, 0, 0, 0xFF, 0xFF]),
+            None
+        );
+        assert_eq!(fast.decode_signal_by_name(256, "Reserved", &payload), None);
+
+        // Encoding writes the transmitted bytes only
+        let mut out = [0xFF; 8];
+        assert_eq!(
+            fast.encode_from(256, &[2000.0, 50.0, 1.0], &mut out),
+            Some(3)
+        );
+        assert_eq!(out[..3], [0x40, 0x1F, 0x5A]);
+    }
+
+    #[test]
+    fn test_fast_dbc_signal_names() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+
+BO_ 300 Diag : 8 ECM
+ SG_ Counter : 56|8@1+ (1,0) [0|255] "" *
+ SG_ Speed m0 : 8|16@1+ (0.1,0) [0|6553.5] "km/h" *
+ SG_ Page M : 0|8@1+ (1,0) [0|255] "" *
+ SG_ Temp m1 : 8|8@1- (1,-40) [-40|215] "C" *
+
+BO_ 2147484672 ExtMsg : 8 ECM
+ SG_ Value : 0|8@1+ (1,0) [0|255] "" *
+"#,
+        )
+        .unwrap();
+
+        for fast in [FastDbc::new(dbc.clone()), FastDbc::new_minimal(dbc.clone())] {
+            assert_eq!(fast.signal_names(256).unwrap(), ["RPM", "Temp"]);
+            assert_eq!(fast.signal_names_extended(0x400).unwrap(), ["Value"]);
+            assert_eq!(fast.signal_names(512), None);
+
+            // Names zip with the decoded values and match the full decoder
+            let payload = [0x40, 0x1F, 0x5A, 0x00, 0x00, 0x00, 0x00, 0x00];
+            let mut values = vec![0.0f64; fast.max_signals()];
+            let mut indices = vec![0usize; fast.max_signals()];
+            let count = fast.decode_into(256, &payload, &mut values).unwrap();
+            assert_eq!(
+                fast.active_signals_into(256, &payload, &mut indices),
+                Some(count)
+            );
+            assert_eq!(indices[..count], [0, 1]);
+            let decoded = dbc.decode(256, &payload, false).unwrap();
+            for ((name, value), signal) in
+                fast.signal_names(256).unwrap().iter().zip(&values[..count]).zip(&decoded)
+            {
+                assert_eq!((name.as_str(), *value), (signal.name, signal.value));
+            }
+
+            // Multiplexed output maps to names through the active signal indices
+            let names = fast.signal_names(300).unwrap();
+            assert_eq!(names, ["Counter", "Speed", "Page", "Temp"]);
+            let payload = [0x01, 0x5A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07];
+            let count = fast.decode_into(300, &payload, &mut values).unwrap();
+            assert_eq!(
+                fast.active_signals_into(300, &payload, &mut indices),
+                Some(count)
+            );
+            let active: Vec<&str> = indices[..count].iter().map(|&i| names[i].as_str()).collect();
+            assert_eq!(active, ["Page", "Counter", "Temp"]);
+            let decoded = dbc.decode(300, &payload, false).unwrap();
+            assert!(decoded.iter().map(|s| s.name).eq(active.iter().copied()));
+            assert_eq!(
+                fast.active_signals_into(300, &payload[..1], &mut indices),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_fast_dbc_decode_signal_not_found() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+"#,
+        )
+        .unwrap();
+
+        let fast = FastDbc::new(dbc);
+        let payload = [0x00; 8];
+
+        assert!(fast.decode_signal(256, 1, &payload).is_none());
+        assert!(fast.decode_signal(512, 0, &payload).is_none());
+        assert!(fast.decode_signal(256, 0, &payload[..1]).is_none());
+        assert!(fast.decode_signal_by_name(256, "Missing", &payload).is_none());
+    }
+
+    #[test]
+    fn test_fast_dbc_encode_from_round_trip() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+ SG_ Pressure : 31|12@0+ (0.1,0) [0|409.5] "kPa" *
+
+BO_ 2147484672 Brake : 2 ECM
+ SG_ Torque : 0|16@1- (0.5,0) [-16384|16383.5] "Nm" *
+"#,
+        )
+        .unwrap();
+        let fast = FastDbc::new(dbc);
+
+        let mut payload = [0xAA; 8];
+        assert_eq!(
+            fast.encode_from(256, &[2000.0, -25.0, 123.4], &mut payload),
+            Some(5)
+        );
+        // Bytes beyond the signals are untouched; bytes within are rebuilt from zero
+        assert_eq!(payload[5..], [0xAA; 3]);
+
+        let mut values = [0.0; 3];
+        assert_eq!(fast.decode_into(256, &payload, &mut values), Some(3));
+        assert_eq!(values[0], 2000.0);
+        assert_eq!(values[1], -25.0);
+        assert!((values[2] - 123.4).abs() < 1e-9);
+
+        // Out-of-range values are clamped to the bit width
+        fast.encode_from(256, &[1e9, -1e9, -5.0], &mut payload).unwrap();
+        let mut raw = [0i64; 3];
+        fast.decode_raw_into(256, &payload, &mut raw).unwrap();
+        assert_eq!(raw, [0xFFFF, -128, 0]);
+
+        // Extended IDs, and the not-found and short-buffer cases
+        let mut payload = [0u8; 2];
+        assert_eq!(
+            fast.encode_extended_from(0x400, &[-100.0], &mut payload),
+            Some(2)
+        );
+        assert_eq!(payload, (-200i16).to_le_bytes());
+        assert_eq!(fast.encode_from(0x400, &[0.0], &mut payload), None);
+        assert_eq!(fast.encode_from(256, &[0.0], &mut payload), None);
+    }
+
+    #[test]
+    fn test_fast_dbc_decode_multiplexed() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 300 Diag : 8 ECM
+ SG_ Counter : 56|8@1+ (1,0) [0|255] "" *
+ SG_ Speed m0 : 8|16@1+ (0.1,0) [0|6553.5] "km/h" *
+ SG_ Page M : 0|8@1+ (1,0) [0|255] "" *
+ SG_ Temp m1 : 8|8@1- (1,-40) [-40|215] "C" *
+ SG_ Pressure m1 : 16|16@1+ (0.5,0) [0|32767.5] "kPa" *
+"#,
+        )
+        .unwrap();
+        let fast = FastDbc::new(dbc.clone());
+        let mut values = vec![0.0f64; fast.max_signals()];
+        let mut raw = vec![0i64; fast.max_signals()];
+
+        // Page 0: [Page, Counter, Speed]
+        let payload = [0x00, 0xE8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x07];
+        assert_eq!(fast.decode_into(300, &payload, &mut values), Some(3));
+        assert_eq!(values[..3], [0.0, 7.0, 100.0]);
+        assert_eq!(fast.decode_raw_into(300, &payload, &mut raw), Some(3));
+        assert_eq!(raw[..3], [0, 7, 1000]);
+
+        // Page 1: [Page, Counter, Temp, Pressure]
+        let payload = [0x01, 0x5A, 0xC8, 0x00, 0x00, 0x00, 0x00, 0x07];
+        assert_eq!(fast.decode_into(300, &payload, &mut values), Some(4));
+        assert_eq!(values[..4], [1.0, 7.0, 50.0, 100.0]);
+
+        // Same signals and order as the full decoder
+        let decoded = dbc.decode(300, &payload, false).unwrap();
+        let names: Vec<&str> = decoded.iter().map(|s| s.name).collect();
+        assert_eq!(names, ["Page", "Counter", "Temp", "Pressure"]);
+        assert!(decoded.iter().zip(&values).all(|(signal, &value)| signal.value == value));
+
+        // Page 2 selects no multiplexed signals
+        let payload = [0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07];
+        assert_eq!(fast.decode_into(300, &payload, &mut values), Some(2));
+        assert_eq!(values[..2], [2.0, 7.0]);
+
+        // A short buffer receives the leading signals only
+        let payload = [0x01, 0x5A, 0xC8, 0x00, 0x00, 0x00, 0x00, 0x07];
+        assert_eq!(fast.decode_into(300, &payload, &mut values[..3]), Some(3));
+        assert_eq!(values[..3], [1.0, 7.0, 50.0]);
+    }
+
+    #[test]
+    fn test_fast_dbc_encode_from_multiplexed_round_trip() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 300 Diag : 8 ECM
+ SG_ Counter : 56|8@1+ (1,0) [0|255] "" *
+ SG_ Speed m0 : 8|16@1+ (0.1,0) [0|6553.5] "km/h" *
+ SG_ Page M : 0|8@1+ (1,0) [0|255] "" *
+ SG_ Temp m1 : 8|8@1- (1,-40) [-40|215] "C" *
+ SG_ Pressure m1 : 16|16@1+ (0.5,0) [0|32767.5] "kPa" *
+"#,
+        )
+        .unwrap();
+        let fast = FastDbc::new(dbc);
+        let mut payload = [0xFF; 8];
+        let mut values = [0.0f64; 4];
+
+        // Page 1: [Page, Counter, Temp, Pressure]; Speed overlaps Temp and is not written
+        assert_eq!(
+            fast.encode_from(300, &[1.0, 7.0, 50.0, 100.0], &mut payload),
+            Some(8)
+        );
+        assert_eq!(payload, [0x01, 0x5A, 0xC8, 0x00, 0x00, 0x00, 0x00, 0x07]);
+        assert_eq!(fast.decode_into(300, &payload, &mut values), Some(4));
+        assert_eq!(values, [1.0, 7.0, 50.0, 100.0]);
+
+        // Page 0: [Page, Counter, Speed]
+        assert_eq!(
+            fast.encode_from(300, &[0.0, 7.0, 100.0], &mut payload),
+            Some(8)
+        );
+        assert_eq!(payload, [0x00, 0xE8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x07]);
+        assert_eq!(fast.decode_into(300, &payload, &mut values), Some(3));
+        assert_eq!(values[..3], [0.0, 7.0, 100.0]);
+    }
+
+    #[test]
+    fn test_fast_dbc_float_signals() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 400 Sensor : 8 ECM
+ SG_ Page M : 0|8@1+ (1,0) [0|255] "" *
+ SG_ Torque m0 : 32|32@1- (2,1) [-1000|1000] "Nm" *
+ SG_ Count m1 : 32|32@1- (1,0) [-1000|1000] "" *
+
+BO_ 401 Precise : 8 ECM
+ SG_ Position : 0|64@1- (1,0) [-1000000|1000000] "m" *
+
+SIG_VALTYPE_ 400 Torque : 1;
+SIG_VALTYPE_ 401 Position : 2;
+"#,
+        )
+        .unwrap();
+        let fast = FastDbc::new(dbc.clone());
+        let mut values = [0.0f64; 2];
+
+        // Page 0 selects the float signal: 1.25 * 2 + 1
+        let mut payload = [0u8; 8];
+        payload[4..].copy_from_slice(&1.25f32.to_le_bytes());
+        assert_eq!(fast.decode_into(400, &payload, &mut values), Some(2));
+        assert_eq!(values, [0.0, 3.5]);
+        let decoded = dbc.decode(400, &payload, false).unwrap();
+        assert!(decoded.iter().zip(&values).all(|(signal, &value)| signal.value == value));
+
+        // Page 1 reads the same bits as a signed integer
+        payload[0] = 1;
+        assert_eq!(fast.decode_into(400, &payload, &mut values), Some(2));
+        assert_eq!(values, [1.0, f64::from(1.25f32.to_bits() as i32)]);
+
+        // Doubles round trip exactly and decode_raw returns their bits
+        let mut payload = [0u8; 8];
+        assert_eq!(fast.encode_from(401, &[-123.456], &mut payload), Some(8));
+        assert_eq!(payload, (-123.456f64).to_le_bytes());
+        assert_eq!(
+            fast.decode_signal_by_name(401, "Position", &payload),
+            Some(-123.456)
+        );
+        let mut raw = [0i64; 1];
+        fast.decode_raw_into(401, &payload, &mut raw).unwrap();
+        assert_eq!(raw[0], (-123.456f64).to_bits() as i64);
+    }
+
+    #[test]
+    fn test_fast_dbc_decode_extended_multiplexing() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 500 ComplexMux : 8 ECM
+ SG_ Mux1 M : 0|8@1+ (1,0) [0|255] "" *
+ SG_ Signal_A m0 : 16|16@1+ (0.1,0) [0|100] "" *
+ SG_ Signal_B m0 : 32|8@1+ (1,0) [0|255] "" *
+
+SG_MUL_VAL_ 500 Signal_A Mux1 0-5,10-15 ;
+"#,
+        )
+        .unwrap();
+
+        for fast in [FastDbc::new(dbc.clone()), FastDbc::new_minimal(dbc.clone())] {
+            let mut values = vec![0.0f64; fast.max_signals()];
+
+            // Mux1 = 12: Signal_A by its SG_MUL_VAL_ range, Signal_B (m0) is inactive
+            let payload = [12, 0x00, 0x64, 0x00, 0x2A, 0x00, 0x00, 0x00];
+            assert_eq!(fast.decode_into(500, &payload, &mut values), Some(2));
+            assert_eq!(values[..2], [12.0, 10.0]);
+            assert_eq!(dbc.decode(500, &payload, false).unwrap().len(), 2);
+
+            // Mux1 = 0: both
+            let payload = [0, 0x00, 0x64, 0x00, 0x2A, 0x00, 0x00, 0x00];
+            assert_eq!(fast.decode_into(500, &payload, &mut values), Some(3));
+            assert_eq!(values[..3], [0.0, 10.0, 42.0]);
+
+            // Mux1 = 7: neither
+            let payload = [7, 0x00, 0x64, 0x00, 0x2A, 0x00, 0x00, 0x00];
+            assert_eq!(fast.decode_into(500, &payload, &mut values), Some(1));
+        }
+    }
+
+    #[test]
+    fn test_fast_dbc_new_minimal() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Gear : 16|8@1+ (1,0) [0|5] "" *
+
+CM_ "Powertrain bus";
+CM_ BO_ 256 "Engine status";
+CM_ SG_ 256 RPM "Engine speed";
+VAL_ 256 Gear 0 "Park" 1 "Drive" ;
+"#,
+        )
+        .unwrap();
+
+        let full = FastDbc::new(dbc.clone());
+        let minimal = FastDbc::new_minimal(dbc);
+
+        // Decoding is unaffected
+        let payload = [0x40, 0x1F, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let mut full_values = vec![0.0f64; full.max_signals()];
+        let mut minimal_values = vec![0.0f64; minimal.max_signals()];
+        assert_eq!(
+            full.decode_into(256, &payload, &mut full_values),
+            minimal.decode_into(256, &payload, &mut minimal_values)
+        );
+        assert_eq!(full_values, minimal_values);
+
+        // Names and units survive, descriptive metadata does not
+        let message = minimal.get(256).unwrap();
+        assert_eq!(message.name(), "Engine");
+        assert_eq!(message.comment(), None);
+        let rpm = message.signals().find("RPM").unwrap();
+        assert_eq!(rpm.unit(), Some("rpm"));
+        assert_eq!(rpm.comment(), None);
+        assert_eq!(minimal.dbc().comment(), None);
+        assert!(minimal.dbc().value_descriptions_for_signal(256, "Gear").is_none());
+        assert!(full.dbc().value_descriptions_for_signal(256, "Gear").is_some());
+    }
 }
@@ -0,0 +1,320 @@
+//! `no_std` fast lookup wrapper backed by fixed-capacity storage.
+
+use super::MAX_STANDARD_ID;
+use crate::{Dbc, MAX_MESSAGES, Message};
+use heapless::index_map::FnvIndexMap;
+
+/// Sentinel marking an unused slot in the standard ID table.
+const NO_MESSAGE: u16 = u16::MAX;
+
+// Message indices are stored as `u16` to keep the standard ID table small.
+const _: () = assert!(MAX_MESSAGES < NO_MESSAGE as usize);
+
+// `FnvIndexMap` needs a power-of-two capacity; build.rs rejects other `DBC_MAX_MESSAGES`
+// values with `heapless`, this keeps the requirement visible where the map is declared.
+const _: () = assert!(MAX_MESSAGES.is_power_of_two());
+
+/// `no_std` counterpart of `FastDbc` for embedded targets.
+///
+/// Standard CAN IDs below `N` are resolved through a direct lookup table stored inline,
+/// all other IDs through a fixed-capacity hash map. No heap allocation is performed.
+/// Decoding writes the same signals in the same order as `FastDbc::decode_into`: the
+/// multiplexer switches first, then the signals active for the switch values.
+///
+/// The default `N` covers the full 11-bit range (2048 entries, 4 KiB). Lower it to
+/// trade lookup speed for memory when only small IDs are in use.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use dbc_rs::{Dbc, HeaplessFastDbc};
+///
+/// let dbc = Dbc::parse(content)?;
+/// let fast: HeaplessFastDbc = HeaplessFastDbc::new(dbc);
+///
+/// let mut values = [0.0f64; 16];
+/// if let Some(count) = fast.decode_into(id, &payload, &mut values) {
+///     // values[0..count] contains physical values
+/// }
+/// ```
+pub struct HeaplessFastDbc<const N: usize = MAX_STANDARD_ID> {
+    /// The underlying DBC
+    dbc: Dbc,
+    /// Direct lookup table for standard CAN IDs below `N`
+    /// Value is the message index, or `NO_MESSAGE` if not present
+    standard_ids: [u16; N],
+    /// Hash map for extended CAN IDs and standard IDs >= `N`
+    extended_ids: FnvIndexMap<u32, u16, { MAX_MESSAGES }>,
+}
+
+impl<const N: usize> core::fmt::Debug for HeaplessFastDbc<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HeaplessFastDbc")
+            .field("message_count", &self.message_count())
+            .finish()
+    }
+}
+
+impl<const N: usize> HeaplessFastDbc<N> {
+    /// Create a new wrapper from a Dbc, building the ID lookup tables.
+    pub fn new(dbc: Dbc) -> Self {
+        let mut standard_ids = [NO_MESSAGE; N];
+        let mut extended_ids = FnvIndexMap::new();
+
+        for (msg_idx, msg) in dbc.messages().iter().enumerate() {
+            // Cannot truncate: `msg_idx < MAX_MESSAGES < NO_MESSAGE`
+            let msg_idx = msg_idx as u16;
+            let id = msg.id_with_flag();
+            if !msg.is_extended() && (id as usize) < N {
+                standard_ids[id as usize] = msg_idx;
+            } else {
+                // Cannot fail: the map has room for `MAX_MESSAGES` entries
+                let _ = extended_ids.insert(id, msg_idx);
+            }
+        }
+
+        Self {
+            dbc,
+            standard_ids,
+            extended_ids,
+        }
+    }
+
+    // ========================================================================
+    // Message Lookup
+    // ========================================================================
+
+    /// Get message index for a standard CAN ID.
+    #[inline(always)]
+    fn get_index(&self, id: u32) -> Option<usize> {
+        if (id as usize) < N {
+            let idx = self.standard_ids[id as usize];
+            if idx != NO_MESSAGE {
+                Some(idx as usize)
+            } else {
+                None
+            }
+        } else {
+            self.extended_ids.get(&id).map(|&idx| idx as usize)
+        }
+    }
+
+    /// Get message index for an extended CAN ID.
+    #[inline(always)]
+    fn get_index_extended(&self, id: u32) -> Option<usize> {
+        let extended_id = id | Message::EXTENDED_ID_FLAG;
+        self.extended_ids.get(&extended_id).map(|&idx| idx as usize)
+    }
+
+    /// Get a message by standard (11-bit) CAN ID.
+    #[inline]
+    pub fn get(&self, id: u32) -> Option<&Message> {
+        self.get_index(id).and_then(|idx| self.dbc.messages().at(idx))
+    }
+
+    /// Get a message by extended (29-bit) CAN ID.
+    #[inline]
+    pub fn get_extended(&self, id: u32) -> Option<&Message> {
+        self.get_index_extended(id).and_then(|idx| self.dbc.messages().at(idx))
+    }
+
+    /// Get a message by CAN ID, trying extended if standard not found.
+    #[inline]
+    pub fn get_any(&self, id: u32) -> Option<&Message> {
+        self.get(id).or_else(|| self.get_extended(id))
+    }
+
+    /// Get the `VECTOR__INDEPENDENT_SIG_MSG` pseudo-message (ID `0xC0000000`), if present.
+    ///
+    /// See `FastDbc::pseudo_message` for details.
+    #[inline]
+    pub fn pseudo_message(&self) -> Option<&Message> {
+        self.extended_ids
+            .get(&Message::PSEUDO_MESSAGE_ID)
+            .and_then(|&idx| self.dbc.messages().at(idx as usize))
+    }
+
+    // ========================================================================
+    // Decode
+    // ========================================================================
+
+    /// Decode a message by standard CAN ID into the output buffer.
+    ///
+    /// Multiplexer switches are written first, followed by the signals active for the
+    /// decoded switch values, as by [`Dbc::decode`]. Signals beyond `out.len()` are
+    /// dropped.
+    ///
+    /// # Returns
+    /// Number of signals decoded, or `None` if message not found, payload too short or a
+    /// multiplexer switch is negative.
+    #[inline]
+    pub fn decode_into(&self, id: u32, data: &[u8], out: &mut [f64]) -> Option<usize> {
+        self.decode_message(self.get(id)?, data, out)
+    }
+
+    /// Decode a message by extended CAN ID into the output buffer.
+    #[inline]
+    pub fn decode_extended_into(&self, id: u32, data: &[u8], out: &mut [f64]) -> Option<usize> {
+        self.decode_message(self.get_extended(id)?, data, out)
+    }
+
+    #[inline(always)]
+    fn decode_message(&self, message: &Message, data: &[u8], out: &mut [f64]) -> Option<usize> {
+        let mut count = 0;
+        self.dbc
+            .decode_message_each(message, data, |signal| {
+                if let Some(slot) = out.get_mut(count) {
+                    *slot = signal.value;
+                    count += 1;
+                }
+                Ok(())
+            })
+            .ok()?;
+        Some(count)
+    }
+
+    // ========================================================================
+    // Accessors
+    // ========================================================================
+
+    /// Get the number of messages.
+    #[inline]
+    pub fn message_count(&self) -> usize {
+        self.dbc.messages().len()
+    }
+
+    /// Check if a message with this standard CAN ID exists.
+    #[inline]
+    pub fn contains(&self, id: u32) -> bool {
+        self.get_index(id).is_some()
+    }
+
+    /// Check if a message with this extended CAN ID exists.
+    #[inline]
+    pub fn contains_extended(&self, id: u32) -> bool {
+        self.get_index_extended(id).is_some()
+    }
+
+    /// Get the underlying Dbc.
+    #[inline]
+    pub fn dbc(&self) -> &Dbc {
+        &self.dbc
+    }
+
+    /// Consume and return the underlying Dbc.
+    #[inline]
+    pub fn into_dbc(self) -> Dbc {
+        self.dbc
+    }
+}
+
+impl<const N: usize> From<Dbc> for HeaplessFastDbc<N> {
+    fn from(dbc: Dbc) -> Self {
+        Self::new(dbc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DBC: &str = r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+
+BO_ 3000 LargeId : 8 ECM
+ SG_ Value : 0|16@1+ (1,0) [0|65535] "" *
+
+BO_ 2147484672 ExtendedMsg : 8 ECM
+ SG_ Speed : 0|16@1+ (0.1,0) [0|6553.5] "km/h" *
+
+BO_ 3221225472 VECTOR__INDEPENDENT_SIG_MSG: 0 Vector__XXX
+ SG_ TireTemp : 0|16@1+ (0.03125,-273) [-273|1734.96875] "deg C" Vector__XXX
+"#;
+
+    #[test]
+    fn test_heapless_fast_dbc_decode_into() {
+        let fast: HeaplessFastDbc = HeaplessFastDbc::new(Dbc::parse(DBC).unwrap());
+
+        assert_eq!(fast.message_count(), 4);
+        assert!(fast.contains(256));
+        assert!(!fast.contains(512));
+        assert_eq!(fast.get(256).unwrap().name(), "Engine");
+
+        // RPM = 2000 (raw 8000), Temp = 50°C (raw 90)
+        let payload = [0x40, 0x1F, 0x5A, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let mut values = [0.0f64; 4];
+
+        assert_eq!(fast.decode_into(256, &payload, &mut values), Some(2));
+        assert_eq!(values[0], 2000.0);
+        assert_eq!(values[1], 50.0);
+
+        assert!(fast.decode_into(512, &payload, &mut values).is_none());
+        assert!(fast.decode_into(256, &payload[..1], &mut values).is_none());
+    }
+
+    #[test]
+    fn test_heapless_fast_dbc_decode_multiplexed() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 400 MuxMsg : 8 ECM
+ SG_ Mode M : 0|8@1+ (1,0) [0|255] "" *
+ SG_ Speed m0 : 8|16@1+ (0.1,0) [0|6553.5] "km/h" *
+ SG_ Temp m1 : 8|8@1- (1,-40) [-40|215] "C" *
+ SG_ Counter : 56|8@1+ (1,0) [0|255] "" *
+"#,
+        )
+        .unwrap();
+        let fast: HeaplessFastDbc = HeaplessFastDbc::new(dbc);
+        let mut values = [0.0f64; 4];
+
+        // Mode 1: Speed is inactive, so only Mode, Temp and Counter are written
+        let payload = [0x01, 0x5A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07];
+        assert_eq!(fast.decode_into(400, &payload, &mut values), Some(3));
+        assert_eq!(values[..3], [1.0, 50.0, 7.0]);
+
+        // Mode 0: Speed replaces Temp
+        let payload = [0x00, 0xE8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x07];
+        assert_eq!(fast.decode_into(400, &payload, &mut values), Some(3));
+        assert_eq!(values[..3], [0.0, 100.0, 7.0]);
+
+        // A short output buffer keeps the leading signals
+        let mut short = [0.0f64; 2];
+        assert_eq!(fast.decode_into(400, &payload, &mut short), Some(2));
+        assert_eq!(short, [0.0, 100.0]);
+    }
+
+    #[test]
+    fn test_heapless_fast_dbc_large_and_extended_ids() {
+        // A small table pushes ID 256 into the map as well
+        let fast: HeaplessFastDbc<128> = HeaplessFastDbc::new(Dbc::parse(DBC).unwrap());
+
+        assert!(fast.contains(256));
+        assert!(fast.contains(3000));
+        assert!(!fast.contains(0x400));
+        assert!(fast.contains_extended(0x400));
+        assert_eq!(fast.get_any(0x400).unwrap().name(), "ExtendedMsg");
+        assert_eq!(
+            fast.pseudo_message().unwrap().name(),
+            "VECTOR__INDEPENDENT_SIG_MSG"
+        );
+
+        let payload = [0xE8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let mut values = [0.0f64; 1];
+
+        assert_eq!(fast.decode_into(3000, &payload, &mut values), Some(1));
+        assert_eq!(values[0], 1000.0);
+        assert_eq!(
+            fast.decode_extended_into(0x400, &payload, &mut values),
+            Some(1)
+        );
+        assert_eq!(values[0], 100.0);
+    }
+}
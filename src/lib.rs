@@ -42,13 +42,19 @@ mod bit_timing;
 mod byte_order;
 mod compat;
 mod dbc;
+mod environment_variable;
 mod error;
 mod extended_multiplexing;
+#[cfg(feature = "std")]
+mod hasher;
 mod message;
 mod nodes;
 mod parser;
 mod receivers;
 mod signal;
+mod signal_group;
+mod signal_type;
+mod signal_type_ref;
 mod value_descriptions;
 mod version;
 
@@ -57,8 +63,8 @@ mod version;
 // and have no runtime dependencies. Builders themselves are std-only.
 mod macros;
 
-// High-performance wrapper (std-only)
-#[cfg(feature = "std")]
+// High-performance wrapper (std, or heapless for the `no_std` variant)
+#[cfg(any(feature = "std", feature = "heapless"))]
 mod fast_dbc;
 
 #[cfg(feature = "attributes")]
@@ -67,13 +73,28 @@ pub use attribute::{
 };
 pub use bit_timing::BitTiming;
 pub use byte_order::ByteOrder;
-pub use dbc::{Dbc, DecodedSignal};
+#[cfg(feature = "encoding")]
+pub use dbc::Encoding;
+pub use dbc::{
+    ChecksumAlgorithm, Dbc, DbcEvents, DbcStats, DecodedFrame, DecodedSignal, FrameMeta,
+    ParseEvent, ParseOptions,
+};
+#[cfg(feature = "std")]
+pub use dbc::{DbcDiff, MessageDiff, SignalDiff};
+#[cfg(feature = "std")]
+pub use dbc::{SerializeOptions, SignalRef};
+pub use environment_variable::{
+    EnvironmentVariable, EnvironmentVariableAccess, EnvironmentVariableType,
+};
 pub use error::{Error, Result};
 pub use extended_multiplexing::ExtendedMultiplexing;
 pub use message::{Message, Signals};
 pub use nodes::{Node, Nodes};
-pub use receivers::Receivers;
-pub use signal::Signal;
+pub use receivers::{Receivers, ReceiversIter};
+pub use signal::{EncodeMode, Signal, SignalValueType};
+pub use signal_group::SignalGroup;
+pub use signal_type::SignalType;
+pub use signal_type_ref::SignalTypeRef;
 pub use value_descriptions::ValueDescriptions;
 pub use version::Version;
 
@@ -85,6 +106,8 @@ pub use bit_timing::BitTimingBuilder;
 #[cfg(feature = "std")]
 pub use dbc::DbcBuilder;
 #[cfg(feature = "std")]
+pub use environment_variable::EnvironmentVariableBuilder;
+#[cfg(feature = "std")]
 pub use extended_multiplexing::ExtendedMultiplexingBuilder;
 #[cfg(feature = "std")]
 pub use message::MessageBuilder;
@@ -95,6 +118,8 @@ pub use receivers::ReceiversBuilder;
 #[cfg(feature = "std")]
 pub use signal::SignalBuilder;
 #[cfg(feature = "std")]
+pub use signal_group::SignalGroupBuilder;
+#[cfg(feature = "std")]
 pub use value_descriptions::ValueDescriptionsBuilder;
 #[cfg(feature = "std")]
 pub use version::VersionBuilder;
@@ -102,6 +127,8 @@ pub use version::VersionBuilder;
 // High-performance wrapper
 #[cfg(feature = "std")]
 pub use fast_dbc::FastDbc;
+#[cfg(feature = "heapless")]
+pub use fast_dbc::HeaplessFastDbc;
 
 pub(crate) use parser::Parser;
 
@@ -121,13 +148,20 @@ pub const PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const SPECIFICATION: &str = include_str!("../SPECIFICATIONS.md");
 
 // Maximum limits for two-pass parsing (no alloc)
-// Note: All MAX_* constants are now defined in limits.rs (generated by build.rs)
-// and can be overridden at build time via environment variables:
+// Note: All MAX_* constants are public and defined in limits.rs (generated by build.rs).
+// They can be overridden at build time via environment variables:
 // - DBC_MAX_MESSAGES (default: 8192, must be power of 2 for heapless)
-// - DBC_MAX_SIGNALS_PER_MESSAGE (default: 64)
+// - DBC_MAX_SIGNALS_PER_MESSAGE (default: 256)
 // - DBC_MAX_NODES (default: 256)
 // - DBC_MAX_VALUE_DESCRIPTIONS (default: 64)
-// - DBC_MAX_NAME_SIZE (default: 32, per DBC specification)
+// - DBC_MAX_NAME_SIZE (default: 32, per DBC specification; 128 with the `long-names` feature)
+// - DBC_MAX_EXTENDED_MULTIPLEXING (default: 512)
+// - DBC_MAX_SIGNAL_TYPES (default: 64)
+// - DBC_MAX_SIGNAL_TYPE_REFS (default: 512)
+// - DBC_MAX_SIGNAL_GROUPS (default: 256)
+// - DBC_MAX_ENVIRONMENT_VARIABLES (default: 64)
+// - DBC_MAX_ATTRIBUTE_DEFINITIONS, DBC_MAX_ATTRIBUTE_VALUES, DBC_MAX_ATTRIBUTE_ENUM_VALUES
+//   (`attributes` feature only; defaults: 256, 4096, 64)
 include!(concat!(env!("OUT_DIR"), "/limits.rs"));
 
 // DBC file format keywords
@@ -146,7 +180,12 @@ pub(crate) const VAL_: &str = "VAL_";
 pub(crate) const SIG_GROUP_: &str = "SIG_GROUP_";
 pub(crate) const SIG_VALTYPE_: &str = "SIG_VALTYPE_";
 pub(crate) const EV_: &str = "EV_";
+pub(crate) const ENVVAR_DATA_: &str = "ENVVAR_DATA_";
+pub(crate) const EV_DATA_: &str = "EV_DATA_";
 pub(crate) const BO_TX_BU_: &str = "BO_TX_BU_";
+pub(crate) const SGTYPE_: &str = "SGTYPE_";
+pub(crate) const SGTYPE_VAL_: &str = "SGTYPE_VAL_";
+pub(crate) const SIG_TYPE_REF_: &str = "SIG_TYPE_REF_";
 
 // Additional keywords used in code
 pub(crate) const VECTOR_XXX: &str = "Vector__XXX";
@@ -96,6 +96,7 @@ impl MessageBuilder {
     /// # Ok::<(), dbc_rs::Error>(())
     /// ```
     pub fn build(self) -> Result<Message> {
+        let effective_dlc = self.effective_dlc;
         let (id, name, dlc, sender, signals, comment) = self.extract_fields()?;
         // Build all signals first
         let built_signals: Vec<Signal> = signals
@@ -104,20 +105,27 @@ impl MessageBuilder {
             .collect::<Result<Vec<_>>>()?;
         // Validate before construction
         Message::validate(id, &name, dlc, &sender, &built_signals)?;
+        if effective_dlc.is_some_and(|effective_dlc| effective_dlc > 64) {
+            return Err(Error::message(Error::MESSAGE_DLC_TOO_LARGE));
+        }
 
         // Convert to owned compat types (validation passed, so these should succeed)
         let name_str: compat::String<{ MAX_NAME_SIZE }> = compat::validate_name(&name)?;
         let sender_str: compat::String<{ MAX_NAME_SIZE }> = compat::validate_name(&sender)?;
         let signals_collection = Signals::from_slice(&built_signals);
 
-        Ok(Message::new(
+        let mut message = Message::new(
             id,
             name_str,
             dlc,
             sender_str,
             signals_collection,
             comment.map(|c| c.into()),
-        ))
+        );
+        if let Some(effective_dlc) = effective_dlc {
+            message.set_dlc_override(effective_dlc);
+        }
+        Ok(message)
     }
 }
 
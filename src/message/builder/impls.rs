@@ -19,6 +19,7 @@ impl MessageBuilder {
             id: None,
             name: None,
             dlc: None,
+            effective_dlc: None,
             sender: None,
             signals: Vec::new(),
             comment: None,
@@ -36,6 +37,11 @@ impl MessageBuilder {
         u8,
         "Sets the Data Length Code (DLC) - the number of data bytes in the message (0-8 for standard CAN, 0-64 for CAN FD)."
     );
+    builder_setter!(
+        effective_dlc,
+        u8,
+        "Sets the transmitted length when it differs from the DLC (the `GenMsgLength` attribute). Signals beyond it are not decoded; see [`Message::effective_dlc`](crate::Message::effective_dlc)."
+    );
 
     // String field setters generated by macros
     builder_string_setter!(name, "Sets the message name.");
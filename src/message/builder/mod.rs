@@ -23,6 +23,7 @@ pub struct MessageBuilder {
     id: Option<u32>,
     name: Option<String>,
     dlc: Option<u8>,
+    effective_dlc: Option<u8>,
     sender: Option<String>,
     signals: Vec<SignalBuilder>,
     comment: Option<String>,
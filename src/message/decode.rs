@@ -6,7 +6,9 @@ impl Message {
     /// Decode all signals into the output buffer (physical values).
     ///
     /// This is a zero-allocation decode path for high-speed CAN processing.
-    /// Signals are decoded in order and written to the output buffer.
+    /// Signals are decoded in order and written to the output buffer. Signals beyond
+    /// the transmitted length ([`effective_dlc`](Self::effective_dlc)) are skipped, as
+    /// in [`Dbc::decode`](crate::Dbc::decode).
     ///
     /// # Arguments
     /// * `data` - Raw CAN payload bytes
@@ -35,9 +37,10 @@ impl Message {
 
         // Use zip to avoid redundant bounds checks - iterates min(signals, out) times
         let mut count = 0;
-        for (out_val, signal) in out.iter_mut().zip(self.signals().iter()) {
+        let transmitted = self.signals().iter().filter(|s| self.is_transmitted(s));
+        for (out_val, signal) in out.iter_mut().zip(transmitted) {
             // decode_raw returns (raw_value, physical_value)
-            *out_val = signal.decode_raw(data).map(|(_, p)| p).unwrap_or(0.0);
+            *out_val = signal.decode_raw_and_physical(data).map(|(_, p)| p).unwrap_or(0.0);
             count += 1;
         }
 
@@ -47,7 +50,8 @@ impl Message {
     /// Decode all signals into the output buffer (raw integer values).
     ///
     /// Returns raw values before factor/offset conversion.
-    /// Useful for encoding or debugging.
+    /// Useful for encoding or debugging. Signals are written as by
+    /// [`decode_into`](Self::decode_into).
     ///
     /// # Arguments
     /// * `data` - Raw CAN payload bytes
@@ -64,8 +68,9 @@ impl Message {
 
         // Use zip to avoid redundant bounds checks
         let mut count = 0;
-        for (out_val, signal) in out.iter_mut().zip(self.signals().iter()) {
-            *out_val = signal.decode_raw(data).map(|(r, _)| r).unwrap_or(0);
+        let transmitted = self.signals().iter().filter(|s| self.is_transmitted(s));
+        for (out_val, signal) in out.iter_mut().zip(transmitted) {
+            *out_val = signal.decode_raw_and_physical(data).map(|(r, _)| r).unwrap_or(0);
             count += 1;
         }
 
@@ -74,20 +79,22 @@ impl Message {
 
     /// Decode a single signal by index.
     ///
-    /// Returns the physical value or `None` if index is out of bounds or decode fails.
+    /// Returns the physical value or `None` if index is out of bounds, the signal lies
+    /// beyond the transmitted length, or decode fails.
     #[inline]
     pub fn decode_signal(&self, index: usize, data: &[u8]) -> Option<f64> {
-        let signal = self.signals().at(index)?;
-        signal.decode_raw(data).ok().map(|(_, physical)| physical)
+        let signal = self.signals().at(index).filter(|s| self.is_transmitted(s))?;
+        signal.decode_physical(data)
     }
 
     /// Decode a single signal by index (raw value).
     ///
-    /// Returns the raw integer value or `None` if index is out of bounds or decode fails.
+    /// Returns the raw integer value or `None` if index is out of bounds, the signal lies
+    /// beyond the transmitted length, or decode fails.
     #[inline]
     pub fn decode_signal_raw(&self, index: usize, data: &[u8]) -> Option<i64> {
-        let signal = self.signals().at(index)?;
-        signal.decode_raw(data).ok().map(|(raw, _)| raw)
+        let signal = self.signals().at(index).filter(|s| self.is_transmitted(s))?;
+        signal.decode_raw(data)
     }
 }
 
@@ -122,6 +129,46 @@ BO_ 256 Engine : 8 ECM
         assert_eq!(values[1], 50.0); // Temp
     }
 
+    #[cfg(feature = "attributes")]
+    #[test]
+    fn test_decode_into_skips_untransmitted_signals() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" *
+ SG_ Reserved : 48|16@1+ (1,0) [0|65535] "" *
+
+BA_DEF_ BO_ "GenMsgLength" INT 0 64;
+BA_ "GenMsgLength" BO_ 256 3;
+"#,
+        )
+        .unwrap();
+
+        let msg = dbc.messages().find_by_id(256).unwrap();
+        let payload = [0x40, 0x1F, 0x5A];
+
+        // Reserved lies beyond the 3 transmitted bytes, as in Dbc::decode
+        let mut values = [-1.0f64; 4];
+        assert_eq!(msg.decode_into(&payload, &mut values), 2);
+        assert_eq!(values, [2000.0, 50.0, -1.0, -1.0]);
+        assert_eq!(dbc.decode(256, &payload, false).unwrap().len(), 2);
+
+        let mut raw = [-1i64; 4];
+        assert_eq!(msg.decode_raw_into(&payload, &mut raw), 2);
+        assert_eq!(raw, [8000, 90, -1, -1]);
+
+        assert_eq!(msg.decode_signal(1, &payload), Some(50.0));
+        assert_eq!(msg.decode_signal(2, &[0, 0, 0, 0, 0, 0, 0xFF, 0xFF]), None);
+        assert_eq!(
+            msg.decode_signal_raw(2, &[0, 0, 0, 0, 0, 0, 0xFF, 0xFF]),
+            None
+        );
+    }
+
     #[test]
     fn test_decode_raw_into() {
         let dbc = Dbc::parse(
@@ -1,5 +1,9 @@
 use super::{Message, Signals};
-use crate::compat::{Comment, Name};
+use crate::{
+    ByteOrder, Error, MAX_SIGNALS_PER_MESSAGE, Result, Signal,
+    compat::{Comment, Name, Vec},
+};
+use core::hash::{Hash, Hasher};
 
 impl Message {
     pub(crate) fn new(
@@ -18,6 +22,19 @@ impl Message {
             sender,
             signals,
             comment,
+            dlc_override: None,
+            #[cfg(feature = "spans")]
+            span: None,
+        }
+    }
+
+    /// Copy of this message, including signals and comments, under a new ID and name.
+    #[cfg(feature = "std")]
+    pub(crate) fn with_id_and_name(&self, id: u32, name: Name) -> Self {
+        Self {
+            id,
+            name,
+            ..self.clone()
         }
     }
 
@@ -119,6 +136,128 @@ impl Message {
         self.dlc
     }
 
+    /// Returns the number of bytes actually transmitted for this message.
+    ///
+    /// Some databases keep a nominal DLC on the `BO_` line and declare the transmitted
+    /// length through the `GenMsgLength` message attribute. Precedence is:
+    /// 1. an explicit `BA_ "GenMsgLength" BO_ <id> <len>;` value between 0 and 64
+    /// 2. the DLC declared on the `BO_` line
+    ///
+    /// The attribute default (`BA_DEF_DEF_`) is not applied, and the override is only
+    /// read when the `attributes` feature is enabled. Decoding uses this length: signals
+    /// lying beyond it are not transmitted and are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///
+    /// BA_DEF_ BO_ "GenMsgLength" INT 0 64;
+    /// BA_ "GenMsgLength" BO_ 256 4;
+    /// "#)?;
+    ///
+    /// let message = dbc.messages().find("Engine").unwrap();
+    /// assert_eq!(message.dlc(), 8);
+    /// assert_eq!(message.effective_dlc(), 4);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn effective_dlc(&self) -> u8 {
+        self.dlc_override.unwrap_or(self.dlc)
+    }
+
+    /// Returns `true` if the message is a CAN FD frame, i.e. its payload exceeds 8 bytes.
+    ///
+    /// The length is inferred from the DLC; an FD frame carrying 8 bytes or less is
+    /// indistinguishable from a classic frame here. Lengths above 8 bytes are always one
+    /// of the CAN FD sizes 12, 16, 20, 24, 32, 48 or 64.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Classic : 8 ECM
+    /// BO_ 512 Fd : 64 ECM
+    /// "#)?;
+    /// assert!(!dbc.messages().find("Classic").unwrap().is_fd());
+    /// assert!(dbc.messages().find("Fd").unwrap().is_fd());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn is_fd(&self) -> bool {
+        self.dlc > Self::MAX_CLASSIC_PAYLOAD_SIZE
+    }
+
+    /// Returns a zero-initialized payload buffer sized for this message.
+    ///
+    /// The length is the [`dlc`](Self::dlc) in bytes. Validation only admits classic CAN
+    /// and CAN FD payload lengths; should a message carry another length above 8 bytes, it
+    /// is padded up to the next CAN FD length (12, 16, 20, 24, 32, 48 or 64).
+    /// [`Dbc::encode`](crate::Dbc::encode) starts from this buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let payload = dbc.messages().find("Engine").unwrap().payload_template();
+    /// assert_eq!(payload.as_slice(), [0u8; 8]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn payload_template(&self) -> Vec<u8, { Message::MAX_PAYLOAD_SIZE }> {
+        let len = if self.dlc <= Self::MAX_CLASSIC_PAYLOAD_SIZE {
+            self.dlc
+        } else {
+            Self::FD_PAYLOAD_SIZES
+                .iter()
+                .copied()
+                .find(|&len| len >= self.dlc)
+                .unwrap_or(Self::MAX_PAYLOAD_SIZE as u8)
+        };
+        core::iter::repeat_n(0, len as usize).collect()
+    }
+
+    /// Sets the transmitted length (from the `GenMsgLength` attribute).
+    /// Set while parsing BA_ entries, which are processed after messages, and by the builder.
+    #[cfg(any(feature = "attributes", feature = "std"))]
+    #[inline]
+    pub(crate) fn set_dlc_override(&mut self, dlc: u8) {
+        self.dlc_override = Some(dlc);
+    }
+
+    /// Returns `true` if `signal` lies within the transmitted length.
+    /// Always `true` unless the DLC is overridden, as signals are validated against the DLC.
+    #[inline]
+    pub(crate) fn is_transmitted(&self, signal: &crate::Signal) -> bool {
+        match self.dlc_override {
+            Some(dlc) => Self::signal_end_bit(signal) < u16::from(dlc) * 8,
+            None => true,
+        }
+    }
+
     /// Get the sender node name for this message.
     ///
     /// The sender is the node that transmits this message on the CAN bus.
@@ -176,11 +315,115 @@ impl Message {
         &self.signals
     }
 
+    /// Returns the signals sorted by name.
+    ///
+    /// Names are compared case-sensitively (byte-wise), so `Z` sorts before `a`. Signals
+    /// sharing a name keep their declaration order. Useful for deterministic field order
+    /// in generated code; use [`signals`](Self::signals) for declaration order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ Torque : 16|16@1+ (0.1,0) [0|500] "Nm" ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" ECM
+    /// "#)?;
+    ///
+    /// let message = dbc.messages().find("Engine").unwrap();
+    /// let names: Vec<&str> = message.signals_by_name().map(|s| s.name()).collect();
+    /// assert_eq!(names, ["RPM", "Torque"]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "iterator is lazy and does nothing unless consumed"]
+    pub fn signals_by_name(&self) -> impl Iterator<Item = &Signal> + '_ {
+        let mut sorted: Vec<(usize, &Signal), { MAX_SIGNALS_PER_MESSAGE }> =
+            self.signals.iter().enumerate().collect();
+        // Break ties on the declaration index to keep the unstable sort deterministic
+        sorted.as_mut_slice().sort_unstable_by(|(a_idx, a), (b_idx, b)| {
+            a.name().cmp(b.name()).then(a_idx.cmp(b_idx))
+        });
+        sorted.into_iter().map(|(_, signal)| signal)
+    }
+
+    /// Returns the multiplexer switch signal (marked `M`), if the message is multiplexed.
+    ///
+    /// With extended multiplexing a message can contain nested switches (`m1M`); this
+    /// returns the top-level one, i.e. the first switch that is not itself multiplexed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 300 Sensors : 8 ECM
+    ///  SG_ Channel M : 0|8@1+ (1,0) [0|255] "" *
+    ///  SG_ Temperature m0 : 8|16@1+ (0.1,0) [0|100] "degC" *
+    ///  SG_ Pressure m1 : 8|16@1+ (0.1,0) [0|1000] "kPa" *
+    /// "#)?;
+    ///
+    /// let message = dbc.messages().find("Sensors").unwrap();
+    /// assert_eq!(message.multiplexer_switch().map(|s| s.name()), Some("Channel"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn multiplexer_switch(&self) -> Option<&Signal> {
+        self.signals
+            .iter()
+            .find(|s| s.is_multiplexer_switch() && s.multiplexer_switch_value().is_none())
+    }
+
+    /// Returns the signals active when the multiplexer switch has `switch_value`.
+    ///
+    /// Yields, in declaration order, every signal without a multiplexer indicator, the
+    /// switch itself, and the signals marked `m<switch_value>`. Only the `m<value>`
+    /// markers of the message are considered; use
+    /// [`Dbc::multiplexed_signals`](crate::Dbc::multiplexed_signals) to also apply
+    /// `SG_MUL_VAL_` extended multiplexing ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 300 Sensors : 8 ECM
+    ///  SG_ Channel M : 0|8@1+ (1,0) [0|255] "" *
+    ///  SG_ Temperature m0 : 8|16@1+ (0.1,0) [0|100] "degC" *
+    ///  SG_ Pressure m1 : 8|16@1+ (0.1,0) [0|1000] "kPa" *
+    ///  SG_ Counter : 24|8@1+ (1,0) [0|255] "" *
+    /// "#)?;
+    ///
+    /// let message = dbc.messages().find("Sensors").unwrap();
+    /// let names: Vec<&str> = message.multiplexed_signals(1).map(|s| s.name()).collect();
+    /// assert_eq!(names, ["Channel", "Pressure", "Counter"]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "iterator is lazy and does nothing unless consumed"]
+    pub fn multiplexed_signals(&self, switch_value: u64) -> impl Iterator<Item = &Signal> + '_ {
+        self.signals
+            .iter()
+            .filter(move |s| s.multiplexer_switch_value().is_none_or(|value| value == switch_value))
+    }
+
     /// Returns the minimum number of bytes required to decode all signals in this message.
     ///
     /// This calculates the actual byte coverage of all signals, which may be less than
     /// the declared DLC. Use this when validating frame payloads for decoding - the
     /// payload must have at least this many bytes to decode all signals successfully.
+    /// Signals beyond the [`effective_dlc`](Self::effective_dlc) are not counted.
     ///
     /// # Examples
     ///
@@ -203,21 +446,177 @@ impl Message {
     /// ```
     #[must_use = "return value should be used"]
     pub fn min_bytes_required(&self) -> u8 {
-        if self.signals.is_empty() {
-            return 0;
-        }
-
-        let mut max_bit: u16 = 0;
-        for signal in self.signals.iter() {
+        let mut max_bit: Option<u16> = None;
+        for signal in self.signals.iter().filter(|s| self.is_transmitted(s)) {
             let (_lsb, msb) =
                 Self::bit_range(signal.start_bit(), signal.length(), signal.byte_order());
-            if msb > max_bit {
-                max_bit = msb;
+            if max_bit.is_none_or(|max_bit| msb > max_bit) {
+                max_bit = Some(msb);
             }
         }
 
         // Convert max bit position to bytes: (max_bit / 8) + 1
-        ((max_bit / 8) + 1) as u8
+        max_bit.map_or(0, |max_bit| ((max_bit / 8) + 1) as u8)
+    }
+
+    /// Payload bits occupied by `signal`, from its start bit onwards.
+    ///
+    /// Bit `n` is bit `n % 8` of byte `n / 8`. Big-endian signals follow the Motorola
+    /// sawtooth order: down within a byte, then on to bit 7 of the next byte.
+    pub(crate) fn signal_bits(signal: &Signal) -> impl Iterator<Item = u16> + '_ {
+        let mut bit = signal.start_bit();
+        (0..signal.length()).map(move |_| {
+            let current = bit;
+            bit = match signal.byte_order() {
+                ByteOrder::LittleEndian => bit.wrapping_add(1),
+                ByteOrder::BigEndian if bit % 8 == 0 => bit.wrapping_add(15),
+                ByteOrder::BigEndian => bit - 1,
+            };
+            current
+        })
+    }
+
+    /// Returns the signal occupying payload bit `bit`.
+    ///
+    /// Bit `n` is bit `n % 8` of byte `n / 8`, and big-endian signals are matched on their
+    /// Motorola bit layout rather than a contiguous range. When multiplexed signals share
+    /// a bit, the first signal in definition order is returned. Returns `None` for unused
+    /// bits and bits beyond the DLC.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|12@1+ (1,0) [0|4095] "" ECM
+    ///  SG_ Temp : 23|10@0+ (1,0) [0|1023] "" ECM
+    /// "#)?;
+    ///
+    /// let message = dbc.messages().find("Engine").unwrap();
+    /// assert_eq!(message.signal_at_bit(11).map(|s| s.name()), Some("RPM"));
+    /// // Temp covers bits 23..16 of byte 2, then bits 31..30 of byte 3
+    /// assert_eq!(message.signal_at_bit(30).map(|s| s.name()), Some("Temp"));
+    /// assert!(message.signal_at_bit(24).is_none());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn signal_at_bit(&self, bit: u16) -> Option<&Signal> {
+        if usize::from(bit) >= usize::from(self.dlc) * 8 {
+            return None;
+        }
+        self.signals.iter().find(|signal| Self::signal_bits(signal).any(|b| b == bit))
+    }
+
+    /// Returns, for each bit of the payload, the number of signals occupying it.
+    ///
+    /// The returned vector has `dlc * 8` entries indexed as in
+    /// [`signal_at_bit`](Self::signal_at_bit). A count of `0` marks a gap, and a count
+    /// above `1` marks bits shared by several signals, which is only valid between
+    /// multiplexed signals.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 1 ECM
+    ///  SG_ Mode M : 0|2@1+ (1,0) [0|3] "" ECM
+    ///  SG_ A m0 : 2|4@1+ (1,0) [0|15] "" ECM
+    ///  SG_ B m1 : 4|4@1+ (1,0) [0|15] "" ECM
+    /// "#)?;
+    ///
+    /// let coverage = dbc.messages().find("Engine").unwrap().bit_coverage();
+    /// assert_eq!(coverage.as_slice(), [1, 1, 1, 1, 2, 2, 1, 1]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn bit_coverage(&self) -> Vec<u8, { Message::MAX_PAYLOAD_SIZE * 8 }> {
+        let mut coverage: Vec<u8, { Message::MAX_PAYLOAD_SIZE * 8 }> =
+            core::iter::repeat_n(0, usize::from(self.dlc) * 8).collect();
+        for signal in self.signals.iter() {
+            for bit in Self::signal_bits(signal) {
+                if let Some(count) = coverage.as_mut_slice().get_mut(usize::from(bit)) {
+                    *count = count.saturating_add(1);
+                }
+            }
+        }
+        coverage
+    }
+
+    /// Moves the signal named `name` to `new_start_bit`.
+    ///
+    /// The message is re-validated with the signal at its new position, so moving a
+    /// signal beyond the DLC or onto another signal fails. On error the message is
+    /// left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`](crate::Error::Validation) if no signal is named
+    /// `name`, if `new_start_bit` exceeds 511, or if the moved signal does not pass
+    /// message validation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" ECM
+    ///  SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" ECM
+    /// "#)?;
+    ///
+    /// let mut message = dbc.messages().find("Engine").unwrap().clone();
+    /// message.move_signal("Temp", 32)?;
+    /// assert_eq!(message.signals().find("Temp").unwrap().start_bit(), 32);
+    ///
+    /// // Overlaps RPM
+    /// assert!(message.move_signal("Temp", 8).is_err());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn move_signal(&mut self, name: &str, new_start_bit: u16) -> Result<()> {
+        if new_start_bit > 511 {
+            return Err(Error::Validation(Error::SIGNAL_PARSE_INVALID_START_BIT));
+        }
+        let moved = self
+            .signals
+            .find(name)
+            .ok_or(Error::Validation(Error::SIGNAL_NOT_FOUND))?
+            .with_start_bit(new_start_bit);
+
+        let signals: Vec<Signal, { MAX_SIGNALS_PER_MESSAGE }> = self
+            .signals
+            .iter()
+            .map(|s| {
+                if s.name() == name {
+                    moved.clone()
+                } else {
+                    s.clone()
+                }
+            })
+            .collect();
+        Self::validate(
+            self.id,
+            self.name(),
+            self.dlc,
+            self.sender(),
+            signals.as_slice(),
+        )?;
+
+        self.signals = Signals::from(signals);
+        Ok(())
     }
 
     /// Returns the message comment from CM_ BO_ entry, if present.
@@ -234,18 +633,107 @@ impl Message {
         self.comment = Some(comment);
     }
 
+    /// Replaces the sender node.
+    #[cfg(feature = "std")]
+    pub(crate) fn set_sender(&mut self, sender: Name) {
+        self.sender = sender;
+    }
+
+    /// Replaces the message name.
+    #[cfg(feature = "std")]
+    pub(crate) fn set_name(&mut self, name: Name) {
+        self.name = name;
+    }
+
+    /// Returns where the message is defined in the parsed input.
+    ///
+    /// The span is `(start_line, start_column, end_line)`: lines and columns are 1-based,
+    /// the start is the `BO_` keyword and the end line is that of the last `SG_` line
+    /// (or of the `BO_` line for messages without signals). Returns `None` for messages
+    /// that were not parsed, or when the `spans` feature (enabled by `std`) is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    ///  SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" *
+    /// "#)?;
+    ///
+    /// let message = dbc.messages().find("Engine").unwrap();
+    /// assert_eq!(message.span(), Some((5, 1, 7)));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn span(&self) -> Option<(usize, usize, usize)> {
+        #[cfg(feature = "spans")]
+        return self.span;
+        #[cfg(not(feature = "spans"))]
+        None
+    }
+
+    /// Records where the message is defined. Used during parsing.
+    #[cfg(feature = "spans")]
+    #[inline]
+    pub(crate) fn set_span(&mut self, span: (usize, usize, usize)) {
+        self.span = Some(span);
+    }
+
     /// Returns a mutable reference to the signals collection.
     /// Used internally during parsing when CM_ entries are processed after signals.
     #[inline]
     pub(crate) fn signals_mut(&mut self) -> &mut Signals {
         &mut self.signals
     }
+
+    /// Removes the message comment and all signal comments.
+    #[cfg(feature = "std")]
+    pub(crate) fn clear_comments(&mut self) {
+        self.comment = None;
+        for signal in self.signals.iter_mut() {
+            signal.clear_comment();
+        }
+    }
+}
+
+// Source spans are not part of a message's identity
+impl PartialEq for Message {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.name == other.name
+            && self.dlc == other.dlc
+            && self.sender == other.sender
+            && self.signals == other.signals
+            && self.comment == other.comment
+            && self.dlc_override == other.dlc_override
+    }
+}
+
+impl Eq for Message {}
+
+impl Hash for Message {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.name.hash(state);
+        self.dlc.hash(state);
+        self.sender.hash(state);
+        self.signals.hash(state);
+        self.comment.hash(state);
+        self.dlc_override.hash(state);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Parser, Signal};
+    use crate::{Dbc, Parser};
 
     #[test]
     fn test_message_getters_edge_cases() {
@@ -260,4 +748,200 @@ mod tests {
         assert_eq!(message.dlc(), 1);
         assert_eq!(message.sender(), "B");
     }
+
+    #[test]
+    fn test_signal_at_bit_and_coverage_mixed_byte_order() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Mixed : 4 ECM
+ SG_ Le : 0|12@1+ (1,0) [0|4095] "" ECM
+ SG_ Be : 23|10@0+ (1,0) [0|1023] "" ECM
+ SG_ Mux M : 12|2@1+ (1,0) [0|3] "" ECM
+ SG_ A m0 : 26|4@1+ (1,0) [0|15] "" ECM
+ SG_ B m1 : 28|2@1+ (1,0) [0|3] "" ECM
+"#,
+        )
+        .unwrap();
+        let message = dbc.messages().find("Mixed").unwrap();
+        let name_at = |bit| message.signal_at_bit(bit).map(|s| s.name());
+
+        assert_eq!(name_at(0), Some("Le"));
+        assert_eq!(name_at(11), Some("Le"));
+        assert_eq!(name_at(12), Some("Mux"));
+        assert_eq!(name_at(14), None);
+        // Big-endian: bits 23..16, then 31..30
+        assert_eq!(name_at(16), Some("Be"));
+        assert_eq!(name_at(23), Some("Be"));
+        assert_eq!(name_at(24), None);
+        assert_eq!(name_at(30), Some("Be"));
+        assert_eq!(name_at(31), Some("Be"));
+        // Shared multiplexed bits belong to the first signal
+        assert_eq!(name_at(28), Some("A"));
+        assert_eq!(name_at(32), None);
+
+        let coverage = message.bit_coverage();
+        assert_eq!(coverage.len(), 32);
+        assert!(coverage.as_slice()[..14].iter().all(|&n| n == 1));
+        assert_eq!(&coverage.as_slice()[14..16], [0, 0]);
+        assert!(coverage.as_slice()[16..24].iter().all(|&n| n == 1));
+        assert_eq!(&coverage.as_slice()[24..32], [0, 0, 1, 1, 2, 2, 1, 1]);
+    }
+
+    #[test]
+    fn test_signals_by_name() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ speed : 0|8@1+ (1,0) [0|255] "" ECM
+ SG_ Temp : 8|8@1+ (1,0) [0|255] "" ECM
+ SG_ Dup : 16|8@1+ (1,0) [0|255] "" ECM
+ SG_ Alpha : 24|8@1+ (1,0) [0|255] "" ECM
+ SG_ Dup : 32|8@1+ (1,0) [0|255] "" ECM
+"#,
+        )
+        .unwrap();
+        let message = dbc.messages().find("Engine").unwrap();
+
+        // Uppercase sorts before lowercase; duplicates keep declaration order
+        assert!(
+            message.signals_by_name().map(|s| (s.name(), s.start_bit())).eq([
+                ("Alpha", 24),
+                ("Dup", 16),
+                ("Dup", 32),
+                ("Temp", 8),
+                ("speed", 0)
+            ])
+        );
+        // Declaration order is untouched
+        assert_eq!(message.signals().at(0).unwrap().name(), "speed");
+    }
+
+    #[test]
+    fn test_multiplexed_signals_by_switch_value() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 300 Sensors : 8 ECM
+ SG_ Channel M : 0|8@1+ (1,0) [0|255] "" *
+ SG_ Temperature m0 : 8|16@1+ (0.1,0) [0|100] "degC" *
+ SG_ Pressure m1 : 8|16@1+ (0.1,0) [0|1000] "kPa" *
+ SG_ Counter : 24|8@1+ (1,0) [0|255] "" *
+
+BO_ 301 Plain : 8 ECM
+ SG_ Speed : 0|16@1+ (1,0) [0|65535] "" *
+"#,
+        )
+        .unwrap();
+        let sensors = dbc.messages().find("Sensors").unwrap();
+        assert_eq!(
+            sensors.multiplexer_switch().map(|s| s.name()),
+            Some("Channel")
+        );
+        assert!(sensors.multiplexed_signals(0).map(|s| s.name()).eq([
+            "Channel",
+            "Temperature",
+            "Counter"
+        ]));
+        assert!(sensors.multiplexed_signals(7).map(|s| s.name()).eq(["Channel", "Counter"]));
+
+        let plain = dbc.messages().find("Plain").unwrap();
+        assert!(plain.multiplexer_switch().is_none());
+        assert_eq!(plain.multiplexed_signals(0).count(), 1);
+    }
+
+    #[test]
+    fn test_payload_template_matches_dlc() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Classic : 8 ECM
+BO_ 257 Short : 3 ECM
+BO_ 258 Fd : 64 ECM
+BO_ 259 FdShort : 12 ECM
+"#,
+        )
+        .unwrap();
+        let len = |name| dbc.messages().find(name).unwrap().payload_template().len();
+
+        assert_eq!(len("Classic"), 8);
+        assert_eq!(len("Short"), 3);
+        assert_eq!(len("Fd"), 64);
+        assert_eq!(len("FdShort"), 12);
+        let template = dbc.messages().find("Classic").unwrap().payload_template();
+        assert!(template.iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_payload_template_pads_unvalidated_lengths() {
+        // Parsing rejects these lengths; a message built without validation is padded
+        let message = |dlc| {
+            Message::new(
+                256,
+                Name::try_from("Odd").unwrap(),
+                dlc,
+                Name::try_from("ECM").unwrap(),
+                Signals::from_slice(&[]),
+                None,
+            )
+        };
+
+        assert_eq!(message(10).payload_template().len(), 12);
+        assert_eq!(message(33).payload_template().len(), 48);
+        assert_eq!(message(200).payload_template().len(), 64);
+    }
+
+    #[test]
+    fn test_move_signal_revalidates() {
+        let dbc = Dbc::parse(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Engine : 8 ECM
+ SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" ECM
+ SG_ Temp : 16|8@1- (1,-40) [-40|215] "C" ECM
+"#,
+        )
+        .unwrap();
+        let mut message = dbc.messages().find("Engine").unwrap().clone();
+
+        // Into RPM: rejected, message unchanged
+        assert_eq!(
+            message.move_signal("Temp", 8),
+            Err(Error::Validation(Error::SIGNAL_OVERLAP))
+        );
+        assert_eq!(message.signals().find("Temp").unwrap().start_bit(), 16);
+
+        // Out of the collision into free space
+        message.move_signal("Temp", 56).unwrap();
+        assert_eq!(message.signals().find("Temp").unwrap().start_bit(), 56);
+        assert_eq!(message.signals().find("RPM").unwrap().start_bit(), 0);
+
+        // RPM can now take the old Temp position
+        message.move_signal("RPM", 16).unwrap();
+        assert_eq!(message.signals().find("RPM").unwrap().start_bit(), 16);
+
+        assert_eq!(
+            message.move_signal("Temp", 60),
+            Err(Error::Validation(Error::SIGNAL_EXTENDS_BEYOND_MESSAGE))
+        );
+        assert_eq!(
+            message.move_signal("Temp", 512),
+            Err(Error::Validation(Error::SIGNAL_PARSE_INVALID_START_BIT))
+        );
+        assert_eq!(
+            message.move_signal("Missing", 0),
+            Err(Error::Validation(Error::SIGNAL_NOT_FOUND))
+        );
+    }
 }
@@ -41,7 +41,8 @@ pub use signals::Signals;
 /// println!("Message: {} (ID: {})", message.name(), message.id());
 /// # Ok::<(), dbc_rs::Error>(())
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Message {
     id: u32,
     name: Name,
@@ -50,6 +51,12 @@ pub struct Message {
     signals: Signals,
     /// Comment text from CM_ BO_ entry
     comment: Option<Comment>,
+    /// Transmitted length from the `GenMsgLength` attribute, overriding `dlc`
+    dlc_override: Option<u8>,
+    /// Source location `(start line, start column, end line)` when parsed
+    #[cfg(feature = "spans")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: Option<(usize, usize, usize)>,
 }
 
 impl Message {
@@ -63,11 +70,20 @@ impl Message {
     // Invalid: 0x20000000 to 0x7FFFFFFF and 0xA0000000+ (except 0xC0000000)
 
     /// Maximum 29-bit extended CAN ID value
-    const MAX_EXTENDED_ID: u32 = 0x1FFF_FFFF;
+    pub(crate) const MAX_EXTENDED_ID: u32 = 0x1FFF_FFFF;
     /// Bit 31 flag indicating extended CAN ID format
     pub(crate) const EXTENDED_ID_FLAG: u32 = 0x8000_0000;
     /// Maximum extended CAN ID with bit 31 flag set
     const MAX_EXTENDED_ID_WITH_FLAG: u32 = Self::EXTENDED_ID_FLAG | Self::MAX_EXTENDED_ID;
     /// Special pseudo-message ID for VECTOR__INDEPENDENT_SIG_MSG (per spec Section 8.6)
-    const PSEUDO_MESSAGE_ID: u32 = 0xC000_0000;
+    pub(crate) const PSEUDO_MESSAGE_ID: u32 = 0xC000_0000;
+    /// Maximum payload size in bytes (CAN FD)
+    pub(crate) const MAX_PAYLOAD_SIZE: usize = 64;
+    /// Maximum payload size in bytes (classic CAN)
+    pub(crate) const MAX_CLASSIC_PAYLOAD_SIZE: u8 = 8;
+    /// CAN FD payload sizes above 8 bytes (DLC codes 9-15)
+    pub(crate) const FD_PAYLOAD_SIZES: [u8; 7] = [12, 16, 20, 24, 32, 48, 64];
+    /// Message attribute (BA_ BO_) overriding the DLC declared on the BO_ line
+    #[cfg(feature = "attributes")]
+    pub(crate) const DLC_OVERRIDE_ATTRIBUTE: &'static str = "GenMsgLength";
 }
@@ -3,6 +3,37 @@ use crate::{Error, MAX_NAME_SIZE, Parser, Result, Signal, compat};
 
 impl Message {
     pub(crate) fn parse(parser: &mut Parser, signals: &[Signal]) -> Result<Self> {
+        let (id, name, dlc, sender) = Self::parse_header(parser)?;
+
+        // Validate before construction
+        Message::validate(id, name, dlc, sender, signals).map_err(|e| {
+            crate::error::map_val_error_with_line(
+                e,
+                |msg| parser.err_message(msg),
+                || parser.err_message(Error::MESSAGE_ERROR_PREFIX),
+            )
+        })?;
+
+        // Convert to owned types (validation already done, so unwrap is safe)
+        let name_str: compat::String<{ MAX_NAME_SIZE }> = compat::validate_name(name)?;
+        let sender_str: compat::String<{ MAX_NAME_SIZE }> = compat::validate_name(sender)?;
+        let signals_collection = Signals::from_slice(signals);
+
+        // Note: comment is None here - it gets set later from CM_ BO_ entries
+        Ok(Message::new(
+            id,
+            name_str,
+            dlc,
+            sender_str,
+            signals_collection,
+            None,
+        ))
+    }
+
+    /// Parse a `BO_ <id> <name> : <dlc> <sender>` header line, borrowing the names.
+    ///
+    /// The parser must be limited to the header line; trailing content is rejected.
+    pub(crate) fn parse_header<'a>(parser: &mut Parser<'a>) -> Result<(u32, &'a str, u8, &'a str)> {
         // Message parsing must always start with "BO_" keyword
         let line = parser.line();
         parser
@@ -13,7 +44,8 @@ impl Message {
         let _ = parser.skip_whitespace();
 
         // Parse message ID
-        let id = parser.parse_u32_with_error(|| Error::message(Error::MESSAGE_INVALID_ID))?;
+        let id =
+            parser.parse_message_id_with_error(|| Error::message(Error::MESSAGE_INVALID_ID))?;
 
         // Skip whitespace
         let line = parser.line();
@@ -53,29 +85,7 @@ impl Message {
             return Err(parser.err_expected("Unexpected content after message sender"));
         }
 
-        // Validate before construction
-        Message::validate(id, name, dlc, sender, signals).map_err(|e| {
-            crate::error::map_val_error_with_line(
-                e,
-                |msg| parser.err_message(msg),
-                || parser.err_message(Error::MESSAGE_ERROR_PREFIX),
-            )
-        })?;
-
-        // Convert to owned types (validation already done, so unwrap is safe)
-        let name_str: compat::String<{ MAX_NAME_SIZE }> = compat::validate_name(name)?;
-        let sender_str: compat::String<{ MAX_NAME_SIZE }> = compat::validate_name(sender)?;
-        let signals_collection = Signals::from_slice(signals);
-
-        // Note: comment is None here - it gets set later from CM_ BO_ entries
-        Ok(Message::new(
-            id,
-            name_str,
-            dlc,
-            sender_str,
-            signals_collection,
-            None,
-        ))
+        Ok((id, name, dlc, sender))
     }
 }
 
@@ -4,6 +4,11 @@ use crate::{MAX_SIGNALS_PER_MESSAGE, Signal, compat::Vec};
 ///
 /// Uses `Vec<Signal>` for dynamic sizing.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Signals {
     signals: Vec<Signal, { MAX_SIGNALS_PER_MESSAGE }>,
 }
@@ -140,6 +145,26 @@ impl Signals {
     pub(crate) fn find_mut(&mut self, name: &str) -> Option<&mut Signal> {
         self.signals.iter_mut().find(|s| s.name() == name)
     }
+
+    /// Get the signals as a slice.
+    #[cfg(any(feature = "std", feature = "serde"))]
+    pub(crate) fn as_slice(&self) -> &[Signal] {
+        self.signals.as_slice()
+    }
+
+    /// Get a mutable iterator over the signals.
+    #[cfg(feature = "std")]
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut Signal> + '_ {
+        self.signals.iter_mut()
+    }
+
+    /// Sort the signals by start bit, signals sharing a start bit by name.
+    #[cfg(feature = "std")]
+    pub(crate) fn sort_by_start_bit(&mut self) {
+        self.signals
+            .as_mut_slice()
+            .sort_by(|a, b| (a.start_bit(), a.name()).cmp(&(b.start_bit(), b.name())));
+    }
 }
 
 #[cfg(test)]
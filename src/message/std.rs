@@ -1,4 +1,6 @@
 use super::Message;
+#[cfg(feature = "std")]
+use crate::{SerializeOptions, Signal};
 
 #[cfg(feature = "std")]
 impl Message {
@@ -6,7 +8,7 @@ impl Message {
     pub fn to_dbc_string(&self) -> std::string::String {
         format!(
             "BO_ {} {} : {} {}",
-            self.id(),
+            self.id_with_flag(),
             self.name(),
             self.dlc(),
             self.sender()
@@ -15,17 +17,127 @@ impl Message {
 
     #[must_use = "return value should be used"]
     pub fn to_string_full(&self) -> std::string::String {
+        self.to_string_full_with(&SerializeOptions::default())
+    }
+
+    /// Serialize the `BO_` line and its `SG_` lines, ordering signals per `options`.
+    pub(crate) fn to_string_full_with(&self, options: &SerializeOptions) -> std::string::String {
         let mut result = std::string::String::with_capacity(200 + (self.signals.len() * 100));
         result.push_str(&self.to_dbc_string());
         result.push('\n');
 
-        for signal in self.signals().iter() {
+        let mut signals: std::vec::Vec<&Signal> = self.signals().iter().collect();
+        if options.sort_signals_by_start_bit {
+            signals.sort_by_key(|signal| signal.start_bit());
+        }
+        for signal in signals {
             result.push_str(&signal.to_dbc_string());
             result.push('\n');
         }
 
         result
     }
+
+    /// Returns, for each bit of the payload, the name of the signal that occupies it.
+    ///
+    /// The returned vector has `dlc * 8` entries indexed by bit position (bit `n` is bit
+    /// `n % 8` of byte `n / 8`). Big-endian signals follow the Motorola sawtooth bit order.
+    /// Bits not covered by any signal are `None`. When multiplexed signals share bits, the
+    /// first signal in definition order owns them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 2 ECM
+    ///  SG_ Temp : 0|4@1+ (1,0) [0|15] "" ECM
+    /// "#)?;
+    ///
+    /// let message = dbc.messages().find("Engine").unwrap();
+    /// let map = message.bit_owner_map();
+    /// assert_eq!(map.len(), 16);
+    /// assert_eq!(map[3], Some("Temp"));
+    /// assert_eq!(map[4], None);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn bit_owner_map(&self) -> std::vec::Vec<Option<&str>> {
+        let total_bits = usize::from(self.dlc) * 8;
+        let mut map = vec![None; total_bits];
+
+        for signal in self.signals().iter() {
+            for bit in Self::signal_bits(signal) {
+                if let Some(slot) = map.get_mut(usize::from(bit)) {
+                    slot.get_or_insert(signal.name());
+                }
+            }
+        }
+
+        map
+    }
+
+    /// Returns every pair of signals occupying a common payload bit.
+    ///
+    /// Unlike message validation, which stops at the first conflict, this reports all of
+    /// them. Overlap is decided on the occupied bits, so big-endian signals are compared
+    /// on their Motorola bit layout. Multiplexed signals with different switch values
+    /// never coexist and are not reported; a multiplexed signal overlapping a plain
+    /// signal or one with the same switch value is. Pairs are in definition order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 2 ECM
+    ///  SG_ Mode M : 0|2@1+ (1,0) [0|3] "" ECM
+    ///  SG_ A m0 : 2|4@1+ (1,0) [0|15] "" ECM
+    ///  SG_ B m1 : 2|4@1+ (1,0) [0|15] "" ECM
+    /// "#)?;
+    ///
+    /// // A and B share bits but are never active at the same time
+    /// assert!(dbc.messages().find("Engine").unwrap().overlaps().is_empty());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn overlaps(&self) -> std::vec::Vec<(&str, &str)> {
+        let bit_sets: std::vec::Vec<[u64; 8]> = self
+            .signals()
+            .iter()
+            .map(|signal| {
+                let mut set = [0u64; 8];
+                for bit in Self::signal_bits(signal).map(usize::from) {
+                    if let Some(word) = set.get_mut(bit / 64) {
+                        *word |= 1 << (bit % 64);
+                    }
+                }
+                set
+            })
+            .collect();
+
+        let mut pairs = std::vec::Vec::new();
+        for (i, (sig1, set1)) in self.signals().iter().zip(&bit_sets).enumerate() {
+            for (sig2, set2) in self.signals().iter().zip(&bit_sets).skip(i + 1) {
+                let exclusive = matches!(
+                    (sig1.multiplexer_switch_value(), sig2.multiplexer_switch_value()),
+                    (Some(a), Some(b)) if a != b
+                );
+                if !exclusive && set1.iter().zip(set2).any(|(a, b)| a & b != 0) {
+                    pairs.push((sig1.name(), sig2.name()));
+                }
+            }
+        }
+        pairs
+    }
 }
 
 #[cfg(feature = "std")]
@@ -38,7 +150,7 @@ impl core::fmt::Display for Message {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Parser, Signal};
+    use crate::{Parser, Signal, message::Signals};
 
     #[test]
     fn test_message_to_dbc_string() {
@@ -111,6 +223,19 @@ mod tests {
         assert_eq!(dbc_string, "BO_ 536870911 ExtendedID : 8 ECM");
     }
 
+    #[test]
+    fn test_message_to_dbc_string_keeps_extended_flag() {
+        let data = b"BO_ 2147484672 Diagnostics : 8 ECM";
+        let mut parser = Parser::new(data).unwrap();
+        let signals: &[Signal] = &[];
+        let message = Message::parse(&mut parser, signals).unwrap();
+
+        assert_eq!(
+            message.to_dbc_string(),
+            "BO_ 2147484672 Diagnostics : 8 ECM"
+        );
+    }
+
     #[test]
     fn test_message_to_dbc_string_dlc_edge_cases() {
         // Test DLC = 1
@@ -212,6 +337,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_message_bit_owner_map() {
+        let data = b"BO_ 256 EngineData : 4 ECM";
+        let mut parser = Parser::new(data).unwrap();
+
+        // Little-endian: bits 0..12
+        let rpm =
+            Signal::parse(&mut Parser::new(b"SG_ RPM : 0|12@1+ (1,0) [0|4095] \"\"").unwrap())
+                .unwrap();
+        // Big-endian: starts at bit 23, 10 bits -> bits 23..16 then 31..30
+        let temp =
+            Signal::parse(&mut Parser::new(b"SG_ Temp : 23|10@0+ (1,0) [0|1023] \"\"").unwrap())
+                .unwrap();
+
+        let message = Message::parse(&mut parser, &[rpm, temp]).unwrap();
+        let map = message.bit_owner_map();
+
+        assert_eq!(map.len(), 32);
+        assert_eq!(map[0], Some("RPM"));
+        assert_eq!(map[11], Some("RPM"));
+        assert_eq!(map[12], None);
+        assert_eq!(map[16], Some("Temp"));
+        assert_eq!(map[23], Some("Temp"));
+        assert_eq!(map[24], None);
+        assert_eq!(map[29], None);
+        assert_eq!(map[30], Some("Temp"));
+        assert_eq!(map[31], Some("Temp"));
+    }
+
+    #[test]
+    fn test_message_overlaps() {
+        let data = b"BO_ 256 EngineData : 8 ECM";
+        let mut parser = Parser::new(data).unwrap();
+
+        // Three mutually overlapping signals; the big-endian one covers bits 15..12
+        let a =
+            Signal::parse(&mut Parser::new(b"SG_ A : 8|8@1+ (1,0) [0|255] \"\"").unwrap()).unwrap();
+        let b =
+            Signal::parse(&mut Parser::new(b"SG_ B : 15|4@0+ (1,0) [0|15] \"\"").unwrap()).unwrap();
+        let c = Signal::parse(&mut Parser::new(b"SG_ C : 4|12@1+ (1,0) [0|4095] \"\"").unwrap())
+            .unwrap();
+        // Big-endian bits 23..16 do not touch byte 1
+        let d = Signal::parse(&mut Parser::new(b"SG_ D : 23|8@0+ (1,0) [0|255] \"\"").unwrap())
+            .unwrap();
+
+        // Overlapping signals fail validation, so build the message without them first
+        let mut message = Message::parse(&mut parser, &[]).unwrap();
+        message.signals = Signals::from_slice(&[a, b, c, d]);
+
+        assert_eq!(message.overlaps(), [("A", "B"), ("A", "C"), ("B", "C")]);
+    }
+
     #[test]
     fn test_message_signals_iterator_collect() {
         let data = b"BO_ 256 EngineData : 8 ECM";
@@ -28,9 +28,13 @@ impl Message {
 
         // Per DBC spec Section 8.3: DLC can be 0-8 for CAN 2.0, 0-64 for CAN FD
         // DLC = 0 is valid (e.g., for control messages without data payload)
+        // Above 8 bytes, only the lengths encodable by CAN FD DLC codes 9-15 are valid
         if dlc > 64 {
             return Err(Error::Validation(Error::MESSAGE_DLC_TOO_LARGE));
         }
+        if dlc > Self::MAX_CLASSIC_PAYLOAD_SIZE && !Self::FD_PAYLOAD_SIZES.contains(&dlc) {
+            return Err(Error::Validation(Error::MESSAGE_DLC_INVALID_FD_LENGTH));
+        }
 
         // Validate ID is in a valid range
         let id_valid = id <= Self::MAX_EXTENDED_ID
@@ -52,13 +56,9 @@ impl Message {
         if id != Self::PSEUDO_MESSAGE_ID {
             let max_bits = u16::from(dlc) * 8;
             for signal in signals.iter() {
-                // Calculate the actual bit range for this signal (accounting for byte order)
-                let (lsb, msb) =
-                    Self::bit_range(signal.start_bit(), signal.length(), signal.byte_order());
                 // Check if the signal extends beyond the message boundary
                 // The signal's highest bit position must be less than max_bits
-                let signal_max_bit = lsb.max(msb);
-                if signal_max_bit >= max_bits {
+                if Self::signal_end_bit(signal) >= max_bits {
                     return Err(Error::Validation(Error::SIGNAL_EXTENDS_BEYOND_MESSAGE));
                 }
             }
@@ -108,6 +108,13 @@ impl Message {
         Ok(())
     }
 
+    /// Highest payload bit occupied by `signal`, accounting for its byte order.
+    #[inline]
+    pub(crate) fn signal_end_bit(signal: &Signal) -> u16 {
+        let (lsb, msb) = Self::bit_range(signal.start_bit(), signal.length(), signal.byte_order());
+        lsb.max(msb)
+    }
+
     pub(crate) fn bit_range(start_bit: u16, length: u16, byte_order: ByteOrder) -> (u16, u16) {
         match byte_order {
             ByteOrder::LittleEndian => {
@@ -193,6 +200,26 @@ mod tests {
         assert_eq!(message.signals().len(), 1);
     }
 
+    #[test]
+    fn test_message_fd_lengths() {
+        let signal =
+            Signal::parse(&mut Parser::new(b"SG_ Late : 400|16@1+ (1,0) [0|65535] \"\"").unwrap())
+                .unwrap();
+        let mut parser = Parser::new(b"BO_ 256 FdData : 64 ECM").unwrap();
+        let message = Message::parse(&mut parser, &[signal]).unwrap();
+        assert!(message.is_fd());
+
+        for dlc in [9u8, 33] {
+            assert_eq!(
+                Message::validate(256, "FdData", dlc, "ECM", &[]),
+                Err(Error::Validation(Error::MESSAGE_DLC_INVALID_FD_LENGTH))
+            );
+        }
+        for dlc in [0u8, 8, 12, 48, 64] {
+            assert!(Message::validate(256, "FdData", dlc, "ECM", &[]).is_ok());
+        }
+    }
+
     #[test]
     fn test_message_little_endian_bit_range_calculation() {
         // Test little-endian bit range calculation
@@ -235,6 +235,33 @@ impl Nodes {
             .and_then(|node| node.comment())
     }
 
+    /// Appends a node, failing if the name is taken or the list is full.
+    #[cfg(feature = "std")]
+    pub(crate) fn push(&mut self, node: Node) -> crate::Result<()> {
+        if self.contains(node.name()) {
+            return Err(crate::Error::Validation(crate::Error::NODES_DUPLICATE_NAME));
+        }
+        self.nodes
+            .push(node)
+            .map_err(|_| crate::Error::Validation(crate::Error::NODES_TOO_MANY))
+    }
+
+    /// Removes a node by name, returning `true` if it was present.
+    #[cfg(feature = "std")]
+    pub(crate) fn remove(&mut self, node_name: &str) -> bool {
+        if !self.contains(node_name) {
+            return false;
+        }
+        self.nodes = self.nodes.iter().filter(|node| node.name() != node_name).cloned().collect();
+        true
+    }
+
+    /// Sorts the nodes alphabetically by name.
+    #[cfg(feature = "std")]
+    pub(crate) fn sort(&mut self) {
+        self.nodes.as_mut_slice().sort_by(|a, b| a.name().cmp(b.name()));
+    }
+
     /// Sets the comment for a node by name.
     ///
     /// Returns `true` if the node was found and the comment was set,
@@ -16,6 +16,7 @@ pub use builder::NodesBuilder;
 
 /// Represents a single node (ECU) with its name and optional comment.
 #[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Node {
     name: Name,
     comment: Option<Comment>,
@@ -119,6 +120,11 @@ type InnerNodes = Vec<Node, { MAX_NODES }>;
 /// - Empty node list is valid (`BU_:`)
 /// - Maximum 32 characters per node name by default
 #[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Nodes {
     nodes: InnerNodes,
 }
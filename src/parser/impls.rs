@@ -1,18 +1,45 @@
 use super::Parser;
 use crate::Error;
 
+/// UTF-8 byte order mark, written at the start of files by some Windows tools.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
 impl<'a> Parser<'a> {
+    /// Create a parser over `input`, starting after a leading UTF-8 byte order mark.
+    ///
+    /// Positions stay offsets into `input`, BOM included.
     pub fn new(input: &'a [u8]) -> crate::Result<Self> {
-        if input.is_empty() {
+        let pos = if input.starts_with(UTF8_BOM) {
+            UTF8_BOM.len()
+        } else {
+            0
+        };
+        if input.len() == pos {
             return Err(Error::unexpected_eof());
         }
         Ok(Self {
             input,
-            pos: 0,
+            pos,
             line: 1,
+            hex_message_ids: false,
         })
     }
 
+    /// Create a parser over `input`, numbering its first line `line`.
+    ///
+    /// Used when re-parsing a slice of a larger input so errors report the line in the
+    /// original input.
+    pub fn new_at_line(input: &'a [u8], line: usize) -> crate::Result<Self> {
+        let mut parser = Self::new(input)?;
+        parser.line = line;
+        Ok(parser)
+    }
+
+    /// Enable or disable hexadecimal message IDs in [`parse_message_id`](Self::parse_message_id).
+    pub fn set_hex_message_ids(&mut self, enabled: bool) {
+        self.hex_message_ids = enabled;
+    }
+
     #[inline]
     #[must_use = "return value should be used"]
     pub fn pos(&self) -> usize {
@@ -25,6 +52,22 @@ impl<'a> Parser<'a> {
         self.line
     }
 
+    /// 1-based column of the current position, in bytes from the start of the line.
+    #[cfg(feature = "spans")]
+    #[must_use = "return value should be used"]
+    pub fn column(&self) -> usize {
+        let first_line_start = if self.input.starts_with(UTF8_BOM) {
+            UTF8_BOM.len()
+        } else {
+            0
+        };
+        let line_start = self.input[..self.pos]
+            .iter()
+            .rposition(|&b| b == b'\n' || b == b'\r')
+            .map_or(first_line_start, |idx| idx + 1);
+        self.pos - line_start + 1
+    }
+
     #[inline]
     #[must_use = "return value should be used"]
     pub fn remaining(&self) -> &'a [u8] {
@@ -323,4 +366,26 @@ mod tests {
         let err = parser.err_signal("some message");
         assert_eq!(err.line(), Some(1));
     }
+
+    #[test]
+    fn new_skips_utf8_bom() {
+        let parser = Parser::new(b"\xEF\xBB\xBFVERSION").unwrap();
+        assert_eq!(parser.pos(), 3);
+        assert_eq!(parser.remaining(), b"VERSION");
+        assert_eq!(parser.line(), 1);
+    }
+
+    #[test]
+    fn new_fails_on_bom_only_input() {
+        assert!(Parser::new(b"\xEF\xBB\xBF").is_err());
+    }
+
+    #[cfg(feature = "spans")]
+    #[test]
+    fn column_excludes_utf8_bom() {
+        let mut parser = Parser::new(b"\xEF\xBB\xBFVERSION").unwrap();
+        assert_eq!(parser.column(), 1);
+        parser.pos = 5;
+        assert_eq!(parser.column(), 3);
+    }
 }
@@ -31,6 +31,7 @@ const KEYWORDS_S: &[&str] = &[
     "SG_MUL_VAL_",
     "SIG_GROUP_",
     "SGTYPE_VAL_",
+    "SGTYPE_",
     "SG_",
 ];
 
@@ -55,6 +56,16 @@ const KEYWORDS_N: &[&str] = &["NS_DESC_", "NS_"];
 // 'F' keywords
 const KEYWORDS_F: &[&str] = &["FILTER"];
 
+/// Returns `true` if `name` is one of the DBC keywords.
+#[cfg(feature = "std")]
+pub(crate) fn is_keyword(name: &str) -> bool {
+    [
+        KEYWORDS_B, KEYWORDS_S, KEYWORDS_V, KEYWORDS_C, KEYWORDS_E, KEYWORDS_N, KEYWORDS_F,
+    ]
+    .iter()
+    .any(|group| group.contains(&name))
+}
+
 impl<'a> Parser<'a> {
     pub fn peek_next_keyword(&mut self) -> crate::Result<&'a str> {
         // Skip newlines and spaces to find the next keyword
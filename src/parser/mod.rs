@@ -5,9 +5,14 @@ mod parse;
 mod skip;
 mod take;
 
+#[cfg(feature = "std")]
+pub(crate) use keyword::is_keyword;
+
 #[derive(Debug)]
 pub struct Parser<'a> {
     input: &'a [u8],
     pos: usize,
     line: usize,
+    /// Accept `0x`-prefixed message IDs in [`Parser::parse_message_id`]
+    hex_message_ids: bool,
 }
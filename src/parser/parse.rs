@@ -65,6 +65,49 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a message ID.
+    ///
+    /// IDs are decimal, as required by the DBC format. If enabled with
+    /// [`set_hex_message_ids`](Self::set_hex_message_ids), a `0x`/`0X` prefix selects
+    /// hexadecimal instead.
+    pub fn parse_message_id(&mut self) -> crate::Result<u32> {
+        if !self.hex_message_ids || !(self.starts_with(b"0x") || self.starts_with(b"0X")) {
+            return self.parse_u32();
+        }
+
+        let start_pos = self.pos;
+        let start_line = self.line;
+        let input_len = self.input.len();
+        self.pos += 2;
+        let digits_start = self.pos;
+        // Read until whitespace, colon, pipe, @, or end of input
+        while self.pos < input_len {
+            if self.input[self.pos].is_ascii_hexdigit() {
+                self.advance_one();
+            } else if self.matches_any(b" \t:|@") || self.at_newline() {
+                break;
+            } else {
+                self.pos = start_pos;
+                return Err(Error::expected_at(Error::EXPECTED_NUMBER, start_line));
+            }
+        }
+
+        if self.pos == digits_start {
+            self.pos = start_pos;
+            return Err(Error::expected_at(Error::EXPECTED_NUMBER, start_line));
+        }
+
+        let num_bytes = &self.input[digits_start..self.pos];
+        let num_str = from_utf8(num_bytes).map_err(|_| {
+            self.pos = start_pos;
+            Error::expected_at(Error::INVALID_UTF8, start_line)
+        })?;
+        u32::from_str_radix(num_str, 16).map_err(|_| {
+            self.pos = start_pos;
+            Error::expected_at(Error::INVALID_NUMBER_FORMAT, start_line)
+        })
+    }
+
     #[allow(dead_code)]
     pub fn parse_u64(&mut self) -> crate::Result<u64> {
         let start_pos = self.pos;
@@ -173,7 +216,7 @@ impl<'a> Parser<'a> {
                 }
             } else if matches!(
                 byte,
-                b' ' | b'\t' | b'\n' | b'\r' | b':' | b',' | b')' | b']' | b'|'
+                b' ' | b'\t' | b'\n' | b'\r' | b':' | b',' | b')' | b']' | b'|' | b';'
             ) {
                 break;
             } else {
@@ -219,11 +262,13 @@ impl<'a> Parser<'a> {
 
         // Subsequent characters can be alphanumeric or underscore
         // Terminators include comma per DBC spec Section 9.5 (receivers = receiver {',' receiver})
+        // and semicolon for statements ending directly after a name (e.g. `SIG_GROUP_ ... Sig;`)
+        // A trailing `//` comment may also follow a name directly (e.g. `SG_ ... ECM// note`)
         while self.pos < input_len {
             let byte = self.input[self.pos];
             if byte.is_ascii_alphanumeric() || byte == b'_' {
                 self.advance_one();
-            } else if self.matches_any(b" \t:,") || self.at_newline() {
+            } else if self.matches_any(b" \t:,;") || self.at_newline() || self.starts_with(b"//") {
                 // Comma added to support comma-separated receiver lists per DBC spec
                 break;
             } else {
@@ -264,14 +309,14 @@ impl<'a> Parser<'a> {
         self.parse_identifier().map_err(|_| map_error().with_line(line))
     }
 
-    /// Parse a u32 with a custom error mapping.
-    /// Consolidates the pattern: `parse_u32().map_err(|_| Error::X(...))`.
-    pub fn parse_u32_with_error<F>(&mut self, map_error: F) -> crate::Result<u32>
+    /// Parse a message ID with a custom error mapping.
+    /// Consolidates the pattern: `parse_message_id().map_err(|_| Error::X(...))`.
+    pub fn parse_message_id_with_error<F>(&mut self, map_error: F) -> crate::Result<u32>
     where
         F: FnOnce() -> Error,
     {
         let line = self.line;
-        self.parse_u32().map_err(|_| map_error().with_line(line))
+        self.parse_message_id().map_err(|_| map_error().with_line(line))
     }
 
     /// Parse a u8 with a custom error mapping.
@@ -463,6 +508,13 @@ mod tests {
         assert!((parser.parse_f64().unwrap() - 1.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_parse_f64_with_semicolon_delimiter() {
+        // Attribute values are terminated directly by `;` (e.g. `BA_ ... 40.0;`)
+        let mut parser = Parser::new(b"40.0;").unwrap();
+        assert!((parser.parse_f64().unwrap() - 40.0).abs() < 1e-10);
+    }
+
     #[test]
     fn test_parse_f64_with_bracket_delimiter() {
         let mut parser = Parser::new(b"0.25]").unwrap();
@@ -499,6 +551,12 @@ mod tests {
         assert_eq!(parser.parse_identifier().unwrap(), "Receiver1");
     }
 
+    #[test]
+    fn test_parse_identifier_with_semicolon_delimiter() {
+        let mut parser = Parser::new(b"TypeName;").unwrap();
+        assert_eq!(parser.parse_identifier().unwrap(), "TypeName");
+    }
+
     #[test]
     fn test_parse_identifier_number_start() {
         let mut parser = Parser::new(b"123Signal ").unwrap();
@@ -539,20 +597,48 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_u32_with_error() {
+    fn test_parse_message_id_with_error() {
         let mut parser = Parser::new(b"256 ").unwrap();
-        let result = parser.parse_u32_with_error(|| Error::message(Error::MESSAGE_INVALID_ID));
+        let result =
+            parser.parse_message_id_with_error(|| Error::message(Error::MESSAGE_INVALID_ID));
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 256);
     }
 
     #[test]
-    fn test_parse_u32_with_error_invalid() {
+    fn test_parse_message_id_with_error_invalid() {
         let mut parser = Parser::new(b"abc ").unwrap();
-        let result = parser.parse_u32_with_error(|| Error::message(Error::MESSAGE_INVALID_ID));
+        let result =
+            parser.parse_message_id_with_error(|| Error::message(Error::MESSAGE_INVALID_ID));
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_message_id_hex() {
+        // Hexadecimal IDs are rejected unless enabled
+        let mut parser = Parser::new(b"0x100 ").unwrap();
+        assert!(parser.parse_message_id().is_err());
+        assert_eq!(parser.pos(), 0);
+
+        parser.set_hex_message_ids(true);
+        assert_eq!(parser.parse_message_id().unwrap(), 0x100);
+
+        for (input, expected) in [
+            (&b"0X80000400:"[..], Some(0x8000_0400)),
+            (b"256 ", Some(256)),
+            (b"0x ", None),
+            (b"0x1G0 ", None),
+            (b"0x100000000 ", None),
+        ] {
+            let mut parser = Parser::new(input).unwrap();
+            parser.set_hex_message_ids(true);
+            assert_eq!(parser.parse_message_id().ok(), expected);
+            if expected.is_none() {
+                assert_eq!(parser.pos(), 0);
+            }
+        }
+    }
+
     #[test]
     fn test_parse_u8_with_error() {
         let mut parser = Parser::new(b"8 ").unwrap();
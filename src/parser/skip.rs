@@ -70,6 +70,27 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Skip spaces and tabs, stopping at newlines.
+    pub fn skip_spaces_and_tabs(&mut self) {
+        while matches!(self.current_byte(), Some(b' ') | Some(b'\t')) {
+            self.pos += 1;
+        }
+    }
+
+    /// Skip a trailing `//` comment at the end of a line, leaving the newline unconsumed.
+    /// Leading spaces and tabs are skipped too; anything else leaves the position unchanged.
+    pub fn skip_trailing_comment(&mut self) {
+        let start = self.pos;
+        self.skip_spaces_and_tabs();
+        if !self.starts_with(b"//") {
+            self.pos = start;
+            return;
+        }
+        while !self.eof() && !self.at_newline() {
+            self.pos += 1;
+        }
+    }
+
     /// Skip whitespace optionally (don't error if no whitespace).
     /// Consolidates the pattern: `let _ = parser.skip_whitespace();` or `skip_whitespace().ok()`.
     #[inline]
@@ -286,4 +307,17 @@ mod tests {
         parser.expect(b"test\n").unwrap();
         assert_eq!(parser.line(), 2);
     }
+
+    #[test]
+    fn skip_trailing_comment_stops_at_newline() {
+        let mut parser = Parser::new(b" \t// generated\nnext").unwrap();
+        parser.skip_trailing_comment();
+        assert!(parser.at_newline());
+        assert_eq!(parser.line(), 1);
+
+        // Not a comment: position is left unchanged
+        let mut parser = Parser::new(b"  / x").unwrap();
+        parser.skip_trailing_comment();
+        assert_eq!(parser.pos, 0);
+    }
 }
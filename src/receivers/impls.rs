@@ -1,5 +1,4 @@
-use super::{ReceiverNames, Receivers};
-use crate::compat::Name;
+use super::{ReceiverNames, Receivers, ReceiversIter};
 
 impl Receivers {
     pub(crate) fn new_none() -> Self {
@@ -11,6 +10,28 @@ impl Receivers {
         Receivers::Nodes(nodes)
     }
 
+    /// Removes `node` from the receiver list, falling back to `None` once it is empty.
+    #[cfg(feature = "std")]
+    pub(crate) fn remove(&mut self, node: &str) {
+        if let Receivers::Nodes(nodes) = self {
+            let remaining: ReceiverNames =
+                nodes.iter().filter(|name| name.as_str() != node).cloned().collect();
+            *self = if remaining.is_empty() {
+                Receivers::None
+            } else {
+                Receivers::Nodes(remaining)
+            };
+        }
+    }
+
+    /// Sorts the receiver list alphabetically.
+    #[cfg(feature = "std")]
+    pub(crate) fn sort(&mut self) {
+        if let Receivers::Nodes(nodes) = self {
+            nodes.as_mut_slice().sort();
+        }
+    }
+
     /// Returns an iterator over the receiver node names.
     ///
     /// For `Receivers::None`, the iterator will be empty.
@@ -62,7 +83,7 @@ impl Receivers {
     /// ```
     #[inline]
     #[must_use = "iterator is lazy and does nothing unless consumed"]
-    pub fn iter(&self) -> impl Iterator<Item = &str> {
+    pub fn iter(&self) -> ReceiversIter<'_> {
         match self {
             Receivers::Nodes(nodes) => ReceiversIter {
                 nodes: Some(nodes.as_slice()),
@@ -213,9 +234,14 @@ impl Receivers {
     }
 }
 
-struct ReceiversIter<'a> {
-    nodes: Option<&'a [Name]>,
-    pos: usize,
+impl<'a> IntoIterator for &'a Receivers {
+    type Item = &'a str;
+    type IntoIter = ReceiversIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
 impl<'a> Iterator for ReceiversIter<'a> {
@@ -233,8 +259,15 @@ impl<'a> Iterator for ReceiversIter<'a> {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.nodes.map_or(0, |nodes| nodes.len() - self.pos);
+        (remaining, Some(remaining))
+    }
 }
 
+impl ExactSizeIterator for ReceiversIter<'_> {}
+
 #[cfg(test)]
 mod tests {
     use super::Receivers;
@@ -271,6 +304,28 @@ mod tests {
         }
     }
 
+    // Tests for IntoIterator
+    mod test_into_iter {
+        use super::*;
+
+        #[test]
+        fn test_receivers_into_iter() {
+            let input = "TCM BCM";
+            let mut parser = Parser::new(input.as_bytes()).unwrap();
+            let result = Receivers::parse(&mut parser).unwrap();
+            let mut names = [""; 2];
+            for (slot, name) in names.iter_mut().zip(&result) {
+                *slot = name;
+            }
+            assert_eq!(names, ["TCM", "BCM"]);
+            assert_eq!((&result).into_iter().len(), 2);
+
+            let none = Receivers::new_none();
+            assert_eq!((&none).into_iter().len(), 0);
+            assert_eq!((&none).into_iter().next(), None);
+        }
+    }
+
     // Tests for len()
     mod test_len {
         use super::*;
@@ -61,9 +61,19 @@ type ReceiverNames = Vec<Name, { MAX_NODES - 1 }>;
 /// - `Vector__XXX` indicates no specific receiver
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[allow(clippy::large_enum_variant)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Receivers {
     /// Specific receiver nodes - vector of node names.
     Nodes(ReceiverNames),
     /// No explicit receivers specified (serializes as `Vector__XXX`).
     None,
 }
+
+/// Iterator over the receiver node names, created by [`Receivers::iter`].
+///
+/// Yields nothing for [`Receivers::None`].
+#[derive(Debug, Clone)]
+pub struct ReceiversIter<'a> {
+    nodes: Option<&'a [Name]>,
+    pos: usize,
+}
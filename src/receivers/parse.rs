@@ -48,7 +48,8 @@ impl Receivers {
             }
 
             // Check again if we're at a newline after skipping whitespace/commas
-            if parser.at_newline() || parser.eof() {
+            // A trailing `//` comment also ends the list; the caller skips it
+            if parser.at_newline() || parser.eof() || parser.starts_with(b"//") {
                 break;
             }
 
@@ -209,6 +210,15 @@ mod tests {
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    fn test_parse_receivers_stops_at_trailing_comment() {
+        let input = "TCM,BCM// generated";
+        let mut parser = Parser::new(input.as_bytes()).unwrap();
+        let result = Receivers::parse(&mut parser).unwrap();
+        assert!(result.iter().eq(["TCM", "BCM"]));
+        assert!(parser.starts_with(b"//"));
+    }
+
     #[test]
     fn test_parse_receivers_too_many() {
         use crate::compat;
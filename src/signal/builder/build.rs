@@ -471,4 +471,50 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_signal_try_new_matches_builder() {
+        let built = minimal_signal().unit("km/h").build().unwrap();
+        let direct = Signal::try_new(
+            "TestSignal",
+            0,
+            8,
+            ByteOrder::LittleEndian,
+            true,
+            1.0,
+            0.0,
+            0.0,
+            255.0,
+            Some("km/h"),
+            ReceiversBuilder::new().none().build().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(direct, built);
+    }
+
+    #[test]
+    fn test_signal_try_new_validates() {
+        let try_new = |name: &str, length: u16, min: f64, max: f64| {
+            Signal::try_new(
+                name,
+                0,
+                length,
+                ByteOrder::LittleEndian,
+                true,
+                1.0,
+                0.0,
+                min,
+                max,
+                None,
+                ReceiversBuilder::new().none().build().unwrap(),
+            )
+        };
+
+        assert!(try_new("", 8, 0.0, 255.0).is_err());
+        assert!(try_new("Test", 0, 0.0, 255.0).is_err());
+        assert!(try_new("Test", 513, 0.0, 255.0).is_err());
+        assert!(try_new("Test", 8, 10.0, 0.0).is_err());
+        assert!(try_new("Test", 8, 0.0, 255.0).is_ok());
+    }
 }
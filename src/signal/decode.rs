@@ -1,7 +1,66 @@
 use super::Signal;
-use crate::{Error, Result};
+use crate::{Error, Message, Result};
 
 impl Signal {
+    /// Extract this signal's raw value from a payload.
+    ///
+    /// Uses the signal's own start bit, length and byte order, and sign-extends signed
+    /// signals. For float and double signals (see [`value_type`](Self::value_type)) the
+    /// raw value is the IEEE-754 bit pattern. Returns `None` if the payload is too short
+    /// to hold the signal. [`encode_raw_to`](Self::encode_raw_to) writes a raw value back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let rpm = dbc.messages().find_by_id(256).unwrap().signals().find("RPM").unwrap();
+    /// assert_eq!(rpm.decode_raw(&[0x40, 0x1F]), Some(8000));
+    /// assert_eq!(rpm.decode_raw(&[0x40]), None);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn decode_raw(&self, payload: &[u8]) -> Option<i64> {
+        self.decode_raw_and_physical(payload).ok().map(|(raw, _)| raw)
+    }
+
+    /// Extract this signal's physical value (`raw * factor + offset`) from a payload.
+    ///
+    /// Returns `None` if the payload is too short to hold the signal.
+    /// See [`decode_raw`](Self::decode_raw) for how the raw value is extracted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let rpm = dbc.messages().find_by_id(256).unwrap().signals().find("RPM").unwrap();
+    /// assert_eq!(rpm.decode_physical(&[0x40, 0x1F]), Some(2000.0));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn decode_physical(&self, payload: &[u8]) -> Option<f64> {
+        self.decode_raw_and_physical(payload).ok().map(|(_, physical)| physical)
+    }
+
     /// Decode the signal and return both raw and physical values in a single pass.
     ///
     /// This is an optimized method for multiplexer switch decoding where both the
@@ -17,10 +76,11 @@ impl Signal {
     /// * `Ok((raw_value, physical_value))` - The raw signed integer and physical (factor+offset) value
     /// * `Err(Error)` - If the signal extends beyond the data length
     #[inline]
-    pub(crate) fn decode_raw(&self, data: &[u8]) -> Result<(i64, f64)> {
+    pub(crate) fn decode_raw_and_physical(&self, data: &[u8]) -> Result<(i64, f64)> {
         let start_bit = self.start_bit as usize;
         let length = self.length as usize;
-        let end_byte = (start_bit + length - 1) / 8;
+        // Big-endian signals do not extend linearly from the start bit
+        let end_byte = usize::from(Message::signal_end_bit(self)) / 8;
 
         if end_byte >= data.len() {
             return Err(Error::Decoding(Error::SIGNAL_EXTENDS_BEYOND_DATA));
@@ -28,6 +88,11 @@ impl Signal {
 
         let raw_bits = self.byte_order.extract_bits(data, start_bit, length);
 
+        // Float and double signals: the raw value is the IEEE-754 bit pattern
+        if let Some(value) = self.value_type.bits_to_f64(raw_bits) {
+            return Ok((raw_bits as i64, value * self.factor + self.offset));
+        }
+
         let raw_value = if self.unsigned {
             raw_bits as i64
         } else {
@@ -48,7 +113,28 @@ impl Signal {
 #[cfg(test)]
 mod tests {
     use super::Signal;
-    use crate::Parser;
+    use crate::{Error, Parser};
+
+    #[test]
+    fn test_decode_big_endian_payload_extent() {
+        // 7|16@0 occupies bytes 0-1
+        let signal = Signal::parse(
+            &mut Parser::new(b"SG_ TestSignal : 7|16@0+ (1,0) [0|65535] \"\"").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(signal.decode_raw(&[0x01, 0x02]), Some(0x0102));
+
+        // 0|16@0 occupies bytes 0-2
+        let signal = Signal::parse(
+            &mut Parser::new(b"SG_ TestSignal : 0|16@0+ (1,0) [0|65535] \"\"").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            signal.decode_raw_and_physical(&[0x01, 0x02]),
+            Err(Error::Decoding(Error::SIGNAL_EXTENDS_BEYOND_DATA))
+        );
+        assert!(signal.decode_raw(&[0x01, 0x02, 0x03]).is_some());
+    }
 
     #[test]
     fn test_decode_little_endian() {
@@ -58,7 +144,7 @@ mod tests {
         .unwrap();
         // Test value 0x0102 = 258: little-endian bytes are [0x02, 0x01]
         let data = [0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-        let (_raw, value) = signal.decode_raw(&data).unwrap();
+        let (_raw, value) = signal.decode_raw_and_physical(&data).unwrap();
         assert_eq!(value, 258.0);
     }
 
@@ -70,7 +156,7 @@ mod tests {
         .unwrap();
         // Test big-endian decoding: value 0x0100 = 256 at bit 0-15
         let data = [0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-        let (_raw, value) = signal.decode_raw(&data).unwrap();
+        let (_raw, value) = signal.decode_raw_and_physical(&data).unwrap();
         // Verify it decodes to a valid value within range
         assert!((0.0..=65535.0).contains(&value));
     }
@@ -82,7 +168,7 @@ mod tests {
                 .unwrap();
         // Raw value 90 with offset -40 = 50°C
         let data = [0x5A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-        let (_raw, value) = signal.decode_raw(&data).unwrap();
+        let (_raw, value) = signal.decode_raw_and_physical(&data).unwrap();
         assert_eq!(value, 50.0);
     }
 
@@ -94,7 +180,7 @@ mod tests {
         // Test big-endian decoding with factor
         // Big-endian at bit 0-15: bytes [0x1F, 0x40]
         let data = [0x1F, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-        let (_raw, value) = signal.decode_raw(&data).unwrap();
+        let (_raw, value) = signal.decode_raw_and_physical(&data).unwrap();
         // Verify it decodes and applies factor correctly (value should be positive)
         assert!((0.0..=16383.75).contains(&value)); // Max u16 * 0.25
     }
@@ -119,7 +205,7 @@ mod tests {
 
         // Spec example: bytes [0x64, 0x00]
         let data = [0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-        let (_raw, value) = signal.decode_raw(&data).unwrap();
+        let (_raw, value) = signal.decode_raw_and_physical(&data).unwrap();
 
         // Expected: raw=100, physical=100*0.1=10.0
         assert_eq!(
@@ -143,7 +229,7 @@ mod tests {
 
         // Spec example: bytes [0x03, 0xE8]
         let data = [0x03, 0xE8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-        let (_raw, value) = signal.decode_raw(&data).unwrap();
+        let (_raw, value) = signal.decode_raw_and_physical(&data).unwrap();
 
         // Expected: raw=1000 (0x03E8), physical=1000*0.01=10.0
         assert_eq!(
@@ -167,7 +253,7 @@ mod tests {
         // Test 1: raw=127 → physical=87°C
         // Little-endian: signal at bit 16 means byte 2
         let data1 = [0x00, 0x00, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00];
-        let (_raw, value1) = signal.decode_raw(&data1).unwrap();
+        let (_raw, value1) = signal.decode_raw_and_physical(&data1).unwrap();
         assert_eq!(
             value1, 87.0,
             "Spec Section 10.5: raw=127 should decode to 87°C"
@@ -175,7 +261,7 @@ mod tests {
 
         // Test 2: raw=0 → physical=-40°C
         let data2 = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-        let (_raw, value2) = signal.decode_raw(&data2).unwrap();
+        let (_raw, value2) = signal.decode_raw_and_physical(&data2).unwrap();
         assert_eq!(
             value2, -40.0,
             "Spec Section 10.5: raw=0 should decode to -40°C"
@@ -215,7 +301,34 @@ mod tests {
         // raw=400 → physical = 400 * 0.25 + 100 = 200
         // Little-endian: 400 = 0x0190 → bytes [0x90, 0x01]
         let data = [0x90, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-        let (_raw, value) = signal.decode_raw(&data).unwrap();
+        let (_raw, value) = signal.decode_raw_and_physical(&data).unwrap();
         assert_eq!(value, 200.0, "Spec Section 10.5: 400 * 0.25 + 100 = 200");
     }
+
+    #[test]
+    fn test_decode_raw_and_physical_little_endian() {
+        let signal = Signal::parse(
+            &mut Parser::new(b"SG_ Temp : 8|12@1- (0.5,-10) [-1034|1013.5] \"C\"").unwrap(),
+        )
+        .unwrap();
+        // Raw -2 (0xFFE) in bits 8..20
+        let data = [0x00, 0xFE, 0x0F];
+        assert_eq!(signal.decode_raw(&data), Some(-2));
+        assert_eq!(signal.decode_physical(&data), Some(-11.0));
+        assert_eq!(signal.decode_raw(&data[..2]), None);
+        assert_eq!(signal.decode_physical(&data[..2]), None);
+    }
+
+    #[test]
+    fn test_decode_raw_and_physical_big_endian() {
+        let signal = Signal::parse(
+            &mut Parser::new(b"SG_ Speed : 7|16@0+ (0.1,0) [0|6553.5] \"km/h\"").unwrap(),
+        )
+        .unwrap();
+        // Raw 1000 (0x03E8), most significant byte first
+        let data = [0x03, 0xE8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        assert_eq!(signal.decode_raw(&data), Some(1000));
+        assert_eq!(signal.decode_physical(&data), Some(100.0));
+        assert_eq!(signal.decode_raw(&data[..1]), None);
+    }
 }
@@ -1,5 +1,5 @@
 use super::Signal;
-use crate::{Error, Result};
+use crate::{ByteOrder, EncodeMode, Error, Message, Result, SignalValueType};
 
 /// Round to nearest integer (half away from zero).
 /// Equivalent to libm::round but without the dependency.
@@ -20,6 +20,9 @@ impl Signal {
     /// (after factor and offset have been applied) back to the raw integer
     /// value that can be inserted into a CAN message payload.
     ///
+    /// Out-of-range values are rejected; see [`encode_raw_with`](Self::encode_raw_with)
+    /// to clamp or wrap them instead.
+    ///
     /// # Arguments
     ///
     /// * `physical_value` - The physical value to encode (e.g., 2000.0 for RPM)
@@ -36,10 +39,93 @@ impl Signal {
     /// ```
     #[inline]
     pub fn encode_raw(&self, physical_value: f64) -> Result<u64> {
-        // Validate value is within min/max range
-        if physical_value < self.min || physical_value > self.max {
-            return Err(Error::Encoding(Error::ENCODING_VALUE_OUT_OF_RANGE));
-        }
+        self.encode_raw_with(physical_value, EncodeMode::Reject)
+    }
+
+    /// Encode a physical value to raw bits, handling out-of-range values per `mode`.
+    ///
+    /// Like [`encode_raw`](Self::encode_raw), but values outside `[min, max]` are
+    /// rejected, clamped or wrapped as described by [`EncodeMode`].
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Encoding`] with [`Error::ENCODING_VALUE_OUT_OF_RANGE`] for NaN, or in
+    ///   [`EncodeMode::Reject`] if the value lies outside `[min, max]`
+    /// - [`Error::Encoding`] with [`Error::ENCODING_VALUE_OVERFLOW`] in
+    ///   [`EncodeMode::Reject`] if the raw value does not fit in `length` bits
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::{Dbc, EncodeMode};
+    /// # let dbc_content = "VERSION \"1.0\"\nBO_ 500 MSG_NAME: 8 ECU\n SG_ SIGNAL_NAME : 0|8@1- (1,0) [-100|100] \"\" ECU\n";
+    /// # let dbc = Dbc::parse(dbc_content).unwrap();
+    /// let message = dbc.messages().find("MSG_NAME").unwrap();
+    /// let signal = message.signals().find("SIGNAL_NAME").unwrap();
+    /// assert_eq!(signal.encode_raw_with(-150.0, EncodeMode::Clamp)?, 0x9C); // -100
+    /// assert_eq!(signal.encode_raw_with(130.0, EncodeMode::Wrap)?, 130);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn encode_raw_with(&self, physical_value: f64, mode: EncodeMode) -> Result<u64> {
+        let raw_signed = self.physical_to_raw(physical_value, mode)?;
+
+        // Two's complement representation masked to the signal bit length
+        // (a no-op for non-negative values, which already fit)
+        let mask = if self.length >= 64 {
+            u64::MAX
+        } else {
+            (1u64 << self.length) - 1
+        };
+        Ok((raw_signed as u64) & mask)
+    }
+
+    /// Convert a physical value to the raw integer value, rejecting values that don't fit.
+    ///
+    /// Unlike clamping, this fails if the physical value lies outside `[min, max]` (or is
+    /// NaN), or if the rounded raw value doesn't fit the signal's bit field. Signed signals
+    /// return a negative raw value rather than its two's complement bits. Float and double
+    /// signals are not rounded and return the IEEE-754 bit pattern.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Encoding`] with [`Error::ENCODING_VALUE_OUT_OF_RANGE`] if the value lies
+    ///   outside `[min, max]`
+    /// - [`Error::Encoding`] with [`Error::ENCODING_VALUE_OVERFLOW`] if the raw value does
+    ///   not fit in `length` bits
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc_content = "VERSION \"1.0\"\nBO_ 500 MSG_NAME: 8 ECU\n SG_ SIGNAL_NAME : 0|8@1- (0.5,0) [-64|63.5] \"\" ECU\n";
+    /// # let dbc = Dbc::parse(dbc_content).unwrap();
+    /// let message = dbc.messages().find("MSG_NAME").unwrap();
+    /// let signal = message.signals().find("SIGNAL_NAME").unwrap();
+    /// assert_eq!(signal.physical_to_raw_checked(-10.0), Ok(-20));
+    /// assert!(signal.physical_to_raw_checked(100.0).is_err());
+    /// ```
+    pub fn physical_to_raw_checked(&self, physical_value: f64) -> Result<i64> {
+        self.physical_to_raw(physical_value, EncodeMode::Reject)
+    }
+
+    /// Convert a physical value to the raw integer value, handling out-of-range values
+    /// per `mode`. [`EncodeMode::Wrap`] may return a value wider than the bit field,
+    /// which callers mask.
+    fn physical_to_raw(&self, physical_value: f64, mode: EncodeMode) -> Result<i64> {
+        let in_range = (self.min..=self.max).contains(&physical_value);
+        let physical_value = match mode {
+            _ if physical_value.is_nan() => {
+                return Err(Error::Encoding(Error::ENCODING_VALUE_OUT_OF_RANGE));
+            }
+            EncodeMode::Reject if !in_range => {
+                return Err(Error::Encoding(Error::ENCODING_VALUE_OUT_OF_RANGE));
+            }
+            // `[0|0]` leaves the range unspecified: only the bit field limits the value
+            EncodeMode::Clamp if !in_range && !(self.min == 0.0 && self.max == 0.0) => {
+                physical_value.max(self.min).min(self.max)
+            }
+            _ => physical_value,
+        };
 
         // Reverse the decode formula: raw = (physical - offset) / factor
         // Handle factor == 0 to avoid division by zero (shouldn't happen in valid DBC)
@@ -50,52 +136,82 @@ impl Signal {
             0.0
         };
 
+        // Float and double signals store the IEEE-754 bit pattern
+        if let Some(bits) = self.value_type.f64_to_bits(raw_float) {
+            return Ok(bits as i64);
+        }
+
+        if mode == EncodeMode::Wrap {
+            return Ok(self.wrap_raw(raw_float));
+        }
+
         // Round to nearest integer
         let raw_signed = round(raw_float) as i64;
 
-        // Handle signed vs unsigned encoding
-        let raw_bits = if self.unsigned {
-            // Unsigned: ensure non-negative and fits in bit length
-            if raw_signed < 0 {
-                return Err(Error::Encoding(Error::ENCODING_VALUE_OVERFLOW));
-            }
-            let raw_unsigned = raw_signed as u64;
-            let max_value = if self.length >= 64 {
-                u64::MAX
+        if self.raw_fits(raw_signed) {
+            return Ok(raw_signed);
+        }
+        match mode {
+            EncodeMode::Clamp => Ok(self.saturate_raw(raw_signed)),
+            _ => Err(Error::Encoding(Error::ENCODING_VALUE_OVERFLOW)),
+        }
+    }
+
+    /// Round `raw` and reduce it modulo 2^`length`, returning the two's complement bits.
+    ///
+    /// Works on the float so values beyond the `i64` range wrap instead of saturating.
+    fn wrap_raw(&self, raw: f64) -> i64 {
+        // Floats of magnitude 2^52 and above are already integers
+        let rounded = if raw.abs() < 4_503_599_627_370_496.0 {
+            round(raw)
+        } else {
+            raw
+        };
+        let modulus = (1u128 << self.length.min(64)) as f64;
+        let mut wrapped = rounded % modulus;
+        if wrapped < 0.0 {
+            wrapped += modulus;
+        }
+        wrapped as u64 as i64
+    }
+
+    /// Saturate `raw` to the bounds of the signal's integer bit field.
+    fn saturate_raw(&self, raw: i64) -> i64 {
+        if self.length >= 64 {
+            raw
+        } else if self.unsigned {
+            let max = if self.length >= 63 {
+                i64::MAX
             } else {
-                (1u64 << self.length) - 1
+                (1i64 << self.length) - 1
             };
-            if raw_unsigned > max_value {
-                return Err(Error::Encoding(Error::ENCODING_VALUE_OVERFLOW));
-            }
-            raw_unsigned
+            raw.clamp(0, max)
         } else {
-            // Signed: use two's complement encoding
-            // Check if value fits in signed range for this bit length
             let half_range = 1i64 << (self.length - 1);
-            let min_signed = -half_range;
-            let max_signed = half_range - 1;
-
-            if raw_signed < min_signed || raw_signed > max_signed {
-                return Err(Error::Encoding(Error::ENCODING_VALUE_OVERFLOW));
-            }
+            raw.clamp(-half_range, half_range - 1)
+        }
+    }
 
-            // Convert to two's complement representation
-            // For positive values, just cast. For negative, mask to bit length.
-            if raw_signed >= 0 {
-                raw_signed as u64
-            } else {
-                // Two's complement: mask to signal bit length
-                let mask = if self.length >= 64 {
-                    u64::MAX
-                } else {
-                    (1u64 << self.length) - 1
-                };
-                (raw_signed as u64) & mask
-            }
-        };
+    /// Whether `raw` fits in the signal's bit field, as unsigned or two's complement.
+    fn raw_fits(&self, raw: i64) -> bool {
+        if self.length >= 64 {
+            true
+        } else if self.unsigned || self.value_type != SignalValueType::Integer {
+            raw >= 0 && (self.length >= 63 || raw < 1i64 << self.length)
+        } else {
+            let half_range = 1i64 << (self.length - 1);
+            (-half_range..half_range).contains(&raw)
+        }
+    }
 
-        Ok(raw_bits)
+    /// Fail if the signal extends beyond a payload of `len` bytes.
+    fn check_payload_len(&self, len: usize) -> Result<()> {
+        // Big-endian signals do not extend linearly from the start bit
+        let end_byte = usize::from(Message::signal_end_bit(self)) / 8;
+        if end_byte >= len {
+            return Err(Error::Encoding(Error::SIGNAL_EXTENDS_BEYOND_DATA));
+        }
+        Ok(())
     }
 
     /// Encode a physical value and insert it into a payload buffer.
@@ -115,24 +231,222 @@ impl Signal {
     /// * `Err(Error)` - If encoding failed or signal extends beyond payload
     #[inline]
     pub fn encode_to(&self, physical_value: f64, payload: &mut [u8]) -> Result<()> {
-        let start_bit = self.start_bit as usize;
-        let length = self.length as usize;
-        let end_byte = (start_bit + length - 1) / 8;
+        self.encode_to_with(physical_value, payload, EncodeMode::Reject)
+    }
 
-        if end_byte >= payload.len() {
-            return Err(Error::Encoding(Error::SIGNAL_EXTENDS_BEYOND_DATA));
-        }
+    /// Encode a physical value into a payload buffer, handling out-of-range values per
+    /// `mode`.
+    ///
+    /// Like [`encode_to`](Self::encode_to), using
+    /// [`encode_raw_with`](Self::encode_raw_with) to compute the raw bits.
+    pub fn encode_to_with(
+        &self,
+        physical_value: f64,
+        payload: &mut [u8],
+        mode: EncodeMode,
+    ) -> Result<()> {
+        self.check_payload_len(payload.len())?;
+        let raw_bits = self.encode_raw_with(physical_value, mode)?;
+        self.byte_order.insert_bits(
+            payload,
+            self.start_bit as usize,
+            self.length as usize,
+            raw_bits,
+        );
+        Ok(())
+    }
 
-        let raw_bits = self.encode_raw(physical_value)?;
-        self.byte_order.insert_bits(payload, start_bit, length, raw_bits);
+    /// Write a raw value into a payload buffer.
+    ///
+    /// This is the inverse of [`decode_raw`](Self::decode_raw): `raw` is written as is,
+    /// without factor, offset or range checks, using the signal's start bit, length and
+    /// byte order. Signed signals take a negative `raw` as two's complement; float and
+    /// double signals take the IEEE-754 bit pattern. Bits outside the signal are left
+    /// untouched, which makes this handy for testing bit layouts in isolation.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Encoding`] with [`Error::SIGNAL_EXTENDS_BEYOND_DATA`] if the signal does
+    ///   not fit in `payload`
+    /// - [`Error::Encoding`] with [`Error::ENCODING_VALUE_OVERFLOW`] if `raw` does not fit
+    ///   in the signal's bit field (e.g. a negative value for an unsigned signal)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc_content = "VERSION \"1.0\"\nBO_ 500 MSG_NAME: 8 ECU\n SG_ SIGNAL_NAME : 7|12@0- (1,0) [-2048|2047] \"\" ECU\n";
+    /// # let dbc = Dbc::parse(dbc_content).unwrap();
+    /// let message = dbc.messages().find("MSG_NAME").unwrap();
+    /// let signal = message.signals().find("SIGNAL_NAME").unwrap();
+    /// let mut payload = [0u8; 2];
+    /// signal.encode_raw_to(-5, &mut payload)?;
+    /// assert_eq!(signal.decode_raw(&payload), Some(-5));
+    /// assert!(signal.encode_raw_to(2048, &mut payload).is_err());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn encode_raw_to(&self, raw: i64, payload: &mut [u8]) -> Result<()> {
+        self.check_payload_len(payload.len())?;
+        if !self.raw_fits(raw) {
+            return Err(Error::Encoding(Error::ENCODING_VALUE_OVERFLOW));
+        }
+        let mask = if self.length >= 64 {
+            u64::MAX
+        } else {
+            (1u64 << self.length) - 1
+        };
+        self.byte_order.insert_bits(
+            payload,
+            self.start_bit as usize,
+            self.length as usize,
+            raw as u64 & mask,
+        );
         Ok(())
     }
+
+    /// Returns the absolute bit position of the signal's least significant bit.
+    ///
+    /// For little-endian signals this is the start bit. For big-endian signals, whose
+    /// start bit is the most significant bit, this is the physical bit that receives the
+    /// least significant bit when the signal is encoded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc_content = "VERSION \"1.0\"\nBO_ 500 MSG_NAME: 8 ECU\n SG_ SIGNAL_NAME : 7|12@0+ (1,0) [0|4095] \"\" ECU\n";
+    /// # let dbc = Dbc::parse(dbc_content).unwrap();
+    /// let message = dbc.messages().find("MSG_NAME").unwrap();
+    /// let signal = message.signals().find("SIGNAL_NAME").unwrap();
+    /// // The high 8 bits fill byte 0, the low 4 bits land in bits 3..0 of byte 1
+    /// assert_eq!(signal.bit_position(), 8);
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn bit_position(&self) -> u16 {
+        match self.byte_order {
+            ByteOrder::LittleEndian => self.start_bit,
+            ByteOrder::BigEndian => {
+                let mut position = self.start_bit;
+                self.for_each_big_endian_chunk(|byte, shift, _| position = byte * 8 + shift);
+                position
+            }
+        }
+    }
+
+    /// Returns the bits this signal occupies within its byte span.
+    ///
+    /// The mask is relative to a little-endian `u64` window starting at the signal's
+    /// first byte (`start_bit / 8`): bit `n` of the mask is bit `n % 8` of byte
+    /// `start_bit / 8 + n / 8`. OR-ing `mask` into that window (or AND-ing with `!mask`)
+    /// sets (or clears) exactly the bits written by [`encode_to`](Self::encode_to), for
+    /// either byte order. Bits beyond the 8-byte window (possible only for long unaligned
+    /// signals) are not represented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc_content = "VERSION \"1.0\"\nBO_ 500 MSG_NAME: 8 ECU\n SG_ SIGNAL_NAME : 12|8@1+ (1,0) [0|255] \"\" ECU\n";
+    /// # let dbc = Dbc::parse(dbc_content).unwrap();
+    /// let message = dbc.messages().find("MSG_NAME").unwrap();
+    /// let signal = message.signals().find("SIGNAL_NAME").unwrap();
+    /// // Window starts at byte 1; the signal covers its bits 4..12
+    /// assert_eq!(signal.mask(), 0x0FF0);
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn mask(&self) -> u64 {
+        let first_byte = self.start_bit / 8;
+        match self.byte_order {
+            ByteOrder::LittleEndian => {
+                let shift = self.start_bit % 8;
+                let bits = self.length.min(64 - shift);
+                (u64::MAX >> (64 - bits)) << shift
+            }
+            ByteOrder::BigEndian => {
+                let mut mask = 0u64;
+                self.for_each_big_endian_chunk(|byte, shift, bits| {
+                    let offset = (byte - first_byte) * 8 + shift;
+                    if offset < 64 {
+                        mask |= ((1u64 << bits) - 1) << offset;
+                    }
+                });
+                mask
+            }
+        }
+    }
+
+    /// Walk the per-byte chunks of a big-endian signal in the order
+    /// `ByteOrder::insert_bits` writes them, as `(byte, shift, bits)`.
+    fn for_each_big_endian_chunk(&self, mut f: impl FnMut(u16, u16, u16)) {
+        let mut remaining = self.length;
+        let mut be_bit = self.start_bit;
+        while remaining > 0 {
+            let bit_in_byte = be_bit % 8;
+            let bits = remaining.min(bit_in_byte + 1);
+            f(be_bit / 8, 7 - bit_in_byte, bits);
+            remaining -= bits;
+            be_bit += bits;
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Signal;
-    use crate::Parser;
+    use crate::{ByteOrder, Error, Parser};
+
+    /// Mask of `signal` derived by inserting an all-ones raw value into an empty payload.
+    fn inserted_mask(signal: &Signal) -> u64 {
+        let mut payload = [0u8; 16];
+        signal.byte_order().insert_bits(
+            &mut payload,
+            signal.start_bit() as usize,
+            signal.length() as usize,
+            u64::MAX >> (64 - signal.length()),
+        );
+        let first = signal.start_bit() as usize / 8;
+        let mut window = [0u8; 8];
+        window.copy_from_slice(&payload[first..first + 8]);
+        u64::from_le_bytes(window)
+    }
+
+    #[test]
+    fn test_mask_aligned() {
+        let signal = Signal::parse(
+            &mut Parser::new(b"SG_ RPM : 16|16@1+ (0.25,0) [0|8000] \"rpm\"").unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(signal.bit_position(), 16);
+        assert_eq!(signal.mask(), 0xFFFF);
+        assert_eq!(signal.mask(), inserted_mask(&signal));
+    }
+
+    #[test]
+    fn test_mask_cross_byte() {
+        let little =
+            Signal::parse(&mut Parser::new(b"SG_ Temp : 12|8@1- (1,-40) [-40|87] \"\"").unwrap())
+                .unwrap();
+        assert_eq!(little.byte_order(), ByteOrder::LittleEndian);
+        assert_eq!(little.bit_position(), 12);
+        assert_eq!(little.mask(), 0x0FF0);
+        assert_eq!(little.mask(), inserted_mask(&little));
+
+        let big =
+            Signal::parse(&mut Parser::new(b"SG_ Gear : 11|12@0+ (1,0) [0|4095] \"\"").unwrap())
+                .unwrap();
+        assert_eq!(big.byte_order(), ByteOrder::BigEndian);
+        assert_eq!(big.bit_position(), 8);
+        assert_eq!(big.mask(), 0x00FF);
+        assert_eq!(big.mask(), inserted_mask(&big));
+
+        let big =
+            Signal::parse(&mut Parser::new(b"SG_ Load : 7|12@0+ (1,0) [0|4095] \"\"").unwrap())
+                .unwrap();
+        assert_eq!(big.bit_position(), 8);
+        assert_eq!(big.mask(), 0x0FFF);
+        assert_eq!(big.mask(), inserted_mask(&big));
+    }
 
     #[test]
     fn test_encode_raw_unsigned() {
@@ -189,6 +503,140 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_encode_raw_with_modes_above_max() {
+        use crate::EncodeMode;
+
+        // 8-bit unsigned, max below the bit-field limit
+        let signal =
+            Signal::parse(&mut Parser::new(b"SG_ Temp : 0|8@1+ (1,-40) [-40|160] \"C\"").unwrap())
+                .unwrap();
+
+        // Reject is the default and matches encode_raw
+        assert_eq!(EncodeMode::default(), EncodeMode::Reject);
+        let out_of_range = Err(Error::Encoding(Error::ENCODING_VALUE_OUT_OF_RANGE));
+        assert_eq!(
+            signal.encode_raw_with(250.0, EncodeMode::Reject),
+            out_of_range
+        );
+        assert_eq!(signal.encode_raw(250.0), out_of_range);
+
+        // Clamp encodes max: 160 - -40 = 200
+        assert_eq!(signal.encode_raw_with(250.0, EncodeMode::Clamp), Ok(200));
+        assert_eq!(signal.encode_raw_with(-100.0, EncodeMode::Clamp), Ok(0));
+
+        // Wrap keeps the low 8 bits of 290
+        assert_eq!(
+            signal.encode_raw_with(250.0, EncodeMode::Wrap),
+            Ok(290 & 0xFF)
+        );
+
+        // In-range values are unaffected by the mode
+        for mode in [EncodeMode::Reject, EncodeMode::Clamp, EncodeMode::Wrap] {
+            assert_eq!(signal.encode_raw_with(10.0, mode), Ok(50));
+            assert_eq!(signal.encode_raw_with(f64::NAN, mode), out_of_range);
+        }
+    }
+
+    #[test]
+    fn test_encode_raw_with_clamp_saturates_bit_field() {
+        use crate::EncodeMode;
+
+        // Declared range exceeds what a signed 8-bit field can hold
+        let signal = Signal::parse(
+            &mut Parser::new(b"SG_ Torque : 0|8@1- (1,0) [-500|500] \"Nm\"").unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            signal.encode_raw_with(300.0, EncodeMode::Reject),
+            Err(Error::Encoding(Error::ENCODING_VALUE_OVERFLOW))
+        );
+        assert_eq!(signal.encode_raw_with(300.0, EncodeMode::Clamp), Ok(0x7F));
+        assert_eq!(signal.encode_raw_with(-300.0, EncodeMode::Clamp), Ok(0x80));
+        assert_eq!(
+            signal.encode_raw_with(300.0, EncodeMode::Wrap),
+            Ok(300 & 0xFF)
+        );
+        assert_eq!(
+            signal.encode_raw_with(600.0, EncodeMode::Wrap),
+            Ok(600 & 0xFF)
+        );
+
+        let mut payload = [0u8; 2];
+        signal.encode_to_with(600.0, &mut payload, EncodeMode::Clamp).unwrap();
+        assert_eq!(payload, [0x7F, 0x00]);
+    }
+
+    #[test]
+    fn test_encode_raw_with_unspecified_range_and_huge_values() {
+        use crate::EncodeMode;
+
+        // `[0|0]`: Clamp only saturates at the bit field
+        let signal =
+            Signal::parse(&mut Parser::new(b"SG_ Counter : 0|8@1+ (1,0) [0|0] \"\"").unwrap())
+                .unwrap();
+        assert_eq!(signal.encode_raw_with(42.0, EncodeMode::Clamp), Ok(42));
+        assert_eq!(signal.encode_raw_with(300.0, EncodeMode::Clamp), Ok(0xFF));
+        assert_eq!(signal.encode_raw_with(-5.0, EncodeMode::Clamp), Ok(0));
+
+        // Wrap reduces values beyond the i64 range instead of saturating them
+        let huge = 1_180_591_620_717_411_303_424.0; // 2^70
+        assert_eq!(signal.encode_raw_with(huge, EncodeMode::Wrap), Ok(0));
+        assert_eq!(
+            signal.encode_raw_with(huge + 262_144.0, EncodeMode::Wrap),
+            Ok(0)
+        );
+        assert_eq!(signal.encode_raw_with(-260.0, EncodeMode::Wrap), Ok(252));
+
+        let wide = Signal::parse(&mut Parser::new(b"SG_ Wide : 0|64@1+ (1,0) [0|0] \"\"").unwrap())
+            .unwrap();
+        assert_eq!(wide.encode_raw_with(huge, EncodeMode::Wrap), Ok(0));
+        assert_eq!(
+            wide.encode_raw_with(1e19, EncodeMode::Wrap),
+            Ok(10_000_000_000_000_000_000)
+        );
+        assert_eq!(wide.encode_raw_with(-1.0, EncodeMode::Wrap), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn test_physical_to_raw_checked() {
+        let signal =
+            Signal::parse(&mut Parser::new(b"SG_ Temp : 0|8@1- (0.5,-10) [-50|50] \"C\"").unwrap())
+                .unwrap();
+
+        // In range: (-30 - -10) / 0.5 = -40
+        assert_eq!(signal.physical_to_raw_checked(-30.0), Ok(-40));
+        assert_eq!(signal.physical_to_raw_checked(50.0), Ok(120));
+
+        // Below min, above max and NaN
+        let out_of_range = Err(Error::Encoding(Error::ENCODING_VALUE_OUT_OF_RANGE));
+        assert_eq!(signal.physical_to_raw_checked(-50.5), out_of_range);
+        assert_eq!(signal.physical_to_raw_checked(50.5), out_of_range);
+        assert_eq!(signal.physical_to_raw_checked(f64::NAN), out_of_range);
+    }
+
+    #[test]
+    fn test_physical_to_raw_checked_field_overflow() {
+        // Declared range exceeds what 4 unsigned bits can hold (0..=15)
+        let signal =
+            Signal::parse(&mut Parser::new(b"SG_ Level : 0|4@1+ (1,0) [0|100] \"\"").unwrap())
+                .unwrap();
+        assert_eq!(signal.physical_to_raw_checked(15.0), Ok(15));
+        assert_eq!(
+            signal.physical_to_raw_checked(16.0),
+            Err(Error::Encoding(Error::ENCODING_VALUE_OVERFLOW))
+        );
+
+        // Signed 4 bits hold -8..=7
+        let signal =
+            Signal::parse(&mut Parser::new(b"SG_ Trim : 0|4@1- (1,0) [-100|100] \"\"").unwrap())
+                .unwrap();
+        assert_eq!(signal.physical_to_raw_checked(-8.0), Ok(-8));
+        assert!(signal.physical_to_raw_checked(-9.0).is_err());
+        assert!(signal.physical_to_raw_checked(8.0).is_err());
+    }
+
     #[test]
     fn test_encode_decode_roundtrip() {
         // Test that encode(decode(x)) == x for the raw value
@@ -203,7 +651,7 @@ mod tests {
 
         // Decode the raw value back
         let data = [0xE8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]; // 1000 in LE
-        let (decoded_raw, decoded_physical) = signal.decode_raw(&data).unwrap();
+        let (decoded_raw, decoded_physical) = signal.decode_raw_and_physical(&data).unwrap();
         assert_eq!(decoded_raw, 1000);
         assert!((decoded_physical - 100.0).abs() < 0.001);
     }
@@ -265,4 +713,82 @@ mod tests {
         assert_eq!(payload[1], 3);
         assert_eq!(payload[2], 0xFF);
     }
+
+    #[test]
+    fn test_encode_to_big_endian_payload_extent() {
+        // 7|16@0 occupies bytes 0-1 even though 7 + 16 > 16
+        let signal = Signal::parse(
+            &mut Parser::new(b"SG_ Pressure : 7|16@0+ (0.01,0) [0|655.35] \"kPa\"").unwrap(),
+        )
+        .unwrap();
+
+        let mut payload = [0x00; 2];
+        signal.encode_to(10.0, &mut payload).unwrap();
+        assert_eq!(payload, [0x03, 0xE8]);
+    }
+
+    #[test]
+    fn test_encode_raw_to_big_endian_signed() {
+        let signal = Signal::parse(
+            &mut Parser::new(b"SG_ Temp : 7|12@0- (0.5,0) [-1024|1023.5] \"C\"").unwrap(),
+        )
+        .unwrap();
+
+        for raw in [-2048, -5, -1, 0, 1, 2047] {
+            let mut payload = [0x00; 2];
+            signal.encode_raw_to(raw, &mut payload).unwrap();
+            assert_eq!(signal.decode_raw(&payload), Some(raw), "raw {raw}");
+
+            // Same bits as encoding the physical value
+            let mut physical = [0x00; 2];
+            signal.encode_to(raw as f64 * 0.5, &mut physical).unwrap();
+            assert_eq!(payload, physical, "raw {raw}");
+        }
+
+        // -5 is 0xFFB in 12-bit two's complement; the high 8 bits fill byte 0
+        let mut payload = [0x00; 2];
+        signal.encode_raw_to(-5, &mut payload).unwrap();
+        assert_eq!(payload[0], 0xFF);
+
+        // Bits outside the signal are preserved
+        let mut payload = [0xFF; 2];
+        signal.encode_raw_to(0, &mut payload).unwrap();
+        assert_eq!(
+            u64::from(u16::from_le_bytes(payload)),
+            !signal.mask() & 0xFFFF
+        );
+
+        assert_eq!(
+            signal.encode_raw_to(2048, &mut payload),
+            Err(Error::Encoding(Error::ENCODING_VALUE_OVERFLOW))
+        );
+        assert_eq!(
+            signal.encode_raw_to(-2049, &mut payload),
+            Err(Error::Encoding(Error::ENCODING_VALUE_OVERFLOW))
+        );
+        assert_eq!(
+            signal.encode_raw_to(0, &mut payload[..1]),
+            Err(Error::Encoding(Error::SIGNAL_EXTENDS_BEYOND_DATA))
+        );
+    }
+
+    #[test]
+    fn test_encode_raw_to_unsigned() {
+        let signal =
+            Signal::parse(&mut Parser::new(b"SG_ Gear : 8|4@1+ (1,0) [0|15] \"\"").unwrap())
+                .unwrap();
+
+        let mut payload = [0xFF; 2];
+        signal.encode_raw_to(5, &mut payload).unwrap();
+        assert_eq!(payload, [0xFF, 0xF5]);
+        assert_eq!(signal.decode_raw(&payload), Some(5));
+        assert_eq!(
+            signal.encode_raw_to(-1, &mut payload),
+            Err(Error::Encoding(Error::ENCODING_VALUE_OVERFLOW))
+        );
+        assert_eq!(
+            signal.encode_raw_to(16, &mut payload),
+            Err(Error::Encoding(Error::ENCODING_VALUE_OVERFLOW))
+        );
+    }
 }
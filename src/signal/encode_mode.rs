@@ -0,0 +1,44 @@
+/// How encoding handles physical values outside a signal's `[min, max]` range.
+///
+/// Used by [`Signal::encode_raw_with`](crate::Signal::encode_raw_with),
+/// [`Signal::encode_to_with`](crate::Signal::encode_to_with) and
+/// [`Dbc::encode_with`](crate::Dbc::encode_with). The plain `encode` methods use
+/// [`Reject`](Self::Reject).
+///
+/// NaN is rejected in every mode.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use dbc_rs::{Dbc, EncodeMode};
+///
+/// let dbc = Dbc::parse(r#"VERSION "1.0"
+///
+/// BU_: ECM
+///
+/// BO_ 256 Engine : 8 ECM
+///  SG_ Temp : 0|8@1+ (1,0) [0|200] "degC" *
+/// "#)?;
+/// let temp = dbc.messages().find("Engine").unwrap().signals().find("Temp").unwrap();
+///
+/// assert!(temp.encode_raw_with(300.0, EncodeMode::Reject).is_err());
+/// assert_eq!(temp.encode_raw_with(300.0, EncodeMode::Clamp)?, 200);
+/// assert_eq!(temp.encode_raw_with(300.0, EncodeMode::Wrap)?, 300 % 256);
+/// # Ok::<(), dbc_rs::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EncodeMode {
+    /// Fail with [`Error::ENCODING_VALUE_OUT_OF_RANGE`](crate::Error::ENCODING_VALUE_OUT_OF_RANGE)
+    /// if the value lies outside `[min, max]`, or with
+    /// [`Error::ENCODING_VALUE_OVERFLOW`](crate::Error::ENCODING_VALUE_OVERFLOW) if the raw
+    /// value does not fit the bit field.
+    #[default]
+    Reject,
+    /// Clamp the value into `[min, max]` before scaling. A raw value that still does not
+    /// fit the bit field saturates at its bounds. A `[0|0]` range is treated as
+    /// unspecified, so only the bit field limits the value.
+    Clamp,
+    /// Skip the range check and keep only the low `length` bits of the raw value, so
+    /// out-of-range values wrap around at the bit boundary, however large they are.
+    Wrap,
+}
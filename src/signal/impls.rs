@@ -1,5 +1,5 @@
-use super::Signal;
-use crate::{ByteOrder, Receivers};
+use super::{Signal, SignalValueType};
+use crate::{ByteOrder, Receivers, Result, compat::Name};
 use core::hash::{Hash, Hasher};
 
 #[cfg(feature = "std")]
@@ -40,9 +40,82 @@ impl Signal {
             is_multiplexer_switch: false,
             multiplexer_switch_value: None,
             comment,
+            start_value: None,
+            value_type: SignalValueType::Integer,
+            #[cfg(feature = "spans")]
+            span: None,
         }
     }
 
+    /// Creates a validated `Signal` directly, without going through [`SignalBuilder`](crate::SignalBuilder).
+    ///
+    /// This is the low-level counterpart to the builder for performance-sensitive code
+    /// generation: all fields are passed at once and the same validation as
+    /// [`SignalBuilder::build`](crate::SignalBuilder::build) is applied, without the
+    /// intermediate `Option` wrapping. The signal is created without a comment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Name is empty
+    /// - Signal length is zero or exceeds 512 bits
+    /// - Min value exceeds max value
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{ByteOrder, ReceiversBuilder, Signal};
+    ///
+    /// let signal = Signal::try_new(
+    ///     "EngineSpeed",
+    ///     0,
+    ///     16,
+    ///     ByteOrder::LittleEndian,
+    ///     true,
+    ///     0.25,
+    ///     0.0,
+    ///     0.0,
+    ///     8000.0,
+    ///     Some("rpm"),
+    ///     ReceiversBuilder::new().add_node("TCM").build()?,
+    /// )?;
+    ///
+    /// assert_eq!(signal.name(), "EngineSpeed");
+    /// assert_eq!(signal.unit(), Some("rpm"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[allow(clippy::too_many_arguments)] // Mirrors the DBC `SG_` line field by field
+    pub fn try_new(
+        name: &str,
+        start_bit: u16,
+        length: u16,
+        byte_order: ByteOrder,
+        unsigned: bool,
+        factor: f64,
+        offset: f64,
+        min: f64,
+        max: f64,
+        unit: Option<&str>,
+        receivers: Receivers,
+    ) -> crate::Result<Self> {
+        Self::validate(name, length, min, max)?;
+        Ok(Self::new(
+            std::string::String::from(name).into(),
+            start_bit,
+            length,
+            byte_order,
+            unsigned,
+            factor,
+            offset,
+            min,
+            max,
+            unit.map(|u| std::string::String::from(u).into()),
+            receivers,
+            None,
+        ))
+    }
+
     /// Returns the signal name.
     ///
     /// # Examples
@@ -82,6 +155,87 @@ impl Signal {
         self.start_bit
     }
 
+    /// Returns a copy of this signal placed at a different start bit.
+    ///
+    /// All other properties are kept. The result is not validated against a message;
+    /// use [`Message::move_signal`](crate::Message::move_signal) to relocate a signal
+    /// within its message with overlap and boundary checks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc_content = "VERSION \"1.0\"\nBO_ 500 MSG_NAME: 8 ECU\n SG_ SIGNAL_NAME : 16|8@1+ (1,0) [0|0] \"\" ECU\n";
+    /// # let dbc = Dbc::parse(dbc_content).unwrap();
+    /// let message = dbc.messages().find("MSG_NAME").unwrap();
+    /// let signal = message.signals().find("SIGNAL_NAME").unwrap();
+    /// let moved = signal.with_start_bit(24);
+    /// assert_eq!(moved.start_bit(), 24);
+    /// assert_eq!(moved.length(), signal.length());
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn with_start_bit(&self, start_bit: u16) -> Self {
+        Self {
+            start_bit,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this signal with a different factor and offset.
+    ///
+    /// All other properties, including the `[min|max]` range, are kept. Used with
+    /// `Dbc::map_signals` for unit conversions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc_content = "VERSION \"1.0\"\nBO_ 500 MSG_NAME: 8 ECU\n SG_ SIGNAL_NAME : 16|8@1+ (1,0) [0|0] \"\" ECU\n";
+    /// # let dbc = Dbc::parse(dbc_content).unwrap();
+    /// let message = dbc.messages().find("MSG_NAME").unwrap();
+    /// let signal = message.signals().find("SIGNAL_NAME").unwrap();
+    /// let scaled = signal.with_scaling(0.5, -10.0);
+    /// assert_eq!(scaled.factor(), 0.5);
+    /// assert_eq!(scaled.offset(), -10.0);
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn with_scaling(&self, factor: f64, offset: f64) -> Self {
+        Self {
+            factor,
+            offset,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this signal with a different `[min|max]` range.
+    ///
+    /// The range is not validated here; `Dbc::map_signals` rejects ranges where `min > max`.
+    #[must_use = "return value should be used"]
+    pub fn with_range(&self, min: f64, max: f64) -> Self {
+        Self {
+            min,
+            max,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this signal with a different unit, or without a unit for `None`
+    /// or an empty string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the unit exceeds the maximum name length.
+    pub fn with_unit(&self, unit: Option<&str>) -> Result<Self> {
+        let unit = match unit {
+            Some(unit) if !unit.is_empty() => Some(Name::try_from(unit)?),
+            _ => None,
+        };
+        Ok(Self {
+            unit,
+            ..self.clone()
+        })
+    }
+
     /// Returns the length of the signal in bits.
     ///
     /// # Examples
@@ -120,6 +274,42 @@ impl Signal {
         self.byte_order
     }
 
+    /// Returns `true` if the signal is little-endian (Intel format, `@1`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc_content = "VERSION \"1.0\"\nBO_ 500 MSG_NAME: 8 ECU\n SG_ INTEL : 0|8@1+ (1,0) [0|0] \"\" ECU\n SG_ MOTOROLA : 15|8@0+ (1,0) [0|0] \"\" ECU\n";
+    /// # let dbc = Dbc::parse(dbc_content).unwrap();
+    /// let message = dbc.messages().find("MSG_NAME").unwrap();
+    /// assert!(message.signals().find("INTEL").unwrap().is_little_endian());
+    /// assert!(!message.signals().find("MOTOROLA").unwrap().is_little_endian());
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn is_little_endian(&self) -> bool {
+        self.byte_order == ByteOrder::LittleEndian
+    }
+
+    /// Returns `true` if the signal is big-endian (Motorola format, `@0`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc_content = "VERSION \"1.0\"\nBO_ 500 MSG_NAME: 8 ECU\n SG_ INTEL : 0|8@1+ (1,0) [0|0] \"\" ECU\n SG_ MOTOROLA : 15|8@0+ (1,0) [0|0] \"\" ECU\n";
+    /// # let dbc = Dbc::parse(dbc_content).unwrap();
+    /// let message = dbc.messages().find("MSG_NAME").unwrap();
+    /// assert!(message.signals().find("MOTOROLA").unwrap().is_big_endian());
+    /// assert!(!message.signals().find("INTEL").unwrap().is_big_endian());
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn is_big_endian(&self) -> bool {
+        self.byte_order == ByteOrder::BigEndian
+    }
+
     /// Returns `true` if the signal is unsigned, `false` if signed.
     ///
     /// In DBC format, `+` indicates unsigned and `-` indicates signed.
@@ -216,6 +406,41 @@ impl Signal {
         self.max
     }
 
+    /// Returns the physical `(min, max)` range this signal's raw bits can represent.
+    ///
+    /// Computed from the bit length and signedness of the raw value, scaled by factor
+    /// and offset. Lengths above 64 bits are treated as 64, the widest decodable raw value.
+    /// Float and double signals span the finite range of `f32` and `f64` respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc_content = "VERSION \"1.0\"\nBO_ 500 MSG_NAME: 8 ECU\n SG_ SIGNAL_NAME : 0|8@1+ (1,-40) [-40|85] \"\" ECU\n";
+    /// # let dbc = Dbc::parse(dbc_content).unwrap();
+    /// let message = dbc.messages().find("MSG_NAME").unwrap();
+    /// let signal = message.signals().find("SIGNAL_NAME").unwrap();
+    /// assert_eq!(signal.representable_range(), (-40.0, 215.0));
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn representable_range(&self) -> (f64, f64) {
+        let length = self.length.min(64) as u32;
+        let (raw_min, raw_max) = match self.value_type {
+            SignalValueType::Float => (f64::from(f32::MIN), f64::from(f32::MAX)),
+            SignalValueType::Double => (f64::MIN, f64::MAX),
+            SignalValueType::Integer if self.unsigned => (0.0, (u64::MAX >> (64 - length)) as f64),
+            SignalValueType::Integer => {
+                let raw_max = i64::MAX >> (64 - length);
+                ((-raw_max - 1) as f64, raw_max as f64)
+            }
+        };
+
+        let a = raw_min * self.factor + self.offset;
+        let b = raw_max * self.factor + self.offset;
+        // A negative factor flips the range
+        if a <= b { (a, b) } else { (b, a) }
+    }
+
     /// Returns the unit of measurement for this signal, if specified.
     ///
     /// Returns `None` if no unit was defined in the DBC file.
@@ -285,6 +510,167 @@ impl Signal {
     pub(crate) fn set_comment(&mut self, comment: crate::compat::Comment) {
         self.comment = Some(comment);
     }
+
+    /// Removes the signal comment.
+    #[cfg(feature = "std")]
+    pub(crate) fn clear_comment(&mut self) {
+        self.comment = None;
+    }
+
+    /// Replaces the signal name.
+    #[cfg(feature = "std")]
+    pub(crate) fn set_name(&mut self, name: Name) {
+        self.name = name;
+    }
+
+    /// Removes `node` from the receivers of this signal.
+    #[cfg(feature = "std")]
+    pub(crate) fn remove_receiver(&mut self, node: &str) {
+        self.receivers.remove(node);
+    }
+
+    /// Sorts the receivers of this signal alphabetically.
+    #[cfg(feature = "std")]
+    pub(crate) fn sort_receivers(&mut self) {
+        self.receivers.sort();
+    }
+
+    /// Returns the raw initial value of the signal, if defined.
+    ///
+    /// Taken from a `BA_ "GenSigStartValue" SG_ <id> <signal> <raw>;` attribute when the
+    /// DBC is parsed. Like the attribute, the value is raw (before factor and offset);
+    /// non-integral values are ignored. Always `None` without the `attributes` feature.
+    /// See [`Dbc::initial_payload`](crate::Dbc::initial_payload).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ Temp : 0|8@1+ (1,-40) [-40|215] "degC" *
+    ///
+    /// BA_DEF_ SG_ "GenSigStartValue" INT 0 255;
+    /// BA_ "GenSigStartValue" SG_ 256 Temp 40;
+    /// "#)?;
+    ///
+    /// let temp = dbc.messages().find("Engine").unwrap().signals().find("Temp").unwrap();
+    /// assert_eq!(temp.start_value(), Some(40));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn start_value(&self) -> Option<i64> {
+        self.start_value
+    }
+
+    /// Sets the raw initial value (from the `GenSigStartValue` attribute).
+    /// Used internally during parsing when BA_ entries are processed after signals.
+    #[cfg(feature = "attributes")]
+    #[inline]
+    pub(crate) fn set_start_value(&mut self, start_value: i64) {
+        self.start_value = Some(start_value);
+    }
+
+    /// Returns the extended value type of the signal.
+    ///
+    /// Taken from a `SIG_VALTYPE_ <id> <signal> : <type>;` entry when the DBC is parsed,
+    /// and [`SignalValueType::Integer`] if there is none. Float and double signals are
+    /// decoded by reading their raw bits as an IEEE-754 value, then applying factor and
+    /// offset. Their raw values (see [`decode_raw`](Self::decode_raw)) are the bit
+    /// patterns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::{Dbc, SignalValueType};
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ Torque : 0|32@1- (1,0) [-1000|1000] "Nm" *
+    ///
+    /// SIG_VALTYPE_ 256 Torque : 1;
+    /// "#)?;
+    ///
+    /// let torque = dbc.messages().find("Engine").unwrap().signals().find("Torque").unwrap();
+    /// assert_eq!(torque.value_type(), SignalValueType::Float);
+    /// let payload = 12.5f32.to_le_bytes();
+    /// assert_eq!(torque.decode_physical(&payload), Some(12.5));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn value_type(&self) -> SignalValueType {
+        self.value_type
+    }
+
+    /// Returns `true` if the signal holds an IEEE-754 float or double.
+    ///
+    /// Shorthand for checking [`value_type`](Self::value_type) against
+    /// [`SignalValueType::Integer`].
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn is_float(&self) -> bool {
+        self.value_type != SignalValueType::Integer
+    }
+
+    /// Sets the extended value type (from a `SIG_VALTYPE_` entry).
+    /// Used internally during parsing when SIG_VALTYPE_ entries are processed after signals.
+    ///
+    /// A float type is ignored unless the signal is 32 bits long, a double unless it is
+    /// 64 bits long; such signals stay integers.
+    pub(crate) fn set_value_type(&mut self, value_type: SignalValueType) {
+        if value_type.required_length().is_none_or(|length| length == self.length) {
+            self.value_type = value_type;
+        }
+    }
+
+    /// Returns where the signal is defined in the parsed input.
+    ///
+    /// The span is `(start_line, start_column, end_line)`: lines and columns are 1-based
+    /// and the start is the `SG_` keyword. Returns `None` for signals that were not
+    /// parsed as part of a [`Dbc`](crate::Dbc), or when the `spans` feature (enabled by
+    /// `std`) is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse(r#"VERSION "1.0"
+    ///
+    /// BU_: ECM
+    ///
+    /// BO_ 256 Engine : 8 ECM
+    ///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+    /// "#)?;
+    ///
+    /// let signal = dbc.messages().find("Engine").unwrap().signals().find("RPM").unwrap();
+    /// assert_eq!(signal.span(), Some((6, 2, 6)));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn span(&self) -> Option<(usize, usize, usize)> {
+        #[cfg(feature = "spans")]
+        return self.span;
+        #[cfg(not(feature = "spans"))]
+        None
+    }
+
+    /// Records where the signal is defined. Used during parsing.
+    #[cfg(feature = "spans")]
+    #[inline]
+    pub(crate) fn set_span(&mut self, span: (usize, usize, usize)) {
+        self.span = Some(span);
+    }
 }
 
 impl PartialEq for Signal {
@@ -303,6 +689,8 @@ impl PartialEq for Signal {
             && self.is_multiplexer_switch == other.is_multiplexer_switch
             && self.multiplexer_switch_value == other.multiplexer_switch_value
             && self.comment == other.comment
+            && self.start_value == other.start_value
+            && self.value_type == other.value_type
     }
 }
 
@@ -327,6 +715,8 @@ impl Hash for Signal {
         self.is_multiplexer_switch.hash(state);
         self.multiplexer_switch_value.hash(state);
         self.comment.hash(state);
+        self.start_value.hash(state);
+        self.value_type.hash(state);
     }
 }
 
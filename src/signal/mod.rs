@@ -1,10 +1,12 @@
 mod decode;
 mod encode;
+mod encode_mode;
 mod impls;
 mod parse;
 #[cfg(feature = "std")]
 mod std;
 mod validate;
+mod value_type;
 
 #[cfg(feature = "std")]
 mod builder;
@@ -15,6 +17,8 @@ use crate::{
 };
 #[cfg(feature = "std")]
 pub use builder::SignalBuilder;
+pub use encode_mode::EncodeMode;
+pub use value_type::SignalValueType;
 
 /// Position info: (start_bit, length, byte_order, unsigned)
 type Position = (u16, u16, ByteOrder, bool);
@@ -54,6 +58,7 @@ type Range = (f64, f64);
 /// # Ok::<(), dbc_rs::Error>(())
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Signal {
     name: Name,
     start_bit: u16,
@@ -73,4 +78,19 @@ pub struct Signal {
     multiplexer_switch_value: Option<u64>,
     /// Comment text from CM_ SG_ entry
     comment: Option<Comment>,
+    /// Raw initial value from the `GenSigStartValue` attribute
+    start_value: Option<i64>,
+    /// Extended value type from a `SIG_VALTYPE_` entry
+    #[cfg_attr(feature = "serde", serde(default))]
+    value_type: SignalValueType,
+    /// Source location `(start line, start column, end line)` when parsed
+    #[cfg(feature = "spans")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    span: Option<(usize, usize, usize)>,
+}
+
+impl Signal {
+    /// Signal attribute (BA_ SG_) holding the raw initial value
+    #[cfg(feature = "attributes")]
+    pub(crate) const START_VALUE_ATTRIBUTE: &'static str = "GenSigStartValue";
 }
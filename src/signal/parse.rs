@@ -1,4 +1,4 @@
-use super::{Position, Range, Scaling, Signal};
+use super::{Position, Range, Scaling, Signal, SignalValueType};
 use crate::{ByteOrder, Error, MAX_NAME_SIZE, Parser, Receivers, Result, compat::Name};
 
 impl Signal {
@@ -64,7 +64,7 @@ impl Signal {
         Ok((start_bit, length, byte_order, unsigned))
     }
 
-    fn parse_factor_offset<'b>(parser: &mut Parser<'b>) -> Result<Scaling> {
+    pub(crate) fn parse_factor_offset<'b>(parser: &mut Parser<'b>) -> Result<Scaling> {
         // Expect opening parenthesis
         parser.expect_with_msg(b"(", "Expected '(' to start factor/offset")?;
 
@@ -93,7 +93,7 @@ impl Signal {
         Ok((factor, offset))
     }
 
-    fn parse_range<'b>(parser: &mut Parser<'b>) -> Result<Range> {
+    pub(crate) fn parse_range<'b>(parser: &mut Parser<'b>) -> Result<Range> {
         // Expect opening bracket
         parser.expect_with_msg(b"[", "Expected '[' to start min/max range")?;
 
@@ -105,7 +105,8 @@ impl Signal {
             .parse_f64_or_default(0.0)
             .map_err(|_| parser.err_signal(Error::SIGNAL_PARSE_INVALID_MIN))?;
 
-        // Expect pipe, then skip whitespace
+        // Expect pipe (optionally preceded by whitespace), then skip whitespace
+        parser.skip_newlines_and_spaces();
         parser.expect_then_skip(b"|")?;
 
         // Parse max (may be empty, default to 0.0)
@@ -122,7 +123,7 @@ impl Signal {
         Ok((min, max))
     }
 
-    fn parse_unit(parser: &mut Parser) -> Result<Option<Name>> {
+    pub(crate) fn parse_unit(parser: &mut Parser) -> Result<Option<Name>> {
         // Expect opening quote
         parser.expect_with_msg(b"\"", "Expected '\"' to start unit string")?;
 
@@ -232,11 +233,15 @@ impl Signal {
         // Parse range: [min|max]
         let (min, max) = Self::parse_range(parser)?;
 
-        // Skip whitespace
-        parser.skip_newlines_and_spaces();
+        // Skip spaces and tabs, but not newlines: the unit and receivers may be omitted
+        parser.skip_spaces_and_tabs();
 
-        // Parse unit: "unit" or ""
-        let unit = Self::parse_unit(parser)?;
+        // Parse unit: "unit" or "", or nothing
+        let unit = if parser.current_byte() == Some(b'"') {
+            Self::parse_unit(parser)?
+        } else {
+            None
+        };
 
         // Skip whitespace (but not newlines) before parsing receivers
         // Newlines indicate end of signal line, so we need to preserve them for Receivers::parse
@@ -274,6 +279,10 @@ impl Signal {
             is_multiplexer_switch,
             multiplexer_switch_value,
             comment: None,
+            start_value: None,
+            value_type: SignalValueType::Integer,
+            #[cfg(feature = "spans")]
+            span: None,
         })
     }
 }
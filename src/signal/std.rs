@@ -11,6 +11,17 @@ impl Signal {
 
         result.push_str(" SG_ ");
         result.push_str(self.name());
+
+        // Multiplexer indicator: M, m<value> or m<value>M (extended multiplexing)
+        if let Some(value) = self.multiplexer_switch_value() {
+            result.push_str(" m");
+            result.push_str(&value.to_string());
+            if self.is_multiplexer_switch() {
+                result.push('M');
+            }
+        } else if self.is_multiplexer_switch() {
+            result.push_str(" M");
+        }
         result.push_str(" : ");
         result.push_str(&self.start_bit().to_string());
         result.push('|');
@@ -19,10 +30,7 @@ impl Signal {
 
         // Byte order: 0 for BigEndian (Motorola), 1 for LittleEndian (Intel)
         // Per Vector DBC spec v1.0.1: "Big endian is stored as '0', little endian is stored as '1'"
-        match self.byte_order() {
-            crate::ByteOrder::BigEndian => result.push('0'), // @0 = Big Endian (Motorola)
-            crate::ByteOrder::LittleEndian => result.push('1'), // @1 = Little Endian (Intel)
-        }
+        result.push(self.byte_order().as_dbc_digit());
 
         // Sign: + for unsigned, - for signed
         if self.is_unsigned() {
@@ -96,6 +104,19 @@ mod tests {
                 r#"SG_ Flag : 24|1@0+ (1,0) [0|1] "" *"#,
                 " SG_ Flag : 24|1@0+ (1,0) [0|1] \"\" Vector__XXX",
             ),
+            (
+                r#"SG_ Mux M : 0|8@1+ (1,0) [0|255] "" ECM"#,
+                " SG_ Mux M : 0|8@1+ (1,0) [0|255] \"\" ECM",
+            ),
+            (
+                r#"SG_ Data m3 : 8|8@1+ (1,0) [0|255] "" ECM"#,
+                " SG_ Data m3 : 8|8@1+ (1,0) [0|255] \"\" ECM",
+            ),
+            (
+                // Extended multiplexing: multiplexed signal that is itself a switch
+                r#"SG_ Pid m1M : 8|8@1+ (1,0) [0|255] "" ECM"#,
+                " SG_ Pid m1M : 8|8@1+ (1,0) [0|255] \"\" ECM",
+            ),
         ];
 
         for (input_line, expected_output) in test_cases {
@@ -124,6 +145,14 @@ mod tests {
             assert_eq!(signal.min(), signal2.min());
             assert_eq!(signal.max(), signal2.max());
             assert_eq!(signal.unit(), signal2.unit());
+            assert_eq!(
+                signal.is_multiplexer_switch(),
+                signal2.is_multiplexer_switch()
+            );
+            assert_eq!(
+                signal.multiplexer_switch_value(),
+                signal2.multiplexer_switch_value()
+            );
         }
     }
 }
@@ -0,0 +1,106 @@
+/// Extended value type of a signal, declared by a `SIG_VALTYPE_` entry.
+///
+/// Signals without a `SIG_VALTYPE_` entry are [`Integer`](Self::Integer). Float and double
+/// signals hold an IEEE-754 value in their raw bits, to which factor and offset are applied
+/// as usual.
+///
+/// In DBC files the type is written as a number:
+/// - `0` = Integer
+/// - `1` = Float (32-bit)
+/// - `2` = Double (64-bit)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignalValueType {
+    /// Signed or unsigned integer, as given by the `SG_` definition.
+    #[default]
+    Integer,
+    /// IEEE-754 single precision float (`1` in DBC files). The signal is 32 bits long.
+    Float,
+    /// IEEE-754 double precision float (`2` in DBC files). The signal is 64 bits long.
+    Double,
+}
+
+impl SignalValueType {
+    /// Map the number written in a `SIG_VALTYPE_` entry to a value type.
+    pub(crate) fn from_dbc_code(code: u64) -> Option<Self> {
+        match code {
+            0 => Some(SignalValueType::Integer),
+            1 => Some(SignalValueType::Float),
+            2 => Some(SignalValueType::Double),
+            _ => None,
+        }
+    }
+
+    /// The number written for this value type in a `SIG_VALTYPE_` entry.
+    #[cfg(feature = "std")]
+    pub(crate) fn dbc_code(self) -> u8 {
+        match self {
+            SignalValueType::Integer => 0,
+            SignalValueType::Float => 1,
+            SignalValueType::Double => 2,
+        }
+    }
+
+    /// Signal length in bits required by a floating-point value type.
+    pub(crate) fn required_length(self) -> Option<u16> {
+        match self {
+            SignalValueType::Integer => None,
+            SignalValueType::Float => Some(32),
+            SignalValueType::Double => Some(64),
+        }
+    }
+
+    /// Interpret raw signal bits as a number, or `None` for integer signals.
+    #[inline]
+    pub(crate) fn bits_to_f64(self, bits: u64) -> Option<f64> {
+        match self {
+            SignalValueType::Integer => None,
+            SignalValueType::Float => Some(f64::from(f32::from_bits(bits as u32))),
+            SignalValueType::Double => Some(f64::from_bits(bits)),
+        }
+    }
+
+    /// Raw signal bits holding `value`, or `None` for integer signals.
+    #[inline]
+    pub(crate) fn f64_to_bits(self, value: f64) -> Option<u64> {
+        match self {
+            SignalValueType::Integer => None,
+            SignalValueType::Float => Some(u64::from((value as f32).to_bits())),
+            SignalValueType::Double => Some(value.to_bits()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SignalValueType;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_signal_value_type_codes() {
+        for value_type in [
+            SignalValueType::Integer,
+            SignalValueType::Float,
+            SignalValueType::Double,
+        ] {
+            assert_eq!(
+                SignalValueType::from_dbc_code(u64::from(value_type.dbc_code())),
+                Some(value_type)
+            );
+        }
+        assert_eq!(SignalValueType::from_dbc_code(3), None);
+        assert_eq!(SignalValueType::default(), SignalValueType::Integer);
+    }
+
+    #[test]
+    fn test_signal_value_type_bits() {
+        let float = SignalValueType::Float;
+        assert_eq!(float.bits_to_f64(0x3FA0_0000), Some(1.25));
+        assert_eq!(float.f64_to_bits(-2.5), Some(0xC020_0000));
+        let double = SignalValueType::Double;
+        assert_eq!(double.bits_to_f64(1.5f64.to_bits()), Some(1.5));
+        assert_eq!(double.f64_to_bits(-0.25), Some((-0.25f64).to_bits()));
+        assert_eq!(SignalValueType::Integer.bits_to_f64(1), None);
+        assert_eq!(SignalValueType::Integer.f64_to_bits(1.0), None);
+    }
+}
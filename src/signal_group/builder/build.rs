@@ -0,0 +1,92 @@
+use super::SignalGroupBuilder;
+use crate::{Error, Result, SignalGroup, compat::validate_name, signal_group::SignalNames};
+
+impl SignalGroupBuilder {
+    /// Builds the `SignalGroup` from the builder configuration.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(SignalGroup)` if successful, or `Err(Error)` if:
+    /// - message_id is not set
+    /// - name is not set or invalid
+    /// - Any name exceeds MAX_NAME_SIZE
+    /// - More than MAX_SIGNALS_PER_MESSAGE signals have been added
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::SignalGroupBuilder;
+    ///
+    /// let group = SignalGroupBuilder::new()
+    ///     .message_id(256)
+    ///     .name("EngineGroup")
+    ///     .repetitions(1)
+    ///     .add_signal("RPM")
+    ///     .build()?;
+    /// assert!(group.contains("RPM"));
+    ///
+    /// // Missing name
+    /// assert!(SignalGroupBuilder::new().message_id(256).build().is_err());
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn build(self) -> Result<SignalGroup> {
+        let message_id =
+            self.message_id.ok_or_else(|| Error::expected("message_id is required"))?;
+
+        let name_str = self.name.ok_or_else(|| Error::expected("name is required"))?;
+        let name =
+            validate_name(&name_str).map_err(|_| Error::expected(Error::MAX_NAME_SIZE_EXCEEDED))?;
+
+        let mut signals = SignalNames::new();
+        for signal_name in self.signals {
+            let signal_name = validate_name(&signal_name)
+                .map_err(|_| Error::expected(Error::MAX_NAME_SIZE_EXCEEDED))?;
+            signals
+                .push(signal_name)
+                .map_err(|_| Error::expected(Error::MESSAGE_TOO_MANY_SIGNALS))?;
+        }
+
+        Ok(SignalGroup::new(
+            message_id,
+            name,
+            self.repetitions,
+            signals,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SignalGroupBuilder;
+
+    #[test]
+    fn test_signal_group_builder_basic() {
+        let group = SignalGroupBuilder::new()
+            .message_id(256)
+            .name("EngineGroup")
+            .repetitions(2)
+            .add_signals(["RPM", "Temp"])
+            .build()
+            .unwrap();
+
+        assert_eq!(group.message_id(), 256);
+        assert_eq!(group.name(), "EngineGroup");
+        assert_eq!(group.repetitions(), 2);
+        assert_eq!(group.signal_names().collect::<Vec<_>>(), ["RPM", "Temp"]);
+    }
+
+    #[test]
+    fn test_signal_group_builder_missing_fields() {
+        assert!(SignalGroupBuilder::new().name("EngineGroup").build().is_err());
+        assert!(SignalGroupBuilder::new().message_id(256).build().is_err());
+    }
+
+    #[test]
+    fn test_signal_group_builder_name_too_long() {
+        let result = SignalGroupBuilder::new()
+            .message_id(256)
+            .name("A".repeat(crate::MAX_NAME_SIZE + 1))
+            .build();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use super::SignalGroupBuilder;
+use std::vec::Vec;
+
+impl SignalGroupBuilder {
+    /// Creates a new `SignalGroupBuilder` with no fields set and one repetition.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::SignalGroupBuilder;
+    ///
+    /// let builder = SignalGroupBuilder::new();
+    /// // Must set message_id and name before building
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            message_id: None,
+            name: None,
+            repetitions: 1,
+            signals: Vec::new(),
+        }
+    }
+
+    /// Sets the message ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_id` - The CAN message ID the group belongs to, with the extended ID
+    ///   flag (bit 31) set for 29-bit IDs, as written in the DBC file
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::SignalGroupBuilder;
+    ///
+    /// let builder = SignalGroupBuilder::new()
+    ///     .message_id(256);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "builder method returns modified builder"]
+    pub fn message_id(mut self, message_id: u32) -> Self {
+        self.message_id = Some(message_id);
+        self
+    }
+
+    /// Sets the group name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::SignalGroupBuilder;
+    ///
+    /// let builder = SignalGroupBuilder::new()
+    ///     .name("EngineGroup");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "builder method returns modified builder"]
+    pub fn name(mut self, name: impl AsRef<str>) -> Self {
+        self.name = Some(name.as_ref().to_string());
+        self
+    }
+
+    /// Sets the number of repetitions (defaults to 1).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::SignalGroupBuilder;
+    ///
+    /// let builder = SignalGroupBuilder::new()
+    ///     .repetitions(2);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "builder method returns modified builder"]
+    pub fn repetitions(mut self, repetitions: u32) -> Self {
+        self.repetitions = repetitions;
+        self
+    }
+
+    /// Adds a signal to the group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::SignalGroupBuilder;
+    ///
+    /// let builder = SignalGroupBuilder::new()
+    ///     .add_signal("RPM")
+    ///     .add_signal("Temp");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "builder method returns modified builder"]
+    pub fn add_signal(mut self, signal_name: impl AsRef<str>) -> Self {
+        self.signals.push(signal_name.as_ref().to_string());
+        self
+    }
+
+    /// Adds multiple signals to the group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::SignalGroupBuilder;
+    ///
+    /// let builder = SignalGroupBuilder::new()
+    ///     .add_signals(["RPM", "Temp"]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "builder method returns modified builder"]
+    pub fn add_signals(mut self, signal_names: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.signals
+            .extend(signal_names.into_iter().map(|name| name.as_ref().to_string()));
+        self
+    }
+
+    /// Clears all signals from the group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::SignalGroupBuilder;
+    ///
+    /// let builder = SignalGroupBuilder::new()
+    ///     .add_signal("RPM")
+    ///     .clear_signals();
+    /// ```
+    #[must_use = "builder method returns modified builder"]
+    pub fn clear_signals(mut self) -> Self {
+        self.signals.clear();
+        self
+    }
+}
+
+impl Default for SignalGroupBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
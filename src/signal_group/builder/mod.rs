@@ -0,0 +1,38 @@
+/// Builder for creating `SignalGroup` programmatically.
+///
+/// Signal groups (SIG_GROUP_) collect related signals of one message under a common
+/// name. Add the built group to a DBC with
+/// [`DbcBuilder::add_signal_group`](crate::DbcBuilder::add_signal_group).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use dbc_rs::SignalGroupBuilder;
+///
+/// let group = SignalGroupBuilder::new()
+///     .message_id(256)
+///     .name("EngineGroup")
+///     .add_signal("RPM")
+///     .add_signal("Temp")
+///     .build()?;
+///
+/// assert_eq!(group.message_id(), 256);
+/// assert_eq!(group.name(), "EngineGroup");
+/// assert_eq!(group.repetitions(), 1);
+/// assert_eq!(group.len(), 2);
+/// # Ok::<(), dbc_rs::Error>(())
+/// ```
+///
+/// # Feature Requirements
+///
+/// This builder requires the `std` feature to be enabled.
+#[derive(Debug, Clone)]
+pub struct SignalGroupBuilder {
+    message_id: Option<u32>,
+    name: Option<String>,
+    repetitions: u32,
+    signals: std::vec::Vec<String>,
+}
+
+mod build;
+mod impls;
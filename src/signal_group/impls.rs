@@ -0,0 +1,129 @@
+use super::{SignalGroup, SignalNames};
+use crate::compat::Name;
+
+impl SignalGroup {
+    pub(crate) fn new(message_id: u32, name: Name, repetitions: u32, signals: SignalNames) -> Self {
+        Self {
+            message_id,
+            name,
+            repetitions,
+            signals,
+        }
+    }
+
+    /// Copy of this entry for another message.
+    #[cfg(feature = "std")]
+    pub(crate) fn with_message_id(&self, message_id: u32) -> Self {
+        Self {
+            message_id,
+            ..self.clone()
+        }
+    }
+
+    /// Replace `old` by `new` in the member signal names.
+    #[cfg(feature = "std")]
+    pub(crate) fn rename_signal(&mut self, old: &str, new: &Name) {
+        for signal in self.signals.iter_mut() {
+            if signal.as_str() == old {
+                *signal = new.clone();
+            }
+        }
+    }
+
+    /// Drop `name` from the member signal names.
+    #[cfg(feature = "std")]
+    pub(crate) fn remove_signal(&mut self, name: &str) {
+        self.signals =
+            self.signals.iter().filter(|signal| signal.as_str() != name).cloned().collect();
+    }
+
+    /// Returns the CAN message ID this signal group belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n SG_ RPM : 0|16@1+ (0.25,0) [0|8000] \"rpm\" *\n\nSIG_GROUP_ 256 EngineGroup 1 : RPM;\n")?;
+    /// let group = dbc.signal_groups().iter().next().unwrap();
+    /// assert_eq!(group.message_id(), 256);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn message_id(&self) -> u32 {
+        self.message_id
+    }
+
+    /// Returns the signal group name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n SG_ RPM : 0|16@1+ (0.25,0) [0|8000] \"rpm\" *\n\nSIG_GROUP_ 256 EngineGroup 1 : RPM;\n")?;
+    /// let group = dbc.signal_groups().iter().next().unwrap();
+    /// assert_eq!(group.name(), "EngineGroup");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the number of repetitions of the signal group.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn repetitions(&self) -> u32 {
+        self.repetitions
+    }
+
+    /// Returns an iterator over the names of the signals in this group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n SG_ RPM : 0|16@1+ (0.25,0) [0|8000] \"rpm\" *\n SG_ Temp : 16|8@1- (1,-40) [-40|215] \"degC\" *\n\nSIG_GROUP_ 256 EngineGroup 1 : RPM Temp;\n")?;
+    /// let group = dbc.signal_groups().iter().next().unwrap();
+    /// let names: Vec<&str> = group.signal_names().collect();
+    /// assert_eq!(names, ["RPM", "Temp"]);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "iterator is lazy and does nothing unless consumed"]
+    pub fn signal_names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.signals.iter().map(|s| s.as_str())
+    }
+
+    /// Returns the number of signals in this group.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn len(&self) -> usize {
+        self.signals.len()
+    }
+
+    /// Returns `true` if the group contains no signals.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn is_empty(&self) -> bool {
+        self.signals.is_empty()
+    }
+
+    /// Returns `true` if the signal with the given name is a member of this group.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n SG_ RPM : 0|16@1+ (0.25,0) [0|8000] \"rpm\" *\n\nSIG_GROUP_ 256 EngineGroup 1 : RPM;\n")?;
+    /// let group = dbc.signal_groups().iter().next().unwrap();
+    /// assert!(group.contains("RPM"));
+    /// assert!(!group.contains("Temp"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn contains(&self, signal_name: &str) -> bool {
+        self.signals.iter().any(|s| s.as_str() == signal_name)
+    }
+}
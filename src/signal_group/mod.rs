@@ -0,0 +1,72 @@
+//! Signal group definition (SIG_GROUP_)
+//!
+//! Signal groups collect related signals of a message that belong together logically
+//! (for example, signals that must be updated atomically).
+//!
+//! # DBC Format
+//!
+//! ```text
+//! SIG_GROUP_ <message_id> <group_name> <repetitions> : <signal_name> ... ;
+//! ```
+//!
+//! # Example
+//!
+//! ```text
+//! SIG_GROUP_ 256 EngineGroup 1 : RPM Temp;
+//! ```
+
+use crate::{
+    MAX_SIGNALS_PER_MESSAGE,
+    compat::{Name, Vec},
+};
+
+mod impls;
+mod parse;
+#[cfg(feature = "std")]
+mod std;
+
+#[cfg(feature = "std")]
+mod builder;
+
+#[cfg(feature = "std")]
+pub use builder::SignalGroupBuilder;
+
+/// Signal names belonging to a signal group
+type SignalNames = Vec<Name, { MAX_SIGNALS_PER_MESSAGE }>;
+
+/// Signal group definition (SIG_GROUP_)
+///
+/// Groups signals of a single message under a common name. Signal groups do not
+/// affect the message layout.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use dbc_rs::Dbc;
+///
+/// let dbc = Dbc::parse(r#"VERSION "1.0"
+///
+/// BU_: ECM
+///
+/// BO_ 256 Engine : 8 ECM
+///  SG_ RPM : 0|16@1+ (0.25,0) [0|8000] "rpm" *
+///  SG_ Temp : 16|8@1- (1,-40) [-40|215] "degC" *
+///
+/// SIG_GROUP_ 256 EngineGroup 1 : RPM Temp;
+/// "#)?;
+///
+/// let group = dbc.signal_groups().iter().next().unwrap();
+/// assert_eq!(group.message_id(), 256);
+/// assert_eq!(group.name(), "EngineGroup");
+/// assert_eq!(group.repetitions(), 1);
+/// assert_eq!(group.signal_names().collect::<Vec<_>>(), ["RPM", "Temp"]);
+/// # Ok::<(), dbc_rs::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignalGroup {
+    message_id: u32,
+    name: Name,
+    repetitions: u32,
+    signals: SignalNames,
+}
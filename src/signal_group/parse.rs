@@ -0,0 +1,89 @@
+use super::{SignalGroup, SignalNames};
+use crate::{Parser, compat::validate_name};
+
+impl SignalGroup {
+    /// Parse a SIG_GROUP_ entry
+    ///
+    /// Expects the parser to be positioned after the SIG_GROUP_ keyword.
+    /// Parses: message_id group_name repetitions [:] signal_name ... ;
+    /// Example: 256 EngineGroup 1 : RPM Temp;
+    ///
+    /// Returns `None` if parsing fails (caller should skip to end of line).
+    pub(crate) fn parse(parser: &mut Parser) -> Option<SignalGroup> {
+        parser.skip_newlines_and_spaces();
+
+        let message_id = parser.parse_message_id().ok()?;
+        parser.skip_newlines_and_spaces();
+
+        let name = validate_name(parser.parse_identifier().ok()?).ok()?;
+        parser.skip_newlines_and_spaces();
+
+        let repetitions = parser.parse_u32().ok()?;
+        parser.skip_newlines_and_spaces();
+
+        // The colon before the signal list is optional in the wild
+        let _ = parser.expect(b":").ok();
+
+        // The signal list ends at `;` or, if that is missing, at the end of the line
+        let mut signals = SignalNames::new();
+        loop {
+            parser.skip_spaces_and_tabs();
+            if parser.eof() || parser.at_newline() || parser.expect(b";").is_ok() {
+                break;
+            }
+            let signal_name = validate_name(parser.parse_identifier().ok()?).ok()?;
+            signals.push(signal_name).ok()?;
+            // Signal names may be separated by commas or whitespace
+            let _ = parser.expect(b",").ok();
+        }
+
+        Some(SignalGroup::new(message_id, name, repetitions, signals))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SignalGroup;
+    use crate::Parser;
+
+    #[test]
+    fn test_parse_signal_group() {
+        let input = b" 256 EngineGroup 1 : RPM Temp;";
+        let mut parser = Parser::new(input).unwrap();
+        let group = SignalGroup::parse(&mut parser).unwrap();
+        assert_eq!(group.message_id(), 256);
+        assert_eq!(group.name(), "EngineGroup");
+        assert_eq!(group.repetitions(), 1);
+        let mut names = group.signal_names();
+        assert_eq!(names.next(), Some("RPM"));
+        assert_eq!(names.next(), Some("Temp"));
+        assert_eq!(names.next(), None);
+    }
+
+    #[test]
+    fn test_parse_signal_group_without_colon() {
+        let input = b" 256 EngineGroup 2 RPM Temp ;";
+        let mut parser = Parser::new(input).unwrap();
+        let group = SignalGroup::parse(&mut parser).unwrap();
+        assert_eq!(group.repetitions(), 2);
+        assert_eq!(group.len(), 2);
+        assert!(group.contains("Temp"));
+    }
+
+    #[test]
+    fn test_parse_signal_group_without_semicolon_stops_at_line_end() {
+        let input = b" 256 EngineGroup 1 : RPM Temp\nBO_ 512 Brake : 8 ECM";
+        let mut parser = Parser::new(input).unwrap();
+        let group = SignalGroup::parse(&mut parser).unwrap();
+        assert_eq!(group.len(), 2);
+        assert!(!group.contains("BO_"));
+        assert!(parser.at_newline());
+    }
+
+    #[test]
+    fn test_parse_signal_group_invalid_repetitions() {
+        let input = b" 256 EngineGroup x : RPM;";
+        let mut parser = Parser::new(input).unwrap();
+        assert!(SignalGroup::parse(&mut parser).is_none());
+    }
+}
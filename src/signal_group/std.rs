@@ -0,0 +1,42 @@
+use super::SignalGroup;
+use std::{
+    fmt::{Display, Formatter, Result},
+    string::String,
+};
+
+impl SignalGroup {
+    /// Converts the signal group to its DBC file representation.
+    ///
+    /// Returns a string in the format: `SIG_GROUP_ message_id name repetitions : Sig1 Sig2;`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n SG_ RPM : 0|16@1+ (0.25,0) [0|8000] \"rpm\" *\n SG_ Temp : 16|8@1- (1,-40) [-40|215] \"degC\" *\n\nSIG_GROUP_ 256 EngineGroup 1 : RPM Temp;\n")?;
+    /// let group = dbc.signal_groups().iter().next().unwrap();
+    /// assert_eq!(group.to_dbc_string(), "SIG_GROUP_ 256 EngineGroup 1 : RPM Temp;");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn to_dbc_string(&self) -> String {
+        let mut result = format!(
+            "SIG_GROUP_ {} {} {} :",
+            self.message_id(),
+            self.name(),
+            self.repetitions()
+        );
+        for signal_name in self.signal_names() {
+            result.push(' ');
+            result.push_str(signal_name);
+        }
+        result.push(';');
+        result
+    }
+}
+
+impl Display for SignalGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.to_dbc_string())
+    }
+}
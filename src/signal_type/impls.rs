@@ -0,0 +1,164 @@
+use super::SignalType;
+use crate::{ByteOrder, ValueDescriptions, compat::Name};
+
+impl SignalType {
+    #[allow(clippy::too_many_arguments)] // Internal method, parse is the public entry point
+    pub(crate) fn new(
+        name: Name,
+        size: u16,
+        byte_order: ByteOrder,
+        unsigned: bool,
+        factor: f64,
+        offset: f64,
+        min: f64,
+        max: f64,
+        unit: Option<Name>,
+        default_value: f64,
+        value_table: Option<Name>,
+    ) -> Self {
+        Self {
+            name,
+            size,
+            byte_order,
+            unsigned,
+            factor,
+            offset,
+            min,
+            max,
+            unit,
+            default_value,
+            value_table,
+            value_descriptions: None,
+        }
+    }
+
+    /// Attach value descriptions declared via `SGTYPE_VAL_`
+    pub(crate) fn set_value_descriptions(&mut self, value_descriptions: ValueDescriptions) {
+        self.value_descriptions = Some(value_descriptions);
+    }
+
+    /// Returns the signal type name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nSGTYPE_ TempType : 8@1- (1,-40) [-40|215] \"degC\" 0, TempTable;\n")?;
+    /// let signal_type = dbc.signal_types().iter().next().unwrap();
+    /// assert_eq!(signal_type.name(), "TempType");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the signal size in bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nSGTYPE_ TempType : 8@1- (1,-40) [-40|215] \"degC\" 0, TempTable;\n")?;
+    /// let signal_type = dbc.signal_types().iter().next().unwrap();
+    /// assert_eq!(signal_type.size(), 8);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn size(&self) -> u16 {
+        self.size
+    }
+
+    /// Returns the byte order (endianness) of the signal type.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
+    /// Returns `true` if the signal type is unsigned, `false` if signed.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn is_unsigned(&self) -> bool {
+        self.unsigned
+    }
+
+    /// Returns the scaling factor applied to the raw value.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Returns the offset added after scaling.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Returns the minimum physical value.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the maximum physical value.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Returns the unit of the signal type, or `None` if the unit is empty.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn unit(&self) -> Option<&str> {
+        self.unit.as_ref().map(|u| u.as_str())
+    }
+
+    /// Returns the default value of the signal type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nSGTYPE_ TempType : 8@1- (1,-40) [-40|215] \"degC\" 20, TempTable;\n")?;
+    /// let signal_type = dbc.signal_types().iter().next().unwrap();
+    /// assert_eq!(signal_type.default_value(), 20.0);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn default_value(&self) -> f64 {
+        self.default_value
+    }
+
+    /// Returns the name of the value table associated with the signal type, if any.
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn value_table(&self) -> Option<&str> {
+        self.value_table.as_ref().map(|v| v.as_str())
+    }
+
+    /// Returns the value descriptions declared for this signal type via `SGTYPE_VAL_`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nSGTYPE_ TempType : 8@1- (1,-40) [-40|215] \"degC\" 0, TempTable;\nSGTYPE_VAL_ TempType 255 \"Invalid\" ;\n")?;
+    /// let signal_type = dbc.signal_types().iter().next().unwrap();
+    /// let value_descriptions = signal_type.value_descriptions().unwrap();
+    /// assert_eq!(value_descriptions.get(255), Some("Invalid"));
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn value_descriptions(&self) -> Option<&ValueDescriptions> {
+        self.value_descriptions.as_ref()
+    }
+}
@@ -0,0 +1,70 @@
+//! Signal type definition (SGTYPE_)
+//!
+//! Signal types describe reusable signal properties (size, byte order, sign, scaling,
+//! range, unit and default value) that signals can refer to via `SIG_TYPE_REF_`.
+//!
+//! # DBC Format
+//!
+//! ```text
+//! SGTYPE_ <name> : <size>@<byte_order><sign> (<factor>,<offset>) [<min>|<max>] "<unit>" <default>, <value_table>;
+//! SGTYPE_VAL_ <name> <value> "<description>" ... ;
+//! ```
+//!
+//! # Example
+//!
+//! ```text
+//! SGTYPE_ TempType : 8@1- (1,-40) [-40|215] "degC" 0, TempTable;
+//! SGTYPE_VAL_ TempType 255 "Invalid" ;
+//! ```
+
+use crate::{ByteOrder, ValueDescriptions, compat::Name};
+
+mod impls;
+mod parse;
+#[cfg(feature = "std")]
+mod std;
+
+/// Signal type definition (SGTYPE_)
+///
+/// A named set of signal properties that can be shared between signals. Signals are
+/// associated with a signal type through [`SignalTypeRef`](crate::SignalTypeRef) entries.
+/// Value descriptions declared with `SGTYPE_VAL_` are attached to the signal type.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use dbc_rs::Dbc;
+///
+/// let dbc = Dbc::parse(r#"VERSION "1.0"
+///
+/// BU_: ECM
+///
+/// BO_ 256 Engine : 8 ECM
+///  SG_ Temp : 0|8@1- (1,-40) [-40|215] "degC" *
+///
+/// SGTYPE_ TempType : 8@1- (1,-40) [-40|215] "degC" 0, TempTable;
+/// SGTYPE_VAL_ TempType 255 "Invalid" ;
+/// "#)?;
+///
+/// let signal_type = dbc.signal_types().iter().next().unwrap();
+/// assert_eq!(signal_type.name(), "TempType");
+/// assert_eq!(signal_type.size(), 8);
+/// assert_eq!(signal_type.value_table(), Some("TempTable"));
+/// # Ok::<(), dbc_rs::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignalType {
+    name: Name,
+    size: u16,
+    byte_order: ByteOrder,
+    unsigned: bool,
+    factor: f64,
+    offset: f64,
+    min: f64,
+    max: f64,
+    unit: Option<Name>,
+    default_value: f64,
+    value_table: Option<Name>,
+    value_descriptions: Option<ValueDescriptions>,
+}
@@ -0,0 +1,121 @@
+use super::SignalType;
+use crate::{ByteOrder, Parser, Signal, compat::validate_name};
+
+impl SignalType {
+    /// Parse an SGTYPE_ entry
+    ///
+    /// Expects the parser to be positioned after the SGTYPE_ keyword.
+    /// Parses: name : size@byte_order sign (factor,offset) [min|max] "unit" default_value , value_table ;
+    /// Example: TempType : 8@1- (1,-40) [-40|215] "degC" 0, TempTable;
+    ///
+    /// Returns `None` if parsing fails (caller should skip to end of line).
+    pub(crate) fn parse(parser: &mut Parser) -> Option<SignalType> {
+        parser.skip_newlines_and_spaces();
+
+        // Parse signal type name
+        let name = validate_name(parser.parse_identifier().ok()?).ok()?;
+        parser.skip_newlines_and_spaces();
+        parser.expect(b":").ok()?;
+        parser.skip_newlines_and_spaces();
+
+        // Parse size@byte_order sign
+        let size = u16::try_from(parser.parse_u32().ok()?).ok()?;
+        parser.expect(b"@").ok()?;
+        let byte_order = if parser.expect(b"0").is_ok() {
+            ByteOrder::BigEndian
+        } else if parser.expect(b"1").is_ok() {
+            ByteOrder::LittleEndian
+        } else {
+            return None;
+        };
+        let unsigned = if parser.expect(b"+").is_ok() {
+            true
+        } else if parser.expect(b"-").is_ok() {
+            false
+        } else {
+            return None;
+        };
+        parser.skip_newlines_and_spaces();
+
+        // Scaling, range and unit share the SG_ syntax
+        let (factor, offset) = Signal::parse_factor_offset(parser).ok()?;
+        parser.skip_newlines_and_spaces();
+        let (min, max) = Signal::parse_range(parser).ok()?;
+        parser.skip_newlines_and_spaces();
+        let unit = Signal::parse_unit(parser).ok()?;
+        parser.skip_newlines_and_spaces();
+
+        // Parse default value
+        let default_value = parser.parse_f64().ok()?;
+        parser.skip_newlines_and_spaces();
+
+        // Parse optional ", value_table"
+        let mut value_table = None;
+        if parser.expect(b",").is_ok() {
+            parser.skip_newlines_and_spaces();
+            if !parser.starts_with(b";") {
+                value_table = Some(validate_name(parser.parse_identifier().ok()?).ok()?);
+                parser.skip_newlines_and_spaces();
+            }
+        }
+        let _ = parser.expect(b";").ok();
+
+        Some(SignalType::new(
+            name,
+            size,
+            byte_order,
+            unsigned,
+            factor,
+            offset,
+            min,
+            max,
+            unit,
+            default_value,
+            value_table,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+    use super::SignalType;
+    use crate::{ByteOrder, Parser};
+
+    #[test]
+    fn test_parse_signal_type() {
+        let input = b" TempType : 8@1- (1,-40) [-40|215] \"degC\" 0, TempTable;";
+        let mut parser = Parser::new(input).unwrap();
+        let signal_type = SignalType::parse(&mut parser).unwrap();
+        assert_eq!(signal_type.name(), "TempType");
+        assert_eq!(signal_type.size(), 8);
+        assert_eq!(signal_type.byte_order(), ByteOrder::LittleEndian);
+        assert!(!signal_type.is_unsigned());
+        assert_eq!(signal_type.factor(), 1.0);
+        assert_eq!(signal_type.offset(), -40.0);
+        assert_eq!(signal_type.min(), -40.0);
+        assert_eq!(signal_type.max(), 215.0);
+        assert_eq!(signal_type.unit(), Some("degC"));
+        assert_eq!(signal_type.default_value(), 0.0);
+        assert_eq!(signal_type.value_table(), Some("TempTable"));
+        assert!(signal_type.value_descriptions().is_none());
+    }
+
+    #[test]
+    fn test_parse_signal_type_without_value_table() {
+        let input = b" Counter : 4@0+ (1,0) [0|15] \"\" 0,;";
+        let mut parser = Parser::new(input).unwrap();
+        let signal_type = SignalType::parse(&mut parser).unwrap();
+        assert_eq!(signal_type.byte_order(), ByteOrder::BigEndian);
+        assert!(signal_type.is_unsigned());
+        assert_eq!(signal_type.unit(), None);
+        assert_eq!(signal_type.value_table(), None);
+    }
+
+    #[test]
+    fn test_parse_signal_type_invalid() {
+        let input = b" TempType : 8@2- (1,-40) [-40|215] \"degC\" 0, TempTable;";
+        let mut parser = Parser::new(input).unwrap();
+        assert!(SignalType::parse(&mut parser).is_none());
+    }
+}
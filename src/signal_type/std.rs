@@ -0,0 +1,82 @@
+use super::SignalType;
+use std::{
+    fmt::{Display, Formatter, Result, Write},
+    string::String,
+};
+
+impl SignalType {
+    /// Converts the signal type to its DBC file representation.
+    ///
+    /// Returns a string in the format:
+    /// `SGTYPE_ Name : size@byte_order sign (factor,offset) [min|max] "unit" default, value_table;`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use dbc_rs::Dbc;
+    ///
+    /// let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nSGTYPE_ TempType : 8@1- (1,-40) [-40|215] \"degC\" 0, TempTable;\n")?;
+    /// let signal_type = dbc.signal_types().iter().next().unwrap();
+    /// assert_eq!(
+    ///     signal_type.to_dbc_string(),
+    ///     "SGTYPE_ TempType : 8@1- (1,-40) [-40|215] \"degC\" 0, TempTable;"
+    /// );
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn to_dbc_string(&self) -> String {
+        let mut result = String::with_capacity(80);
+
+        result.push_str("SGTYPE_ ");
+        result.push_str(self.name());
+        result.push_str(" : ");
+        write!(result, "{}@", self.size()).unwrap();
+        result.push(self.byte_order().as_dbc_digit());
+        result.push(if self.is_unsigned() { '+' } else { '-' });
+        write!(
+            result,
+            " ({},{}) [{}|{}] \"{}\" {},",
+            self.factor(),
+            self.offset(),
+            self.min(),
+            self.max(),
+            self.unit().unwrap_or(""),
+            self.default_value()
+        )
+        .unwrap();
+        if let Some(value_table) = self.value_table() {
+            result.push(' ');
+            result.push_str(value_table);
+        }
+        result.push(';');
+
+        result
+    }
+}
+
+impl Display for SignalType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.to_dbc_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SignalType;
+    use crate::Parser;
+
+    #[test]
+    fn test_signal_type_to_dbc_string_round_trip() {
+        let test_cases = [
+            "SGTYPE_ TempType : 8@1- (1,-40) [-40|215] \"degC\" 0, TempTable;",
+            "SGTYPE_ Counter : 4@0+ (1,0) [0|15] \"\" 0,;",
+            "SGTYPE_ Speed : 16@1+ (0.01,0) [0|655.35] \"km/h\" 12.5, SpeedTable;",
+        ];
+
+        for expected in test_cases {
+            let mut parser = Parser::new(&expected.as_bytes()[7..]).unwrap();
+            let signal_type = SignalType::parse(&mut parser).unwrap();
+            assert_eq!(signal_type.to_dbc_string(), expected);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::SignalTypeRef;
+use crate::compat::Name;
+
+impl SignalTypeRef {
+    pub(crate) fn new(message_id: u32, signal_name: Name, type_name: Name) -> Self {
+        Self {
+            message_id,
+            signal_name,
+            type_name,
+        }
+    }
+
+    /// Copy of this entry for another message.
+    #[cfg(feature = "std")]
+    pub(crate) fn with_message_id(&self, message_id: u32) -> Self {
+        Self {
+            message_id,
+            ..self.clone()
+        }
+    }
+
+    /// Replace `old` by `new` as the referencing signal name.
+    #[cfg(feature = "std")]
+    pub(crate) fn rename_signal(&mut self, old: &str, new: &Name) {
+        if self.signal_name.as_str() == old {
+            self.signal_name = new.clone();
+        }
+    }
+
+    /// Returns the CAN message ID of the referencing signal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n SG_ Temp : 0|8@1- (1,-40) [-40|215] \"degC\" *\n\nSIG_TYPE_REF_ 256 Temp : TempType;\n")?;
+    /// let type_ref = dbc.signal_type_refs().iter().next().unwrap();
+    /// assert_eq!(type_ref.message_id(), 256);
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn message_id(&self) -> u32 {
+        self.message_id
+    }
+
+    /// Returns the name of the referencing signal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n SG_ Temp : 0|8@1- (1,-40) [-40|215] \"degC\" *\n\nSIG_TYPE_REF_ 256 Temp : TempType;\n")?;
+    /// let type_ref = dbc.signal_type_refs().iter().next().unwrap();
+    /// assert_eq!(type_ref.signal_name(), "Temp");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn signal_name(&self) -> &str {
+        self.signal_name.as_str()
+    }
+
+    /// Returns the name of the referenced signal type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n SG_ Temp : 0|8@1- (1,-40) [-40|215] \"degC\" *\n\nSIG_TYPE_REF_ 256 Temp : TempType;\n")?;
+    /// let type_ref = dbc.signal_type_refs().iter().next().unwrap();
+    /// assert_eq!(type_ref.type_name(), "TempType");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[inline]
+    #[must_use = "return value should be used"]
+    pub fn type_name(&self) -> &str {
+        self.type_name.as_str()
+    }
+}
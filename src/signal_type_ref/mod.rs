@@ -0,0 +1,58 @@
+//! Signal type reference (SIG_TYPE_REF_)
+//!
+//! Associates a signal of a message with a signal type declared via `SGTYPE_`.
+//!
+//! # DBC Format
+//!
+//! ```text
+//! SIG_TYPE_REF_ <message_id> <signal_name> : <signal_type_name> ;
+//! ```
+//!
+//! # Example
+//!
+//! ```text
+//! SIG_TYPE_REF_ 256 Temp : TempType;
+//! ```
+
+use crate::compat::Name;
+
+mod impls;
+mod parse;
+#[cfg(feature = "std")]
+mod std;
+
+/// Signal type reference (SIG_TYPE_REF_)
+///
+/// Links the signal `signal_name` of message `message_id` to the
+/// [`SignalType`](crate::SignalType) named `type_name`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use dbc_rs::Dbc;
+///
+/// let dbc = Dbc::parse(r#"VERSION "1.0"
+///
+/// BU_: ECM
+///
+/// BO_ 256 Engine : 8 ECM
+///  SG_ Temp : 0|8@1- (1,-40) [-40|215] "degC" *
+///
+/// SGTYPE_ TempType : 8@1- (1,-40) [-40|215] "degC" 0, TempTable;
+///
+/// SIG_TYPE_REF_ 256 Temp : TempType;
+/// "#)?;
+///
+/// let type_ref = dbc.signal_type_refs().iter().next().unwrap();
+/// assert_eq!(type_ref.message_id(), 256);
+/// assert_eq!(type_ref.signal_name(), "Temp");
+/// assert_eq!(type_ref.type_name(), "TempType");
+/// # Ok::<(), dbc_rs::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignalTypeRef {
+    message_id: u32,
+    signal_name: Name,
+    type_name: Name,
+}
@@ -0,0 +1,52 @@
+use super::SignalTypeRef;
+use crate::{Parser, compat::validate_name};
+
+impl SignalTypeRef {
+    /// Parse a SIG_TYPE_REF_ entry
+    ///
+    /// Expects the parser to be positioned after the SIG_TYPE_REF_ keyword.
+    /// Parses: message_id signal_name : signal_type_name ;
+    /// Example: 256 Temp : TempType;
+    ///
+    /// Returns `None` if parsing fails (caller should skip to end of line).
+    pub(crate) fn parse(parser: &mut Parser) -> Option<SignalTypeRef> {
+        parser.skip_newlines_and_spaces();
+
+        let message_id = parser.parse_message_id().ok()?;
+        parser.skip_newlines_and_spaces();
+
+        let signal_name = validate_name(parser.parse_identifier().ok()?).ok()?;
+        parser.skip_newlines_and_spaces();
+        parser.expect(b":").ok()?;
+        parser.skip_newlines_and_spaces();
+
+        let type_name = validate_name(parser.parse_identifier().ok()?).ok()?;
+        parser.skip_newlines_and_spaces();
+        let _ = parser.expect(b";").ok();
+
+        Some(SignalTypeRef::new(message_id, signal_name, type_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SignalTypeRef;
+    use crate::Parser;
+
+    #[test]
+    fn test_parse_signal_type_ref() {
+        let input = b" 256 Temp : TempType;";
+        let mut parser = Parser::new(input).unwrap();
+        let type_ref = SignalTypeRef::parse(&mut parser).unwrap();
+        assert_eq!(type_ref.message_id(), 256);
+        assert_eq!(type_ref.signal_name(), "Temp");
+        assert_eq!(type_ref.type_name(), "TempType");
+    }
+
+    #[test]
+    fn test_parse_signal_type_ref_missing_colon() {
+        let input = b" 256 Temp TempType;";
+        let mut parser = Parser::new(input).unwrap();
+        assert!(SignalTypeRef::parse(&mut parser).is_none());
+    }
+}
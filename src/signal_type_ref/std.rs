@@ -0,0 +1,36 @@
+use super::SignalTypeRef;
+use std::{
+    fmt::{Display, Formatter, Result},
+    string::String,
+};
+
+impl SignalTypeRef {
+    /// Converts the signal type reference to its DBC file representation.
+    ///
+    /// Returns a string in the format: `SIG_TYPE_REF_ message_id signal_name : type_name;`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use dbc_rs::Dbc;
+    /// # let dbc = Dbc::parse("VERSION \"1.0\"\n\nBU_: ECM\n\nBO_ 256 Engine : 8 ECM\n SG_ Temp : 0|8@1- (1,-40) [-40|215] \"degC\" *\n\nSIG_TYPE_REF_ 256 Temp : TempType;\n")?;
+    /// let type_ref = dbc.signal_type_refs().iter().next().unwrap();
+    /// assert_eq!(type_ref.to_dbc_string(), "SIG_TYPE_REF_ 256 Temp : TempType;");
+    /// # Ok::<(), dbc_rs::Error>(())
+    /// ```
+    #[must_use = "return value should be used"]
+    pub fn to_dbc_string(&self) -> String {
+        format!(
+            "SIG_TYPE_REF_ {} {} : {};",
+            self.message_id(),
+            self.signal_name(),
+            self.type_name()
+        )
+    }
+}
+
+impl Display for SignalTypeRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.to_dbc_string())
+    }
+}
@@ -8,6 +8,12 @@ impl ValueDescriptions {
         Self { entries }
     }
 
+    /// Sort the entries by value, keeping the order of duplicate values.
+    #[cfg(feature = "std")]
+    pub(crate) fn sort(&mut self) {
+        self.entries.as_mut_slice().sort_by_key(|(value, _)| *value);
+    }
+
     /// Get the description for a numeric value
     ///
     /// # Examples
@@ -1,4 +1,5 @@
 mod impls;
+mod parse;
 
 #[cfg(feature = "std")]
 mod builder;
@@ -43,6 +44,11 @@ pub use builder::ValueDescriptionsBuilder;
 /// # Ok::<(), dbc_rs::Error>(())
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct ValueDescriptions {
     entries: ValueDescEntries,
 }
@@ -0,0 +1,103 @@
+use super::ValueDescriptions;
+use crate::{
+    Parser,
+    compat::{Name, ValueDescEntries},
+};
+
+impl ValueDescriptions {
+    /// Parse value-description pairs up to and including the terminating `;`
+    ///
+    /// Shared by `VAL_` and `SGTYPE_VAL_` entries once the leading object reference
+    /// (message ID and signal name, or signal type name) has been consumed.
+    /// Parses: value1 "desc1" value2 "desc2" ... ;
+    ///
+    /// On malformed input, skips to the end of the line and returns the pairs
+    /// parsed so far. A missing `;` is tolerated: the entry ends at end of input, or
+    /// before the next line if that line does not continue the value list.
+    pub(crate) fn parse_entries(parser: &mut Parser) -> ValueDescEntries {
+        let mut entries: ValueDescEntries = ValueDescEntries::new();
+        loop {
+            let line = parser.line();
+            parser.skip_newlines_and_spaces();
+            // Check for semicolon (end of statement)
+            if parser.starts_with(b";") {
+                parser.expect(b";").ok();
+                break;
+            }
+            // Parse value (as i64 first to handle negative values like -1, then convert to u64)
+            // Note: -1 (0xFFFFFFFF) is the magic number for global value descriptions in message_id,
+            // but values in VAL_ can also be negative
+            let value = match parser.parse_i64() {
+                Ok(v) => {
+                    // Handle -1 specially: convert to 0xFFFFFFFF (u32::MAX) instead of large u64
+                    if v == -1 { 0xFFFF_FFFFu64 } else { v as u64 }
+                }
+                Err(_) => {
+                    // A new line that is not a value starts the next statement
+                    if parser.line() == line {
+                        parser.skip_to_end_of_line();
+                    }
+                    break;
+                }
+            };
+            parser.skip_newlines_and_spaces();
+            // Parse description string (expect quote, then take until quote)
+            if parser.expect(b"\"").is_err() {
+                parser.skip_to_end_of_line();
+                break;
+            }
+            let description_bytes = match parser.take_until_quote(false, 1024) {
+                Ok(bytes) => bytes,
+                Err(_) => {
+                    parser.skip_to_end_of_line();
+                    break;
+                }
+            };
+            let description = match core::str::from_utf8(description_bytes)
+                .ok()
+                .and_then(|s| Name::try_from(s).ok())
+            {
+                Some(desc) => desc,
+                None => {
+                    parser.skip_to_end_of_line();
+                    break;
+                }
+            };
+            let _ = entries.push((value, description));
+        }
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueDescriptions;
+    use crate::Parser;
+
+    #[test]
+    fn test_parse_entries_missing_semicolon() {
+        let mut parser = Parser::new(b" 0 \"Off\" 1 \"On\"\nCM_ \"Next\";").unwrap();
+        let entries = ValueDescriptions::parse_entries(&mut parser);
+        assert_eq!(entries.len(), 2);
+        // The following statement is left for the caller
+        assert!(parser.starts_with(b"CM_"));
+    }
+
+    #[test]
+    fn test_parse_entries() {
+        let mut parser = Parser::new(b" 0 \"Off\" 1 \"On\" ;").unwrap();
+        let entries = ValueDescriptions::parse_entries(&mut parser);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries.get(1).map(|(v, d)| (*v, d.as_str())),
+            Some((1, "On"))
+        );
+    }
+
+    #[test]
+    fn test_parse_entries_stops_on_malformed_pair() {
+        let mut parser = Parser::new(b" 0 \"Off\" 1 On ;").unwrap();
+        let entries = ValueDescriptions::parse_entries(&mut parser);
+        assert_eq!(entries.len(), 1);
+    }
+}
@@ -48,6 +48,11 @@ use crate::compat::Name;
 /// - `"1.0-beta"` - Version with suffix
 /// - `""` - Empty version string (allowed)
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Version {
     version: Name,
 }
@@ -77,6 +77,23 @@ BO_ 256 Test : {} ECM
     }
 }
 
+#[test]
+fn test_invalid_can_fd_dlc_rejected() {
+    // Above 8 bytes, only CAN FD lengths are valid
+    for dlc in [9, 10, 33, 63] {
+        let dbc_str = format!(
+            r#"VERSION "1.0"
+
+BU_: ECM
+
+BO_ 256 Test : {} ECM
+"#,
+            dlc
+        );
+        assert!(Dbc::parse(&dbc_str).is_err(), "Should reject DLC {}", dlc);
+    }
+}
+
 #[test]
 fn test_valid_dlc_zero() {
     // Per DBC spec Section 8.3: DLC 0 is valid (e.g., for control messages without data payload)
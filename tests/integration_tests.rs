@@ -2,7 +2,7 @@
 
 #[cfg(feature = "std")]
 mod std {
-    use dbc_rs::Dbc;
+    use dbc_rs::{ByteOrder, Dbc, Error, Receivers};
     use std::fs::read_to_string;
 
     #[test]
@@ -720,4 +720,604 @@ BO_ 3221225472 VECTOR__INDEPENDENT_SIG_MSG : 0 Vector__XXX
             "Message ID should be exactly 2024 (0x7E8)"
         );
     }
+
+    #[test]
+    fn test_signal_types_and_groups_round_trip() {
+        let content = read_to_string("tests/data/signal_types_groups.dbc")
+            .expect("Failed to read signal_types_groups.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse signal_types_groups.dbc");
+
+        assert_eq!(dbc.signal_types().len(), 2);
+        assert_eq!(dbc.signal_type_refs().len(), 3);
+        assert_eq!(dbc.signal_groups().len(), 2);
+        let gear_type = dbc.signal_type("GearType").expect("GearType not found");
+        assert_eq!(
+            gear_type.value_descriptions().and_then(|vd| vd.get(2)),
+            Some("Second")
+        );
+        // Negative values survive the round trip
+        let temp_type = dbc.signal_type("TempType").expect("TempType not found");
+        assert_eq!(
+            temp_type.value_descriptions().and_then(|vd| vd.get(-5i64 as u64)),
+            Some("Error")
+        );
+
+        let serialized = dbc.to_string();
+
+        // Sections are written in canonical order
+        let position = |keyword: &str| {
+            serialized
+                .find(keyword)
+                .unwrap_or_else(|| panic!("{keyword} missing from serialized output"))
+        };
+        assert!(position("BO_ 512") < position("SGTYPE_ "));
+        assert!(position("SGTYPE_ ") < position("CM_ "));
+        assert!(position("CM_ ") < position("SGTYPE_VAL_ "));
+        assert!(position("SGTYPE_VAL_ ") < position("SIG_TYPE_REF_ "));
+        assert!(position("SIG_TYPE_REF_ ") < position("SIG_GROUP_ "));
+
+        // Nothing is lost on re-parse
+        let reparsed = Dbc::parse(&serialized).expect("Failed to re-parse serialized DBC");
+        assert_eq!(dbc.signal_types(), reparsed.signal_types());
+        assert_eq!(dbc.signal_type_refs(), reparsed.signal_type_refs());
+        assert_eq!(dbc.signal_groups(), reparsed.signal_groups());
+        assert_eq!(dbc.messages().len(), reparsed.messages().len());
+        assert_eq!(serialized, reparsed.to_string());
+    }
+
+    #[test]
+    fn test_parse_spans() {
+        let content = read_to_string("tests/data/simple.dbc").expect("Failed to read simple.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse simple.dbc");
+
+        let engine = dbc.messages().find("EngineStatus").expect("EngineStatus not found");
+        assert_eq!(engine.span(), Some((5, 1, 7)));
+        let spans: Vec<_> = engine.signals().iter().map(|s| s.span()).collect();
+        assert_eq!(spans, [Some((6, 2, 6)), Some((7, 2, 7))]);
+
+        let vehicle = dbc.messages().find("VehicleSpeed").expect("VehicleSpeed not found");
+        assert_eq!(vehicle.span(), Some((9, 1, 10)));
+        let speed = vehicle.signals().find("Speed").expect("Speed not found");
+        assert_eq!(speed.span(), Some((10, 2, 10)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_round_trip() {
+        for path in ["tests/data/round_trip.dbc", "tests/data/attributes.dbc"] {
+            let content = read_to_string(path).expect("Failed to read fixture");
+            let dbc = Dbc::parse(&content).expect("Failed to parse fixture");
+
+            let json = serde_json::to_string(&dbc).expect("Failed to serialize to JSON");
+            let restored: Dbc = serde_json::from_str(&json).expect("Failed to deserialize JSON");
+
+            assert_eq!(dbc.messages(), restored.messages());
+            assert_eq!(dbc.nodes(), restored.nodes());
+            assert_eq!(dbc.to_string(), restored.to_string());
+        }
+
+        // The extended multiplexing index is rebuilt: Pid 13 selects VehicleSpeed
+        let content =
+            read_to_string("tests/data/round_trip.dbc").expect("Failed to read round_trip.dbc");
+        let dbc = Dbc::parse(&content).unwrap();
+        let restored: Dbc = serde_json::from_str(&serde_json::to_string(&dbc).unwrap()).unwrap();
+        let payload = [0x01, 0x0D, 0x10, 0x27, 0x00, 0x00, 0x00, 0x00];
+        let decoded = restored.decode(0x400, &payload, true).unwrap();
+        assert_eq!(decoded, dbc.decode(0x400, &payload, true).unwrap());
+        assert!(decoded.iter().any(|s| s.name == "VehicleSpeed"));
+        assert!(!decoded.iter().any(|s| s.name == "CoolantTemp"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_rejects_invalid_dbc() {
+        let content =
+            read_to_string("tests/data/round_trip.dbc").expect("Failed to read round_trip.dbc");
+        let dbc = Dbc::parse(&content).unwrap();
+        let mut value = serde_json::to_value(&dbc).unwrap();
+
+        // Deserialization validates like parsing: the sender must be a known node
+        value["messages"][0]["sender"] = "Unknown".into();
+        assert!(serde_json::from_value::<Dbc>(value.clone()).is_err());
+
+        // Duplicate message IDs are rejected when the ID index is rebuilt
+        value["messages"][0]["sender"] = "ECM".into();
+        value["messages"][2]["id"] = 256.into();
+        assert!(serde_json::from_value::<Dbc>(value).is_err());
+    }
+
+    #[test]
+    fn test_to_string_round_trip() {
+        let content =
+            read_to_string("tests/data/round_trip.dbc").expect("Failed to read round_trip.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse round_trip.dbc");
+        assert_eq!(dbc.value_descriptions().len(), 3);
+        assert_eq!(dbc.extended_multiplexing().len(), 3);
+
+        let serialized = dbc.to_string();
+
+        // Sections are written in canonical order
+        let position = |keyword: &str| {
+            serialized
+                .find(keyword)
+                .unwrap_or_else(|| panic!("{keyword} missing from serialized output"))
+        };
+        assert!(position("VERSION ") < position("NS_ :"));
+        assert!(position("NS_ :") < position("BS_:"));
+        assert!(position("BS_:") < position("BU_:"));
+        assert!(position("BU_:") < position("BO_ "));
+        assert!(position("BO_ 512") < position("CM_ "));
+        assert!(position("CM_ ") < position("VAL_ "));
+        assert!(position("VAL_ ") < position("SG_MUL_VAL_ "));
+
+        let reparsed = Dbc::parse(&serialized).expect("Failed to re-parse serialized DBC");
+        assert_eq!(dbc.version(), reparsed.version());
+        assert_eq!(dbc.nodes(), reparsed.nodes());
+        assert_eq!(dbc.messages(), reparsed.messages());
+        assert_eq!(dbc.value_descriptions(), reparsed.value_descriptions());
+        assert_eq!(
+            dbc.extended_multiplexing(),
+            reparsed.extended_multiplexing()
+        );
+        assert_eq!(dbc.comment(), reparsed.comment());
+        assert_eq!(
+            reparsed
+                .value_descriptions_for_signal(256, "State")
+                .and_then(|vd| vd.get(u32::MAX as u64)),
+            Some("Fault")
+        );
+        assert_eq!(serialized, reparsed.to_string());
+    }
+
+    #[test]
+    fn test_validate_signal_ranges_consistent() {
+        let content = read_to_string("tests/data/inconsistent_ranges.dbc")
+            .expect("Failed to read inconsistent_ranges.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse inconsistent_ranges.dbc");
+
+        let flagged: Vec<(&str, &str)> = dbc
+            .validate_signal_ranges_consistent()
+            .map(|(message, signal)| (message.name(), signal.name()))
+            .collect();
+        assert_eq!(
+            flagged,
+            [("EngineStatus", "CoolantTemp"), ("Brake", "Pressure")]
+        );
+
+        // Well-formed fixtures produce no warnings
+        let content = read_to_string("tests/data/simple.dbc").expect("Failed to read simple.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse simple.dbc");
+        assert_eq!(dbc.validate_signal_ranges_consistent().count(), 0);
+    }
+
+    #[test]
+    fn test_validate_no_self_receiving() {
+        let content = read_to_string("tests/data/self_receiving.dbc")
+            .expect("Failed to read self_receiving.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse self_receiving.dbc");
+
+        let flagged: Vec<(&str, &str)> = dbc
+            .validate_no_self_receiving()
+            .map(|(message, signal)| (message.name(), signal.name()))
+            .collect();
+        assert_eq!(
+            flagged,
+            [("EngineStatus", "EngineSpeed"), ("Brake", "Pressure")]
+        );
+
+        // Well-formed fixtures produce no warnings
+        let content = read_to_string("tests/data/simple.dbc").expect("Failed to read simple.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse simple.dbc");
+        assert_eq!(dbc.validate_no_self_receiving().count(), 0);
+    }
+
+    #[test]
+    fn test_parse_signal_overruns_dlc() {
+        let content = read_to_string("tests/data/signal_overruns_dlc.dbc")
+            .expect("Failed to read signal_overruns_dlc.dbc");
+
+        match Dbc::parse(&content) {
+            Err(Error::SignalOutOfBounds {
+                message_id,
+                signal,
+                end_bit,
+                available_bits,
+                line,
+            }) => {
+                assert_eq!(message_id, 256);
+                assert_eq!(signal.as_ref(), "Checksum");
+                assert_eq!(end_bit, 67);
+                assert_eq!(available_bits, 64);
+                assert_eq!(line, Some(8));
+            }
+            other => panic!("Expected SignalOutOfBounds error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_missing_trailing_semicolons() {
+        let content = read_to_string("tests/data/missing_semicolon_val.dbc")
+            .expect("Failed to read missing_semicolon_val.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse missing_semicolon_val.dbc");
+        let gear = dbc.value_descriptions_for_signal(256, "Gear").unwrap();
+        assert_eq!(gear.len(), 3);
+        assert_eq!(gear.get(2), Some("Drive"));
+
+        let content = read_to_string("tests/data/missing_semicolon_cm.dbc")
+            .expect("Failed to read missing_semicolon_cm.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse missing_semicolon_cm.dbc");
+        let engine = dbc.messages().find_by_id(256).unwrap();
+        // A VAL_ without ';' must not swallow the statement on the next line
+        assert_eq!(engine.comment(), Some("Engine message"));
+        assert_eq!(
+            engine.signals().find("Gear").unwrap().comment(),
+            Some("Selected gear")
+        );
+        assert_eq!(
+            engine.signals().find("Mode").unwrap().comment(),
+            Some("Operating mode")
+        );
+        assert_eq!(
+            dbc.value_descriptions_for_signal(256, "Mode").unwrap().len(),
+            2
+        );
+        assert_eq!(
+            dbc.value_descriptions_for_signal(256, "Gear").unwrap().len(),
+            2
+        );
+        assert_eq!(dbc.extended_multiplexing().len(), 1);
+    }
+
+    #[test]
+    fn test_capacity_limits_are_public() {
+        let limits = [
+            dbc_rs::MAX_NODES,
+            dbc_rs::MAX_SIGNALS_PER_MESSAGE,
+            dbc_rs::MAX_MESSAGES,
+            dbc_rs::MAX_NAME_SIZE,
+            dbc_rs::MAX_VALUE_DESCRIPTIONS,
+            dbc_rs::MAX_EXTENDED_MULTIPLEXING,
+        ];
+        assert!(limits.iter().all(|&limit| limit > 0));
+
+        // Downstream code can check a database fits before deploying it
+        let content =
+            read_to_string("tests/data/complete.dbc").expect("Failed to read complete.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse complete.dbc");
+        assert!(dbc.nodes().len() <= dbc_rs::MAX_NODES);
+        assert!(dbc.messages().len() <= dbc_rs::MAX_MESSAGES);
+        assert!(dbc.extended_multiplexing().len() <= dbc_rs::MAX_EXTENDED_MULTIPLEXING);
+        for message in dbc.messages().iter() {
+            assert!(message.signals().len() <= dbc_rs::MAX_SIGNALS_PER_MESSAGE);
+            assert!(message.name().len() <= dbc_rs::MAX_NAME_SIZE);
+        }
+    }
+
+    #[test]
+    fn test_statistics_complete_dbc() {
+        let content =
+            read_to_string("tests/data/complete.dbc").expect("Failed to read complete.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse complete.dbc");
+
+        let stats = dbc.statistics();
+        assert_eq!(stats.messages, 4);
+        assert_eq!(stats.signals, 15);
+        assert_eq!(stats.max_signals_per_message, 4);
+        assert_eq!(stats.multiplexed_messages, 0);
+        assert_eq!(stats.extended_messages, 0);
+        assert_eq!(stats.unspecified_range_signals, 0);
+        assert_eq!(stats.bitrate, None);
+        // Cycle times 50, 100 and 20 ms, and the 100 ms default for SensorData:
+        // 135 bits per 8-byte frame, 115 bits per 6-byte frame
+        #[cfg(feature = "attributes")]
+        assert_eq!(
+            stats.bits_per_second,
+            Some(2700.0 + 1350.0 + 5750.0 + 1150.0)
+        );
+        assert!(dbc.statistics().to_string().contains("Messages                       4\n"));
+    }
+
+    #[test]
+    fn test_parse_duplicate_nodes() {
+        let content = read_to_string("tests/data/duplicate_nodes.dbc")
+            .expect("Failed to read duplicate_nodes.dbc");
+
+        match Dbc::parse(&content) {
+            Err(Error::Nodes { msg, line }) => {
+                assert_eq!(msg, Error::NODES_DUPLICATE_NAME);
+                assert_eq!(line, Some(9));
+            }
+            other => panic!("Expected Nodes error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_byte_order_mismatch() {
+        // Motorola signal declared with the Intel (LSB) start bit: 55 was intended
+        let content = read_to_string("tests/data/byte_order_mismatch.dbc")
+            .expect("Failed to read byte_order_mismatch.dbc");
+        match Dbc::parse(&content) {
+            Err(Error::SignalByteOrderMismatch {
+                message_id,
+                signal,
+                byte_order,
+                line,
+            }) => {
+                assert_eq!(message_id, 256);
+                assert_eq!(signal.as_ref(), "Speed");
+                assert_eq!(byte_order, ByteOrder::BigEndian);
+                assert_eq!(line, Some(6));
+            }
+            other => panic!("Expected SignalByteOrderMismatch error, got {:?}", other),
+        }
+
+        // Intel signal declared with the Motorola (MSB) start bit: 0 was intended
+        let content = read_to_string("tests/data/byte_order_mismatch_intel.dbc")
+            .expect("Failed to read byte_order_mismatch_intel.dbc");
+        match Dbc::parse(&content) {
+            Err(Error::SignalByteOrderMismatch {
+                signal,
+                byte_order,
+                line,
+                ..
+            }) => {
+                assert_eq!(signal.as_ref(), "RearRight");
+                assert_eq!(byte_order, ByteOrder::LittleEndian);
+                assert_eq!(line, Some(7));
+            }
+            other => panic!("Expected SignalByteOrderMismatch error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_duplicate_multiplexer_switch() {
+        let content = read_to_string("tests/data/duplicate_multiplexer_switch.dbc")
+            .expect("Failed to read duplicate_multiplexer_switch.dbc");
+
+        match Dbc::parse(&content) {
+            Err(Error::DuplicateMultiplexerSwitch {
+                message_id,
+                first,
+                second,
+            }) => {
+                assert_eq!(message_id, 300);
+                assert_eq!(first.as_ref(), "ServiceId");
+                assert_eq!(second.as_ref(), "SubFunction");
+            }
+            other => panic!("Expected DuplicateMultiplexerSwitch error, got {:?}", other),
+        }
+
+        // Accepted once SG_MUL_VAL_ nests SubFunction under ServiceId
+        let nested = format!(
+            "{content}\nSG_MUL_VAL_ 300 SubFunction ServiceId 16-16 ;\n\
+             SG_MUL_VAL_ 300 SessionType SubFunction 1-1 ;\n\
+             SG_MUL_VAL_ 300 ResetType SubFunction 2-2 ;\n"
+        );
+        let dbc = Dbc::parse(&nested).expect("Failed to parse nested multiplexing");
+        assert_eq!(dbc.extended_multiplexing().len(), 3);
+    }
+
+    #[cfg(feature = "attributes")]
+    #[test]
+    fn test_dlc_override_dbc() {
+        let content =
+            read_to_string("tests/data/dlc_override.dbc").expect("Failed to read dlc_override.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse dlc_override.dbc");
+
+        let engine = dbc.messages().find_by_id(256).unwrap();
+        assert_eq!(engine.dlc(), 8);
+        assert_eq!(engine.effective_dlc(), 3);
+        assert_eq!(engine.min_bytes_required(), 3);
+
+        // The attribute default does not override the declared DLC
+        let transmission = dbc.messages().find_by_id(512).unwrap();
+        assert_eq!(transmission.effective_dlc(), 8);
+
+        // A 3-byte frame decodes; Reserved lies beyond the transmitted length
+        let decoded = dbc.decode(256, &[0x40, 0x1F, 0x5A], false).unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].value, 2000.0);
+        assert_eq!(decoded[1].value, 50.0);
+
+        let decoded = dbc.decode(256, &[0x40, 0x1F, 0x5A, 0, 0, 0, 0xFF, 0xFF], false).unwrap();
+        assert!(decoded.iter().all(|s| s.name != "Reserved"));
+    }
+
+    #[test]
+    fn test_parse_trailing_comments_dbc() {
+        let content = read_to_string("tests/data/trailing_comments.dbc")
+            .expect("Failed to read trailing_comments.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse trailing_comments.dbc");
+
+        assert_eq!(dbc.messages().len(), 2);
+
+        let engine = dbc.messages().find_by_id(256).unwrap();
+        assert!(engine.signals().iter().map(|s| s.name()).eq([
+            "EngineSpeed",
+            "CoolantTemp",
+            "FlowRate",
+            "Status"
+        ]));
+        assert_eq!(engine.signals().at(1).unwrap().receivers().len(), 2);
+        // `//` inside a quoted unit is not a comment
+        assert_eq!(engine.signals().at(2).unwrap().unit(), Some("l//h"));
+
+        let transmission = dbc.messages().find_by_id(512).unwrap();
+        assert_eq!(transmission.sender(), "TCM");
+        assert_eq!(transmission.signals().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_bare_signals_dbc() {
+        let content =
+            read_to_string("tests/data/bare_signals.dbc").expect("Failed to read bare_signals.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse bare_signals.dbc");
+
+        let engine = dbc.messages().find_by_id(256).unwrap();
+        assert!(engine.signals().iter().map(|s| s.name()).eq([
+            "EngineSpeed",
+            "CoolantTemp",
+            "Load",
+            "Status"
+        ]));
+        for signal in engine.signals().iter() {
+            assert_eq!(signal.receivers(), &Receivers::None);
+        }
+        assert_eq!(engine.signals().at(0).unwrap().unit(), None);
+        assert_eq!(engine.signals().at(0).unwrap().max(), 16383.75);
+        assert_eq!(engine.signals().at(1).unwrap().unit(), Some("degC"));
+        assert_eq!(engine.signals().at(3).unwrap().unit(), None);
+
+        let transmission = dbc.messages().find_by_id(512).unwrap();
+        let gear = transmission.signals().find("Gear").unwrap();
+        assert_eq!(gear.unit(), None);
+        assert_eq!(gear.receivers(), &Receivers::None);
+    }
+
+    #[cfg(feature = "attributes")]
+    #[test]
+    fn test_signal_attributes_dbc() {
+        let content = read_to_string("tests/data/signal_attributes.dbc")
+            .expect("Failed to read signal_attributes.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse signal_attributes.dbc");
+
+        // Explicit BA_ ... SG_ assignments, including an extended message ID
+        let start_value = dbc.signal_attribute(256, "CoolantTemp", "GenSigStartValue");
+        assert_eq!(start_value.and_then(|v| v.as_float()), Some(40.0));
+        let send_type = dbc.signal_attribute(256, "CoolantTemp", "GenSigSendType");
+        assert_eq!(send_type.and_then(|v| v.as_int()), Some(1));
+        let gear = dbc.signal_attribute(2566844926, "Gear", "GenSigStartValue");
+        assert_eq!(gear.and_then(|v| v.as_float()), Some(1.0));
+
+        // Assignments are scoped to (message ID, signal name)
+        assert!(dbc.signal_attribute(256, "EngineSpeed", "GenSigStartValue").is_none());
+        assert!(dbc.signal_attribute(2566844926, "CoolantTemp", "GenSigStartValue").is_none());
+
+        // Unassigned signals fall back to BA_DEF_DEF_
+        let fallback = dbc.signal_attribute_or_default(256, "EngineSpeed", "GenSigStartValue");
+        assert_eq!(fallback.and_then(|v| v.as_float()), Some(0.0));
+        let assigned = dbc.signal_attribute_or_default(256, "CoolantTemp", "GenSigStartValue");
+        assert_eq!(assigned.and_then(|v| v.as_float()), Some(40.0));
+
+        // GenSigStartValue is linked back into the signals
+        let engine = dbc.messages().find_by_id(256).unwrap();
+        assert_eq!(
+            engine.signals().find("CoolantTemp").unwrap().start_value(),
+            Some(40)
+        );
+        assert_eq!(
+            engine.signals().find("EngineSpeed").unwrap().start_value(),
+            None
+        );
+        let transmission = dbc.messages().find_by_id(2566844926).unwrap();
+        assert_eq!(
+            transmission.signals().find("Gear").unwrap().start_value(),
+            Some(1)
+        );
+
+        let payload = dbc.initial_payload(256).unwrap();
+        assert_eq!(payload.as_slice(), &[0, 0, 40, 0, 0, 0, 0, 0]);
+        let payload = dbc.initial_payload(2566844926).unwrap();
+        assert_eq!(payload.as_slice(), &[1, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[cfg(feature = "attributes")]
+    #[test]
+    fn test_attributes_round_trip() {
+        use dbc_rs::{AttributeTarget, AttributeValue};
+
+        let content =
+            read_to_string("tests/data/attributes.dbc").expect("Failed to read attributes.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse attributes.dbc");
+
+        assert_eq!(dbc.attribute_definitions().len(), 7);
+        assert_eq!(dbc.attribute_defaults().len(), 7);
+        assert_eq!(dbc.attribute_values().len(), 8);
+
+        let serialized = dbc.to_string();
+        assert!(
+            serialized
+                .contains("BA_DEF_ BO_ \"GenMsgSendType\" ENUM \"Cyclic\",\"Event\",\"IfActive\";")
+        );
+        assert!(serialized.contains("BA_DEF_DEF_ \"GenSigStartValue\" 0.0;"));
+        assert!(serialized.contains("BA_ \"BusType\" \"CAN\";"));
+        assert!(serialized.contains("BA_ \"GenSigStartValue\" SG_ 256 CoolantTemp 40.0;"));
+
+        // Attributes follow the comments, definitions before defaults before values
+        let position = |keyword: &str| {
+            serialized
+                .find(keyword)
+                .unwrap_or_else(|| panic!("{keyword} missing from serialized output"))
+        };
+        assert!(position("CM_ ") < position("BA_DEF_ "));
+        assert!(position("BA_DEF_ ") < position("BA_DEF_DEF_ "));
+        assert!(position("BA_DEF_DEF_ ") < position("BA_ \""));
+
+        // Nothing is lost on re-parse, including value types
+        let reparsed = Dbc::parse(&serialized).expect("Failed to re-parse serialized DBC");
+        assert_eq!(
+            dbc.attribute_definitions(),
+            reparsed.attribute_definitions()
+        );
+        assert_eq!(dbc.attribute_defaults(), reparsed.attribute_defaults());
+        assert_eq!(dbc.attribute_values(), reparsed.attribute_values());
+        assert_eq!(
+            reparsed.attribute_values().get(
+                "GenSigStartValue",
+                &AttributeTarget::Signal(256, "CoolantTemp".try_into().unwrap())
+            ),
+            Some(&AttributeValue::Float(40.0))
+        );
+        assert_eq!(serialized, reparsed.to_string());
+    }
+
+    #[cfg(feature = "arxml")]
+    #[test]
+    fn test_to_arxml_golden() {
+        let content =
+            read_to_string("tests/data/arxml_export.dbc").expect("Failed to read arxml_export.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse arxml_export.dbc");
+        let expected = read_to_string("tests/data/arxml_export.arxml")
+            .expect("Failed to read arxml_export.arxml");
+
+        assert_eq!(dbc.to_arxml(), expected);
+    }
+
+    #[cfg(feature = "kcd")]
+    #[test]
+    fn test_to_kcd_golden() {
+        let content =
+            read_to_string("tests/data/kcd_export.dbc").expect("Failed to read kcd_export.dbc");
+        let dbc = Dbc::parse(&content).expect("Failed to parse kcd_export.dbc");
+        let expected =
+            read_to_string("tests/data/kcd_export.kcd").expect("Failed to read kcd_export.kcd");
+
+        assert_eq!(dbc.to_kcd(), expected);
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn test_parse_latin1_dbc() {
+        use dbc_rs::Encoding;
+
+        let bytes = std::fs::read("tests/data/latin1.dbc").expect("Failed to read latin1.dbc");
+
+        // Strict UTF-8 parsing rejects the umlauts
+        assert!(Dbc::parse_bytes(&bytes).is_err());
+
+        let dbc = Dbc::parse_bytes_with_encoding(&bytes, Encoding::Latin1)
+            .expect("Failed to parse latin1.dbc");
+        let message = dbc.messages().find_by_id(256).expect("Message 256 not found");
+        let signal = message.signals().find("Oeltemperatur").expect("Oeltemperatur not found");
+
+        assert_eq!(
+            signal.comment(),
+            Some("Öltemperatur im Ölwannenbereich gemäß Sensor")
+        );
+        assert_eq!(signal.unit(), Some("°C"));
+        assert_eq!(
+            dbc.node_comment("Motorsteuergeraet"),
+            Some("Steuergerät für den Motor")
+        );
+    }
 }